@@ -27,6 +27,9 @@
 //!
 //! Type aliases are provided for common byte orders in the [`big_endian`],
 //! [`little_endian`], [`network_endian`], and [`native_endian`] submodules.
+//! This module also defines suffixed aliases for each type and byte order -
+//! e.g., [`U32Be`], [`U32Le`], and [`U32Ne`] for [`U32`] in big-endian,
+//! little-endian, and native-endian byte order, respectively.
 //!
 //! # Example
 //!
@@ -75,6 +78,32 @@ use core::{
 
 use super::*;
 
+macro_rules! byte_order_fns {
+    ($($read:ident, $write:ident => $native:ident),*) => {
+        $(
+            #[doc = concat!("Reads a `", stringify!($native), "` from `bytes` in this byte order.")]
+            #[must_use = "has no side effects"]
+            #[inline(always)]
+            fn $read(bytes: &[u8; mem::size_of::<$native>()]) -> $native {
+                match Self::ORDER {
+                    Order::BigEndian => $native::from_be_bytes(*bytes),
+                    Order::LittleEndian => $native::from_le_bytes(*bytes),
+                }
+            }
+
+            /// Writes `n` to a byte array in this byte order.
+            #[must_use = "has no side effects"]
+            #[inline(always)]
+            fn $write(n: $native) -> [u8; mem::size_of::<$native>()] {
+                match Self::ORDER {
+                    Order::BigEndian => n.to_be_bytes(),
+                    Order::LittleEndian => n.to_le_bytes(),
+                }
+            }
+        )*
+    };
+}
+
 /// A type-level representation of byte order.
 ///
 /// This type is implemented by [`BigEndian`] and [`LittleEndian`], which
@@ -87,9 +116,41 @@ use super::*;
 /// big-endian byte order.
 ///
 /// [`U32<BigEndian>`]: U32
+///
+/// `ByteOrder` also provides associated functions to convert between native
+/// integers and byte arrays in the given byte order, without constructing a
+/// wrapper value. These mirror the functions of the same names in the
+/// [`byteorder`] crate:
+///
+/// ```
+/// use zerocopy::byteorder::{BigEndian, ByteOrder, LittleEndian};
+///
+/// assert_eq!(BigEndian::read_u32(&[0, 0, 1, 2]), 0x0102);
+/// assert_eq!(LittleEndian::write_u16(0x0102), [2, 1]);
+/// ```
+///
+/// [`byteorder`]: https://docs.rs/byteorder
 pub trait ByteOrder: Copy + Clone + Debug + Display + Eq + PartialEq + Ord + PartialOrd {
+    /// The opposite byte order.
+    ///
+    /// This is [`LittleEndian`] for [`BigEndian`], and vice versa.
+    type Opposite: ByteOrder<Opposite = Self>;
+
     #[doc(hidden)]
     const ORDER: Order;
+
+    byte_order_fns!(
+        read_u16, write_u16 => u16,
+        read_u32, write_u32 => u32,
+        read_u64, write_u64 => u64,
+        read_u128, write_u128 => u128,
+        read_usize, write_usize => usize,
+        read_i16, write_i16 => i16,
+        read_i32, write_i32 => i32,
+        read_i64, write_i64 => i64,
+        read_i128, write_i128 => i128,
+        read_isize, write_isize => isize
+    );
 }
 
 #[allow(missing_copy_implementations, missing_debug_implementations)]
@@ -106,6 +167,8 @@ pub enum Order {
 pub enum BigEndian {}
 
 impl ByteOrder for BigEndian {
+    type Opposite = LittleEndian;
+
     const ORDER: Order = Order::BigEndian;
 }
 
@@ -123,6 +186,8 @@ impl Display for BigEndian {
 pub enum LittleEndian {}
 
 impl ByteOrder for LittleEndian {
+    type Opposite = BigEndian;
+
     const ORDER: Order = Order::LittleEndian;
 }
 
@@ -158,6 +223,179 @@ pub type BE = BigEndian;
 /// A type alias for [`LittleEndian`].
 pub type LE = LittleEndian;
 
+/// A byte order which is only known at runtime.
+///
+/// Whereas [`ByteOrder`] selects a byte order at compile time, `RuntimeEndian`
+/// represents a byte order which is only discovered while parsing - for
+/// example, from a byte-order mark (see [`detect_endian`]).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum RuntimeEndian {
+    /// Big-endian byte order.
+    Big,
+    /// Little-endian byte order.
+    Little,
+}
+
+impl RuntimeEndian {
+    /// The endianness used by this platform.
+    #[cfg(target_endian = "big")]
+    pub const NATIVE: RuntimeEndian = RuntimeEndian::Big;
+
+    /// The endianness used by this platform.
+    #[cfg(target_endian = "little")]
+    pub const NATIVE: RuntimeEndian = RuntimeEndian::Little;
+
+    /// The runtime equivalent of the byte order `O`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::byteorder::{BigEndian, RuntimeEndian};
+    ///
+    /// assert_eq!(RuntimeEndian::of::<BigEndian>(), RuntimeEndian::Big);
+    /// ```
+    #[must_use]
+    #[inline(always)]
+    pub fn of<O: ByteOrder>() -> RuntimeEndian {
+        match O::ORDER {
+            Order::BigEndian => RuntimeEndian::Big,
+            Order::LittleEndian => RuntimeEndian::Little,
+        }
+    }
+}
+
+/// Detects the byte order indicated by a two-byte byte-order mark.
+///
+/// Many formats begin with a marker whose byte representation reveals the byte
+/// order of the rest of the data; for example, UTF-16's byte-order mark is
+/// `[0xFE, 0xFF]` in big-endian and `[0xFF, 0xFE]` in little-endian, and TIFF
+/// files begin with `b"MM"` (big-endian) or `b"II"` (little-endian).
+///
+/// `detect_endian` returns [`RuntimeEndian::Big`] if `marker` is equal to
+/// `big`, [`RuntimeEndian::Little`] if `marker` is equal to `little`, and
+/// `None` otherwise. If `big` and `little` are equal, `big` takes precedence.
+///
+/// # Examples
+///
+/// ```
+/// use zerocopy::byteorder::{detect_endian, RuntimeEndian};
+///
+/// assert_eq!(detect_endian(b"MM", *b"MM", *b"II"), Some(RuntimeEndian::Big));
+/// assert_eq!(detect_endian(b"II", *b"MM", *b"II"), Some(RuntimeEndian::Little));
+/// assert_eq!(detect_endian(b"XX", *b"MM", *b"II"), None);
+/// ```
+#[must_use]
+#[inline]
+pub fn detect_endian(marker: &[u8; 2], big: [u8; 2], little: [u8; 2]) -> Option<RuntimeEndian> {
+    if *marker == big {
+        Some(RuntimeEndian::Big)
+    } else if *marker == little {
+        Some(RuntimeEndian::Little)
+    } else {
+        None
+    }
+}
+
+/// A reader of integers whose byte order is only known at runtime.
+///
+/// `EndianReader` reads integers from the front of a byte slice, decoding each
+/// according to a [`RuntimeEndian`]. It is typically constructed with
+/// [`EndianReader::from_marker`], which first consumes a byte-order mark.
+///
+/// # Examples
+///
+/// ```
+/// use zerocopy::byteorder::{EndianReader, RuntimeEndian};
+///
+/// // A TIFF header: a byte-order mark, the magic number 42, and the offset
+/// // of the first image file directory.
+/// let bytes = [b'I', b'I', 42, 0, 8, 0, 0, 0];
+///
+/// let mut reader = EndianReader::from_marker(&bytes[..], *b"MM", *b"II").unwrap();
+/// assert_eq!(reader.endian(), RuntimeEndian::Little);
+/// assert_eq!(reader.read_u16(), Some(42));
+/// assert_eq!(reader.read_u32(), Some(8));
+/// assert_eq!(reader.read_u8(), None);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct EndianReader<'a> {
+    bytes: &'a [u8],
+    endian: RuntimeEndian,
+}
+
+macro_rules! endian_reader_read_fns {
+    ($($read:ident => $native:ident),*) => {
+        $(
+            #[doc = concat!("Reads a `", stringify!($native), "` from the front of the remaining bytes.")]
+            ///
+            /// Returns `None`, without consuming any bytes, if too few bytes
+            /// remain.
+            #[inline]
+            pub fn $read(&mut self) -> Option<$native> {
+                const SIZE: usize = mem::size_of::<$native>();
+                let (head, rest) = self.bytes.try_split_at(SIZE).ok()?;
+                let head: [u8; SIZE] = head.try_into().ok()?;
+                self.bytes = rest;
+                Some(match self.endian {
+                    RuntimeEndian::Big => $native::from_be_bytes(head),
+                    RuntimeEndian::Little => $native::from_le_bytes(head),
+                })
+            }
+        )*
+    };
+}
+
+impl<'a> EndianReader<'a> {
+    /// Constructs a reader which decodes `bytes` using `endian`.
+    #[must_use]
+    #[inline]
+    pub const fn new(bytes: &'a [u8], endian: RuntimeEndian) -> EndianReader<'a> {
+        EndianReader { bytes, endian }
+    }
+
+    /// Constructs a reader by consuming a two-byte byte-order mark.
+    ///
+    /// The first two bytes of `bytes` are passed to [`detect_endian`], and the
+    /// returned reader decodes the bytes which follow them using the detected
+    /// byte order. Returns `None` if `bytes` is shorter than two bytes or if
+    /// it does not begin with either `big` or `little`.
+    #[must_use]
+    #[inline]
+    pub fn from_marker(bytes: &'a [u8], big: [u8; 2], little: [u8; 2]) -> Option<EndianReader<'a>> {
+        let (marker, rest) = bytes.try_split_at(2).ok()?;
+        let marker: &[u8; 2] = marker.try_into().ok()?;
+        let endian = detect_endian(marker, big, little)?;
+        Some(EndianReader::new(rest, endian))
+    }
+
+    /// The byte order used to decode integers.
+    #[must_use]
+    #[inline]
+    pub const fn endian(&self) -> RuntimeEndian {
+        self.endian
+    }
+
+    /// The bytes which have not yet been read.
+    #[must_use]
+    #[inline]
+    pub const fn remaining(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    endian_reader_read_fns!(
+        read_u8 => u8,
+        read_u16 => u16,
+        read_u32 => u32,
+        read_u64 => u64,
+        read_u128 => u128,
+        read_i8 => i8,
+        read_i16 => i16,
+        read_i32 => i32,
+        read_i64 => i64,
+        read_i128 => i128
+    );
+}
+
 macro_rules! impl_fmt_trait {
     ($name:ident, $native:ident, $trait:ident) => {
         impl<O: ByteOrder> $trait for $name<O> {
@@ -311,6 +549,22 @@ macro_rules! doc_comment {
     };
 }
 
+// Documents the comparison impls emitted by `impl_ops_traits!`.
+macro_rules! comparison_doc {
+    ($name:ident, $native:ident, "floating point number") => {
+        concat! {"`", stringify!($name), "`'s [`PartialOrd`] impl compares the native values
+returned by [`get`] rather than the bytes in `O` byte order. Like `", stringify!($native), "`,
+`", stringify!($name), "` does not implement [`Ord`], since NaN is unordered with respect to
+every value. To sort a collection of `", stringify!($name), "`s, compare their native values
+using a total order such as [`", stringify!($native), "::total_cmp`]."}
+    };
+    ($name:ident, $native:ident, $number_kind:tt) => {
+        concat! {"`", stringify!($name), "`'s comparison impls ([`PartialOrd`] and [`Ord`]) compare
+the native values returned by [`get`] rather than the bytes in `O` byte order, so
+sorting a collection of `", stringify!($name), "`s yields numeric order."}
+    };
+}
+
 macro_rules! define_max_value_constant {
     ($name:ident, $bytes:expr, "unsigned integer") => {
         /// The maximum value.
@@ -371,9 +625,18 @@ the platform's native endianness.
 making it useful for parsing and serialization. See the module documentation for an
 example of how it can be used for parsing UDP packets.
 
+`", stringify!($name), "`'s formatting impls (such as [`Display`]) format the native
+value returned by [`get`], and honor the same format options (such as width and
+zero-padding) as the corresponding impls for `", stringify!($native), "`.
+
+", comparison_doc!($name, $native, $number_kind), "
+
 [`new`]: crate::byteorder::", stringify!($name), "::new
 [`get`]: crate::byteorder::", stringify!($name), "::get
 [`set`]: crate::byteorder::", stringify!($name), "::set
+[`Display`]: core::fmt::Display
+[`PartialOrd`]: core::cmp::PartialOrd
+[`Ord`]: core::cmp::Ord
 [`FromBytes`]: crate::FromBytes
 [`IntoBytes`]: crate::IntoBytes
 [`Unaligned`]: crate::Unaligned"),
@@ -391,12 +654,13 @@ example of how it can be used for parsing UDP packets.
             /// `$name<O>` is `repr(transparent)`, and so it has the same layout
             /// as its only non-zero field, which is a `u8` array. `u8` arrays
             /// are `Immutable`, `TryFromBytes`, `FromZeros`, `FromBytes`,
-            /// `IntoBytes`, and `Unaligned`.
+            /// `IntoBytes`, `IntoBytesWithPadding`, and `Unaligned`.
             impl_or_verify!(O => Immutable for $name<O>);
             impl_or_verify!(O => TryFromBytes for $name<O>);
             impl_or_verify!(O => FromZeros for $name<O>);
             impl_or_verify!(O => FromBytes for $name<O>);
             impl_or_verify!(O => IntoBytes for $name<O>);
+            unsafe_impl!(O => IntoBytesWithPadding for $name<O>);
             impl_or_verify!(O => Unaligned for $name<O>);
         }
 
@@ -473,6 +737,76 @@ example of how it can be used for parsing UDP packets.
             pub fn set(&mut self, n: $native) {
                 *self = Self::new(n);
             }
+
+            /// Converts the value to the opposite byte order, preserving its
+            /// value.
+            ///
+            /// The returned value stores the bytes of `self` in reverse order.
+            /// This is useful when bridging between formats with different
+            /// byte orders, such as converting a big-endian field to its
+            /// little-endian equivalent.
+            #[must_use = "has no side effects"]
+            #[inline(always)]
+            pub fn to_opposite_endian(self) -> $name<O::Opposite> {
+                let mut bytes = self.0;
+                bytes.reverse();
+                $name(bytes, PhantomData)
+            }
+
+            /// Converts an array of native values to an array of values with
+            /// endianness `O`.
+            ///
+            /// This is equivalent to calling [`new`] on each element, but
+            /// converts the whole array at once, which makes it convenient for
+            /// bulk serialization.
+            ///
+            /// [`new`]: Self::new
+            #[must_use = "has no side effects"]
+            #[inline]
+            pub fn to_endian_array<const N: usize>(native: [$native; N]) -> [$name<O>; N] {
+                native.map(Self::new)
+            }
+
+            /// Converts an array of values with endianness `O` to an array of
+            /// native values.
+            ///
+            /// This is the inverse of [`to_endian_array`], and is equivalent to
+            /// calling [`get`] on each element.
+            ///
+            /// [`to_endian_array`]: Self::to_endian_array
+            /// [`get`]: Self::get
+            #[must_use = "has no side effects"]
+            #[inline]
+            pub fn from_endian_array<const N: usize>(arr: [$name<O>; N]) -> [$native; N] {
+                arr.map(Self::get)
+            }
+        }
+
+        impl<B: ByteSlice, O: ByteOrder> Ref<B, $name<O>> {
+            /// Returns the referenced value as a primitive type, possibly
+            /// performing an endianness swap to guarantee that the return value
+            /// has the endianness of the native platform.
+            ///
+            /// This is equivalent to dereferencing `self` and then calling
+            #[doc = concat!("[`", stringify!($name), "::get`].")]
+            #[must_use = "has no side effects"]
+            #[inline(always)]
+            pub fn get(&self) -> $native {
+                self.deref().get()
+            }
+        }
+
+        impl<B: ByteSliceMut, O: ByteOrder> Ref<B, $name<O>> {
+            /// Updates the referenced value in place as a primitive type,
+            /// possibly performing an endianness swap to guarantee that the
+            /// stored value has the endianness `O`.
+            ///
+            /// This is equivalent to dereferencing `self` and then calling
+            #[doc = concat!("[`", stringify!($name), "::set`].")]
+            #[inline(always)]
+            pub fn set(&mut self, n: $native) {
+                self.deref_mut().set(n)
+            }
         }
 
         // The reasoning behind which traits to implement here is to only
@@ -584,6 +918,26 @@ example of how it can be used for parsing UDP packets.
                 f.debug_tuple(stringify!($name)).field(&self.get()).finish()
             }
         }
+
+        #[cfg(feature = "serde")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+        impl<O: ByteOrder> serde::Serialize for $name<O> {
+            /// Serializes the native value of `self`, not its raw bytes.
+            #[inline]
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serde::Serialize::serialize(&self.get(), serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+        impl<'de, O: ByteOrder> serde::Deserialize<'de> for $name<O> {
+            /// Deserializes a native value, and stores it in byte order `O`.
+            #[inline]
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<$name<O>, D::Error> {
+                <$native as serde::Deserialize<'de>>::deserialize(deserializer).map($name::new)
+            }
+        }
     };
 }
 
@@ -819,6 +1173,447 @@ define_type!(
     []
 );
 
+/// Atomic integers stored in a given byte order.
+///
+/// The types in this module are like the atomic integer types in
+/// [`core::sync::atomic`], except that the endianness of their memory layout is
+/// given by a [`ByteOrder`] type parameter. Unlike the other types in
+/// [`byteorder`](super), they are not [`Unaligned`]: like the
+/// native atomic types, their alignment is equal to their size.
+///
+/// # Examples
+///
+/// ```
+/// use core::sync::atomic::Ordering;
+/// use zerocopy::byteorder::{atomic::AtomicU32, BigEndian};
+///
+/// let head = AtomicU32::<BigEndian>::new(0);
+/// head.store(0x0102, Ordering::Release);
+/// assert_eq!(head.fetch_add(1, Ordering::AcqRel), 0x0102);
+/// assert_eq!(head.load(Ordering::Acquire), 0x0103);
+/// ```
+pub mod atomic {
+    use core::sync::atomic::Ordering;
+
+    use super::*;
+
+    macro_rules! define_atomic_type {
+        ($name:ident, $native:ident, $plain:ident, $bits:expr, $bits_str:literal) => {
+            doc_comment! {
+                concat!("A ", stringify!($bits), "-bit atomic integer stored in a given byte order.
+
+`", stringify!($name), "` is like [`core::sync::atomic::", stringify!($name), "`], except
+that the endianness of its memory layout is given by the type parameter `O`. This
+makes it suitable for atomically-updated fields with a fixed wire endianness, such
+as the indices of a lock-free ring buffer stored in shared memory.
+
+Values are converted between native and `O` byte order around each atomic
+operation. When `O` is the platform's native byte order, these conversions are
+no-ops. Otherwise, [`load`], [`store`], and [`swap`] perform a byte swap, which
+is cheap, but arithmetic operations such as [`fetch_add`] cannot be performed by
+the hardware on byte-swapped values. Instead, they are implemented as a
+compare-and-swap loop, which may retry under contention.
+
+Unlike [`", stringify!($plain), "`](crate::byteorder::", stringify!($plain), "), `", stringify!($name), "`
+is not [`Unaligned`]: like the native atomic types, it has alignment equal to its size.
+
+[`load`]: crate::byteorder::atomic::", stringify!($name), "::load
+[`store`]: crate::byteorder::atomic::", stringify!($name), "::store
+[`swap`]: crate::byteorder::atomic::", stringify!($name), "::swap
+[`fetch_add`]: crate::byteorder::atomic::", stringify!($name), "::fetch_add
+[`Unaligned`]: crate::Unaligned"),
+                #[cfg_attr(any(feature = "derive", test), derive(KnownLayout, FromBytes, IntoBytes))]
+                #[repr(transparent)]
+                pub struct $name<O>(core::sync::atomic::$name, PhantomData<O>);
+            }
+
+            #[cfg(not(any(feature = "derive", test)))]
+            impl_known_layout!(O => $name<O>);
+
+            safety_comment! {
+                /// SAFETY:
+                /// `$name<O>` is `repr(transparent)`, and so it has the same layout
+                /// as its only non-zero field, which is a native atomic integer.
+                /// Native atomic integers are `TryFromBytes`, `FromZeros`,
+                /// `FromBytes`, and `IntoBytes`.
+                impl_or_verify!(O => TryFromBytes for $name<O>);
+                impl_or_verify!(O => FromZeros for $name<O>);
+                impl_or_verify!(O => FromBytes for $name<O>);
+                impl_or_verify!(O => IntoBytes for $name<O>);
+            }
+
+            impl<O: ByteOrder> $name<O> {
+                /// Converts a native value to its representation in byte order `O`.
+                #[inline(always)]
+                fn to_repr(n: $native) -> $native {
+                    match O::ORDER {
+                        Order::BigEndian => n.to_be(),
+                        Order::LittleEndian => n.to_le(),
+                    }
+                }
+
+                /// Converts a representation in byte order `O` to a native value.
+                #[inline(always)]
+                fn from_repr(n: $native) -> $native {
+                    match O::ORDER {
+                        Order::BigEndian => $native::from_be(n),
+                        Order::LittleEndian => $native::from_le(n),
+                    }
+                }
+
+                /// Constructs a new value, possibly performing an endianness swap
+                /// to guarantee that the returned value has endianness `O`.
+                #[must_use = "has no side effects"]
+                #[inline(always)]
+                pub fn new(n: $native) -> $name<O> {
+                    $name(core::sync::atomic::$name::new(Self::to_repr(n)), PhantomData)
+                }
+
+                /// Consumes the atomic and returns the contained value in the
+                /// native endianness.
+                #[must_use = "has no side effects"]
+                #[inline(always)]
+                pub fn into_inner(self) -> $native {
+                    Self::from_repr(self.0.into_inner())
+                }
+
+                /// Loads the value in the native endianness.
+                ///
+                /// `load` takes an [`Ordering`] argument which describes the memory
+                /// ordering of this operation, as in
+                #[doc = concat!("[`", stringify!($name), "::load`](core::sync::atomic::", stringify!($name), "::load).")]
+                ///
+                /// # Panics
+                ///
+                /// Panics if `order` is [`Release`](Ordering::Release) or
+                /// [`AcqRel`](Ordering::AcqRel).
+                #[must_use = "has no side effects"]
+                #[inline(always)]
+                pub fn load(&self, order: Ordering) -> $native {
+                    Self::from_repr(self.0.load(order))
+                }
+
+                /// Stores a native value, converting it to endianness `O`.
+                ///
+                /// `store` takes an [`Ordering`] argument which describes the
+                /// memory ordering of this operation, as in
+                #[doc = concat!("[`", stringify!($name), "::store`](core::sync::atomic::", stringify!($name), "::store).")]
+                ///
+                /// # Panics
+                ///
+                /// Panics if `order` is [`Acquire`](Ordering::Acquire) or
+                /// [`AcqRel`](Ordering::AcqRel).
+                #[inline(always)]
+                pub fn store(&self, n: $native, order: Ordering) {
+                    self.0.store(Self::to_repr(n), order)
+                }
+
+                /// Stores a native value, returning the previous value.
+                ///
+                /// `swap` takes an [`Ordering`] argument which describes the memory
+                /// ordering of this operation, as in
+                #[doc = concat!("[`", stringify!($name), "::swap`](core::sync::atomic::", stringify!($name), "::swap).")]
+                ///
+                /// `swap` is only available on platforms which support atomic
+                /// compare-and-swap operations on
+                #[doc = concat!(stringify!($bits), "-bit")]
+                /// integers.
+                #[cfg(all(zerocopy_target_has_atomic, target_has_atomic = $bits_str))]
+                #[cfg_attr(doc_cfg, doc(cfg(target_has_atomic = $bits_str)))]
+                #[inline(always)]
+                pub fn swap(&self, n: $native, order: Ordering) -> $native {
+                    Self::from_repr(self.0.swap(Self::to_repr(n), order))
+                }
+
+                /// Adds to the current value, returning the previous value.
+                ///
+                /// The addition is performed on the native interpretation of the
+                /// value, and wraps around on overflow.
+                ///
+                /// `fetch_add` takes an [`Ordering`] argument which describes the
+                /// memory ordering of this operation, as in
+                #[doc = concat!("[`", stringify!($name), "::fetch_add`](core::sync::atomic::", stringify!($name), "::fetch_add).")]
+                ///
+                /// If `O` is the platform's native byte order, this is a single
+                /// atomic instruction. Otherwise, the addition cannot be performed
+                /// directly on the byte-swapped representation, and so it is
+                /// implemented as a compare-and-swap loop, which may retry under
+                /// contention.
+                ///
+                /// `fetch_add` is only available on platforms which support
+                /// atomic compare-and-swap operations on
+                #[doc = concat!(stringify!($bits), "-bit")]
+                /// integers.
+                #[cfg(all(zerocopy_target_has_atomic, target_has_atomic = $bits_str))]
+                #[cfg_attr(doc_cfg, doc(cfg(target_has_atomic = $bits_str)))]
+                #[inline]
+                pub fn fetch_add(&self, n: $native, order: Ordering) -> $native {
+                    if RuntimeEndian::of::<O>() == RuntimeEndian::NATIVE {
+                        return self.0.fetch_add(n, order);
+                    }
+
+                    // The failure ordering of a compare-and-swap may not contain a
+                    // release, and may not be stronger than the success ordering.
+                    let fetch_order = match order {
+                        Ordering::Release => Ordering::Relaxed,
+                        Ordering::AcqRel => Ordering::Acquire,
+                        order => order,
+                    };
+                    let mut prev = self.0.load(fetch_order);
+                    loop {
+                        let next = Self::to_repr(Self::from_repr(prev).wrapping_add(n));
+                        match self.0.compare_exchange_weak(prev, next, order, fetch_order) {
+                            Ok(_) => return Self::from_repr(prev),
+                            Err(actual) => prev = actual,
+                        }
+                    }
+                }
+            }
+
+            impl<O: ByteOrder> Default for $name<O> {
+                #[inline(always)]
+                fn default() -> $name<O> {
+                    $name(core::sync::atomic::$name::new(0), PhantomData)
+                }
+            }
+
+            impl<O: ByteOrder> From<$native> for $name<O> {
+                #[inline(always)]
+                fn from(n: $native) -> $name<O> {
+                    $name::new(n)
+                }
+            }
+
+            impl<O: ByteOrder> Debug for $name<O> {
+                #[inline]
+                fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                    // This results in a format like "AtomicU32(42)".
+                    f.debug_tuple(stringify!($name)).field(&self.load(Ordering::Relaxed)).finish()
+                }
+            }
+        };
+    }
+
+    define_atomic_type!(AtomicU16, u16, U16, 16, "16");
+    define_atomic_type!(AtomicU32, u32, U32, 32, "32");
+    define_atomic_type!(AtomicI16, i16, I16, 16, "16");
+    define_atomic_type!(AtomicI32, i32, I32, 32, "32");
+}
+
+/// Non-zero integers stored in a given byte order.
+///
+/// The types in this module are like the non-zero integer types in
+/// [`core::num`], except that they have no alignment requirement and the
+/// endianness of their memory layout is given by a [`ByteOrder`] type
+/// parameter. They are useful for fields in which zero is reserved to mean
+/// "absent".
+///
+/// Since zero is not a valid value, these types are [`TryFromBytes`] rather
+/// than [`FromBytes`]: parsing an all-zero byte sequence fails.
+///
+/// # Examples
+///
+/// ```
+/// use core::num::NonZeroU32 as CoreNonZeroU32;
+/// use zerocopy::{byteorder::{nonzero::NonZeroU32, BigEndian}, IntoBytes, TryFromBytes};
+///
+/// let n = NonZeroU32::<BigEndian>::try_read_from(&[0, 0, 1, 2][..]).unwrap();
+/// assert_eq!(n.get().get(), 0x0102);
+///
+/// let n = NonZeroU32::<BigEndian>::new(CoreNonZeroU32::new(0x0304).unwrap());
+/// assert_eq!(n.as_bytes(), [0, 0, 3, 4]);
+///
+/// assert!(NonZeroU32::<BigEndian>::try_read_from(&[0, 0, 0, 0][..]).is_err());
+/// ```
+///
+/// # Optional values
+///
+/// The non-zero integer types in [`core::num`] have a *niche*: the compiler
+/// knows that zero is never a valid value, and so represents `None` in
+/// `Option<core::num::NonZeroU32>` as zero. As a result,
+/// `Option<core::num::NonZeroU32>` has the same size and layout as `u32`, and
+/// zerocopy implements [`FromBytes`] for it.
+///
+/// The types in this module do not have a niche: they store their value as a
+/// byte array, and the compiler has no way of knowing that the all-zero array
+/// is invalid. `Option<NonZeroU32<O>>` is therefore larger than
+/// `NonZeroU32<O>`, its layout is unspecified, and it does not implement any of
+/// zerocopy's traits. Instead, store a value which may be zero as the
+/// corresponding type in the [`byteorder`] module, which is [`FromBytes`], and
+/// convert it to and from an `Option` using [`From`]:
+///
+/// ```
+/// use zerocopy::{byteorder::{nonzero::NonZeroU32, BigEndian, U32}, FromBytes};
+///
+/// let n = U32::<BigEndian>::read_from(&[0, 0, 1, 2][..]).unwrap();
+/// let n: Option<NonZeroU32<BigEndian>> = n.into();
+/// assert_eq!(n.unwrap().get().get(), 0x0102);
+///
+/// let n = U32::<BigEndian>::read_from(&[0, 0, 0, 0][..]).unwrap();
+/// assert!(Option::<NonZeroU32<BigEndian>>::from(n).is_none());
+/// assert_eq!(U32::from(None::<NonZeroU32<BigEndian>>), U32::ZERO);
+/// ```
+///
+/// [`byteorder`]: crate::byteorder
+/// [`FromBytes`]: crate::FromBytes
+pub mod nonzero {
+    use super::*;
+
+    macro_rules! define_nonzero_type {
+        ($name:ident, $plain:ident, $bits:expr, $bytes:expr) => {
+            doc_comment! {
+                concat!("A ", stringify!($bits), "-bit non-zero integer stored in a given byte order.
+
+`", stringify!($name), "` is like [`core::num::", stringify!($name), "`], except that
+it has no alignment requirement (its alignment is 1), and the endianness of its memory
+layout is given by the type parameter `O`. It can be constructed from a
+`core::num::", stringify!($name), "` using the [`new`] method, and converted back
+using the [`get`] method.
+
+`", stringify!($name), "` implements [`TryFromBytes`], which rejects the all-zero byte
+sequence, as well as [`IntoBytes`] and [`Unaligned`].
+
+[`new`]: crate::byteorder::nonzero::", stringify!($name), "::new
+[`get`]: crate::byteorder::nonzero::", stringify!($name), "::get
+[`TryFromBytes`]: crate::TryFromBytes
+[`IntoBytes`]: crate::IntoBytes
+[`Unaligned`]: crate::Unaligned"),
+                #[derive(Copy, Clone, Eq, PartialEq, Hash)]
+                #[cfg_attr(any(feature = "derive", test), derive(KnownLayout, Immutable, IntoBytes, Unaligned))]
+                #[repr(transparent)]
+                pub struct $name<O>([u8; $bytes], PhantomData<O>);
+            }
+
+            #[cfg(not(any(feature = "derive", test)))]
+            impl_known_layout!(O => $name<O>);
+
+            safety_comment! {
+                /// SAFETY:
+                /// `$name<O>` is `repr(transparent)`, and so it has the same layout
+                /// as its only non-zero field, which is a `u8` array. `u8` arrays
+                /// are `Immutable`, `IntoBytes`, `IntoBytesWithPadding`, and
+                /// `Unaligned`.
+                impl_or_verify!(O => Immutable for $name<O>);
+                impl_or_verify!(O => IntoBytes for $name<O>);
+                unsafe_impl!(O => IntoBytesWithPadding for $name<O>);
+                impl_or_verify!(O => Unaligned for $name<O>);
+                /// SAFETY:
+                /// - `$name<O>` has the same layout as `[u8; $bytes]`, which has
+                ///   no `UnsafeCell`s, so a `*mut $name<O>` and the same pointer
+                ///   cast to `*mut [u8; $bytes]` refer to objects of the same size
+                ///   with `UnsafeCell`s at the same (empty set of) byte ranges.
+                /// - `is_bit_valid` is only called on initialized bytes, and any
+                ///   initialized bytes are a valid `[u8; $bytes]`.
+                /// - A `[u8; $bytes]` is a valid `$name<O>` unless it represents
+                ///   zero, which in either byte order is the all-zero array. The
+                ///   closure only returns `true` if the array is not all zeros.
+                unsafe_impl!(O => TryFromBytes for $name<O>; |n: MaybeAligned<[u8; $bytes]>| {
+                    n.read_unaligned() != [0u8; $bytes]
+                });
+            }
+
+            impl<O: ByteOrder> $name<O> {
+                /// Constructs a new value, possibly performing an endianness swap
+                /// to guarantee that the returned value has endianness `O`.
+                #[must_use = "has no side effects"]
+                #[inline(always)]
+                pub fn new(n: core::num::$name) -> $name<O> {
+                    $name($plain::<O>::new(n.get()).to_bytes(), PhantomData)
+                }
+
+                #[doc = concat!("Returns the value as a `core::num::", stringify!($name), "`,")]
+                /// possibly performing an endianness swap to guarantee that the
+                /// return value has the endianness of the native platform.
+                #[must_use = "has no side effects"]
+                #[inline(always)]
+                pub fn get(self) -> core::num::$name {
+                    let n = $plain::<O>::from_bytes(self.0).get();
+                    // SAFETY: `self` can only be constructed by `new`, which
+                    // stores a non-zero value, or by `TryFromBytes`, which
+                    // rejects the all-zero byte sequence (which is the only
+                    // representation of zero in either byte order). `$name<O>`
+                    // does not implement `FromZeros` or `FromBytes`, so its
+                    // bytes cannot be overwritten with zeros in safe code.
+                    unsafe { core::num::$name::new_unchecked(n) }
+                }
+
+                /// Extracts the bytes of `self` without swapping the byte order.
+                ///
+                /// The returned bytes will be in `O` byte order.
+                #[must_use = "has no side effects"]
+                #[inline(always)]
+                pub const fn to_bytes(self) -> [u8; $bytes] {
+                    self.0
+                }
+            }
+
+            impl<O: ByteOrder> From<core::num::$name> for $name<O> {
+                #[inline(always)]
+                fn from(n: core::num::$name) -> $name<O> {
+                    $name::new(n)
+                }
+            }
+
+            impl<O: ByteOrder> From<$name<O>> for core::num::$name {
+                #[inline(always)]
+                fn from(n: $name<O>) -> core::num::$name {
+                    n.get()
+                }
+            }
+
+            impl<O: ByteOrder> From<$name<O>> for $plain<O> {
+                #[inline(always)]
+                fn from(n: $name<O>) -> $plain<O> {
+                    $plain::from_bytes(n.0)
+                }
+            }
+
+            /// Returns `None` if `n` is zero, and `Some` otherwise.
+            impl<O: ByteOrder> From<$plain<O>> for Option<$name<O>> {
+                #[inline(always)]
+                fn from(n: $plain<O>) -> Option<$name<O>> {
+                    let bytes = n.to_bytes();
+                    // The all-zero array is the only representation of zero
+                    // in either byte order.
+                    if bytes == [0u8; $bytes] {
+                        None
+                    } else {
+                        Some($name(bytes, PhantomData))
+                    }
+                }
+            }
+
+            /// Converts `None` to zero.
+            impl<O: ByteOrder> From<Option<$name<O>>> for $plain<O> {
+                #[inline(always)]
+                fn from(n: Option<$name<O>>) -> $plain<O> {
+                    match n {
+                        Some(n) => n.into(),
+                        None => $plain::ZERO,
+                    }
+                }
+            }
+
+            impl<O: ByteOrder> Debug for $name<O> {
+                #[inline]
+                fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                    // This results in a format like "NonZeroU32(42)".
+                    f.debug_tuple(stringify!($name)).field(&self.get()).finish()
+                }
+            }
+        };
+    }
+
+    define_nonzero_type!(NonZeroU16, U16, 16, 2);
+    define_nonzero_type!(NonZeroU32, U32, 32, 4);
+    define_nonzero_type!(NonZeroU64, U64, 64, 8);
+    define_nonzero_type!(NonZeroU128, U128, 128, 16);
+    define_nonzero_type!(NonZeroI16, I16, 16, 2);
+    define_nonzero_type!(NonZeroI32, I32, 32, 4);
+    define_nonzero_type!(NonZeroI64, I64, 64, 8);
+    define_nonzero_type!(NonZeroI128, I128, 128, 16);
+}
+
 macro_rules! module {
     ($name:ident, $trait:ident, $endianness_str:expr) => {
         /// Numeric primitives stored in
@@ -854,6 +1649,36 @@ module!(little_endian, LittleEndian, "little-endian");
 module!(network_endian, NetworkEndian, "network-endian");
 module!(native_endian, NativeEndian, "native-endian");
 
+macro_rules! define_aliases {
+    ($($ty:ident => $be:ident, $le:ident, $ne:ident;)*) => {
+        $(
+            #[doc = concat!("A [`", stringify!($ty), "`] stored in big-endian byte order.")]
+            pub type $be = $ty<BigEndian>;
+            #[doc = concat!("A [`", stringify!($ty), "`] stored in little-endian byte order.")]
+            pub type $le = $ty<LittleEndian>;
+            #[doc = concat!("A [`", stringify!($ty), "`] stored in native-endian byte order.")]
+            ///
+            /// This has the same byte layout as the corresponding native type.
+            pub type $ne = $ty<NativeEndian>;
+        )*
+    };
+}
+
+define_aliases! {
+    U16 => U16Be, U16Le, U16Ne;
+    U32 => U32Be, U32Le, U32Ne;
+    U64 => U64Be, U64Le, U64Ne;
+    U128 => U128Be, U128Le, U128Ne;
+    Usize => UsizeBe, UsizeLe, UsizeNe;
+    I16 => I16Be, I16Le, I16Ne;
+    I32 => I32Be, I32Le, I32Ne;
+    I64 => I64Be, I64Le, I64Ne;
+    I128 => I128Be, I128Le, I128Ne;
+    Isize => IsizeBe, IsizeLe, IsizeNe;
+    F32 => F32Be, F32Le, F32Ne;
+    F64 => F64Be, F64Le, F64Ne;
+}
+
 #[cfg(any(test, kani))]
 mod tests {
     use super::*;
@@ -957,6 +1782,7 @@ mod tests {
     trait ByteOrderType: FromBytes + IntoBytes + Unaligned + Copy + Eq + Debug {
         type Native: Native;
         type ByteArray: ByteArray;
+        type Opposite: ByteOrderType<Native = Self::Native, ByteArray = Self::ByteArray>;
 
         const ZERO: Self;
 
@@ -965,6 +1791,9 @@ mod tests {
         fn set(&mut self, native: Self::Native);
         fn from_bytes(bytes: Self::ByteArray) -> Self;
         fn into_bytes(self) -> Self::ByteArray;
+        fn to_opposite_endian(self) -> Self::Opposite;
+        fn to_endian_array<const N: usize>(native: [Self::Native; N]) -> [Self; N];
+        fn from_endian_array<const N: usize>(arr: [Self; N]) -> [Self::Native; N];
 
         /// For `f32` and `f64`, NaN values are not considered equal to
         /// themselves. This method is like `assert_eq!`, but it treats NaN
@@ -1025,6 +1854,7 @@ mod tests {
             impl<O: ByteOrder> ByteOrderType for $name<O> {
                 type Native = $native;
                 type ByteArray = [u8; mem::size_of::<$native>()];
+                type Opposite = $name<O::Opposite>;
 
                 const ZERO: $name<O> = $name::ZERO;
 
@@ -1047,6 +1877,18 @@ mod tests {
                 fn into_bytes(self) -> [u8; mem::size_of::<$native>()] {
                     <[u8; mem::size_of::<$native>()]>::from(self)
                 }
+
+                fn to_opposite_endian(self) -> $name<O::Opposite> {
+                    $name::to_opposite_endian(self)
+                }
+
+                fn to_endian_array<const N: usize>(native: [$native; N]) -> [$name<O>; N] {
+                    $name::to_endian_array(native)
+                }
+
+                fn from_endian_array<const N: usize>(arr: [$name<O>; N]) -> [$native; N] {
+                    $name::from_endian_array(arr)
+                }
             }
 
             impl_byte_order_type_unsigned!($name, $sign);
@@ -1230,6 +2072,106 @@ mod tests {
         call_for_all_types!(test_non_native, NonNativeEndian);
     }
 
+    #[cfg_attr(test, test)]
+    #[cfg_attr(kani, kani::proof)]
+    fn test_to_opposite_endian() {
+        fn test<T: ByteOrderType>() {
+            let mut r = SmallRng::seed_from_u64(RNG_SEED);
+            for _ in 0..RAND_ITERS {
+                let native = T::Native::rand(&mut r);
+                let t = T::new(native);
+                let opposite = t.to_opposite_endian();
+
+                // The value is preserved...
+                opposite.get().assert_eq_or_nan(native);
+                // ...while the stored bytes are reversed.
+                assert_eq!(opposite.into_bytes(), t.into_bytes().invert());
+                // Converting back yields the original bytes.
+                assert_eq!(opposite.to_opposite_endian().into_bytes(), t.into_bytes());
+            }
+        }
+
+        call_for_all_types!(test, NativeEndian);
+        call_for_all_types!(test, NonNativeEndian);
+    }
+
+    #[cfg_attr(test, test)]
+    #[cfg_attr(kani, kani::proof)]
+    fn test_endian_array() {
+        fn test<T: ByteOrderType>() {
+            // An empty array converts to an empty array.
+            let empty: [T; 0] = T::to_endian_array([]);
+            assert_eq!(T::from_endian_array(empty).len(), 0);
+
+            let mut r = SmallRng::seed_from_u64(RNG_SEED);
+            for _ in 0..RAND_ITERS {
+                let native = [(); 4].map(|()| T::Native::rand(&mut r));
+                let arr = T::to_endian_array(native);
+                for (t, n) in arr.iter().zip(native.iter()) {
+                    t.assert_eq_or_nan(T::new(*n));
+                }
+                for (n, m) in T::from_endian_array(arr).iter().zip(native.iter()) {
+                    n.assert_eq_or_nan(*m);
+                }
+            }
+        }
+
+        call_for_all_types!(test, NativeEndian);
+        call_for_all_types!(test, NonNativeEndian);
+    }
+
+    #[test]
+    fn test_byte_order_fns() {
+        let mut r = SmallRng::seed_from_u64(RNG_SEED);
+        for _ in 0..RAND_ITERS {
+            let n = u32::rand(&mut r);
+            assert_eq!(BigEndian::read_u32(&n.to_be_bytes()), n);
+            assert_eq!(BigEndian::write_u32(n), n.to_be_bytes());
+            assert_eq!(LittleEndian::read_u32(&n.to_le_bytes()), n);
+            assert_eq!(LittleEndian::write_u32(n), n.to_le_bytes());
+            assert_eq!(NativeEndian::write_u32(n), n.to_ne_bytes());
+
+            let n = i128::rand(&mut r);
+            assert_eq!(BigEndian::read_i128(&BigEndian::write_i128(n)), n);
+            assert_eq!(LittleEndian::read_i128(&LittleEndian::write_i128(n)), n);
+            assert_eq!(BigEndian::write_i128(n), n.to_be_bytes());
+        }
+
+        // The functions agree with the wrapper types.
+        assert_eq!(BigEndian::write_u16(0x0102), U16::<BigEndian>::new(0x0102).to_bytes());
+        assert_eq!(LittleEndian::read_u64(&[1, 0, 0, 0, 0, 0, 0, 0]), 1);
+        assert_eq!(BigEndian::read_isize(&BigEndian::write_isize(-1)), -1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
+
+        let x = U16::<LittleEndian>::from(0x0102);
+        assert_tokens(&x, &[Token::U16(258)]);
+        assert_eq!(x.to_bytes(), [2, 1]);
+
+        // The serialized form does not depend on the byte order.
+        let x = U16::<BigEndian>::from(0x0102);
+        assert_tokens(&x, &[Token::U16(258)]);
+        assert_eq!(x.to_bytes(), [1, 2]);
+
+        assert_tokens(&I64::<BigEndian>::new(-1), &[Token::I64(-1)]);
+        assert_tokens(&F32::<LittleEndian>::new(1.5), &[Token::F32(1.5)]);
+        assert_de_tokens(&U32::<BigEndian>::new(258), &[Token::U64(258)]);
+
+        // Out-of-range values are rejected.
+        assert_de_tokens_error::<U16<BigEndian>>(
+            &[Token::U32(65536)],
+            "invalid value: integer `65536`, expected u16",
+        );
+        assert_de_tokens_error::<U32<BigEndian>>(
+            &[Token::I64(-1)],
+            "invalid value: integer `-1`, expected u32",
+        );
+    }
+
     #[test]
     fn test_ops_impls() {
         // Test implementations of traits in `core::ops`. Some of these are
@@ -1338,6 +2280,132 @@ mod tests {
         test!(@unary Neg, neg, call_for_signed_types, call_for_float_types);
     }
 
+    #[test]
+    fn test_ord_impls() {
+        // Test that comparisons are by native value rather than by byte
+        // order. In particular, sorting should agree with sorting the
+        // equivalent native values.
+
+        fn test<T: ByteOrderType + Ord>()
+        where
+            T::Native: Ord,
+        {
+            let mut r = SmallRng::seed_from_u64(RNG_SEED);
+            let mut natives: Vec<T::Native> =
+                (0..RAND_ITERS.min(64)).map(|_| T::Native::rand(&mut r)).collect();
+            let mut ts: Vec<T> = natives.iter().map(|&n| T::new(n)).collect();
+
+            for (&n0, &n1) in natives.iter().zip(natives.iter().skip(1)) {
+                assert_eq!(T::new(n0).cmp(&T::new(n1)), n0.cmp(&n1));
+                assert_eq!(T::new(n0).partial_cmp(&T::new(n1)), n0.partial_cmp(&n1));
+            }
+
+            natives.sort();
+            ts.sort();
+            assert_eq!(ts.into_iter().map(T::get).collect::<Vec<_>>(), natives);
+        }
+
+        call_for_unsigned_types!(test, NativeEndian);
+        call_for_unsigned_types!(test, NonNativeEndian);
+        call_for_signed_types!(test, NativeEndian);
+        call_for_signed_types!(test, NonNativeEndian);
+
+        // `0x0100` is greater than `0x00FF` even though, in little-endian, its
+        // first byte is smaller.
+        let mut v = [U16::<LittleEndian>::new(0x0100), U16::new(0x00FF), U16::new(1)];
+        v.sort();
+        assert_eq!(v.map(U16::get), [1, 0x00FF, 0x0100]);
+    }
+
+    #[test]
+    fn test_bitwise_ops_stored_bytes() {
+        // Bitwise operators act on the native value, and their results are
+        // stored in the same byte order as their operands.
+        let x = U16::<BigEndian>::from(0xFF00) | U16::from(0x00FF);
+        assert_eq!(x, U16::from(0xFFFF));
+        assert_eq!(x.as_bytes(), [0xFF, 0xFF]);
+
+        let flags = U32::<BigEndian>::from(0x8000_0001);
+        assert_eq!((flags & U32::from(0x0000_FFFF)).as_bytes(), [0, 0, 0, 1]);
+        assert_eq!((flags ^ U32::from(0x8000_0000)).as_bytes(), [0, 0, 0, 1]);
+        assert_eq!((!flags).as_bytes(), [0x7F, 0xFF, 0xFF, 0xFE]);
+        assert_eq!((flags << U32::from(4)).as_bytes(), [0, 0, 0, 0x10]);
+        assert_eq!((flags >> U32::from(31)).as_bytes(), [0, 0, 0, 1]);
+
+        let mut flags = U32::<LittleEndian>::from(0);
+        flags |= U32::from(0b1010);
+        assert_eq!(flags.as_bytes(), [0b1010, 0, 0, 0]);
+        flags &= U32::from(0b0010);
+        assert_eq!(flags.as_bytes(), [0b0010, 0, 0, 0]);
+        flags ^= U32::from(0x0100_0002);
+        assert_eq!(flags.as_bytes(), [0, 0, 0, 1]);
+        flags <<= U32::from(7);
+        assert_eq!(flags.as_bytes(), [0, 0, 0, 0x80]);
+        flags >>= U32::from(31);
+        assert_eq!(flags.get(), 1);
+    }
+
+    #[test]
+    fn test_runtime_endian() {
+        assert_eq!(RuntimeEndian::of::<BigEndian>(), RuntimeEndian::Big);
+        assert_eq!(RuntimeEndian::of::<LittleEndian>(), RuntimeEndian::Little);
+        assert_eq!(RuntimeEndian::of::<NativeEndian>(), RuntimeEndian::NATIVE);
+        assert_ne!(RuntimeEndian::of::<NonNativeEndian>(), RuntimeEndian::NATIVE);
+    }
+
+    #[test]
+    fn test_aliases() {
+        assert_eq!(U32Ne::from(5).as_bytes(), 5u32.to_ne_bytes());
+        assert_eq!(U32Be::from(5).as_bytes(), 5u32.to_be_bytes());
+        assert_eq!(U32Le::from(5).as_bytes(), 5u32.to_le_bytes());
+        assert_eq!(I64Ne::from(-2).as_bytes(), (-2i64).to_ne_bytes());
+        assert_eq!(UsizeNe::from(7).as_bytes(), 7usize.to_ne_bytes());
+        assert_eq!(F64Ne::from(1.5).as_bytes(), 1.5f64.to_ne_bytes());
+
+        // The native-endian aliases have the same layout as the native types,
+        // other than alignment.
+        assert_eq!(mem::size_of::<U128Ne>(), mem::size_of::<u128>());
+        assert_eq!(U16Ne::read_from(&0x0102u16.to_ne_bytes()[..]).unwrap().get(), 0x0102);
+    }
+
+    #[test]
+    fn test_detect_endian() {
+        const BIG: [u8; 2] = [0xFE, 0xFF];
+        const LITTLE: [u8; 2] = [0xFF, 0xFE];
+        assert_eq!(detect_endian(&[0xFE, 0xFF], BIG, LITTLE), Some(RuntimeEndian::Big));
+        assert_eq!(detect_endian(&[0xFF, 0xFE], BIG, LITTLE), Some(RuntimeEndian::Little));
+        assert_eq!(detect_endian(&[0xFE, 0xFE], BIG, LITTLE), None);
+        assert_eq!(detect_endian(&[0, 0], [0, 0], [0, 0]), Some(RuntimeEndian::Big));
+    }
+
+    #[test]
+    fn test_endian_reader() {
+        let bytes = [b'M', b'M', 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+        let mut reader = EndianReader::from_marker(&bytes[..], *b"MM", *b"II").unwrap();
+        assert_eq!(reader.endian(), RuntimeEndian::Big);
+        assert_eq!(reader.read_u16(), Some(0x0102));
+        assert_eq!(reader.read_i32(), Some(0x0304_0506));
+        // Too few bytes remain, so nothing is consumed.
+        assert_eq!(reader.read_u16(), None);
+        assert_eq!(reader.remaining(), [0x07]);
+        assert_eq!(reader.read_u8(), Some(0x07));
+        assert!(reader.remaining().is_empty());
+
+        let bytes =
+            [b'I', b'I', 0x01, 0x02, 0x03, 0x04, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+        let mut reader = EndianReader::from_marker(&bytes[..], *b"MM", *b"II").unwrap();
+        assert_eq!(reader.endian(), RuntimeEndian::Little);
+        assert_eq!(reader.read_u32(), Some(0x0403_0201));
+        assert_eq!(reader.read_i64(), Some(-1));
+        assert_eq!(reader.read_u8(), None);
+
+        assert_eq!(EndianReader::from_marker(&b"XX"[..], *b"MM", *b"II"), None);
+        assert_eq!(EndianReader::from_marker(&b"M"[..], *b"MM", *b"II"), None);
+
+        let mut reader = EndianReader::new(&[0x01, 0x02][..], RuntimeEndian::NATIVE);
+        assert_eq!(reader.read_u16(), Some(u16::from_ne_bytes([0x01, 0x02])));
+    }
+
     #[test]
     fn test_debug_impl() {
         // Ensure that Debug applies format options to the inner value.
@@ -1346,4 +2414,16 @@ mod tests {
         assert_eq!(format!("{:03?}", val), "U16(010)");
         assert_eq!(format!("{:x?}", val), "U16(a)");
     }
+
+    #[test]
+    fn test_display_impl() {
+        // `Display` formats the native value, honoring format options.
+        assert_eq!(format!("{}", U16::<BigEndian>::from(7)), "7");
+        assert_eq!(format!("{:04}", U16::<BigEndian>::from(7)), "0007");
+        assert_eq!(format!("{:>5}", U32::<LittleEndian>::from(42)), "   42");
+        assert_eq!(format!("{:<4}|", I16::<BigEndian>::from(-3)), "-3  |");
+        assert_eq!(format!("{:+}", I64::<NetworkEndian>::from(9)), "+9");
+        assert_eq!(format!("{:.2}", F32::<LittleEndian>::from(1.5)), "1.50");
+        assert_eq!(format!("{:#06x}", U16::<BigEndian>::from(0xAB)), "0x00ab");
+    }
 }
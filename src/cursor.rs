@@ -0,0 +1,190 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+use super::*;
+
+/// A cursor which writes into a fixed-size byte slice.
+///
+/// A `Cursor` wraps a mutable byte slice, `B`, and tracks how many bytes have
+/// been written to it so far. Each write copies into the unwritten suffix of
+/// the slice and advances the cursor's [`position`]. `Cursor` implements
+/// [`core::fmt::Write`] and, when the `std` feature is enabled,
+/// [`std::io::Write`], so it can be used to format directly into a stack
+/// buffer which is later reinterpreted as a structured type.
+///
+/// Writes never grow the underlying slice. Once the slice is full, writes fail
+/// rather than silently truncating.
+///
+/// # Examples
+///
+/// ```
+/// use core::fmt::Write as _;
+/// use zerocopy::Cursor;
+///
+/// let mut buf = [0u8; 16];
+/// let mut cursor = Cursor::new(&mut buf[..]);
+/// write!(cursor, "id={}", 42).unwrap();
+///
+/// assert_eq!(cursor.position(), 5);
+/// assert_eq!(cursor.remaining(), 11);
+/// assert_eq!(&buf[..5], b"id=42");
+/// ```
+///
+/// [`position`]: Cursor::position
+#[derive(Debug)]
+pub struct Cursor<B> {
+    bytes: B,
+    pos: usize,
+}
+
+impl<B: ByteSliceMut> Cursor<B> {
+    /// Constructs a new `Cursor` which writes to the beginning of `bytes`.
+    #[must_use = "has no side effects"]
+    #[inline]
+    pub fn new(bytes: B) -> Cursor<B> {
+        Cursor { bytes, pos: 0 }
+    }
+
+    /// Returns the number of bytes which have been written so far.
+    #[must_use = "has no side effects"]
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the number of bytes which can still be written before the
+    /// underlying slice is full.
+    #[must_use = "has no side effects"]
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        // `pos` never exceeds `bytes.len()`, so this can't underflow.
+        #[allow(clippy::arithmetic_side_effects)]
+        let remaining = self.bytes.len() - self.pos;
+        remaining
+    }
+
+    /// Consumes the `Cursor`, returning the underlying byte slice.
+    ///
+    /// The returned slice is the entire slice passed to [`Cursor::new`], not
+    /// just the portion which has been written.
+    #[must_use = "has no side effects"]
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.bytes
+    }
+
+    /// Copies as many bytes as fit from `src` into the unwritten suffix,
+    /// returning the number of bytes copied.
+    #[inline]
+    fn write_partial(&mut self, src: &[u8]) -> usize {
+        let unwritten = self.bytes.get_mut(self.pos..).unwrap_or_default();
+        let n = core::cmp::min(src.len(), unwritten.len());
+        let (dst, _) = unwritten.split_at_mut(n);
+        let (src, _) = src.split_at(n);
+        dst.copy_from_slice(src);
+        // `n` is at most the number of unwritten bytes, so `pos` can't exceed
+        // `bytes.len()`, and thus can't overflow.
+        #[allow(clippy::arithmetic_side_effects)]
+        {
+            self.pos += n;
+        }
+        n
+    }
+}
+
+impl<B: ByteSliceMut> fmt::Write for Cursor<B> {
+    /// Writes `s` to the cursor.
+    ///
+    /// If `s` does not fit in the remaining space, nothing is written and an
+    /// error is returned.
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if s.len() > self.remaining() {
+            return Err(fmt::Error);
+        }
+        let _ = self.write_partial(s.as_bytes());
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl<B: ByteSliceMut> std::io::Write for Cursor<B> {
+    /// Writes as much of `buf` as fits in the remaining space.
+    ///
+    /// If the cursor is full and `buf` is non-empty, this returns an error of
+    /// kind [`WriteZero`](std::io::ErrorKind::WriteZero).
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if !buf.is_empty() && self.remaining() == 0 {
+            return Err(std::io::ErrorKind::WriteZero.into());
+        }
+        Ok(self.write_partial(buf))
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::Write as _;
+
+    use super::*;
+
+    #[test]
+    fn test_fmt_write() {
+        let mut buf = [0xFFu8; 16];
+        let mut cursor = Cursor::new(&mut buf[..]);
+        write!(cursor, "{}", 1234).unwrap();
+        assert_eq!(cursor.position(), 4);
+        assert_eq!(cursor.remaining(), 12);
+
+        write!(cursor, "-{}", 171u8).unwrap();
+        assert_eq!(cursor.position(), 8);
+
+        let bytes = cursor.into_inner();
+        assert_eq!(&bytes[..8], b"1234-171");
+        assert!(bytes[8..].iter().all(|&b| b == 0xFF));
+    }
+
+    #[test]
+    fn test_fmt_write_full() {
+        let mut buf = [0u8; 4];
+        let mut cursor = Cursor::new(&mut buf[..]);
+        write!(cursor, "{}", 12).unwrap();
+
+        // A write which doesn't fit is rejected without writing anything.
+        assert!(write!(cursor, "{}", 345).is_err());
+        assert_eq!(cursor.position(), 2);
+
+        write!(cursor, "{}", 34).unwrap();
+        assert_eq!(cursor.remaining(), 0);
+        assert!(write!(cursor, "{}", 5).is_err());
+        assert_eq!(&buf, b"1234");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_io_write() {
+        use std::io::{ErrorKind, Write as _};
+
+        let mut buf = [0u8; 6];
+        let mut cursor = Cursor::new(&mut buf[..]);
+        assert_eq!(cursor.write(b"abcd").unwrap(), 4);
+        assert_eq!(cursor.write(b"efgh").unwrap(), 2);
+        assert_eq!(cursor.write(b"").unwrap(), 0);
+        assert_eq!(cursor.write(b"i").unwrap_err().kind(), ErrorKind::WriteZero);
+        assert_eq!(cursor.write_all(b"j").unwrap_err().kind(), ErrorKind::WriteZero);
+        cursor.flush().unwrap();
+        assert_eq!(cursor.position(), 6);
+        assert_eq!(&buf, b"abcdef");
+    }
+}
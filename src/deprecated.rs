@@ -69,7 +69,7 @@ where
     B: SplitByteSlice,
     T: Immutable,
 {
-    #[deprecated(since = "0.8.0", note = "replaced by `Ref::with_trailing_elements_from_prefix`")]
+    #[deprecated(since = "0.8.0", note = "replaced by `Ref::from_prefix_with_elems`")]
     #[must_use = "has no side effects"]
     #[doc(hidden)]
     #[inline]
@@ -77,7 +77,7 @@ where
         Ref::from_prefix_with_elems(bytes, count).ok()
     }
 
-    #[deprecated(since = "0.8.0", note = "replaced by `Ref::with_trailing_elements_from_suffix`")]
+    #[deprecated(since = "0.8.0", note = "replaced by `Ref::from_suffix_with_elems`")]
     #[must_use = "has no side effects"]
     #[doc(hidden)]
     #[inline]
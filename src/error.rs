@@ -18,16 +18,50 @@
 //! - [`TryCastError`]: the error type of fallible reference conversions
 //! - [`TryReadError`]: the error type of fallible read conversions
 //!
+//! ## Tagged parsing errors
+//!
+//! [`parse_tagged!`] parses a header followed by one of several body types, and
+//! returns [`TaggedError`] if parsing either of them fails, or if the header's
+//! tag does not select any body type. If the body could not be parsed, the
+//! error holds a [`BodyError`] describing why.
+//!
 //! ## Accessing the conversion source
 //!
 //! All error types provide an `into_src` method that converts the error into
 //! the source value underlying the failed conversion.
+//!
+//! ## Error trait
+//!
+//! All error types implement [`Display`] with a human-readable message. They
+//! also implement [`Error`] - in `no_std` builds on Rust 1.81.0 and later,
+//! where `Error` is available in `core`, and otherwise when the `std` feature
+//! is enabled - so long as their source and type parameters allow them to be
+//! displayed. This allows them to be propagated with `?` into `Box<dyn
+//! Error>` and used with error-handling libraries.
+//!
+//! [`Display`]: core::fmt::Display
+//! [`Error`]: https://doc.rust-lang.org/core/error/trait.Error.html
+//!
+//! ## Inspecting the failure
+//!
+//! [`AlignmentError`] and [`SizeError`] provide accessors describing why the
+//! conversion failed: [`AlignmentError::required_align`] and
+//! [`AlignmentError::addr`] report the destination's alignment and the source's
+//! address, and [`SizeError::src_size`] and [`SizeError::dst_min_size`] report
+//! the source's size and the destination's minimum size.
+//! [`SizeError::display_with_dst_size`] produces an error message which states
+//! both sizes.
 
-use core::{convert::Infallible, fmt, marker::PhantomData, ops::Deref};
+use core::{convert::Infallible, fmt, marker::PhantomData, mem, num::NonZeroUsize, ops::Deref};
+
+#[cfg(zerocopy_core_error)]
+use core::error::Error;
+#[cfg(all(not(zerocopy_core_error), any(feature = "std", test)))]
+use std::error::Error;
 
-use crate::TryFromBytes;
 #[cfg(doc)]
-use crate::{FromBytes, Ref};
+use crate::{parse_tagged, FromBytes, Ref};
+use crate::{util, DstLayout, KnownLayout, SizeInfo, TrailingSliceLayout, TryFromBytes};
 
 // This is private to remove `ConvertError` from our SemVer obligations for the
 // time being.
@@ -94,18 +128,36 @@ impl<A: fmt::Display, S: fmt::Display, V: fmt::Display> fmt::Display for Convert
     }
 }
 
+#[cfg(any(zerocopy_core_error, feature = "std", test))]
+impl<A, S, V> Error for ConvertError<A, S, V>
+where
+    A: fmt::Display + fmt::Debug,
+    S: fmt::Display + fmt::Debug,
+    V: fmt::Display + fmt::Debug,
+{
+}
+
 /// The error emitted if the conversion source is improperly aligned.
 #[derive(PartialEq, Eq)]
 pub struct AlignmentError<Src, Dst: ?Sized> {
     /// The source value involved in the conversion.
     src: Src,
+    /// The alignment required by the conversion, if it is stricter than the
+    /// alignment of `Dst`.
+    align: Option<NonZeroUsize>,
     /// The inner destination type inolved in the conversion.
     dst: PhantomData<Dst>,
 }
 
 impl<Src, Dst: ?Sized> AlignmentError<Src, Dst> {
     pub(crate) fn new(src: Src) -> Self {
-        Self { src, dst: PhantomData }
+        Self { src, align: None, dst: PhantomData }
+    }
+
+    /// Constructs an error for a conversion which requires the source to be
+    /// aligned to `align`, rather than to the alignment of `Dst`.
+    pub(crate) fn with_align(src: Src, align: NonZeroUsize) -> Self {
+        Self { src, align: Some(align), dst: PhantomData }
     }
 
     /// Produces the source underlying the failed conversion.
@@ -115,11 +167,11 @@ impl<Src, Dst: ?Sized> AlignmentError<Src, Dst> {
     }
 
     pub(crate) fn with_src<NewSrc>(self, new_src: NewSrc) -> AlignmentError<NewSrc, Dst> {
-        AlignmentError { src: new_src, dst: PhantomData }
+        AlignmentError { src: new_src, align: self.align, dst: PhantomData }
     }
 
     pub(crate) fn map_src<NewSrc>(self, f: impl Fn(Src) -> NewSrc) -> AlignmentError<NewSrc, Dst> {
-        AlignmentError { src: f(self.src), dst: PhantomData }
+        AlignmentError { src: f(self.src), align: self.align, dst: PhantomData }
     }
 
     pub(crate) fn into<S, V>(self) -> ConvertError<Self, S, V> {
@@ -127,6 +179,30 @@ impl<Src, Dst: ?Sized> AlignmentError<Src, Dst> {
     }
 }
 
+impl<Src, Dst: ?Sized + KnownLayout> AlignmentError<Src, Dst> {
+    /// The alignment, in bytes, required by the conversion.
+    ///
+    /// This is the alignment of the destination type, unless the conversion
+    /// imposed a stricter alignment (as in
+    /// [`FromBytes::ref_from_at_least_aligned`]).
+    #[inline]
+    pub fn required_align(&self) -> usize {
+        self.align.unwrap_or(Dst::LAYOUT.align).get()
+    }
+}
+
+impl<Src: Deref, Dst: ?Sized> AlignmentError<Src, Dst> {
+    /// The address of the source, which is not a multiple of
+    /// [`required_align`](AlignmentError::required_align).
+    #[inline]
+    pub fn addr(&self) -> usize {
+        #[cfg_attr(__INTERNAL_USE_ONLY_NIGHTLY_FEATURES_IN_TESTS, allow(lossy_provenance_casts))]
+        #[allow(clippy::as_conversions)]
+        let addr = self.src.deref() as *const _ as *const () as usize;
+        addr
+    }
+}
+
 impl<Src, Dst: ?Sized> fmt::Debug for AlignmentError<Src, Dst> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -144,20 +220,30 @@ where
 {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        #[cfg_attr(__INTERNAL_USE_ONLY_NIGHTLY_FEATURES_IN_TESTS, allow(lossy_provenance_casts))]
-        #[allow(clippy::as_conversions)]
-        let addr = self.src.deref() as *const _ as *const () as usize;
-        let addr_align = 2usize.pow(addr.trailing_zeros());
-        f.write_str("the conversion failed because the address of the source (a multiple of ")?;
-        addr_align.fmt(f)?;
-        f.write_str(") is not a multiple of the alignment (")?;
-        core::mem::align_of::<Dst>().fmt(f)?;
-        f.write_str(") of the destination type: ")?;
-        f.write_str(core::any::type_name::<Dst>())?;
-        Ok(())
+        let align = self.align.map_or(mem::align_of::<Dst>(), NonZeroUsize::get);
+        fmt_alignment_error(self.addr(), align, core::any::type_name::<Dst>(), f)
     }
 }
 
+/// Produces the message of an [`AlignmentError`].
+fn fmt_alignment_error(
+    addr: usize,
+    align: usize,
+    dst: &str,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    let addr_align = 2usize.pow(addr.trailing_zeros());
+    f.write_str("the conversion failed because the address of the source (a multiple of ")?;
+    fmt::Display::fmt(&addr_align, f)?;
+    f.write_str(") is not a multiple of the alignment (")?;
+    fmt::Display::fmt(&align, f)?;
+    f.write_str(") of the destination type: ")?;
+    f.write_str(dst)
+}
+
+#[cfg(any(zerocopy_core_error, feature = "std", test))]
+impl<Src: Deref, Dst> Error for AlignmentError<Src, Dst> {}
+
 impl<Src, Dst, S, V> From<AlignmentError<Src, Dst>>
     for ConvertError<AlignmentError<Src, Dst>, S, V>
 {
@@ -208,6 +294,82 @@ impl<Src, Dst: ?Sized> SizeError<Src, Dst> {
     }
 }
 
+impl<Src: Deref, Dst: ?Sized> SizeError<Src, Dst> {
+    /// The size, in bytes, of the source.
+    #[inline]
+    pub fn src_size(&self) -> usize {
+        mem::size_of_val(self.src.deref())
+    }
+}
+
+impl<Src, Dst: ?Sized + KnownLayout> SizeError<Src, Dst> {
+    /// The minimum size, in bytes, of the destination type.
+    ///
+    /// For sized types, this is the size of the type. For slice DSTs, this is
+    /// the size of the type with zero trailing slice elements, which may not
+    /// be the size actually required by a conversion which specifies a
+    /// non-zero number of elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::Ref;
+    ///
+    /// let err = Ref::<_, [u8; 4]>::unaligned_from(&[0u8; 3][..]).unwrap_err();
+    /// assert_eq!(err.src_size(), 3);
+    /// assert_eq!(err.dst_min_size(), 4);
+    /// ```
+    #[inline]
+    pub fn dst_min_size(&self) -> usize {
+        min_size(&Dst::LAYOUT)
+    }
+
+    /// Produces a human-readable error message which, unlike this error's
+    /// [`Display`](fmt::Display) output, also states the size of the
+    /// destination type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::FromBytes;
+    ///
+    /// let err = u32::read_from(&[0u8; 3][..]).unwrap_err();
+    /// assert_eq!(
+    ///     err.display_with_dst_size().to_string(),
+    ///     "the conversion failed because the size of the source (3 bytes) is \
+    ///      incorrect for the size of the destination type (4 bytes): u32",
+    /// );
+    /// ```
+    #[inline]
+    pub fn display_with_dst_size(&self) -> impl fmt::Display + '_
+    where
+        Src: Deref,
+    {
+        struct WithDstSize<'a, Src, Dst: ?Sized>(&'a SizeError<Src, Dst>);
+
+        impl<Src: Deref, Dst: ?Sized + KnownLayout> fmt::Display for WithDstSize<'_, Src, Dst> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let dst = core::any::type_name::<Dst>();
+                fmt_size_error(self.0.src_size(), Some(&Dst::LAYOUT), dst, f)
+            }
+        }
+
+        WithDstSize(self)
+    }
+}
+
+/// The minimum size, in bytes, of a type with the given layout.
+fn min_size(layout: &DstLayout) -> usize {
+    match layout.size_info {
+        SizeInfo::Sized { size } => size,
+        SizeInfo::SliceDst(TrailingSliceLayout { offset, .. }) => {
+            // With zero trailing elements, the type consists of everything up
+            // to its trailing slice, padded out to its alignment.
+            offset.saturating_add(util::padding_needed_for(offset, layout.align))
+        }
+    }
+}
+
 impl<Src, Dst: ?Sized> fmt::Debug for SizeError<Src, Dst> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -216,18 +378,53 @@ impl<Src, Dst: ?Sized> fmt::Debug for SizeError<Src, Dst> {
 }
 
 /// Produces a human-readable error message.
+///
+/// The message states the size of the source. To also state the size of the
+/// destination type, use [`SizeError::display_with_dst_size`].
 impl<Src, Dst: ?Sized> fmt::Display for SizeError<Src, Dst>
 where
     Src: Deref,
 {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str("the conversion failed because the source was incorrectly sized to complete the conversion into the destination type: ")?;
-        f.write_str(core::any::type_name::<Dst>())?;
-        Ok(())
+        fmt_size_error(self.src_size(), None, core::any::type_name::<Dst>(), f)
+    }
+}
+
+/// Produces the message of a [`SizeError`], stating the size of the
+/// destination type if its `layout` is provided.
+fn fmt_size_error(
+    src_size: usize,
+    layout: Option<&DstLayout>,
+    dst: &str,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    f.write_str("the conversion failed because the size of the source (")?;
+    fmt_bytes(src_size, f)?;
+    f.write_str(") is incorrect for the ")?;
+    if let Some(layout) = layout {
+        f.write_str("size of the destination type (")?;
+        fmt_bytes(min_size(layout), f)?;
+        if let SizeInfo::SliceDst(TrailingSliceLayout { elem_size, .. }) = layout.size_info {
+            f.write_str(" plus a multiple of ")?;
+            fmt_bytes(elem_size, f)?;
+        }
+        f.write_str("): ")?;
+    } else {
+        f.write_str("destination type: ")?;
     }
+    f.write_str(dst)
 }
 
+/// Writes `n` as a number of bytes, e.g. "1 byte" or "2 bytes".
+fn fmt_bytes(n: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Display::fmt(&n, f)?;
+    f.write_str(if n == 1 { " byte" } else { " bytes" })
+}
+
+#[cfg(any(zerocopy_core_error, feature = "std", test))]
+impl<Src: Deref, Dst: ?Sized> Error for SizeError<Src, Dst> {}
+
 impl<Src, Dst, A, V> From<SizeError<Src, Dst>> for ConvertError<A, SizeError<Src, Dst>, V> {
     #[inline]
     fn from(err: SizeError<Src, Dst>) -> Self {
@@ -242,11 +439,20 @@ pub struct ValidityError<Src, Dst: ?Sized + TryFromBytes> {
     src: Src,
     /// The inner destination type inolved in the conversion.
     dst: PhantomData<Dst>,
+    /// The index of the first invalid element, if the destination is a slice
+    /// whose elements were validated individually.
+    index: Option<usize>,
 }
 
 impl<Src, Dst: ?Sized + TryFromBytes> ValidityError<Src, Dst> {
     pub(crate) fn new(src: Src) -> Self {
-        Self { src, dst: PhantomData }
+        Self { src, dst: PhantomData, index: None }
+    }
+
+    /// Records that the element at `index` is the first invalid element of
+    /// the source.
+    pub(crate) fn with_index(self, index: usize) -> Self {
+        Self { index: Some(index), ..self }
     }
 
     /// Produces the source underlying the failed conversion.
@@ -255,9 +461,19 @@ impl<Src, Dst: ?Sized + TryFromBytes> ValidityError<Src, Dst> {
         self.src
     }
 
+    /// The index of the first invalid element of the source, if known.
+    ///
+    /// This is `Some` for errors produced by
+    /// [`TryFromBytes::try_ref_from_all`], which validates its source one
+    /// element at a time, and `None` otherwise.
+    #[inline]
+    pub fn invalid_index(&self) -> Option<usize> {
+        self.index
+    }
+
     /// Maps the source value associated with the conversion error.
     pub(crate) fn map_src<NewSrc>(self, f: impl Fn(Src) -> NewSrc) -> ValidityError<NewSrc, Dst> {
-        ValidityError { src: f(self.src), dst: PhantomData }
+        ValidityError { src: f(self.src), dst: PhantomData, index: self.index }
     }
 
     /// Converts the error into a general [`ConvertError`].
@@ -282,10 +498,16 @@ where
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("the conversion failed because the source bytes are not a valid value of the destination type: ")?;
         f.write_str(core::any::type_name::<Dst>())?;
+        if let Some(index) = self.index {
+            write!(f, " (the first invalid element is at index {})", index)?;
+        }
         Ok(())
     }
 }
 
+#[cfg(any(zerocopy_core_error, feature = "std", test))]
+impl<Src: Deref, Dst: ?Sized + TryFromBytes> Error for ValidityError<Src, Dst> {}
+
 impl<Src, Dst: ?Sized + TryFromBytes, A, S> From<ValidityError<Src, Dst>>
     for ConvertError<A, S, ValidityError<Src, Dst>>
 {
@@ -406,9 +628,176 @@ impl<Src, Dst: ?Sized + TryFromBytes> TryReadError<Src, Dst> {
     }
 }
 
+/// The error type of [`parse_tagged!`].
+///
+/// Tagged parsing may fail because the header could not be parsed, because the
+/// header's tag did not select any body type, or because the body could not be
+/// parsed.
+#[derive(PartialEq, Eq)]
+pub enum TaggedError<Src, Header: ?Sized, Tag> {
+    /// The header could not be parsed from the prefix of the source.
+    Header(CastError<Src, Header>),
+    /// The header's tag did not select any body type.
+    ///
+    /// The source is the bytes following the header.
+    UnknownTag(Tag, Src),
+    /// The body selected by the header's tag could not be parsed.
+    ///
+    /// The source is the bytes following the header.
+    Body(BodyError<Src>),
+}
+
+impl<Src, Header: ?Sized, Tag> TaggedError<Src, Header, Tag> {
+    /// Produces the source underlying the failed conversion.
+    ///
+    /// For [`TaggedError::Header`], this is the entire source. Otherwise, it
+    /// is the bytes following the header.
+    #[inline]
+    pub fn into_src(self) -> Src {
+        match self {
+            Self::Header(e) => e.into_src(),
+            Self::UnknownTag(_, src) => src,
+            Self::Body(e) => e.into_src(),
+        }
+    }
+}
+
+impl<Src, Header: ?Sized, Tag: fmt::Debug> fmt::Debug for TaggedError<Src, Header, Tag> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Header(e) => f.debug_tuple("Header").field(e).finish(),
+            Self::UnknownTag(tag, _) => f.debug_tuple("UnknownTag").field(tag).finish(),
+            Self::Body(e) => f.debug_tuple("Body").field(e).finish(),
+        }
+    }
+}
+
+/// Produces a human-readable error message.
+impl<Src, Header, Tag> fmt::Display for TaggedError<Src, Header, Tag>
+where
+    Src: Deref,
+    Header: KnownLayout,
+    Tag: fmt::Display,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Header(e) => e.fmt(f),
+            Self::UnknownTag(tag, _) => {
+                write!(f, "the header's tag ({}) did not select any body type", tag)
+            }
+            Self::Body(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(any(zerocopy_core_error, feature = "std", test))]
+impl<Src, Header, Tag> Error for TaggedError<Src, Header, Tag>
+where
+    Src: Deref,
+    Header: KnownLayout,
+    Tag: fmt::Display + fmt::Debug,
+{
+}
+
+/// The error emitted by [`parse_tagged!`] if the body selected by the header's
+/// tag could not be parsed.
+///
+/// This is the [`CastError`] produced by parsing the body. Since each tag may
+/// select a different body type, the body type is erased from the error's type;
+/// the error instead records the body type's layout and name, so that it
+/// describes the failure as precisely as the underlying [`CastError`] does.
+pub struct BodyError<Src> {
+    /// The underlying error, with its destination type erased.
+    err: ConvertError<Src, Src, Infallible>,
+    /// The layout of the body type.
+    layout: DstLayout,
+    /// The name of the body type.
+    dst: &'static str,
+}
+
+impl<Src> BodyError<Src> {
+    /// Produces the source underlying the failed conversion.
+    #[inline]
+    pub fn into_src(self) -> Src {
+        match self.err {
+            ConvertError::Alignment(src) | ConvertError::Size(src) => src,
+            ConvertError::Validity(i) => match i {},
+        }
+    }
+
+    /// Whether the conversion failed because the source was improperly
+    /// aligned for the body type.
+    ///
+    /// Otherwise, the conversion failed because the source was of incorrect
+    /// size for the body type.
+    #[inline]
+    pub fn is_alignment_error(&self) -> bool {
+        matches!(self.err, ConvertError::Alignment(_))
+    }
+}
+
+impl<Src, Dst: ?Sized + KnownLayout> From<CastError<Src, Dst>> for BodyError<Src> {
+    #[inline]
+    fn from(err: CastError<Src, Dst>) -> Self {
+        let err = match err {
+            ConvertError::Alignment(e) => ConvertError::Alignment(e.into_src()),
+            ConvertError::Size(e) => ConvertError::Size(e.into_src()),
+            ConvertError::Validity(i) => match i {},
+        };
+        Self { err, layout: Dst::LAYOUT, dst: core::any::type_name::<Dst>() }
+    }
+}
+
+impl<Src: PartialEq> PartialEq for BodyError<Src> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.err == other.err && self.dst == other.dst
+    }
+}
+
+impl<Src: Eq> Eq for BodyError<Src> {}
+
+impl<Src> fmt::Debug for BodyError<Src> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = if self.is_alignment_error() { "AlignmentError" } else { "SizeError" };
+        f.debug_struct(name).finish()
+    }
+}
+
+/// Produces a human-readable error message.
+impl<Src: Deref> fmt::Display for BodyError<Src> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.err {
+            ConvertError::Alignment(src) => {
+                #[cfg_attr(
+                    __INTERNAL_USE_ONLY_NIGHTLY_FEATURES_IN_TESTS,
+                    allow(lossy_provenance_casts)
+                )]
+                #[allow(clippy::as_conversions)]
+                let addr = src.deref() as *const _ as *const () as usize;
+                fmt_alignment_error(addr, self.layout.align.get(), self.dst, f)
+            }
+            ConvertError::Size(src) => {
+                fmt_size_error(mem::size_of_val(src.deref()), Some(&self.layout), self.dst, f)
+            }
+            ConvertError::Validity(i) => match *i {},
+        }
+    }
+}
+
+#[cfg(any(zerocopy_core_error, feature = "std", test))]
+impl<Src: Deref> Error for BodyError<Src> {}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use core::ptr;
+
+    use crate::{FromBytes, Immutable, IntoBytes, Ref, TryFromBytes};
 
     #[test]
     fn alignment_display() {
@@ -440,12 +829,198 @@ mod test {
         );
     }
 
+    #[test]
+    fn alignment_accessors() {
+        #[repr(C, align(8))]
+        struct Aligned {
+            bytes: [u8; 16],
+        }
+
+        let aligned = Aligned { bytes: [0; 16] };
+        let src = &aligned.bytes[1..9];
+        let err = match u64::ref_from(src) {
+            Err(CastError::Alignment(err)) => err,
+            _ => panic!("expected an alignment error"),
+        };
+        assert_eq!(err.required_align(), 8);
+        assert_eq!(err.addr() % 8, 1);
+
+        let err = match <[u32]>::ref_from(&aligned.bytes[2..6]) {
+            Err(CastError::Alignment(err)) => err,
+            _ => panic!("expected an alignment error"),
+        };
+        assert_eq!(err.required_align(), 4);
+        assert_eq!(err.addr() % 4, 2);
+    }
+
+    #[test]
+    fn size_accessors() {
+        let err = match <[u8; 8]>::ref_from(&[0u8; 7][..]) {
+            Err(CastError::Size(err)) => err,
+            _ => panic!("expected a size error"),
+        };
+        assert_eq!(err.src_size(), 7);
+        assert_eq!(err.dst_min_size(), 8);
+
+        // A size error for a source with leftover bytes refers to the whole
+        // source, not just the bytes which would have been cast.
+        let err = match <[u8; 8]>::ref_from(&[0u8; 9][..]) {
+            Err(CastError::Size(err)) => err,
+            _ => panic!("expected a size error"),
+        };
+        assert_eq!(err.src_size(), 9);
+
+        // The minimum size of a slice DST includes the padding which follows
+        // its fixed-size prefix.
+        #[derive(KnownLayout, FromBytes, Immutable)]
+        #[repr(C)]
+        struct Dst {
+            a: u16,
+            b: u8,
+            c: [u8],
+        }
+
+        let aligned = [0u16; 1];
+        let err = match Dst::ref_from_prefix(aligned.as_bytes()) {
+            Err(CastError::Size(err)) => err,
+            _ => panic!("expected a size error"),
+        };
+        assert_eq!(err.src_size(), 2);
+        assert_eq!(err.dst_min_size(), 4);
+
+        let err = Ref::<_, [u8; 4]>::unaligned_from(&[0u8; 3][..]).unwrap_err();
+        assert_eq!(err.src_size(), 3);
+        assert_eq!(err.dst_min_size(), 4);
+    }
+
+    #[test]
+    fn into_src() {
+        #[repr(C, align(8))]
+        struct Aligned {
+            bytes: [u8; 16],
+        }
+
+        let mut aligned = Aligned { bytes: [2; 16] };
+
+        // `AlignmentError` and `CastError::Alignment`.
+        let src = &aligned.bytes[1..9];
+        let err = u64::ref_from(src).unwrap_err();
+        assert!(matches!(err, CastError::Alignment(_)));
+        assert!(ptr::eq(err.into_src(), src));
+        let err = match u64::ref_from(src) {
+            Err(CastError::Alignment(err)) => err,
+            _ => panic!("expected an alignment error"),
+        };
+        assert!(ptr::eq(err.into_src(), src));
+
+        // `SizeError` and `CastError::Size`.
+        let src = &aligned.bytes[..7];
+        let err = u64::ref_from(src).unwrap_err();
+        assert!(matches!(err, CastError::Size(_)));
+        assert!(ptr::eq(err.into_src(), src));
+        let err = u64::read_from(src).unwrap_err();
+        assert!(ptr::eq(err.into_src(), src));
+
+        // `ValidityError` and the `Validity` variants of `TryCastError` and
+        // `TryReadError`.
+        let src = &aligned.bytes[..1];
+        let err = bool::try_ref_from(src).unwrap_err();
+        assert!(matches!(err, TryCastError::Validity(_)));
+        assert!(ptr::eq(err.into_src(), src));
+        let err = bool::try_read_from(src).unwrap_err();
+        assert!(matches!(err, TryReadError::Validity(_)));
+        assert!(ptr::eq(err.into_src(), src));
+
+        // The source is preserved when it is mapped from a `Ptr` to a
+        // reference, and so an exclusive reference can be recovered and
+        // reused without cloning the buffer.
+        let src = &mut aligned.bytes[1..9];
+        let addr = src.as_ptr();
+        let src = u64::mut_from(src).unwrap_err().into_src();
+        assert_eq!(src.as_ptr(), addr);
+        let src = <[u8; 8]>::mut_from(src).unwrap();
+        assert_eq!(*src, [2; 8]);
+
+        // The source is preserved when it is mapped to a `ByteSlice`.
+        let src = &aligned.bytes[..7];
+        let err = Ref::<_, u64>::from(src).unwrap_err();
+        assert!(ptr::eq(err.into_src(), src));
+    }
+
     #[test]
     fn size_display() {
         assert_eq!(
-            SizeError::<_, [u8]>::new(&[0u8; 1][..]).to_string(),
-            "the conversion failed because the source was incorrectly sized to complete the conversion into the destination type: [u8]"
+            SizeError::<_, [u16]>::new(&[0u8; 1][..]).to_string(),
+            "the conversion failed because the size of the source (1 byte) is incorrect for the destination type: [u16]"
+        );
+        assert_eq!(
+            SizeError::<_, [u16]>::new(&[0u8; 1][..]).display_with_dst_size().to_string(),
+            "the conversion failed because the size of the source (1 byte) is incorrect for the size of the destination type (0 bytes plus a multiple of 2 bytes): [u16]"
+        );
+        assert_eq!(
+            SizeError::<_, [u8]>::new(&[0u8; 0][..]).display_with_dst_size().to_string(),
+            "the conversion failed because the size of the source (0 bytes) is incorrect for the size of the destination type (0 bytes plus a multiple of 1 byte): [u8]"
+        );
+
+        let err = u64::read_from(&[0u8; 5][..]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the conversion failed because the size of the source (5 bytes) is incorrect for the destination type: u64"
+        );
+        assert_eq!(
+            err.display_with_dst_size().to_string(),
+            "the conversion failed because the size of the source (5 bytes) is incorrect for the size of the destination type (8 bytes): u64"
+        );
+    }
+
+    #[test]
+    fn compound_display() {
+        #[repr(C, align(8))]
+        struct Aligned {
+            bytes: [u8; 16],
+        }
+
+        let aligned = Aligned { bytes: [2; 16] };
+
+        // Compound errors display their underlying error.
+        let err = u64::ref_from(&aligned.bytes[4..12]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the conversion failed because the address of the source (a multiple of 4) is not a multiple of the alignment (8) of the destination type: u64"
         );
+
+        let err = u64::ref_from(&aligned.bytes[..3]).unwrap_err();
+        assert!(err.to_string().contains("(3 bytes)"));
+
+        let err = bool::try_ref_from(&aligned.bytes[..1]).unwrap_err();
+        assert!(err.to_string().ends_with("destination type: bool"));
+
+        let err = bool::try_read_from(&aligned.bytes[..2]).unwrap_err();
+        assert!(err.to_string().contains("(2 bytes)"));
+    }
+
+    #[test]
+    fn error_trait() {
+        fn read(bytes: &[u8]) -> Result<u32, Box<dyn Error + '_>> {
+            let x = u32::try_read_from(bytes)?;
+            let y = <[u8; 2]>::ref_from(&bytes[..2])?;
+            let z = u16::read_from(&bytes[2..])?;
+            Ok(x + u32::from(y[0]) + u32::from(z))
+        }
+
+        assert!(read(&[1, 0, 0, 0]).is_ok());
+        let err = read(&[1, 0, 0]).unwrap_err();
+        assert!(err.to_string().contains("(3 bytes)"));
+
+        fn assert_error<E: Error>() {}
+        assert_error::<AlignmentError<&[u8], u64>>();
+        assert_error::<SizeError<&[u8], [u8]>>();
+        assert_error::<ValidityError<&[u8], bool>>();
+        assert_error::<CastError<&[u8], u64>>();
+        assert_error::<TryCastError<&[u8], bool>>();
+        assert_error::<TryReadError<&[u8], bool>>();
+        assert_error::<TaggedError<&[u8], u8, u8>>();
+        assert_error::<BodyError<&[u8]>>();
     }
 
     #[test]
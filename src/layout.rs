@@ -597,6 +597,30 @@ impl DstLayout {
 
         Ok((elems, split_at))
     }
+
+    /// Computes the largest number of trailing slice elements for which an
+    /// instance of the type described by `self` fits in `bytes` bytes.
+    ///
+    /// Returns `None` if `self` describes a sized type, if `bytes` is too small
+    /// to hold an instance with zero trailing elements, or if the trailing
+    /// slice elements are zero-sized.
+    pub(crate) fn elems_for_bytes(&self, bytes: usize) -> Option<usize> {
+        match self.size_info {
+            SizeInfo::SliceDst(TrailingSliceLayout { offset, elem_size }) => {
+                // Any valid size for the type is a multiple of its alignment,
+                // so any bytes past the last multiple of the alignment can't be
+                // used.
+                let max_total_bytes =
+                    util::round_down_to_next_multiple_of_alignment(bytes, self.align);
+                let max_slice_and_padding_bytes = max_total_bytes.checked_sub(offset)?;
+                // Since `max_total_bytes` is a multiple of the alignment, the
+                // padding after `elems` elements can't push the total size past
+                // `max_total_bytes`.
+                max_slice_and_padding_bytes.checked_div(elem_size)
+            }
+            SizeInfo::Sized { .. } => None,
+        }
+    }
 }
 
 // TODO(#67): For some reason, on our MSRV toolchain, this `allow` isn't
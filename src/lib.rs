@@ -52,7 +52,7 @@
 //!
 //! ##### Conversion Macros
 //!
-//! Zerocopy provides three macros for safe, zero-cost casting between types:
+//! Zerocopy provides four macros for safe, zero-cost casting between types:
 //!
 //! - [`transmute`] converts a value of one type to a value of another type of
 //!   the same size
@@ -60,6 +60,13 @@
 //!   reference of another type of the same size
 //! - [`transmute_ref`] converts transmutes a mutable or immutable reference
 //!   of one type to an immutable reference of another type of the same size
+//! - [`transmute_slice`] converts a slice of one element type to a slice of
+//!   another element type of the same size and the same length
+//!
+//! Additionally, [`try_transmute`], [`try_transmute_ref`], and [`try_transmute_mut`] are the
+//! fallible counterparts of these macros: they check at runtime that the value
+//! or referent is valid for the destination type, which need only be
+//! `TryFromBytes`.
 //!
 //! These macros perform *compile-time* alignment and size checks, but cannot be
 //! used in generic contexts. For generic conversions, use the methods defined
@@ -78,6 +85,12 @@
 //!   the `alloc` crate is added as a dependency, and some allocation-related
 //!   functionality is added.
 //!
+//! - **`std`**   
+//!   Implies `alloc`. When the `std` feature is enabled, the `std` crate is
+//!   added as a dependency, and some functionality which depends on `std` is
+//!   added, such as the [`std::io::Write`] impl for [`Cursor`] and support
+//!   for vectored I/O via [`IntoBytes::as_io_slice`] and [`io_slices!`].
+//!
 //! - **`derive`**   
 //!   Provides derives for the core marker traits via the `zerocopy-derive`
 //!   crate. These derives are re-exported from `zerocopy`, so it is not
@@ -95,6 +108,41 @@
 //!   zerocopy-derive = "0.X"
 //!   ```
 //!
+//!   The derives refer to zerocopy's items as `::zerocopy`. Crates which
+//!   re-export zerocopy under a different path (e.g., from a facade crate) can
+//!   override this by annotating the type with `#[zerocopy(crate = path)]`,
+//!   where `path` is the path to zerocopy's re-export:
+//!
+//!   ```
+//!   # mod facade { pub mod zerocopy { pub use ::zerocopy::*; } }
+//!   use facade::zerocopy::{FromBytes, IntoBytes};
+//!
+//!   #[derive(FromBytes, IntoBytes)]
+//!   #[zerocopy(crate = facade::zerocopy)]
+//!   #[repr(C)]
+//!   struct PacketHeader {
+//!       src_port: [u8; 2],
+//!       dst_port: [u8; 2],
+//!   }
+//!   ```
+//!
+//! - **`bytes`**   
+//!   When the `bytes` feature is enabled, [`ByteSlice`], [`CloneableByteSlice`],
+//!   and [`SplitByteSlice`] are implemented for [`bytes::Bytes`] and
+//!   [`bytes::BytesMut`], so that [`Ref`] can be used to parse
+//!   reference-counted buffers without copying.
+//!
+//! - **`memmap2`**   
+//!   When the `memmap2` feature is enabled, the `memmap` module provides
+//!   functions for interpreting `memmap2::Mmap` and `memmap2::MmapMut` memory
+//!   maps as zerocopy types. This feature requires Rust 1.65 or later.
+//!
+//! - **`serde`**   
+//!   When the `serde` feature is enabled, `Serialize` and `Deserialize` are
+//!   implemented for the integer and floating-point types in the [`byteorder`]
+//!   module. These types are serialized as their native values rather than as
+//!   their raw bytes, so the serialized form does not depend on byte order.
+//!
 //! - **`simd`**   
 //!   When the `simd` feature is enabled, `FromZeros`, `FromBytes`, and
 //!   `IntoBytes` impls are emitted for all stable SIMD types which exist on the
@@ -108,6 +156,11 @@
 //!   available on nightly. Since these types are unstable, support for any type
 //!   may be removed at any point in the future.
 //!
+//! - **`test-util`**   
+//!   When the `test-util` feature is enabled, the `test_util` module provides
+//!   functions which check, in tests, that implementations of zerocopy's
+//!   traits uphold the properties zerocopy relies on.
+//!
 //! [simd-layout]: https://rust-lang.github.io/unsafe-code-guidelines/layout/packed-simd-vectors.html
 //!
 //! # Security Ethos
@@ -280,6 +333,7 @@ extern crate self as zerocopy;
 mod macros;
 
 pub mod byteorder;
+mod cursor;
 mod deprecated;
 // This module is `pub` so that zerocopy's error types and error handling
 // documentation is grouped together in a cohesive module. In practice, we
@@ -290,23 +344,43 @@ pub mod error;
 pub mod layout;
 #[doc(hidden)]
 pub mod macro_util;
+#[cfg(feature = "memmap2")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "memmap2")))]
+pub mod memmap;
+#[cfg(zerocopy_core_net)]
+pub mod net;
 #[doc(hidden)]
 pub mod pointer;
 mod r#ref;
+#[cfg(feature = "alloc")]
+mod shared_bytes;
+#[cfg(feature = "test-util")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "test-util")))]
+pub mod test_util;
+pub mod time;
 mod util;
 // TODO(#252): If we make this pub, come up with a better name.
 mod wrappers;
 
 pub use crate::byteorder::*;
+pub use crate::cursor::*;
 pub use crate::error::*;
 pub use crate::r#ref::*;
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+pub use crate::shared_bytes::*;
 pub use crate::wrappers::*;
 
+#[cfg(any(
+    all(zerocopy_target_has_atomic, target_has_atomic = "64"),
+    all(not(zerocopy_target_has_atomic), not(target_arch = "powerpc"))
+))]
+use core::sync::atomic::{AtomicI64, AtomicU64};
 use core::{
     cell::{self, RefMut, UnsafeCell},
     cmp::Ordering,
     fmt::{self, Debug, Display, Formatter},
-    hash::Hasher,
+    hash::{Hash, Hasher},
     marker::PhantomData,
     mem::{self, ManuallyDrop, MaybeUninit},
     num::{
@@ -327,7 +401,10 @@ use crate::pointer::{invariant, BecauseExclusive, BecauseImmutable};
 #[cfg(any(feature = "alloc", test))]
 extern crate alloc;
 #[cfg(any(feature = "alloc", test))]
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{borrow::Cow, boxed::Box, vec::Vec};
+
+#[cfg(all(feature = "std", not(test)))]
+extern crate std;
 
 #[cfg(any(feature = "alloc", test, kani))]
 use core::alloc::Layout;
@@ -412,8 +489,28 @@ use {FromZeros as FromZeroes, IntoBytes as AsBytes, Ref as LayoutVerified};
 ///
 /// # Limitations
 ///
-/// This derive cannot currently be applied to unsized structs without an
-/// explicit `repr` attribute.
+/// This derive cannot currently be applied to unsized structs without a
+/// `repr(C)` attribute, unless they are `repr(transparent)` and their trailing
+/// field is a slice or `str`. Such a newtype - for example,
+/// `struct Wrapper([u8])` - has the same `PointerMetadata` as the type it
+/// wraps.
+///
+/// Whether the trailing field is a slice or `str` is determined from its
+/// syntax, so this derive cannot be applied to a `repr(transparent)` struct
+/// which wraps another unsized type by name:
+///
+/// ```compile_fail,E0277
+/// # use zerocopy_derive::KnownLayout;
+/// #[derive(KnownLayout)]
+/// #[repr(transparent)]
+/// struct Bytes([u8]);
+///
+/// #[derive(KnownLayout)]
+/// #[repr(transparent)]
+/// struct Wrapper(Bytes); // ⚠ Compile Error!
+/// ```
+///
+/// Use `repr(C)` for such a struct instead.
 #[cfg(any(feature = "derive", test))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "derive")))]
 pub use zerocopy_derive::KnownLayout;
@@ -605,6 +702,89 @@ impl PointerMetadata for usize {
     }
 }
 
+/// Computes the size of the referent of `val` in bytes.
+///
+/// `size_of_val` computes the size of `val` from the layout information
+/// provided by [`KnownLayout`], and returns the same value as
+/// [`core::mem::size_of_val`]. For sized types, this is `size_of::<T>()`. For
+/// slice DSTs, it is the size of the type's fixed prefix plus the size of its
+/// trailing slice elements, rounded up to the type's alignment.
+///
+/// # Examples
+///
+/// ```
+/// use zerocopy::{size_of_val, FromBytes};
+/// # use zerocopy_derive::*;
+///
+/// #[derive(FromBytes, KnownLayout, Immutable)]
+/// #[repr(C)]
+/// struct Packet {
+///     length: [u8; 2],
+///     body: [u8],
+/// }
+///
+/// assert_eq!(size_of_val(&0u32), 4);
+/// assert_eq!(size_of_val(&[0u16; 3][..]), 6);
+///
+/// let bytes = &[0, 3, 1, 2, 3, 4, 5][..];
+/// let (packet, _) = Packet::ref_from_prefix_with_trailing_elements(bytes, 3).unwrap();
+/// assert_eq!(size_of_val(packet), 5);
+/// ```
+#[must_use]
+#[inline]
+pub fn size_of_val<T: KnownLayout + ?Sized>(val: &T) -> usize {
+    match T::size_of_val_raw(NonNull::from(val)) {
+        Some(size) => size,
+        // NOTE: This branch is unreachable, since the referent of a reference
+        // always has a size that fits in an `isize`. We fall back to
+        // `mem::size_of_val` rather than `unreachable!()` to avoid generating
+        // panic paths.
+        None => mem::size_of_val(val),
+    }
+}
+
+/// Computes the number of trailing slice elements which fit in `bytes` bytes.
+///
+/// `elems_for_bytes` is the inverse of the size computation performed by
+/// [`size_of_val`] for slice DSTs. It returns the largest number of trailing
+/// slice elements, `elems`, such that an instance of `T` with `elems` trailing
+/// elements (including its fixed prefix and any trailing padding) is no larger
+/// than `bytes`. This is useful for generic code, such as custom allocators,
+/// which needs to determine how large a `T` can be placed in a region of
+/// memory.
+///
+/// Returns `None` if `bytes` is too small to hold even an instance of `T` with
+/// zero trailing elements, or if `T`'s trailing slice elements are zero-sized
+/// (in which case any number of elements fits).
+///
+/// # Examples
+///
+/// ```
+/// use zerocopy::elems_for_bytes;
+/// # use zerocopy_derive::*;
+///
+/// #[derive(KnownLayout)]
+/// #[repr(C)]
+/// struct Packet {
+///     length: [u8; 2],
+///     body: [u8],
+/// }
+///
+/// assert_eq!(elems_for_bytes::<Packet>(1), None);
+/// assert_eq!(elems_for_bytes::<Packet>(2), Some(0));
+/// assert_eq!(elems_for_bytes::<Packet>(7), Some(5));
+/// assert_eq!(elems_for_bytes::<[u32]>(15), Some(3));
+/// ```
+#[must_use]
+#[inline]
+pub fn elems_for_bytes<T: KnownLayout<PointerMetadata = usize> + ?Sized>(
+    bytes: usize,
+) -> Option<usize> {
+    // NOTE: Since `T`'s pointer metadata is `usize`, `T` is a slice DST, and so
+    // this only returns `None` in the cases documented above.
+    T::LAYOUT.elems_for_bytes(bytes)
+}
+
 // SAFETY: Delegates safety to `DstLayout::for_slice`.
 unsafe impl<T> KnownLayout for [T] {
     #[allow(clippy::missing_inline_in_public_items)]
@@ -673,6 +853,11 @@ impl_known_layout!(
     AtomicBool, AtomicI16, AtomicI32, AtomicI8, AtomicIsize, AtomicU16, AtomicU32,
     AtomicU8, AtomicUsize
 );
+#[cfg(any(
+    all(zerocopy_target_has_atomic, target_has_atomic = "64"),
+    all(not(zerocopy_target_has_atomic), not(target_arch = "powerpc"))
+))]
+impl_known_layout!(AtomicI64, AtomicU64);
 #[rustfmt::skip]
 impl_known_layout!(
     T         => Option<T>,
@@ -966,7 +1151,119 @@ pub unsafe trait Immutable {
 /// }
 /// ```
 ///
+/// Enums must have a defined representation (`repr`s `C`, `u8`, `u16`, `u32`,
+/// `u64`, `usize`, `i8`, `i16`, `i32`, `i64`, or `isize`). Enums with fields
+/// are supported so long as all of their fields are `Immutable`; the derived
+/// implementation reads the enum's tag as prescribed by its representation,
+/// rejects tags which don't correspond to any variant, and then validates the
+/// fields of the variant identified by the tag:
+///
+/// ```
+/// # use zerocopy::TryFromBytes as _;
+/// # use zerocopy_derive::{Immutable, KnownLayout, TryFromBytes};
+/// #[derive(TryFromBytes, KnownLayout, Immutable)]
+/// #[repr(u8)]
+/// enum Command {
+///     Reset,
+///     SetFlag(bool),
+///     Move { x: u8, y: u8 },
+/// }
+///
+/// assert!(matches!(Command::try_read_from(&[1, 1, 0]), Ok(Command::SetFlag(true))));
+/// assert!(matches!(Command::try_read_from(&[2, 3, 4]), Ok(Command::Move { x: 3, y: 4 })));
+///
+/// // `2` isn't a valid `bool`.
+/// assert!(Command::try_read_from(&[1, 2, 0]).is_err());
+/// // `3` doesn't correspond to any variant.
+/// assert!(Command::try_read_from(&[3, 0, 0]).is_err());
+/// ```
+///
 /// [safety conditions]: trait@TryFromBytes#safety
+///
+/// # Custom validators
+///
+/// Structs with invariants beyond the bit validity of their fields can be
+/// annotated with `#[zerocopy(validate = path::to_fn)]`, where `path::to_fn`
+/// is a `fn(&Self) -> bool`. The validator is only called once every field
+/// has been found to be bit-valid, and a value is only accepted if the
+/// validator returns `true`. Validators require `Self: Immutable`, and may be
+/// called on a copy of the candidate value if it is not well-aligned.
+///
+/// ```
+/// # use zerocopy::TryFromBytes as _;
+/// # use zerocopy_derive::{Immutable, KnownLayout, TryFromBytes};
+/// #[derive(TryFromBytes, KnownLayout, Immutable)]
+/// #[zerocopy(validate = Buffer::is_valid)]
+/// #[repr(C)]
+/// struct Buffer {
+///     len: u8,
+///     cap: u8,
+/// }
+///
+/// impl Buffer {
+///     fn is_valid(&self) -> bool {
+///         self.len <= self.cap
+///     }
+/// }
+///
+/// assert!(Buffer::try_ref_from(&[3, 4]).is_ok());
+/// assert!(Buffer::try_ref_from(&[5, 4]).is_err());
+/// ```
+///
+/// `validate` is not supported on enums or unions, nor when deriving
+/// [`FromZeros`] or [`FromBytes`], which require every zeroed or initialized
+/// byte sequence (respectively) to be valid.
+///
+/// # Exhaustive enums
+///
+/// A field-less enum whose variants cover every possible value of its
+/// discriminant is valid for any initialized bytes. Such an enum can be
+/// annotated with `#[zerocopy(exhaustive)]`, in which case this derive
+/// additionally implements [`FromZeros`] and [`FromBytes`] for it. The enum
+/// must have a `repr` of `u8`, `i8`, `u16`, or `i16`, and it must have a
+/// variant for each of the 2<sup>8</sup> or 2<sup>16</sup> possible
+/// discriminants; otherwise, this derive emits an error. Since
+/// `#[zerocopy(exhaustive)]` implies `FromZeros` and `FromBytes`, those
+/// traits must not also be derived.
+///
+/// ```
+/// # use zerocopy::FromBytes as _;
+/// # use zerocopy_derive::{Immutable, KnownLayout, TryFromBytes};
+/// # macro_rules! define_byte_enum {
+/// #     ($($v:ident),*) => {
+/// #[derive(TryFromBytes, KnownLayout, Immutable, Debug, PartialEq)]
+/// #[zerocopy(exhaustive)]
+/// #[repr(u8)]
+/// enum Byte {
+///     V0,
+///     V1,
+///     // ...
+/// #   $($v,)*
+///     V255,
+/// }
+/// #     };
+/// # }
+/// # define_byte_enum!(
+/// #     V2, V3, V4, V5, V6, V7, V8, V9, V10, V11, V12, V13, V14, V15, V16, V17, V18, V19, V20, V21,
+/// #     V22, V23, V24, V25, V26, V27, V28, V29, V30, V31, V32, V33, V34, V35, V36, V37, V38, V39,
+/// #     V40, V41, V42, V43, V44, V45, V46, V47, V48, V49, V50, V51, V52, V53, V54, V55, V56, V57,
+/// #     V58, V59, V60, V61, V62, V63, V64, V65, V66, V67, V68, V69, V70, V71, V72, V73, V74, V75,
+/// #     V76, V77, V78, V79, V80, V81, V82, V83, V84, V85, V86, V87, V88, V89, V90, V91, V92, V93,
+/// #     V94, V95, V96, V97, V98, V99, V100, V101, V102, V103, V104, V105, V106, V107, V108, V109,
+/// #     V110, V111, V112, V113, V114, V115, V116, V117, V118, V119, V120, V121, V122, V123, V124,
+/// #     V125, V126, V127, V128, V129, V130, V131, V132, V133, V134, V135, V136, V137, V138, V139,
+/// #     V140, V141, V142, V143, V144, V145, V146, V147, V148, V149, V150, V151, V152, V153, V154,
+/// #     V155, V156, V157, V158, V159, V160, V161, V162, V163, V164, V165, V166, V167, V168, V169,
+/// #     V170, V171, V172, V173, V174, V175, V176, V177, V178, V179, V180, V181, V182, V183, V184,
+/// #     V185, V186, V187, V188, V189, V190, V191, V192, V193, V194, V195, V196, V197, V198, V199,
+/// #     V200, V201, V202, V203, V204, V205, V206, V207, V208, V209, V210, V211, V212, V213, V214,
+/// #     V215, V216, V217, V218, V219, V220, V221, V222, V223, V224, V225, V226, V227, V228, V229,
+/// #     V230, V231, V232, V233, V234, V235, V236, V237, V238, V239, V240, V241, V242, V243, V244,
+/// #     V245, V246, V247, V248, V249, V250, V251, V252, V253, V254
+/// # );
+///
+/// assert_eq!(Byte::read_from(&[255]), Ok(Byte::V255));
+/// ```
 #[cfg(any(feature = "derive", test))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "derive")))]
 pub use zerocopy_derive::TryFromBytes;
@@ -1358,6 +1655,143 @@ pub unsafe trait TryFromBytes {
         try_ref_from_prefix_suffix(candidate, CastType::Suffix, None).map(swap)
     }
 
+    /// Attempts to interpret the given `candidate` as a `&Self` with a DST
+    /// length equal to `count` without copying.
+    ///
+    /// This method verifies that `candidate.len()` is exactly the size of a
+    /// `Self` with `count` trailing elements, that `candidate` is aligned to
+    /// `align_of::<Self>()`, and that the bytes are a valid instance of `Self`,
+    /// including each of the `count` trailing elements. If any of these checks
+    /// fail, it returns `Err`, and the returned [`TryCastError`] identifies
+    /// which check failed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::{TryCastError, TryFromBytes};
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(TryFromBytes, KnownLayout, Immutable)]
+    /// #[repr(C)]
+    /// struct Flags {
+    ///     version: u8,
+    ///     flags: [bool],
+    /// }
+    ///
+    /// let bytes = &[1, 0, 1, 1][..];
+    /// let flags = Flags::try_ref_from_with_trailing_elements(bytes, 3).unwrap();
+    /// assert_eq!(flags.version, 1);
+    /// assert_eq!(flags.flags, [false, true, true]);
+    ///
+    /// // `bytes` is not the size of a `Flags` with 2 trailing elements.
+    /// let err = Flags::try_ref_from_with_trailing_elements(bytes, 2);
+    /// assert!(matches!(err, Err(TryCastError::Size(_))));
+    ///
+    /// // `2` is not a valid `bool`.
+    /// let bytes = &[1, 0, 2, 1][..];
+    /// let err = Flags::try_ref_from_with_trailing_elements(bytes, 3);
+    /// assert!(matches!(err, Err(TryCastError::Validity(_))));
+    /// ```
+    #[must_use = "has no side effects"]
+    #[inline]
+    fn try_ref_from_with_trailing_elements(
+        candidate: &[u8],
+        count: usize,
+    ) -> Result<&Self, TryCastError<&[u8], Self>>
+    where
+        Self: KnownLayout<PointerMetadata = usize> + Immutable,
+    {
+        match Ptr::from_ref(candidate)
+            .try_cast_into_no_leftover::<Self, BecauseImmutable>(Some(count))
+        {
+            Ok(candidate) => {
+                // This call may panic. If that happens, it doesn't cause any soundness
+                // issues, as we have not generated any invalid state which we need to
+                // fix before returning.
+                //
+                // Note that one panic or post-monomorphization error condition is
+                // calling `try_into_valid` (and thus `is_bit_valid`) with a shared
+                // pointer when `Self: !Immutable`. Since `Self: Immutable`, this panic
+                // condition will not happen.
+                match candidate.try_into_valid() {
+                    Ok(valid) => Ok(valid.as_ref()),
+                    Err(e) => {
+                        Err(e.map_src(|src| src.as_bytes::<BecauseImmutable>().as_ref()).into())
+                    }
+                }
+            }
+            Err(e) => Err(e.map_src(Ptr::as_ref).into()),
+        }
+    }
+
+    /// Attempts to interpret all of `candidate` as a `&[Self]` without
+    /// copying.
+    ///
+    /// This behaves like `<[Self]>::try_ref_from(candidate)`, except that if
+    /// `candidate` contains an invalid element, the returned [`ValidityError`]
+    /// records the index of the first such element, which is available via
+    /// [`ValidityError::invalid_index`] and is included in the error's
+    /// `Display` output. This makes it easier to locate corruption in large
+    /// buffers.
+    ///
+    /// If `candidate.len()` is not a multiple of `size_of::<Self>()`, or
+    /// `candidate` is not aligned to `align_of::<Self>()`, this returns `Err`.
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// This method cannot be used on zero-sized types. Attempting to use this
+    /// method on such types results in a compile-time assertion error; e.g.:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerocopy::*;
+    ///
+    /// let _ = <()>::try_ref_from_all(&[][..]); // ⚠ Compile Error!
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::{TryCastError, TryFromBytes};
+    ///
+    /// let bytes = &[0, 1, 1, 0][..];
+    /// let flags = bool::try_ref_from_all(bytes).unwrap();
+    /// assert_eq!(flags, [false, true, true, false]);
+    ///
+    /// // `2` is not a valid `bool`.
+    /// let bytes = &[0, 1, 2, 0, 3][..];
+    /// match bool::try_ref_from_all(bytes) {
+    ///     Err(TryCastError::Validity(e)) => assert_eq!(e.invalid_index(), Some(2)),
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    #[must_use = "has no side effects"]
+    #[inline]
+    fn try_ref_from_all(candidate: &[u8]) -> Result<&[Self], TryCastError<&[u8], [Self]>>
+    where
+        Self: Sized + KnownLayout + Immutable,
+    {
+        util::assert_dst_is_not_zst::<[Self]>();
+        match Ptr::from_ref(candidate).try_cast_into_no_leftover::<[Self], BecauseImmutable>(None) {
+            Ok(elems) => {
+                // This call may panic. If that happens, it doesn't cause any soundness
+                // issues, as we have not generated any invalid state which we need to
+                // fix before returning.
+                //
+                // Note that one panic or post-monomorphization error condition is
+                // calling `is_bit_valid` with a shared pointer when `Self:
+                // !Immutable`. Since `Self: Immutable`, this panic condition will
+                // not happen.
+                match elems.iter().position(|elem| !Self::is_bit_valid(elem.forget_aligned())) {
+                    Some(index) => Err(ValidityError::new(candidate).with_index(index).into()),
+                    // SAFETY: `is_bit_valid` returned `true` for every element
+                    // of `elems`, so `elems` contains a bit-valid `[Self]`.
+                    None => Ok(unsafe { elems.assume_valid() }.as_ref()),
+                }
+            }
+            Err(e) => Err(e.map_src(Ptr::as_ref).into()),
+        }
+    }
+
     /// Attempts to interpret the given `candidate` as a `&mut Self` without
     /// copying.
     ///
@@ -1627,6 +2061,168 @@ pub unsafe trait TryFromBytes {
         try_mut_from_prefix_suffix(candidate, CastType::Suffix, None).map(swap)
     }
 
+    /// Attempts to interpret the given `candidate` as a `&mut Self` with a DST
+    /// length equal to `count` without copying.
+    ///
+    /// This method verifies that `candidate.len()` is exactly the size of a
+    /// `Self` with `count` trailing elements, that `candidate` is aligned to
+    /// `align_of::<Self>()`, and that the bytes are a valid instance of `Self`,
+    /// including each of the `count` trailing elements. If any of these checks
+    /// fail, it returns `Err`, and the returned [`TryCastError`] identifies
+    /// which check failed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::{TryCastError, TryFromBytes};
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(TryFromBytes, KnownLayout)]
+    /// #[repr(C)]
+    /// struct Flags {
+    ///     version: u8,
+    ///     flags: [bool],
+    /// }
+    ///
+    /// let bytes = &mut [1, 0, 1, 1][..];
+    /// let flags = Flags::try_mut_from_with_trailing_elements(bytes, 3).unwrap();
+    /// assert_eq!(flags.flags, [false, true, true]);
+    /// flags.flags[0] = true;
+    /// assert_eq!(bytes, [1, 1, 1, 1]);
+    ///
+    /// // `bytes` is not the size of a `Flags` with 2 trailing elements.
+    /// let err = Flags::try_mut_from_with_trailing_elements(bytes, 2);
+    /// assert!(matches!(err, Err(TryCastError::Size(_))));
+    ///
+    /// // `2` is not a valid `bool`.
+    /// let bytes = &mut [1, 0, 2, 1][..];
+    /// let err = Flags::try_mut_from_with_trailing_elements(bytes, 3);
+    /// assert!(matches!(err, Err(TryCastError::Validity(_))));
+    /// ```
+    #[must_use = "has no side effects"]
+    #[inline]
+    fn try_mut_from_with_trailing_elements(
+        candidate: &mut [u8],
+        count: usize,
+    ) -> Result<&mut Self, TryCastError<&mut [u8], Self>>
+    where
+        Self: KnownLayout<PointerMetadata = usize>,
+    {
+        match Ptr::from_mut(candidate)
+            .try_cast_into_no_leftover::<Self, BecauseExclusive>(Some(count))
+        {
+            Ok(candidate) => {
+                // This call may panic. If that happens, it doesn't cause any soundness
+                // issues, as we have not generated any invalid state which we need to
+                // fix before returning.
+                //
+                // Note that one panic or post-monomorphization error condition is
+                // calling `try_into_valid` (and thus `is_bit_valid`) with a shared
+                // pointer when `Self: !Immutable`. Since `candidate` is an exclusive
+                // pointer, this panic condition will not happen.
+                match candidate.try_into_valid() {
+                    Ok(valid) => Ok(valid.as_mut()),
+                    Err(e) => {
+                        Err(e.map_src(|src| src.as_bytes::<BecauseExclusive>().as_mut()).into())
+                    }
+                }
+            }
+            Err(e) => Err(e.map_src(Ptr::as_mut).into()),
+        }
+    }
+
+    /// Attempts to interpret the prefix of the given `candidate` as a `&mut
+    /// Self` with a DST length equal to `count` without copying.
+    ///
+    /// This method computes the size of a `Self` with `count` trailing
+    /// elements, and attempts to interpret that many leading bytes of
+    /// `candidate` as a `Self`; any remaining bytes are returned to the caller.
+    /// If `candidate` is too short, or is not aligned to `align_of::<Self>()`,
+    /// or its leading bytes are not a valid instance of `Self` (including each
+    /// of the `count` trailing elements), this returns `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::{TryCastError, TryFromBytes};
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(TryFromBytes, KnownLayout)]
+    /// #[repr(C)]
+    /// struct Flags {
+    ///     version: u8,
+    ///     flags: [bool],
+    /// }
+    ///
+    /// let bytes = &mut [1, 0, 1, 7, 8][..];
+    /// let (flags, suffix) = Flags::try_mut_from_prefix_with_trailing_elements(bytes, 2).unwrap();
+    /// assert_eq!(flags.flags, [false, true]);
+    /// assert_eq!(suffix, [7, 8]);
+    /// flags.flags[1] = false;
+    /// suffix[0] = 0;
+    /// assert_eq!(bytes, [1, 0, 0, 0, 8]);
+    ///
+    /// // `2` is not a valid `bool`.
+    /// let bytes = &mut [1, 0, 2, 7, 8][..];
+    /// let err = Flags::try_mut_from_prefix_with_trailing_elements(bytes, 2);
+    /// assert!(matches!(err, Err(TryCastError::Validity(_))));
+    /// ```
+    #[must_use = "has no side effects"]
+    #[inline]
+    fn try_mut_from_prefix_with_trailing_elements(
+        candidate: &mut [u8],
+        count: usize,
+    ) -> Result<(&mut Self, &mut [u8]), TryCastError<&mut [u8], Self>>
+    where
+        Self: KnownLayout<PointerMetadata = usize>,
+    {
+        try_mut_from_prefix_suffix(candidate, CastType::Prefix, Some(count))
+    }
+
+    /// Attempts to interpret the suffix of the given `candidate` as a `&mut
+    /// Self` with a DST length equal to `count` without copying.
+    ///
+    /// This method computes the size of a `Self` with `count` trailing
+    /// elements, and attempts to interpret that many trailing bytes of
+    /// `candidate` as a `Self`; any preceding bytes are returned to the caller.
+    /// If `candidate` is too short, or its trailing bytes are not aligned to
+    /// `align_of::<Self>()` or are not a valid instance of `Self` (including
+    /// each of the `count` trailing elements), this returns `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::{TryCastError, TryFromBytes};
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(TryFromBytes, KnownLayout)]
+    /// #[repr(C)]
+    /// struct Flags {
+    ///     version: u8,
+    ///     flags: [bool],
+    /// }
+    ///
+    /// let bytes = &mut [7, 8, 1, 0, 1][..];
+    /// let (prefix, flags) = Flags::try_mut_from_suffix_with_trailing_elements(bytes, 2).unwrap();
+    /// assert_eq!(prefix, [7, 8]);
+    /// assert_eq!(flags.flags, [false, true]);
+    ///
+    /// // `bytes` is too short for a `Flags` with 5 trailing elements.
+    /// let err = Flags::try_mut_from_suffix_with_trailing_elements(bytes, 5);
+    /// assert!(matches!(err, Err(TryCastError::Size(_))));
+    /// ```
+    #[must_use = "has no side effects"]
+    #[inline]
+    fn try_mut_from_suffix_with_trailing_elements(
+        candidate: &mut [u8],
+        count: usize,
+    ) -> Result<(&mut [u8], &mut Self), TryCastError<&mut [u8], Self>>
+    where
+        Self: KnownLayout<PointerMetadata = usize>,
+    {
+        try_mut_from_prefix_suffix(candidate, CastType::Suffix, Some(count)).map(swap)
+    }
+
     /// Attempts to read the given `candidate` as a `Self`.
     ///
     /// If the bytes of `candidate` are a valid instance of `Self`, reads those
@@ -1891,6 +2487,59 @@ pub unsafe trait FromZeros: TryFromBytes {
         unsafe { ptr::write_bytes(slf.cast::<u8>(), 0, len) };
     }
 
+    /// Overwrites `self` with zeros in a way the compiler will not elide.
+    ///
+    /// Like [`zero`], this sets every byte in `self` to 0. Unlike `zero`, the
+    /// bytes are written with [volatile writes] and followed by a
+    /// [`compiler_fence`], so the compiler may not optimize away the zeroing
+    /// even if `self` is never read again - for example, if it is dropped or
+    /// goes out of scope immediately afterwards. This makes `zero_secure`
+    /// suitable for clearing secrets such as keys or passwords from memory.
+    ///
+    /// This is a best-effort measure, not a hardware guarantee: it does not
+    /// clear copies of `self` which have been made elsewhere (for example,
+    /// when `self` was moved, or spilled to the stack by the compiler), and it
+    /// does not flush CPU caches or prevent memory from being swapped to disk.
+    /// Since each byte is written individually, `zero_secure` is also slower
+    /// than `zero`.
+    ///
+    /// [`zero`]: FromZeros::zero
+    /// [volatile writes]: core::ptr::write_volatile
+    /// [`compiler_fence`]: core::sync::atomic::compiler_fence
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::FromZeros;
+    ///
+    /// let mut key = [0x42u8; 32];
+    /// // ... use `key` ...
+    /// key.zero_secure();
+    /// assert_eq!(key, [0; 32]);
+    /// ```
+    #[inline]
+    fn zero_secure(&mut self) {
+        let slf: *mut Self = self;
+        let len = mem::size_of_val(self);
+        let bytes = slf.cast::<u8>();
+        for i in 0..len {
+            // SAFETY:
+            // - `i < len`, and `self` is guaranteed by the type system to be
+            //   valid for writes of size `size_of_val(self)`, so `bytes.add(i)`
+            //   is in bounds of `self` and is valid for writes of a `u8`.
+            // - `u8`'s alignment is 1, and thus `bytes.add(i)` is guaranteed to
+            //   be aligned as required by `u8`.
+            // - Since `Self: FromZeros`, the all-zeros instance is a valid
+            //   instance of `Self`. While `self` is only partially zeroed
+            //   during this loop, it is not observable until the loop
+            //   completes.
+            unsafe { ptr::write_volatile(bytes.add(i), 0) };
+        }
+        // Prevent the compiler from reordering later memory operations (such
+        // as deallocating `self`) before the volatile writes above.
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+    }
+
     /// Creates an instance of `Self` from zeroed bytes.
     ///
     /// # Examples
@@ -1915,6 +2564,9 @@ pub unsafe trait FromZeros: TryFromBytes {
     /// assert_eq!(header.length, [0, 0]);
     /// assert_eq!(header.checksum, [0, 0]);
     /// ```
+    ///
+    /// To construct a zeroed value in a `const` context, use the [`zeroed!`]
+    /// macro instead.
     #[must_use = "has no side effects"]
     #[inline(always)]
     fn new_zeroed() -> Self
@@ -2040,7 +2692,101 @@ pub unsafe trait FromZeros: TryFromBytes {
         }
     }
 
-    /// Creates a `Vec<Self>` from zeroed bytes.
+    /// Creates a `Box<Self>` from zeroed bytes, where `Self` is a slice DST
+    /// with `count` trailing slice elements.
+    ///
+    /// This is the slice DST analogue of [`new_box_zeroed`]: the value is
+    /// allocated directly on the heap and zero-initialized, without ever
+    /// being constructed on the stack. The size of the allocation is that of a
+    /// `Self` with `count` trailing elements, including any trailing padding.
+    ///
+    /// If that size is zero, then this function will return a `Box<Self>`
+    /// without allocating.
+    ///
+    /// [`new_box_zeroed`]: FromZeros::new_box_zeroed
+    ///
+    /// # Panics
+    ///
+    /// * Panics if the size of a `Self` with `count` trailing elements
+    ///   overflows `usize` or `isize`.
+    /// * Panics if allocation of that many bytes fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::FromZeros;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(FromZeros, KnownLayout)]
+    /// #[repr(C)]
+    /// struct Samples {
+    ///     rate: u32,
+    ///     data: [u16],
+    /// }
+    ///
+    /// let samples = Samples::new_box_zeroed_with_elems(1024);
+    /// assert_eq!(samples.rate, 0);
+    /// assert_eq!(samples.data.len(), 1024);
+    /// assert!(samples.data.iter().all(|&s| s == 0));
+    /// ```
+    #[must_use = "has no side effects (other than allocation)"]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn new_box_zeroed_with_elems(count: usize) -> Box<Self>
+    where
+        Self: KnownLayout<PointerMetadata = usize>,
+    {
+        let size = count
+            .size_for_metadata(Self::LAYOUT)
+            .expect("size of `Self` with `count` trailing elements overflows `usize`");
+        let align = Self::LAYOUT.align.get();
+        // On stable Rust versions <= 1.64.0, `Layout::from_size_align` has a
+        // bug in which sufficiently-large allocations (those which, when
+        // rounded up to the alignment, overflow `isize`) are not rejected,
+        // which can cause undefined behavior. See #64 for details.
+        //
+        // TODO(#67): Once our MSRV is > 1.64.0, remove this assertion.
+        #[allow(clippy::as_conversions)]
+        let max_alloc = (isize::MAX as usize).saturating_sub(align);
+        assert!(size <= max_alloc);
+        let layout =
+            Layout::from_size_align(size, align).expect("total allocation size overflows `isize`");
+
+        let ptr = if layout.size() != 0 {
+            // SAFETY: `layout` has a non-zero size.
+            let ptr = unsafe { alloc::alloc::alloc_zeroed(layout) };
+            match NonNull::new(ptr) {
+                Some(ptr) => ptr,
+                None => alloc::alloc::handle_alloc_error(layout),
+            }
+        } else {
+            // `Box<Self>` does not allocate when `Self` is zero-sized, but it
+            // does require a non-null, well-aligned dangling pointer for its
+            // allocation. `NonNull::<u8>::dangling()` has address 1, so
+            // offsetting it by `align - 1` bytes produces a dangling pointer
+            // with address `align`.
+            let dangling = NonNull::<u8>::dangling().as_ptr().wrapping_add(align.wrapping_sub(1));
+            // SAFETY: `dangling` has address `align`, which is non-zero.
+            unsafe { NonNull::new_unchecked(dangling) }
+        };
+
+        let ptr = Self::raw_from_ptr_len(ptr, count);
+        // SAFETY:
+        // - By postcondition on `raw_from_ptr_len`, `ptr` has the same address
+        //   as the allocation (or dangling pointer) above, and refers to a
+        //   `Self` with `count` trailing elements. By invariant on
+        //   `KnownLayout::LAYOUT`, such a `Self` has size `size` and alignment
+        //   `align`, and so `ptr` refers to memory allocated by the global
+        //   allocator with layout `layout` (or, if `size` is zero, is a
+        //   well-aligned dangling pointer, which `Box` permits for zero-sized
+        //   referents).
+        // - The memory was zero-initialized, and `Self: FromZeros`, so it
+        //   contains a valid `Self`.
+        unsafe { Box::from_raw(ptr.as_ptr()) }
+    }
+
+    /// Creates a `Vec<Self>` from zeroed bytes.
     ///
     /// This function is useful for allocating large values of `Vec`s and
     /// zero-initializing them, without ever creating a temporary instance of
@@ -2125,6 +2871,38 @@ pub unsafe trait FromZeros: TryFromBytes {
 ///
 /// [safety conditions]: trait@FromBytes#safety
 ///
+/// # Byte array conversions
+///
+/// If the annotated type is also annotated with `#[zerocopy(byte_array_conv)]`,
+/// this derive additionally emits an implementation of `From<[u8; N]>` for the
+/// type, where `N` is the size of the type. The length can also be given
+/// explicitly as `#[zerocopy(byte_array_conv(N))]`; if `N` is not equal to the
+/// size of the type, compilation fails. Combined with
+/// [`derive(IntoBytes)`][derive@IntoBytes], which emits the reverse conversion,
+/// this makes it possible to convert a type to and from a byte array without
+/// spelling out `transmute!`:
+///
+/// ```
+/// # use zerocopy_derive::{FromBytes, IntoBytes};
+/// #[derive(FromBytes, IntoBytes)]
+/// #[zerocopy(byte_array_conv(4))]
+/// #[repr(C)]
+/// struct Rgba {
+///     r: u8,
+///     g: u8,
+///     b: u8,
+///     a: u8,
+/// }
+///
+/// let color = Rgba::from([0xFF, 0x80, 0x00, 0xFF]);
+/// assert_eq!(color.g, 0x80);
+///
+/// let bytes: [u8; 4] = color.into();
+/// assert_eq!(bytes, [0xFF, 0x80, 0x00, 0xFF]);
+/// ```
+///
+/// `byte_array_conv` is not supported on generic types.
+///
 /// # Analysis
 ///
 /// *This section describes, roughly, the analysis performed by this derive to
@@ -2346,6 +3124,97 @@ pub unsafe trait FromBytes: FromZeros {
         }
     }
 
+    /// Interprets the given `bytes` as a `&Self`, requiring that they be aligned
+    /// to at least `ALIGN`.
+    ///
+    /// This behaves like [`ref_from`], except that it additionally requires
+    /// that the address of `bytes` be a multiple of `ALIGN`, which may be
+    /// greater than `align_of::<Self>()`. This is useful when hardware imposes
+    /// a stricter alignment on a buffer than its type does - e.g., when a DMA
+    /// descriptor must be aligned to a cache line. If `bytes` is not aligned to
+    /// `ALIGN`, this returns an [`AlignmentError`] whose [`required_align`] is
+    /// `ALIGN`.
+    ///
+    /// [`ref_from`]: FromBytes::ref_from
+    /// [`required_align`]: AlignmentError::required_align
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// `ALIGN` must be a power of two. Attempting to use this method with any
+    /// other `ALIGN` results in a compile-time assertion error; e.g.:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerocopy::FromBytes;
+    ///
+    /// let _ = u8::ref_from_at_least_aligned::<3>(&[0]); // ⚠ Compile Error!
+    /// ```
+    ///
+    /// Like [`ref_from`], this method cannot yet be used on unsized types whose
+    /// dynamically-sized component is zero-sized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::{error::CastError, FromBytes};
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(FromBytes, KnownLayout, Immutable)]
+    /// #[repr(C)]
+    /// struct Descriptor {
+    ///     addr: [u8; 8],
+    ///     len: [u8; 4],
+    ///     flags: [u8; 4],
+    /// }
+    ///
+    /// #[repr(C, align(64))]
+    /// struct CacheLine([u8; 128]);
+    ///
+    /// let buf = CacheLine([0; 128]);
+    ///
+    /// // `buf` is aligned to 64 bytes.
+    /// let desc = Descriptor::ref_from_at_least_aligned::<64>(&buf.0[..16]);
+    /// assert!(desc.is_ok());
+    ///
+    /// // `buf.0[16..]` is only aligned to 16 bytes.
+    /// let desc = Descriptor::ref_from_at_least_aligned::<64>(&buf.0[16..32]);
+    /// match desc {
+    ///     Err(CastError::Alignment(err)) => assert_eq!(err.required_align(), 64),
+    ///     _ => panic!("expected an alignment error"),
+    /// }
+    /// ```
+    #[must_use = "has no side effects"]
+    #[inline]
+    fn ref_from_at_least_aligned<const ALIGN: usize>(
+        bytes: &[u8],
+    ) -> Result<&Self, CastError<&[u8], Self>>
+    where
+        Self: KnownLayout + Immutable,
+    {
+        trait ConstAssert<const ALIGN: usize> {
+            const ALIGN_IS_POWER_OF_TWO: bool = {
+                let align_is_power_of_two = ALIGN.is_power_of_two();
+                const_assert!(align_is_power_of_two);
+                align_is_power_of_two
+            };
+        }
+
+        impl<T: ?Sized, const ALIGN: usize> ConstAssert<ALIGN> for T {}
+
+        const_assert!(<Self as ConstAssert<ALIGN>>::ALIGN_IS_POWER_OF_TWO);
+
+        // We just asserted that `ALIGN` is a power of two, and thus non-zero,
+        // so this mod operation will not panic.
+        #[allow(clippy::arithmetic_side_effects)]
+        let remainder = util::AsAddress::addr(bytes) % ALIGN;
+        if remainder != 0 {
+            // SAFETY: We just asserted that `ALIGN` is a power of two, and thus
+            // non-zero.
+            let align = unsafe { NonZeroUsize::new_unchecked(ALIGN) };
+            return Err(AlignmentError::with_align(bytes, align).into());
+        }
+        Self::ref_from(bytes)
+    }
+
     /// Interprets the prefix of the given `bytes` as a `&Self` without copying.
     ///
     /// This method returns both a reference to the first `size_of::<Self>()`
@@ -2480,6 +3349,80 @@ pub unsafe trait FromBytes: FromZeros {
         ref_from_prefix_suffix(bytes, None, CastType::Suffix).map(swap)
     }
 
+    /// Interprets the given `bytes` as a sequence of `&Self`s without copying.
+    ///
+    /// This returns an iterator which yields a `&Self` for each consecutive
+    /// `size_of::<Self>()`-byte chunk of `bytes`, like [`slice::chunks_exact`].
+    /// If `bytes.len()` is not a multiple of `size_of::<Self>()`, the final item
+    /// is instead a [`SizeError`] containing the trailing partial chunk.
+    ///
+    /// The alignment of `bytes` is checked once, up front: if `bytes` is not
+    /// aligned to `align_of::<Self>()`, this returns `Err`. Since
+    /// `size_of::<Self>()` is a multiple of `align_of::<Self>()`, every chunk
+    /// of a well-aligned `bytes` is also well-aligned.
+    ///
+    /// [`slice::chunks_exact`]: https://doc.rust-lang.org/std/primitive.slice.html#method.chunks_exact
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// This method cannot be used on zero-sized types. Attempting to use this
+    /// method on such types results in a compile-time assertion error; e.g.:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerocopy::FromBytes;
+    ///
+    /// let _ = <()>::ref_from_chunks(&[]); // ⚠ Compile Error!
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::FromBytes;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(FromBytes, Immutable, Debug, PartialEq)]
+    /// #[repr(C)]
+    /// struct Record {
+    ///     id: u8,
+    ///     value: [u8; 2],
+    /// }
+    ///
+    /// // These bytes encode two `Record`s, followed by a partial record.
+    /// let bytes = &[0, 1, 2, 3, 4, 5, 6][..];
+    ///
+    /// let mut records = Record::ref_from_chunks(bytes).unwrap();
+    ///
+    /// assert_eq!(records.next().unwrap().unwrap(), &Record { id: 0, value: [1, 2] });
+    /// assert_eq!(records.next().unwrap().unwrap(), &Record { id: 3, value: [4, 5] });
+    /// assert_eq!(records.next().unwrap().unwrap_err().into_src(), [6]);
+    /// assert!(records.next().is_none());
+    /// ```
+    #[must_use = "has no side effects"]
+    #[inline]
+    fn ref_from_chunks(bytes: &[u8]) -> Result<RefChunks<'_, Self>, AlignmentError<&[u8], Self>>
+    where
+        Self: Sized + Immutable,
+    {
+        trait ConstAssert: Sized {
+            const SIZE_IS_NON_ZERO: bool = {
+                let size_is_non_zero = mem::size_of::<Self>() != 0;
+                const_assert!(size_is_non_zero);
+                size_is_non_zero
+            };
+        }
+
+        impl<T> ConstAssert for T {}
+
+        const_assert!(<Self as ConstAssert>::SIZE_IS_NON_ZERO);
+
+        if !util::aligned_to::<_, Self>(bytes) {
+            return Err(AlignmentError::new(bytes));
+        }
+        // SAFETY: We just checked that `bytes` is validly-aligned for `Self`,
+        // and asserted that `size_of::<Self>()` is non-zero.
+        Ok(unsafe { RefChunks::new_unchecked(bytes) })
+    }
+
     /// Interprets the given `bytes` as a `&mut Self` without copying.
     ///
     /// If `bytes.len() != size_of::<Self>()` or `bytes` is not aligned to
@@ -2837,6 +3780,188 @@ pub unsafe trait FromBytes: FromZeros {
         ref_from_prefix_suffix(bytes, Some(count), CastType::Prefix)
     }
 
+    /// Interprets the prefix of the given `bytes` as a `&Self` with a DST
+    /// length equal to `count` without copying, and returns the number of
+    /// bytes it occupies.
+    ///
+    /// This behaves like [`ref_from_prefix_with_trailing_elements`], but
+    /// additionally returns the size of the returned `&Self` in bytes. This
+    /// is the number of bytes consumed from the front of `bytes`, including
+    /// any trailing padding, and so is convenient for advancing a cursor when
+    /// chaining parsers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::FromBytes;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(FromBytes, KnownLayout, Immutable)]
+    /// #[repr(C, align(2))]
+    /// struct Record {
+    ///     kind: u8,
+    ///     payload: [u8],
+    /// }
+    ///
+    /// # #[repr(C, align(2))] struct Aligned([u8; 6]);
+    /// # let aligned = Aligned([1, 2, 3, 4, 5, 6]);
+    /// # let bytes = &aligned.0[..];
+    /// // Although a `Record` with 2 payload bytes only needs 3 bytes, it is
+    /// // padded to a multiple of its alignment.
+    /// let (record, size, rest) =
+    ///     Record::ref_from_prefix_with_trailing_elements_and_size(bytes, 2).unwrap();
+    /// assert_eq!(record.payload, [2, 3]);
+    /// assert_eq!(size, 4);
+    /// assert_eq!(rest, &[5, 6]);
+    /// ```
+    ///
+    /// [`ref_from_prefix_with_trailing_elements`]: FromBytes::ref_from_prefix_with_trailing_elements
+    #[must_use = "has no side effects"]
+    #[inline]
+    fn ref_from_prefix_with_trailing_elements_and_size(
+        bytes: &[u8],
+        count: usize,
+    ) -> Result<(&Self, usize, &[u8]), CastError<&[u8], Self>>
+    where
+        Self: KnownLayout<PointerMetadata = usize> + Immutable,
+    {
+        let (slf, rest) = Self::ref_from_prefix_with_trailing_elements(bytes, count)?;
+        Ok((slf, size_of_val(slf), rest))
+    }
+
+    /// Interprets the prefix of the given `bytes` as a `&Self` whose DST
+    /// length is computed from a header at the start of `bytes`, without
+    /// copying.
+    ///
+    /// This encapsulates the common pattern of reading a length field out of a
+    /// header, and then interpreting a structure with that many trailing
+    /// elements. It reads a `H` from the prefix of `bytes`, passes it to
+    /// `count` to compute the number of trailing elements, and then behaves
+    /// like [`ref_from_prefix_with_trailing_elements`] with that count.
+    /// Typically, `H` is the leading, sized portion of `Self`.
+    ///
+    /// The header is copied out of `bytes` before being passed to `count`, and
+    /// so `bytes` need not be aligned to `align_of::<H>()` (although it must
+    /// still be aligned to `align_of::<Self>()`). If `bytes` is too short to
+    /// contain a `H`, or if it is too short to contain a `Self` with the
+    /// computed number of trailing elements, this returns `Err`. In particular,
+    /// a count from an untrusted header which is so large that the size of
+    /// `Self` would overflow `usize` results in an error rather than a panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::{byteorder::big_endian::U16, FromBytes};
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(FromBytes, KnownLayout, Immutable, Unaligned)]
+    /// #[repr(C)]
+    /// struct Header {
+    ///     kind: u8,
+    ///     len: U16,
+    /// }
+    ///
+    /// #[derive(FromBytes, KnownLayout, Immutable, Unaligned)]
+    /// #[repr(C)]
+    /// struct Message {
+    ///     header: Header,
+    ///     body: [u8],
+    /// }
+    ///
+    /// let bytes = &[1, 0, 3, b'a', b'b', b'c', 0xFF][..];
+    ///
+    /// let (msg, rest) = Message::ref_from_prefix_with_trailing_elements_from_header(
+    ///     bytes,
+    ///     |h: &Header| usize::from(h.len.get()),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(msg.header.kind, 1);
+    /// assert_eq!(msg.body, *b"abc");
+    /// assert_eq!(rest, [0xFF]);
+    ///
+    /// // The header claims more body bytes than there are.
+    /// let bytes = &[1, 0, 9, b'a', b'b', b'c'][..];
+    /// assert!(Message::ref_from_prefix_with_trailing_elements_from_header(
+    ///     bytes,
+    ///     |h: &Header| usize::from(h.len.get()),
+    /// )
+    /// .is_err());
+    /// ```
+    ///
+    /// [`ref_from_prefix_with_trailing_elements`]: FromBytes::ref_from_prefix_with_trailing_elements
+    #[must_use = "has no side effects"]
+    #[inline]
+    fn ref_from_prefix_with_trailing_elements_from_header<H, F>(
+        bytes: &[u8],
+        count: F,
+    ) -> Result<(&Self, &[u8]), CastError<&[u8], Self>>
+    where
+        Self: KnownLayout<PointerMetadata = usize> + Immutable,
+        H: FromBytes,
+        F: FnOnce(&H) -> usize,
+    {
+        let header = match H::read_from_prefix(bytes) {
+            Ok(header) => header,
+            Err(_) => return Err(CastError::Size(SizeError::new(bytes))),
+        };
+        // If `count` is so large that the size of `Self` overflows `usize`,
+        // this returns a `SizeError` rather than panicking.
+        Self::ref_from_prefix_with_trailing_elements(bytes, count(&header))
+    }
+
+    /// Interprets the prefix of the given `bytes` as a `&[Self; N]` without
+    /// copying.
+    ///
+    /// This method verifies that `bytes.len() >= N * size_of::<Self>()` and
+    /// that `bytes` is aligned to `align_of::<Self>()`. It reinterprets the
+    /// first `N * size_of::<Self>()` bytes from `bytes` to construct a `&[Self;
+    /// N]`, and returns the remaining bytes to the caller. If either the length
+    /// or alignment check fails, it returns `Err`.
+    ///
+    /// Unlike [`ref_from_prefix_with_trailing_elements`], the number of
+    /// elements is known at compile time, and so the returned reference is to
+    /// an array rather than to a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::FromBytes;
+    /// # use zerocopy_derive::*;
+    ///
+    /// # #[derive(Debug, PartialEq, Eq)]
+    /// #[derive(FromBytes, Immutable)]
+    /// #[repr(C)]
+    /// struct Entry {
+    ///     key: u8,
+    ///     value: u8,
+    /// }
+    ///
+    /// // These are more bytes than are needed to encode four `Entry`s.
+    /// let bytes = &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9][..];
+    ///
+    /// let (entries, rest) = Entry::ref_from_prefix_array::<4>(bytes).unwrap();
+    ///
+    /// assert_eq!(entries, &[
+    ///     Entry { key: 0, value: 1 },
+    ///     Entry { key: 2, value: 3 },
+    ///     Entry { key: 4, value: 5 },
+    ///     Entry { key: 6, value: 7 },
+    /// ]);
+    /// assert_eq!(rest, &[8, 9]);
+    /// ```
+    ///
+    /// [`ref_from_prefix_with_trailing_elements`]: FromBytes::ref_from_prefix_with_trailing_elements
+    #[must_use = "has no side effects"]
+    #[inline]
+    fn ref_from_prefix_array<const N: usize>(
+        bytes: &[u8],
+    ) -> Result<(&[Self; N], &[u8]), CastError<&[u8], [Self; N]>>
+    where
+        Self: Sized + Immutable,
+    {
+        <[Self; N]>::ref_from_prefix(bytes)
+    }
+
     #[deprecated(
         since = "0.8.0",
         note = "renamed to `FromBytes::from_prefix_with_trailing_elements`"
@@ -3101,6 +4226,59 @@ pub unsafe trait FromBytes: FromZeros {
         mut_from_prefix_suffix(bytes, Some(count), CastType::Prefix)
     }
 
+    /// Interprets the prefix of the given `bytes` as a `&mut [Self; N]` without
+    /// copying.
+    ///
+    /// This method verifies that `bytes.len() >= N * size_of::<Self>()` and
+    /// that `bytes` is aligned to `align_of::<Self>()`. It reinterprets the
+    /// first `N * size_of::<Self>()` bytes from `bytes` to construct a `&mut
+    /// [Self; N]`, and returns the remaining bytes to the caller. If either the
+    /// length or alignment check fails, it returns `Err`.
+    ///
+    /// Unlike [`mut_from_prefix_with_trailing_elements`], the number of
+    /// elements is known at compile time, and so the returned reference is to
+    /// an array rather than to a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::FromBytes;
+    /// # use zerocopy_derive::*;
+    ///
+    /// # #[derive(Debug, PartialEq, Eq)]
+    /// #[derive(FromBytes, IntoBytes, Immutable)]
+    /// #[repr(C)]
+    /// struct Entry {
+    ///     key: u8,
+    ///     value: u8,
+    /// }
+    ///
+    /// // These are more bytes than are needed to encode two `Entry`s.
+    /// let bytes = &mut [0, 1, 2, 3, 4, 5][..];
+    ///
+    /// let (entries, rest) = Entry::mut_from_prefix_array::<2>(bytes).unwrap();
+    ///
+    /// assert_eq!(entries, &[Entry { key: 0, value: 1 }, Entry { key: 2, value: 3 }]);
+    /// assert_eq!(rest, &[4, 5]);
+    ///
+    /// entries[1].value = 0xFF;
+    /// rest.fill(0);
+    ///
+    /// assert_eq!(bytes, [0, 1, 2, 0xFF, 0, 0]);
+    /// ```
+    ///
+    /// [`mut_from_prefix_with_trailing_elements`]: FromBytes::mut_from_prefix_with_trailing_elements
+    #[must_use = "has no side effects"]
+    #[inline]
+    fn mut_from_prefix_array<const N: usize>(
+        bytes: &mut [u8],
+    ) -> Result<(&mut [Self; N], &mut [u8]), CastError<&mut [u8], [Self; N]>>
+    where
+        Self: Sized + IntoBytes,
+    {
+        <[Self; N]>::mut_from_prefix(bytes)
+    }
+
     #[deprecated(
         since = "0.8.0",
         note = "renamed to `FromBytes::mut_from_prefix_with_trailing_elements`"
@@ -3205,18 +4383,216 @@ pub unsafe trait FromBytes: FromZeros {
         <[Self]>::mut_from_suffix_with_trailing_elements(bytes, count).ok()
     }
 
-    /// Reads a copy of `Self` from `bytes`.
+    /// Interprets the given `bytes` as a `&Self` without copying, and without
+    /// checking size or alignment.
     ///
-    /// If `bytes.len() != size_of::<Self>()`, `read_from` returns `Err`.
+    /// `ref_from_unchecked` is like [`ref_from`], but skips its size and
+    /// alignment checks. It is intended for hot paths in which those checks
+    /// have already been performed by other means. Most callers should prefer
+    /// [`ref_from`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that [`ref_from`] would return `Ok` if called
+    /// with `bytes`. In particular:
+    /// - `bytes` must be aligned to `align_of::<Self>()`
+    /// - If `Self: Sized`, `bytes.len()` must be equal to `size_of::<Self>()`
+    /// - If `Self` is a slice DST, `bytes.len()` must be a valid size for
+    ///   `Self` with some number of trailing slice elements; the number of
+    ///   elements is computed the same way as in [`ref_from`]
+    ///
+    /// In debug builds, these preconditions are checked, and a violation
+    /// results in a panic.
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// Like [`ref_from`], this method cannot yet be used on unsized types whose
+    /// dynamically-sized component is zero-sized.
     ///
     /// # Examples
     ///
     /// ```
     /// use zerocopy::FromBytes;
-    /// # use zerocopy_derive::*;
     ///
-    /// #[derive(FromBytes)]
-    /// #[repr(C)]
+    /// let bytes = &[0u8, 1, 2, 3][..];
+    /// // SAFETY: `[u8; 4]` has alignment 1 and size 4.
+    /// let arr = unsafe { <[u8; 4]>::ref_from_unchecked(bytes) };
+    /// assert_eq!(arr, &[0, 1, 2, 3]);
+    /// ```
+    ///
+    /// [`ref_from`]: FromBytes::ref_from
+    #[must_use = "has no side effects"]
+    #[inline]
+    unsafe fn ref_from_unchecked(bytes: &[u8]) -> &Self
+    where
+        Self: KnownLayout + Immutable,
+    {
+        debug_assert!(Self::ref_from(bytes).is_ok());
+        let meta = util::elems_for_valid_len::<Self>(bytes.len());
+        let ptr = Self::raw_from_ptr_len(NonNull::from(bytes).cast::<u8>(), meta);
+        // SAFETY:
+        // - `raw_from_ptr_len` returns a pointer with the same address and
+        //   provenance as `bytes`. The caller promises that `bytes` is aligned
+        //   to `Self`'s alignment, and that `bytes.len()` is a valid size for
+        //   `Self`. By postcondition on `elems_for_valid_len`, `meta` describes
+        //   an instance of `Self` whose size is `bytes.len()`. Thus, `ptr`
+        //   addresses exactly the bytes of `bytes`.
+        // - `Self: FromBytes`, so the initialized bytes of `bytes` are a valid
+        //   `Self`.
+        // - `Self: Immutable` and `bytes` is a shared reference, so the
+        //   returned reference cannot be used to violate `bytes`'s aliasing.
+        // - The returned reference has the same lifetime as `bytes`.
+        unsafe { ptr.as_ref() }
+    }
+
+    /// Interprets the given `bytes` as a `&mut Self` without copying, and
+    /// without checking size or alignment.
+    ///
+    /// `mut_from_unchecked` is like [`mut_from`], but skips its size and
+    /// alignment checks. Most callers should prefer [`mut_from`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that [`mut_from`] would return `Ok` if called
+    /// with `bytes`. The requirements are the same as for
+    /// [`ref_from_unchecked`].
+    ///
+    /// In debug builds, these preconditions are checked, and a violation
+    /// results in a panic.
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// Like [`mut_from`], this method cannot yet be used on unsized types whose
+    /// dynamically-sized component is zero-sized.
+    ///
+    /// [`mut_from`]: FromBytes::mut_from
+    /// [`ref_from_unchecked`]: FromBytes::ref_from_unchecked
+    #[must_use = "has no side effects"]
+    #[inline]
+    unsafe fn mut_from_unchecked(bytes: &mut [u8]) -> &mut Self
+    where
+        Self: IntoBytes + KnownLayout,
+    {
+        debug_assert!(Self::mut_from(bytes).is_ok());
+        let meta = util::elems_for_valid_len::<Self>(bytes.len());
+        let mut ptr = Self::raw_from_ptr_len(NonNull::from(bytes).cast::<u8>(), meta);
+        // SAFETY:
+        // - As in `ref_from_unchecked`, `ptr` addresses exactly the bytes of
+        //   `bytes`, is validly aligned for `Self`, and has the same
+        //   provenance as `bytes`.
+        // - `Self: FromBytes`, so the initialized bytes of `bytes` are a valid
+        //   `Self`; `Self: IntoBytes`, so any `Self` written through the
+        //   returned reference leaves `bytes` initialized.
+        // - `bytes` is an exclusive reference, which the returned reference
+        //   replaces for the same lifetime.
+        unsafe { ptr.as_mut() }
+    }
+
+    /// Interprets the given `bytes` as a `&Self` with a DST length equal to
+    /// `count`, without copying and without checking size or alignment.
+    ///
+    /// `ref_from_with_trailing_elements_unchecked` is like
+    /// [`ref_from_with_trailing_elements`], but skips its size and alignment
+    /// checks. Most callers should prefer
+    /// [`ref_from_with_trailing_elements`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that [`ref_from_with_trailing_elements`] would
+    /// return `Ok` if called with `bytes` and `count`. In particular:
+    /// - `bytes` must be aligned to `align_of::<Self>()`
+    /// - `bytes.len()` must be equal to the size of a `Self` with `count`
+    ///   trailing slice elements, including any trailing padding
+    ///
+    /// In debug builds, these preconditions are checked, and a violation
+    /// results in a panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::FromBytes;
+    ///
+    /// let bytes = &[0u8, 1, 2, 3][..];
+    /// // SAFETY: `[[u8; 2]]` has alignment 1, and two `[u8; 2]`s occupy 4
+    /// // bytes.
+    /// let pairs = unsafe { <[[u8; 2]]>::ref_from_with_trailing_elements_unchecked(bytes, 2) };
+    /// assert_eq!(pairs, &[[0, 1], [2, 3]]);
+    /// ```
+    ///
+    /// [`ref_from_with_trailing_elements`]: FromBytes::ref_from_with_trailing_elements
+    #[must_use = "has no side effects"]
+    #[inline]
+    unsafe fn ref_from_with_trailing_elements_unchecked(bytes: &[u8], count: usize) -> &Self
+    where
+        Self: KnownLayout<PointerMetadata = usize> + Immutable,
+    {
+        debug_assert!(Self::ref_from_with_trailing_elements(bytes, count).is_ok());
+        let ptr = Self::raw_from_ptr_len(NonNull::from(bytes).cast::<u8>(), count);
+        // SAFETY:
+        // - `raw_from_ptr_len` returns a pointer with the same address and
+        //   provenance as `bytes`, whose referent has `count` trailing
+        //   elements. The caller promises that `bytes` is aligned to `Self`'s
+        //   alignment, and that `bytes.len()` is the size of such a referent.
+        //   Thus, `ptr` addresses exactly the bytes of `bytes`.
+        // - `Self: FromBytes`, so the initialized bytes of `bytes` are a valid
+        //   `Self`.
+        // - `Self: Immutable` and `bytes` is a shared reference, so the
+        //   returned reference cannot be used to violate `bytes`'s aliasing.
+        // - The returned reference has the same lifetime as `bytes`.
+        unsafe { ptr.as_ref() }
+    }
+
+    /// Interprets the given `bytes` as a `&mut Self` with a DST length equal to
+    /// `count`, without copying and without checking size or alignment.
+    ///
+    /// `mut_from_with_trailing_elements_unchecked` is like
+    /// [`mut_from_with_trailing_elements`], but skips its size and alignment
+    /// checks. Most callers should prefer
+    /// [`mut_from_with_trailing_elements`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that [`mut_from_with_trailing_elements`] would
+    /// return `Ok` if called with `bytes` and `count`. The requirements are the
+    /// same as for [`ref_from_with_trailing_elements_unchecked`].
+    ///
+    /// In debug builds, these preconditions are checked, and a violation
+    /// results in a panic.
+    ///
+    /// [`mut_from_with_trailing_elements`]: FromBytes::mut_from_with_trailing_elements
+    /// [`ref_from_with_trailing_elements_unchecked`]: FromBytes::ref_from_with_trailing_elements_unchecked
+    #[must_use = "has no side effects"]
+    #[inline]
+    unsafe fn mut_from_with_trailing_elements_unchecked(bytes: &mut [u8], count: usize) -> &mut Self
+    where
+        Self: IntoBytes + KnownLayout<PointerMetadata = usize> + Immutable,
+    {
+        debug_assert!(Self::mut_from_with_trailing_elements(bytes, count).is_ok());
+        let mut ptr = Self::raw_from_ptr_len(NonNull::from(bytes).cast::<u8>(), count);
+        // SAFETY:
+        // - As in `ref_from_with_trailing_elements_unchecked`, `ptr` addresses
+        //   exactly the bytes of `bytes`, is validly aligned for `Self`, and
+        //   has the same provenance as `bytes`.
+        // - `Self: FromBytes`, so the initialized bytes of `bytes` are a valid
+        //   `Self`; `Self: IntoBytes`, so any `Self` written through the
+        //   returned reference leaves `bytes` initialized.
+        // - `bytes` is an exclusive reference, which the returned reference
+        //   replaces for the same lifetime.
+        unsafe { ptr.as_mut() }
+    }
+
+    /// Reads a copy of `Self` from `bytes`.
+    ///
+    /// If `bytes.len() != size_of::<Self>()`, `read_from` returns `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::FromBytes;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(FromBytes)]
+    /// #[repr(C)]
     /// struct PacketHeader {
     ///     src_port: [u8; 2],
     ///     dst_port: [u8; 2],
@@ -3248,6 +4624,89 @@ pub unsafe trait FromBytes: FromZeros {
         }
     }
 
+    /// Reads a copy of `Self` from `bytes` into a new `Box<Self>`.
+    ///
+    /// If `bytes.len() != size_of::<Self>()`, `read_boxed_from` returns `Err`.
+    ///
+    /// Unlike [`read_from`], this function copies `bytes` directly into a
+    /// heap allocation, without ever creating a temporary instance of `Self`
+    /// on the stack. This makes it suitable for types which are too large to
+    /// fit on the stack; e.g., `<[u8; 16777216]>::read_boxed_from(bytes)`
+    /// will not overflow the stack, while `read_from` might. It is the
+    /// counterpart to [`new_box_zeroed`] for initializing from bytes rather
+    /// than from zeros.
+    ///
+    /// [`read_from`]: FromBytes::read_from
+    /// [`new_box_zeroed`]: FromZeros::new_box_zeroed
+    ///
+    /// # Panics
+    ///
+    /// Panics if allocation of `size_of::<Self>()` bytes fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::FromBytes;
+    ///
+    /// let bytes = vec![7u8; 1 << 20];
+    ///
+    /// let arr: Box<[u8; 1 << 20]> = <[u8; 1 << 20]>::read_boxed_from(&bytes).unwrap();
+    /// assert!(arr.iter().all(|&b| b == 7));
+    ///
+    /// assert!(<[u8; 1 << 20]>::read_boxed_from(&bytes[1..]).is_err());
+    /// ```
+    #[must_use = "has no side effects (other than allocation)"]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn read_boxed_from(bytes: &[u8]) -> Result<Box<Self>, SizeError<&[u8], Self>>
+    where
+        Self: Sized,
+    {
+        if bytes.len() != mem::size_of::<Self>() {
+            return Err(SizeError::new(bytes));
+        }
+
+        // If `Self` is a ZST, then return a proper boxed instance of it. There
+        // is no allocation, but `Box` does require a correct dangling pointer.
+        // Unlike in `new_box_zeroed`, we don't construct this instance with
+        // `Box::new`: in unoptimized builds, doing so would reserve stack space
+        // for a `Self` even when `Self` is not a ZST.
+        let layout = Layout::new::<Self>();
+        if layout.size() == 0 {
+            // SAFETY: For zero-sized types, `Box::from_raw` requires only that
+            // the pointer be non-null and aligned [1], which `dangling`
+            // guarantees. Since `Self: FromBytes` and `Self` has no bytes, the
+            // resulting `Box` refers to a valid `Self`.
+            //
+            // [1] Per https://doc.rust-lang.org/std/boxed/index.html#memory-layout:
+            //
+            //   For zero-sized values, the `Box` pointer still has to be valid
+            //   for reads and writes and sufficiently aligned.
+            return Ok(unsafe { Box::from_raw(NonNull::<Self>::dangling().as_ptr()) });
+        }
+
+        // SAFETY: We just checked that `layout` has a non-zero size.
+        let ptr = unsafe { alloc::alloc::alloc(layout) };
+        if ptr.is_null() {
+            alloc::alloc::handle_alloc_error(layout);
+        }
+
+        // SAFETY:
+        // - `bytes` is valid for reads of `bytes.len()` bytes.
+        // - `ptr` is non-null, and was just allocated with `layout`, whose
+        //   size is equal to `bytes.len()`. Thus, it is valid for writes of
+        //   `bytes.len()` bytes, and it does not overlap `bytes`.
+        // - Both pointers are trivially aligned for `u8`.
+        unsafe { ptr::copy_nonoverlapping(bytes.as_ptr(), ptr, bytes.len()) };
+
+        // SAFETY: `ptr` was allocated by the global allocator with the layout
+        // of `Self`, and all `size_of::<Self>()` of its bytes were just
+        // initialized. Since `Self: FromBytes`, any initialized sequence of
+        // bytes is a valid `Self`.
+        Ok(unsafe { Box::from_raw(ptr.cast::<Self>()) })
+    }
+
     /// Reads a copy of `Self` from the prefix of `bytes`.
     ///
     /// `read_from_prefix` reads a `Self` from the first `size_of::<Self>()`
@@ -3293,6 +4752,62 @@ pub unsafe trait FromBytes: FromZeros {
         }
     }
 
+    /// Reads the prefix of `bytes` into `self` in place.
+    ///
+    /// `read_into_prefix` overwrites `self` with the first
+    /// `size_of_val(self)` bytes of `bytes`, and returns the remaining bytes.
+    /// Unlike [`read_from_prefix`], it does not construct a new value, which
+    /// makes it useful for re-using an existing value (e.g., one taken from a
+    /// pool) in a loop. If `bytes.len() < size_of_val(self)`, it returns `Err`
+    /// and leaves `self` unmodified.
+    ///
+    /// [`read_from_prefix`]: FromBytes::read_from_prefix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::FromBytes;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(FromBytes, IntoBytes)]
+    /// #[repr(C)]
+    /// struct PacketHeader {
+    ///     src_port: [u8; 2],
+    ///     dst_port: [u8; 2],
+    /// }
+    ///
+    /// // These bytes encode two `PacketHeader`s, followed by a trailing byte.
+    /// let bytes = &[0, 1, 2, 3, 4, 5, 6, 7, 8][..];
+    ///
+    /// let mut header = PacketHeader { src_port: [0; 2], dst_port: [0; 2] };
+    /// let rest = header.read_into_prefix(bytes).unwrap();
+    /// assert_eq!(header.src_port, [0, 1]);
+    /// assert_eq!(header.dst_port, [2, 3]);
+    ///
+    /// let rest = header.read_into_prefix(rest).unwrap();
+    /// assert_eq!(header.src_port, [4, 5]);
+    /// assert_eq!(header.dst_port, [6, 7]);
+    ///
+    /// assert!(header.read_into_prefix(rest).is_err());
+    /// ```
+    #[inline]
+    fn read_into_prefix<'a>(
+        &mut self,
+        bytes: &'a [u8],
+    ) -> Result<&'a [u8], SizeError<&'a [u8], Self>>
+    where
+        Self: IntoBytes,
+    {
+        let dst = self.as_mut_bytes();
+        match bytes.try_split_at(dst.len()) {
+            Ok((prefix, rest)) => {
+                dst.copy_from_slice(prefix);
+                Ok(rest)
+            }
+            Err(bytes) => Err(SizeError::new(bytes)),
+        }
+    }
+
     /// Reads a copy of `Self` from the suffix of `bytes`.
     ///
     /// `read_from_suffix` reads a `Self` from the last `size_of::<Self>()`
@@ -3332,6 +4847,236 @@ pub unsafe trait FromBytes: FromZeros {
         }
     }
 
+    /// Borrows `bytes` as `Self` if possible, or else copies them.
+    ///
+    /// `ref_from_or_copy` returns [`Cow::Borrowed`] if `bytes` is aligned to
+    /// `align_of::<Self>()`, in which case no copy is made, and otherwise
+    /// returns [`Cow::Owned`] with a copy of `Self` read from `bytes` (as by
+    /// [`read_from`]). This lets callers write a single code path which is
+    /// zero-copy whenever the alignment of `bytes` permits. If `bytes.len() !=
+    /// size_of::<Self>()`, it returns `Err`.
+    ///
+    /// [`read_from`]: FromBytes::read_from
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use zerocopy::FromBytes;
+    ///
+    /// let words = [0u32; 3];
+    /// let bytes = zerocopy::slice_as_bytes(&words);
+    ///
+    /// // `bytes` is aligned to `u32`, so it is borrowed.
+    /// assert!(matches!(u32::ref_from_or_copy(&bytes[..4]), Ok(Cow::Borrowed(0))));
+    ///
+    /// // `bytes[1..5]` is not aligned to `u32`, so it is copied.
+    /// assert!(matches!(u32::ref_from_or_copy(&bytes[1..5]), Ok(Cow::Owned(0))));
+    /// ```
+    #[must_use = "has no side effects"]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn ref_from_or_copy(bytes: &[u8]) -> Result<Cow<'_, Self>, SizeError<&[u8], Self>>
+    where
+        Self: Sized + Clone + KnownLayout + Immutable,
+    {
+        match Self::ref_from(bytes) {
+            Ok(r) => Ok(Cow::Borrowed(r)),
+            Err(CastError::Alignment(_)) => Self::read_from(bytes).map(Cow::Owned),
+            Err(CastError::Size(e)) => Err(e),
+            Err(CastError::Validity(i)) => match i {},
+        }
+    }
+
+    /// Reads copies of `n` consecutive values of `Self` from the prefix of
+    /// `bytes`.
+    ///
+    /// `read_vec_from_prefix` reads `n` values of `Self` from the first `n *
+    /// size_of::<Self>()` bytes of `bytes` into a new `Vec`, and returns the
+    /// `Vec` along with the remaining bytes. Since the values are copied,
+    /// `bytes` need not be aligned to `align_of::<Self>()`. This is useful for
+    /// parsing count-prefixed arrays out of buffers whose alignment is unknown,
+    /// where a zero-copy view such as [`ref_from_prefix_with_trailing_elements`]
+    /// may fail. If `n * size_of::<Self>()` overflows `usize`, or if `bytes` is
+    /// shorter than that, it returns `Err`.
+    ///
+    /// [`ref_from_prefix_with_trailing_elements`]: FromBytes::ref_from_prefix_with_trailing_elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::FromBytes;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(FromBytes, Debug, PartialEq)]
+    /// #[repr(C)]
+    /// struct Point {
+    ///     x: u16,
+    ///     y: u16,
+    /// }
+    ///
+    /// // A count followed by that many `Point`s, followed by a trailing byte.
+    /// let bytes = &[2, 1, 0, 2, 0, 3, 0, 4, 0, 5][..];
+    /// let (count, rest) = bytes.split_first().unwrap();
+    ///
+    /// let (points, rest) = Point::read_vec_from_prefix(rest, usize::from(*count)).unwrap();
+    /// assert_eq!(points.len(), 2);
+    /// assert_eq!(points[0], Point { x: u16::from_ne_bytes([1, 0]), y: u16::from_ne_bytes([2, 0]) });
+    /// assert_eq!(rest, [5]);
+    /// ```
+    #[must_use = "has no side effects (other than allocation)"]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn read_vec_from_prefix(
+        bytes: &[u8],
+        n: usize,
+    ) -> Result<(Vec<Self>, &[u8]), SizeError<&[u8], [Self]>>
+    where
+        Self: Sized,
+    {
+        let len = match n.checked_mul(mem::size_of::<Self>()) {
+            Some(len) => len,
+            None => return Err(SizeError::new(bytes)),
+        };
+        let (prefix, rest) = match bytes.try_split_at(len) {
+            Ok(split) => split,
+            Err(bytes) => return Err(SizeError::new(bytes)),
+        };
+
+        let mut vec = Vec::<Self>::with_capacity(n);
+        // SAFETY:
+        // - `prefix` is valid for reads of `len` bytes, and `vec` has capacity
+        //   for `n` elements of `Self`, which occupy `len` bytes, so it is
+        //   valid for writes of `len` bytes.
+        // - Both pointers are trivially aligned to `u8`.
+        // - `vec` is a freshly-allocated buffer, and so it can't overlap with
+        //   `prefix`.
+        unsafe {
+            ptr::copy_nonoverlapping(prefix.as_ptr(), vec.as_mut_ptr().cast::<u8>(), len);
+        }
+        // SAFETY: `vec` has capacity for `n` elements, and its first `n`
+        // elements have been initialized with bytes copied from `prefix`.
+        // Since `Self: FromBytes`, any initialized bytes are a valid `Self`.
+        unsafe { vec.set_len(n) };
+        Ok((vec, rest))
+    }
+
+    /// Interprets a boxed byte slice as a `Box<Self>` without copying.
+    ///
+    /// If `bytes.len()` is a valid size for `Self`, this method reuses the
+    /// allocation of `bytes` as a `Box<Self>`. If `Self` is a slice DST, the
+    /// returned `Box` has as many trailing elements as fit in `bytes`, and
+    /// otherwise `bytes.len()` must be exactly `size_of::<Self>()`. On failure,
+    /// `bytes` is returned unmodified in the [`SizeError`].
+    ///
+    /// `Self` must be [`Unaligned`]. A `Box<[u8]>` is allocated with an
+    /// alignment of 1, and a `Box` must be deallocated with the same alignment
+    /// with which it was allocated. A `Box<Self>` is deallocated with an
+    /// alignment of `align_of::<Self>()`, so it is unsound to reuse the
+    /// allocation for a `Self` with a greater alignment, even if the address of
+    /// `bytes` happens to be suitably aligned:
+    ///
+    /// ```compile_fail,E0277
+    /// use zerocopy::FromBytes;
+    ///
+    /// let bytes = vec![0u8; 4].into_boxed_slice();
+    /// let _ = u32::from_boxed_bytes(bytes); // ⚠ Compile Error!
+    /// ```
+    ///
+    /// To construct a `Box<Self>` for such a type, copy the bytes into a new
+    /// allocation, e.g., with [`read_from`](FromBytes::read_from).
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// This method cannot yet be used on unsized types whose dynamically-sized
+    /// component is zero-sized. Attempting to use this method on such types
+    /// results in a compile-time assertion error; e.g.:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerocopy::*;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(FromBytes, KnownLayout, Unaligned)]
+    /// #[repr(C)]
+    /// struct ZSTy {
+    ///     leading_sized: u8,
+    ///     trailing_dst: [()],
+    /// }
+    ///
+    /// let bytes = vec![0u8; 4].into_boxed_slice();
+    /// let _ = ZSTy::from_boxed_bytes(bytes); // ⚠ Compile Error!
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::{byteorder::big_endian::U16, FromBytes};
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(FromBytes, KnownLayout, Unaligned)]
+    /// #[repr(C)]
+    /// struct Packet {
+    ///     kind: u8,
+    ///     body: [U16],
+    /// }
+    ///
+    /// let bytes = vec![7, 0, 1, 0, 2, 0, 3].into_boxed_slice();
+    /// let addr = bytes.as_ptr();
+    ///
+    /// let packet = Packet::from_boxed_bytes(bytes).unwrap();
+    /// assert_eq!(packet.kind, 7);
+    /// assert_eq!(packet.body, [U16::new(1), U16::new(2), U16::new(3)]);
+    /// // The allocation was reused rather than copied.
+    /// assert_eq!(&packet.kind as *const u8, addr);
+    ///
+    /// // An even number of bytes can't hold a `Packet`.
+    /// let bytes = vec![7, 0, 1, 0].into_boxed_slice();
+    /// let bytes = Packet::from_boxed_bytes(bytes).err().unwrap().into_src();
+    /// assert_eq!(*bytes, [7, 0, 1, 0]);
+    /// ```
+    #[must_use = "has no side effects"]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn from_boxed_bytes(bytes: Box<[u8]>) -> Result<Box<Self>, SizeError<Box<[u8]>, Self>>
+    where
+        Self: KnownLayout + Unaligned,
+    {
+        util::assert_dst_is_not_zst::<Self>();
+        let raw = Box::into_raw(bytes);
+        // SAFETY: `raw` was just produced by `Box::into_raw`, so it is valid,
+        // aligned, and uniquely owned. It is not otherwise accessed while this
+        // reference is live.
+        let bytes = unsafe { &mut *raw };
+        let slf =
+            match Ptr::from_mut(bytes).try_cast_into_no_leftover::<Self, BecauseExclusive>(None) {
+                Ok(slf) => slf.as_non_null(),
+                Err(CastError::Size(_)) => {
+                    // SAFETY: `raw` was produced by `Box::into_raw`, and the
+                    // reference derived from it is no longer live.
+                    return Err(SizeError::new(unsafe { Box::from_raw(raw) }));
+                }
+                Err(CastError::Alignment(_)) => unreachable!(),
+                Err(CastError::Validity(i)) => match i {},
+            };
+        // SAFETY:
+        // - `slf` has the same address as `raw`, which was allocated by the
+        //   global allocator, and `try_cast_into_no_leftover` guarantees that
+        //   it refers to exactly the bytes of `raw`, so `size_of_val` of the
+        //   referent is `raw.len()`.
+        // - `raw` was allocated with an alignment of 1, and `Self: Unaligned`
+        //   guarantees that `align_of::<Self>()` is 1. Thus, the `Box<Self>`
+        //   will be deallocated with the same layout with which it was
+        //   allocated.
+        // - `Self: FromBytes`, so the initialized bytes of `raw` are a valid
+        //   `Self`.
+        // - Ownership of the allocation is transferred from `raw`, which is
+        //   not used again.
+        Ok(unsafe { Box::from_raw(slf.as_ptr()) })
+    }
+
     #[deprecated(since = "0.8.0", note = "`FromBytes::ref_from` now supports slices")]
     #[allow(clippy::must_use_candidate)]
     #[doc(hidden)]
@@ -3430,6 +5175,17 @@ fn mut_from_prefix_suffix<T: FromBytes + KnownLayout + ?Sized>(
 ///
 /// [type layout]: https://doc.rust-lang.org/reference/type-layout.html
 ///
+/// # Byte array conversions
+///
+/// If the annotated type is also annotated with `#[zerocopy(byte_array_conv)]`
+/// or `#[zerocopy(byte_array_conv(N))]`, this derive additionally emits an
+/// implementation of `From<T> for [u8; N]`. See [the `FromBytes`
+/// derive][derive@FromBytes#byte-array-conversions] for details.
+///
+/// A struct with padding can't be `IntoBytes`; see [the
+/// `IntoBytesWithPadding` derive][derive@IntoBytesWithPadding#zeroing-padding]
+/// for a way to obtain its bytes with the padding zeroed.
+///
 /// # Analysis
 ///
 /// *This section describes, roughly, the analysis performed by this derive to
@@ -3448,6 +5204,11 @@ fn mut_from_prefix_suffix<T: FromBytes + KnownLayout + ?Sized>(
 ///   - Its layout must have no padding. This is always true for
 ///     `repr(transparent)` and `repr(packed)`. For `repr(C)`, see the layout
 ///     algorithm described in the [Rust Reference].
+///   - If it is a `repr(C)` struct whose last field is a slice (`[T]`), it
+///     must have no padding for any number of trailing elements: its leading
+///     fields must have no padding between them or before the trailing slice,
+///     and both the size of those leading fields and `size_of::<T>()` must be
+///     multiples of the struct's alignment.
 /// - If the type is an enum:
 ///   - It must be a C-like enum (meaning that all variants have no fields).
 ///   - It must have a defined representation (`repr`s `C`, `u8`, `u16`, `u32`,
@@ -3468,6 +5229,27 @@ pub use zerocopy_derive::IntoBytes;
 /// same size. This is useful for efficiently serializing structured data as raw
 /// bytes.
 ///
+/// # Generic code over values and slices
+///
+/// `IntoBytes` is implemented for slices, `[T]`, but not for references to
+/// them: the bytes of a `&[T]` are those of the reference itself (an address
+/// and a length), not those of the slice it points to. To write code which
+/// accepts either a `&T` or a `&[T]` uniformly, bound the *referent* instead
+/// and relax its `Sized` bound:
+///
+/// ```
+/// use zerocopy::{Immutable, IntoBytes};
+///
+/// fn write_bytes<T: ?Sized + IntoBytes + Immutable>(out: &mut Vec<u8>, t: &T) {
+///     out.extend_from_slice(t.as_bytes());
+/// }
+///
+/// let mut out = Vec::new();
+/// write_bytes(&mut out, &1u16.to_le());
+/// write_bytes(&mut out, &[2u16.to_le(), 3u16.to_le()][..]);
+/// assert_eq!(out, [1, 0, 2, 0, 3, 0]);
+/// ```
+///
 /// # Implementation
 ///
 /// **Do not implement this trait yourself!** Instead, use
@@ -3584,6 +5366,13 @@ pub unsafe trait IntoBytes {
     {
         // Note that this method does not have a `Self: Sized` bound;
         // `size_of_val` works for unsized values too.
+        //
+        // We deliberately don't special-case sized types (such as `[T; N]`):
+        // when `Self: Sized`, `size_of_val` is computed from `Self`'s type
+        // alone, and so it is a compile-time constant equal to
+        // `size_of::<Self>()`. As a result, this method compiles to a pointer
+        // cast paired with a constant length; e.g., for `[u8; 4096]`, it
+        // compiles to just `mov rax, rdi; mov edx, 4096; ret` on x86-64.
         let len = mem::size_of_val(self);
         let slf: *const Self = self;
 
@@ -3687,6 +5476,110 @@ pub unsafe trait IntoBytes {
         unsafe { slice::from_raw_parts_mut(slf.cast::<u8>(), len) }
     }
 
+    /// Gets the bytes of this value as a slice of possibly-uninitialized
+    /// bytes.
+    ///
+    /// `as_maybe_uninit_bytes` is like [`as_bytes`], but returns a
+    /// `&[MaybeUninit<u8>]`, which is the form expected by APIs that operate on
+    /// uninitialized buffers. Every byte of the returned slice is initialized.
+    ///
+    /// [`as_bytes`]: IntoBytes::as_bytes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::mem::MaybeUninit;
+    /// use zerocopy::IntoBytes;
+    ///
+    /// let val = [1u8, 2, 3];
+    /// let bytes: &[MaybeUninit<u8>] = val.as_maybe_uninit_bytes();
+    /// assert_eq!(bytes.len(), 3);
+    /// ```
+    #[must_use = "has no side effects"]
+    #[inline(always)]
+    fn as_maybe_uninit_bytes(&self) -> &[MaybeUninit<u8>]
+    where
+        Self: Immutable,
+    {
+        let bytes = self.as_bytes();
+        // SAFETY: `MaybeUninit<u8>` has the same size, alignment, and ABI as
+        // `u8` [1], and every initialized `u8` is a valid `MaybeUninit<u8>`.
+        // The returned slice addresses the same bytes as `bytes`, and has the
+        // same lifetime. Since the returned slice is immutable, it cannot be
+        // used to de-initialize any bytes of `self`.
+        //
+        // [1] Per https://doc.rust-lang.org/1.78.0/core/mem/union.MaybeUninit.html#layout-1:
+        //
+        //   `MaybeUninit<T>` is guaranteed to have the same size, alignment,
+        //   and ABI as `T`.
+        unsafe { slice::from_raw_parts(bytes.as_ptr().cast::<MaybeUninit<u8>>(), bytes.len()) }
+    }
+
+    /// Gets the bytes of this value mutably as a slice of
+    /// possibly-uninitialized bytes.
+    ///
+    /// `as_mut_maybe_uninit_bytes` is like [`as_mut_bytes`], but returns a
+    /// `&mut [MaybeUninit<u8>]`. This allows `self` to be passed directly to
+    /// APIs which fill uninitialized buffers (such as readers which accept a
+    /// `&mut [MaybeUninit<u8>]`), without first copying through an
+    /// intermediate buffer. Every byte of the returned slice is initialized
+    /// when it is returned.
+    ///
+    /// [`as_mut_bytes`]: IntoBytes::as_mut_bytes
+    ///
+    /// # Safety
+    ///
+    /// The returned slice permits writing uninitialized bytes, which would
+    /// leave `self` in a state that is not a valid `Self`: `Self: FromBytes`
+    /// only guarantees that every *initialized* byte sequence is a valid
+    /// `Self`. The caller must ensure that, by the time the returned reference
+    /// is dropped, every byte of it is initialized. In particular, the caller
+    /// must not call [`MaybeUninit::uninit`] or otherwise write an
+    /// uninitialized byte into the slice unless that byte is subsequently
+    /// overwritten with an initialized value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::mem::MaybeUninit;
+    /// use zerocopy::IntoBytes;
+    ///
+    /// // A reader which writes only initialized bytes to `buf`.
+    /// fn read_buf(src: &[u8], buf: &mut [MaybeUninit<u8>]) {
+    ///     for (dst, src) in buf.iter_mut().zip(src) {
+    ///         *dst = MaybeUninit::new(*src);
+    ///     }
+    /// }
+    ///
+    /// let mut val = [0u8; 4];
+    /// // SAFETY: `read_buf` only writes initialized bytes.
+    /// read_buf(&[1, 2, 3, 4], unsafe { val.as_mut_maybe_uninit_bytes() });
+    /// assert_eq!(val, [1, 2, 3, 4]);
+    /// ```
+    #[must_use = "has no side effects"]
+    #[inline(always)]
+    unsafe fn as_mut_maybe_uninit_bytes(&mut self) -> &mut [MaybeUninit<u8>]
+    where
+        Self: FromBytes,
+    {
+        let bytes = self.as_mut_bytes();
+        // SAFETY: `MaybeUninit<u8>` has the same size, alignment, and ABI as
+        // `u8` [1], and every initialized `u8` is a valid `MaybeUninit<u8>`.
+        // The returned slice addresses the same bytes as `bytes`, and has the
+        // same lifetime. The caller has promised that every byte of the
+        // returned slice will be initialized by the time the returned
+        // reference is dropped, and `Self: FromBytes` ensures that any
+        // sequence of initialized bytes is a valid `Self`.
+        //
+        // [1] Per https://doc.rust-lang.org/1.78.0/core/mem/union.MaybeUninit.html#layout-1:
+        //
+        //   `MaybeUninit<T>` is guaranteed to have the same size, alignment,
+        //   and ABI as `T`.
+        unsafe {
+            slice::from_raw_parts_mut(bytes.as_mut_ptr().cast::<MaybeUninit<u8>>(), bytes.len())
+        }
+    }
+
     /// Writes a copy of `self` to `bytes`.
     ///
     /// If `bytes.len() != size_of_val(self)`, `write_to` returns `Err`.
@@ -3809,6 +5702,54 @@ pub unsafe trait IntoBytes {
         }
     }
 
+    /// Writes a copy of `self` to the prefix of `bytes`, returning the rest.
+    ///
+    /// `write_to_prefix_rest` writes `self` to the first `size_of_val(self)`
+    /// bytes of `bytes`, and returns the remaining bytes so that subsequent
+    /// writes can continue where this one left off. If `bytes.len() <
+    /// size_of_val(self)`, it returns `Err` and leaves `bytes` unmodified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::IntoBytes;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(IntoBytes, Immutable)]
+    /// #[repr(C)]
+    /// struct PacketHeader {
+    ///     src_port: [u8; 2],
+    ///     dst_port: [u8; 2],
+    /// }
+    ///
+    /// let header = PacketHeader { src_port: [0, 1], dst_port: [2, 3] };
+    /// let body = [4u8, 5, 6];
+    ///
+    /// let mut bytes = [0; 8];
+    /// let rest = header.write_to_prefix_rest(&mut bytes[..]).unwrap();
+    /// let rest = body.write_to_prefix_rest(rest).unwrap();
+    /// assert_eq!(rest, [0]);
+    ///
+    /// assert_eq!(bytes, [0, 1, 2, 3, 4, 5, 6, 0]);
+    /// ```
+    #[must_use = "callers should check the return value to see if the operation succeeded"]
+    #[inline]
+    fn write_to_prefix_rest<'a>(
+        &self,
+        bytes: &'a mut [u8],
+    ) -> Result<&'a mut [u8], SizeError<&Self, &'a mut [u8]>>
+    where
+        Self: Immutable,
+    {
+        match bytes.try_split_at(mem::size_of_val(self)) {
+            Ok((prefix, rest)) => {
+                prefix.copy_from_slice(self.as_bytes());
+                Ok(rest)
+            }
+            Err(_) => Err(SizeError::new(self)),
+        }
+    }
+
     /// Writes a copy of `self` to the suffix of `bytes`.
     ///
     /// `write_to_suffix` writes `self` to the last `size_of_val(self)` bytes of
@@ -3887,6 +5828,117 @@ pub unsafe trait IntoBytes {
         Ok(())
     }
 
+    /// Gets the bytes of this value as an [`IoSlice`].
+    ///
+    /// This is equivalent to `IoSlice::new(self.as_bytes())`. It is useful for
+    /// passing `self` to vectored I/O methods such as
+    /// [`Write::write_vectored`] without first copying it into a contiguous
+    /// buffer. To collect the `IoSlice`s of several values, use
+    /// [`io_slices!`].
+    ///
+    /// [`IoSlice`]: std::io::IoSlice
+    /// [`Write::write_vectored`]: std::io::Write::write_vectored
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::IntoBytes;
+    ///
+    /// let value = 0x01020304u32.to_be();
+    /// let slice = value.as_io_slice();
+    /// assert_eq!(&*slice, [1, 2, 3, 4]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+    #[must_use = "has no side effects"]
+    #[inline(always)]
+    fn as_io_slice(&self) -> std::io::IoSlice<'_>
+    where
+        Self: Immutable,
+    {
+        std::io::IoSlice::new(self.as_bytes())
+    }
+
+    /// Converts `self` into its bytes, by value.
+    ///
+    /// `to_bytes_array` moves the bytes of `self` into a `[u8; N]`, where `N`
+    /// must be equal to `size_of::<Self>()`. Unlike [`as_bytes`], this doesn't
+    /// borrow `self`, so it's useful when `self` is being consumed anyway and
+    /// its bytes would otherwise need to be copied out of the borrowed slice.
+    ///
+    /// Like [`transmute!`], this does not run `self`'s destructor, if any.
+    ///
+    /// [`as_bytes`]: IntoBytes::as_bytes
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// `N` must be equal to `size_of::<Self>()`. Attempting to call this
+    /// method with any other `N` results in a compile-time assertion error;
+    /// e.g.:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerocopy::IntoBytes;
+    ///
+    /// let bytes: [u8; 3] = 0u32.to_bytes_array(); // ⚠ Compile Error!
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::IntoBytes;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(IntoBytes)]
+    /// #[repr(C)]
+    /// struct PacketHeader {
+    ///     src_port: [u8; 2],
+    ///     dst_port: [u8; 2],
+    ///     length: [u8; 2],
+    ///     checksum: [u8; 2],
+    /// }
+    ///
+    /// let header = PacketHeader {
+    ///     src_port: [0, 1],
+    ///     dst_port: [2, 3],
+    ///     length: [4, 5],
+    ///     checksum: [6, 7],
+    /// };
+    ///
+    /// let bytes: [u8; 8] = header.to_bytes_array();
+    ///
+    /// assert_eq!(bytes, [0, 1, 2, 3, 4, 5, 6, 7]);
+    /// ```
+    #[must_use = "has no side effects"]
+    #[inline(always)]
+    fn to_bytes_array<const N: usize>(self) -> [u8; N]
+    where
+        Self: Sized,
+    {
+        trait ConstAssert<const N: usize>: Sized {
+            const SIZE_IS_N: bool = {
+                let size_is_n = mem::size_of::<Self>() == N;
+                const_assert!(size_is_n);
+                size_is_n
+            };
+        }
+
+        impl<T, const N: usize> ConstAssert<N> for T {}
+
+        const_assert!(<Self as ConstAssert<N>>::SIZE_IS_N);
+
+        // SAFETY: We just asserted that `size_of::<Self>() == N`, so
+        // `transmute_copy` reads only bytes of `self`. `Self: IntoBytes`
+        // guarantees that all of those bytes are initialized, and so they are
+        // valid `u8`s.
+        let bytes = unsafe { mem::transmute_copy(&self) };
+
+        // The bytes of `self` now belong to `bytes`; like `transmute!`, we
+        // don't drop `self`.
+        mem::forget(self);
+
+        bytes
+    }
+
     #[deprecated(since = "0.8.0", note = "`IntoBytes::as_bytes_mut` was renamed to `as_mut_bytes`")]
     #[doc(hidden)]
     #[inline]
@@ -3898,36 +5950,109 @@ pub unsafe trait IntoBytes {
     }
 }
 
-/// Analyzes whether a type is [`Unaligned`].
+/// Views a slice of `T` as its underlying bytes.
 ///
-/// This derive analyzes, at compile time, whether the annotated type satisfies
-/// the [safety conditions] of `Unaligned` and implements `Unaligned` if it is
-/// sound to do so. This derive can be applied to structs, enums, and unions;
-/// e.g.:
+/// `slice_as_bytes` returns all of the bytes of `slice` as a single, flat
+/// `&[u8]` of length `size_of_val(slice)`, without copying. It is the dual of
+/// [`FromBytes::ref_from`] for slices which are already typed, and is
+/// equivalent to calling [`IntoBytes::as_bytes`] on `slice`.
+///
+/// # Examples
 ///
 /// ```
-/// # use zerocopy_derive::Unaligned;
-/// #[derive(Unaligned)]
+/// use zerocopy::slice_as_bytes;
+///
+/// let words = [0x0102u16, 0x0304];
+/// assert_eq!(slice_as_bytes(&words), [0x0102u16.to_ne_bytes(), 0x0304u16.to_ne_bytes()].concat());
+/// ```
+#[must_use = "has no side effects"]
+#[inline(always)]
+pub fn slice_as_bytes<T: IntoBytes + Immutable>(slice: &[T]) -> &[u8] {
+    slice.as_bytes()
+}
+
+/// Views a slice of `T` as its underlying bytes, mutably.
+///
+/// `slice_as_mut_bytes` returns all of the bytes of `slice` as a single, flat
+/// `&mut [u8]` of length `size_of_val(slice)`, without copying. Since `T:
+/// FromBytes`, any bytes written through the returned slice leave every
+/// element of `slice` valid. It is the dual of [`FromBytes::mut_from`] for
+/// slices which are already typed, and is equivalent to calling
+/// [`IntoBytes::as_mut_bytes`] on `slice`.
+///
+/// # Examples
+///
+/// ```
+/// use zerocopy::slice_as_mut_bytes;
+///
+/// let mut words = [0u16; 2];
+/// slice_as_mut_bytes(&mut words).copy_from_slice(&[0xFF; 4]);
+/// assert_eq!(words, [u16::MAX; 2]);
+/// ```
+#[must_use = "has no side effects"]
+#[inline(always)]
+pub fn slice_as_mut_bytes<T: FromBytes + IntoBytes>(slice: &mut [T]) -> &mut [u8] {
+    slice.as_mut_bytes()
+}
+
+/// Feeds the bytes of `value` into `state`.
+///
+/// `hash_bytes` hashes `value` by passing all of its bytes to
+/// [`Hasher::write`] in a single call. For large types, this is typically
+/// faster than a derived [`Hash`] impl, which hashes each field separately.
+/// It can be used to implement `Hash` by hand:
+///
+/// ```
+/// use core::hash::{Hash, Hasher};
+/// use zerocopy::{hash_bytes, Immutable, IntoBytes};
+/// # use zerocopy_derive::*;
+///
+/// #[derive(IntoBytes, Immutable, PartialEq, Eq)]
 /// #[repr(C)]
-/// struct MyStruct {
-/// # /*
-///     ...
-/// # */
+/// struct Sample {
+///     channels: [u16; 64],
 /// }
 ///
-/// #[derive(Unaligned)]
-/// #[repr(u8)]
-/// enum MyEnum {
-/// #   Variant0,
-/// # /*
-///     ...
-/// # */
+/// impl Hash for Sample {
+///     fn hash<H: Hasher>(&self, state: &mut H) {
+///         hash_bytes(self, state);
+///     }
 /// }
+/// ```
 ///
-/// #[derive(Unaligned)]
-/// #[repr(packed)]
-/// union MyUnion {
-/// #   variant: u8,
+/// Values with identical bytes always hash identically. However, the
+/// resulting hash generally differs from the one produced by a derived `Hash`
+/// impl for the same value, and it depends on `T`'s layout, including its
+/// field order and byte order. It is therefore only stable so long as `T`'s
+/// `repr` and field types don't change. When implementing `Hash` this way,
+/// `Eq` must also compare bytes (e.g., by deriving [`ByteEq`]), or else two
+/// values which compare equal may hash differently.
+///
+#[cfg_attr(feature = "derive", doc = "[`ByteEq`]: zerocopy_derive::ByteEq")]
+#[cfg_attr(
+    not(feature = "derive"),
+    doc = concat!("[`ByteEq`]: https://docs.rs/zerocopy/", env!("CARGO_PKG_VERSION"), "/zerocopy/derive.ByteEq.html"),
+)]
+#[inline]
+pub fn hash_bytes<T, H>(value: &T, state: &mut H)
+where
+    T: IntoBytes + Immutable + ?Sized,
+    H: Hasher,
+{
+    state.write(value.as_bytes());
+}
+
+/// Analyzes whether a type is [`IntoBytesWithPadding`].
+///
+/// This derive analyzes, at compile time, whether the annotated type satisfies
+/// the [safety conditions] of `IntoBytesWithPadding` and implements
+/// `IntoBytesWithPadding` if it is sound to do so. This derive can be applied
+/// to structs whose fields all implement [`Immutable`]; e.g.:
+///
+/// ```
+/// # use zerocopy_derive::IntoBytesWithPadding;
+/// #[derive(IntoBytesWithPadding)]
+/// struct MyStruct {
 /// # /*
 ///     ...
 /// # */
@@ -3937,80 +6062,354 @@ pub unsafe trait IntoBytes {
 /// # Analysis
 ///
 /// *This section describes, roughly, the analysis performed by this derive to
-/// determine whether it is sound to implement `Unaligned` for a given type.
-/// Unless you are modifying the implementation of this derive, or attempting to
-/// manually implement `Unaligned` for a type yourself, you don't need to read
-/// this section.*
+/// determine whether it is sound to implement `IntoBytesWithPadding` for a
+/// given type. Unless you are modifying the implementation of this derive, you
+/// don't need to read this section.*
 ///
 /// If a type has the following properties, then this derive can implement
-/// `Unaligned` for that type:
+/// `IntoBytesWithPadding` for that type:
 ///
-/// - If the type is a struct or union:
-///   - If `repr(align(N))` is provided, `N` must equal 1.
-///   - If the type is `repr(C)` or `repr(transparent)`, all fields must be
-///     [`Unaligned`].
-///   - If the type is not `repr(C)` or `repr(transparent)`, it must be
-///     `repr(packed)` or `repr(packed(1))`.
-/// - If the type is an enum:
-///   - If `repr(align(N))` is provided, `N` must equal 1.
-///   - It must be a C-like enum (meaning that all variants have no fields).
-///   - It must be `repr(i8)` or `repr(u8)`.
+/// - The type must be a struct.
+/// - All fields must be [`Immutable`].
 ///
-/// [safety conditions]: trait@Unaligned#safety
+/// Unlike [`IntoBytes`], this analysis permits padding anywhere in the struct,
+/// and places no requirements on its `repr`: no matter how the struct's fields
+/// are laid out, [`as_bytes_including_padding`] exposes padding bytes as
+/// possibly-uninitialized.
+///
+/// # Zeroing padding
+///
+/// If the struct is also annotated with `#[zerocopy(as_bytes_padded)]`, this
+/// derive additionally emits an inherent `as_bytes_padded` method, which
+/// returns the struct's bytes as a `[u8; size_of::<Self>()]` with any padding
+/// bytes set to zero. Unlike the bytes returned by
+/// [`as_bytes_including_padding`], these are all initialized. If the struct has
+/// no padding, it could implement [`IntoBytes`] instead, and so
+/// `#[zerocopy(as_bytes_padded)]` is rejected with a compile error.
+///
+/// ```
+/// # use zerocopy_derive::{Immutable, IntoBytesWithPadding};
+/// #[derive(IntoBytesWithPadding, Immutable)]
+/// #[zerocopy(as_bytes_padded)]
+/// #[repr(C)]
+/// struct Padded {
+///     a: u8,
+///     // Three padding bytes on most platforms.
+///     b: u32,
+/// }
+///
+/// let bytes = Padded { a: 1, b: 0 }.as_bytes_padded();
+/// assert_eq!(bytes[0], 1);
+/// assert!(bytes[1..].iter().all(|&b| b == 0));
+/// ```
+///
+/// `as_bytes_padded` is sound because it is implemented without `unsafe` code:
+/// it starts with a zeroed array, and copies the bytes of each field, obtained
+/// using [`IntoBytes::as_bytes`], into that array at the field's offset. It
+/// never reads the struct's padding, and so never observes uninitialized
+/// bytes. As a result, all fields must be `IntoBytes` and [`Immutable`], and
+/// `as_bytes_padded` is only supported on sized, non-generic, `repr(C)`
+/// structs.
+///
+/// [`as_bytes_including_padding`]: IntoBytesWithPadding::as_bytes_including_padding
+///
+/// [safety conditions]: trait@IntoBytesWithPadding#safety
 #[cfg(any(feature = "derive", test))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "derive")))]
-pub use zerocopy_derive::Unaligned;
-
-/// Types with no alignment requirement.
-///
-/// If `T: Unaligned`, then `align_of::<T>() == 1`.
+pub use zerocopy_derive::IntoBytesWithPadding;
+
+/// Types whose bytes, including padding, can be viewed as
+/// possibly-uninitialized bytes.
+///
+/// [`IntoBytes`] requires that every byte of a type be initialized, which rules
+/// out types with padding, such as many `#[repr(C)]` structs. In contrast,
+/// `IntoBytesWithPadding` only requires that a type contain no [`UnsafeCell`]s,
+/// and exposes all of its bytes – padding included – as a
+/// `&[MaybeUninit<u8>]`. This is useful for copying or hashing a whole struct
+/// when the contents of its padding bytes are not significant. Since padding
+/// is permitted anywhere, `IntoBytesWithPadding` places no requirements on a
+/// type's layout; in particular, a struct need not be `repr(C)`.
+///
+/// `IntoBytesWithPadding` is implemented for the primitive types, for the
+/// `NonZeroXxx` types and `Option`s of them, for [`Unalign`] and the
+/// [`byteorder`] types, and for arrays, slices, and [`Wrapping`]s of
+/// `IntoBytesWithPadding` types.
+///
+/// Since padding bytes may be uninitialized, the bytes returned by
+/// [`as_bytes_including_padding`] must not be assumed to be initialized. In
+/// particular, they may not be reinterpreted as `&[u8]`. If you need a `&[u8]`,
+/// use [`IntoBytes`] instead.
 ///
 /// # Implementation
 ///
 /// **Do not implement this trait yourself!** Instead, use
-/// [`#[derive(Unaligned)]`][derive] (requires the `derive` Cargo feature);
-/// e.g.:
+/// [`#[derive(IntoBytesWithPadding)]`][derive] (requires the `derive` Cargo
+/// feature); e.g.:
 ///
 /// ```
-/// # use zerocopy_derive::Unaligned;
-/// #[derive(Unaligned)]
-/// #[repr(C)]
+/// # use zerocopy_derive::IntoBytesWithPadding;
+/// #[derive(IntoBytesWithPadding)]
 /// struct MyStruct {
 /// # /*
 ///     ...
 /// # */
 /// }
-///
-/// #[derive(Unaligned)]
-/// #[repr(u8)]
-/// enum MyEnum {
-/// #   Variant0,
-/// # /*
-///     ...
-/// # */
-/// }
-///
-/// #[derive(Unaligned)]
-/// #[repr(packed)]
-/// union MyUnion {
-/// #   variant: u8,
-/// # /*
-///     ...
-/// # */
-/// }
 /// ```
 ///
 /// This derive performs a sophisticated, compile-time safety analysis to
-/// determine whether a type is `Unaligned`.
+/// determine whether a type is `IntoBytesWithPadding`.
 ///
 /// # Safety
 ///
-/// *This section describes what is required in order for `T: Unaligned`, and
-/// what unsafe code may assume of such types. If you don't plan on implementing
-/// `Unaligned` manually, and you don't plan on writing unsafe code that
-/// operates on `Unaligned` types, then you don't need to read this section.*
+/// *This section describes what is required in order for `T:
+/// IntoBytesWithPadding`, and what unsafe code may assume of such types. If you
+/// don't plan on implementing `IntoBytesWithPadding` manually, and you don't
+/// plan on writing unsafe code that operates on `IntoBytesWithPadding` types,
+/// then you don't need to read this section.*
 ///
-/// If `T: Unaligned`, then unsafe code may assume that it is sound to produce a
+/// If `T: IntoBytesWithPadding`, then unsafe code may assume that `T` does not
+/// contain any [`UnsafeCell`]s, either directly or in any of its fields. If a
+/// type is marked as `IntoBytesWithPadding` which violates this contract, it
+/// may cause undefined behavior.
+///
+/// `#[derive(IntoBytesWithPadding)]` only permits [types which satisfy these
+/// requirements][derive-analysis].
+///
+/// [`UnsafeCell`]: core::cell::UnsafeCell
+/// [`as_bytes_including_padding`]: IntoBytesWithPadding::as_bytes_including_padding
+#[cfg_attr(
+    feature = "derive",
+    doc = "[derive]: zerocopy_derive::IntoBytesWithPadding",
+    doc = "[derive-analysis]: zerocopy_derive::IntoBytesWithPadding#analysis"
+)]
+#[cfg_attr(
+    not(feature = "derive"),
+    doc = concat!("[derive]: https://docs.rs/zerocopy/", env!("CARGO_PKG_VERSION"), "/zerocopy/derive.IntoBytesWithPadding.html"),
+    doc = concat!("[derive-analysis]: https://docs.rs/zerocopy/", env!("CARGO_PKG_VERSION"), "/zerocopy/derive.IntoBytesWithPadding.html#analysis"),
+)]
+pub unsafe trait IntoBytesWithPadding {
+    // The `Self: Sized` bound makes it so that `IntoBytesWithPadding` is still
+    // object safe.
+    #[doc(hidden)]
+    fn only_derive_is_allowed_to_implement_this_trait()
+    where
+        Self: Sized;
+
+    /// Gets the bytes of this value, including any padding bytes.
+    ///
+    /// The returned slice has length `size_of_val(self)`. Bytes which
+    /// correspond to fields of `self` are initialized only if those fields'
+    /// bytes are initialized; padding bytes may be uninitialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::IntoBytesWithPadding;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(IntoBytesWithPadding)]
+    /// #[repr(C)]
+    /// struct PacketHeader {
+    ///     kind: u8,
+    ///     // 3 bytes of padding
+    ///     len: u32,
+    /// }
+    ///
+    /// let header = PacketHeader { kind: 1, len: 2 };
+    /// let bytes = header.as_bytes_including_padding();
+    /// assert_eq!(bytes.len(), 8);
+    /// ```
+    #[must_use = "has no side effects"]
+    #[inline(always)]
+    fn as_bytes_including_padding(&self) -> &[MaybeUninit<u8>] {
+        let len = mem::size_of_val(self);
+        let slf: *const Self = self;
+
+        // SAFETY:
+        // - `slf.cast::<MaybeUninit<u8>>()` is valid for reads for `len *
+        //   mem::size_of::<MaybeUninit<u8>>()` many bytes because...
+        //   - `slf` is the same pointer as `self`, and `self` is a reference
+        //     which points to an object whose size is `len`. Thus...
+        //     - The entire region of `len` bytes starting at `slf` is contained
+        //       within a single allocation.
+        //     - `slf` is non-null.
+        //   - `slf` is trivially aligned to `align_of::<MaybeUninit<u8>>() ==
+        //     1`.
+        // - Every byte sequence, including uninitialized bytes, is a valid
+        //   `[MaybeUninit<u8>]`.
+        // - Since `slf` is derived from `self`, and `self` is an immutable
+        //   reference, the only other references to this memory region that
+        //   could exist are other immutable references, and those don't allow
+        //   mutation. `Self: IntoBytesWithPadding` prohibits types which
+        //   contain `UnsafeCell`s, which are the only types for which this rule
+        //   wouldn't be sufficient. Since the returned slice is immutable, it
+        //   cannot be used to de-initialize any bytes of `self`.
+        // - The total size of the resulting slice is no larger than
+        //   `isize::MAX` because no allocation produced by safe code can be
+        //   larger than `isize::MAX`.
+        unsafe { slice::from_raw_parts(slf.cast::<MaybeUninit<u8>>(), len) }
+    }
+}
+
+/// Derives `PartialEq` and `Eq` by comparing the bytes of two values.
+///
+/// This derive can be applied to any type which is [`IntoBytes`] and
+/// [`Immutable`], and implements `PartialEq` by comparing the bytes returned
+/// by [`IntoBytes::as_bytes`], and `Eq` accordingly. For large types, this can
+/// be faster than a field-wise comparison; e.g.:
+///
+/// ```
+/// # use zerocopy_derive::{ByteEq, Immutable, IntoBytes};
+/// #[derive(ByteEq, IntoBytes, Immutable)]
+/// #[repr(C)]
+/// struct Header {
+///     magic: [u8; 4],
+///     lengths: [u32; 8],
+/// }
+///
+/// let a = Header { magic: *b"ZCPY", lengths: [1; 8] };
+/// let b = Header { magic: *b"ZCPY", lengths: [1; 8] };
+/// assert!(a == b);
+/// ```
+///
+/// Comparing bytes is only equivalent to comparing values if the type has no
+/// padding, as padding bytes may take any value. Requiring `IntoBytes` ensures
+/// that there is no padding; if the type is not `IntoBytes`, the derived
+/// impls will not compile.
+///
+/// # Floating-point fields
+///
+/// Byte equality differs from the `PartialEq` impls of `f32` and `f64`: a NaN
+/// is equal to another NaN with the same bits, and `0.0` is not equal to
+/// `-0.0`. Deriving `ByteEq` on a type with floating-point fields is allowed,
+/// but only do so if these semantics are what you want.
+///
+/// ```
+/// # use zerocopy_derive::{ByteEq, Immutable, IntoBytes};
+/// #[derive(ByteEq, IntoBytes, Immutable)]
+/// #[repr(transparent)]
+/// struct Float(f32);
+///
+/// assert!(Float(f32::NAN) == Float(f32::NAN));
+/// assert!(Float(0.0) != Float(-0.0));
+/// ```
+#[cfg(any(feature = "derive", test))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "derive")))]
+pub use zerocopy_derive::ByteEq;
+
+/// Analyzes whether a type is [`Unaligned`].
+///
+/// This derive analyzes, at compile time, whether the annotated type satisfies
+/// the [safety conditions] of `Unaligned` and implements `Unaligned` if it is
+/// sound to do so. This derive can be applied to structs, enums, and unions;
+/// e.g.:
+///
+/// ```
+/// # use zerocopy_derive::Unaligned;
+/// #[derive(Unaligned)]
+/// #[repr(C)]
+/// struct MyStruct {
+/// # /*
+///     ...
+/// # */
+/// }
+///
+/// #[derive(Unaligned)]
+/// #[repr(u8)]
+/// enum MyEnum {
+/// #   Variant0,
+/// # /*
+///     ...
+/// # */
+/// }
+///
+/// #[derive(Unaligned)]
+/// #[repr(packed)]
+/// union MyUnion {
+/// #   variant: u8,
+/// # /*
+///     ...
+/// # */
+/// }
+/// ```
+///
+/// # Analysis
+///
+/// *This section describes, roughly, the analysis performed by this derive to
+/// determine whether it is sound to implement `Unaligned` for a given type.
+/// Unless you are modifying the implementation of this derive, or attempting to
+/// manually implement `Unaligned` for a type yourself, you don't need to read
+/// this section.*
+///
+/// If a type has the following properties, then this derive can implement
+/// `Unaligned` for that type:
+///
+/// - If the type is a struct or union:
+///   - If `repr(align(N))` is provided, `N` must equal 1.
+///   - If the type is `repr(C)` or `repr(transparent)`, all fields must be
+///     [`Unaligned`].
+///   - If the type is not `repr(C)` or `repr(transparent)`, it must be
+///     `repr(packed)` or `repr(packed(1))`.
+/// - If the type is an enum:
+///   - If `repr(align(N))` is provided, `N` must equal 1.
+///   - It must be a C-like enum (meaning that all variants have no fields).
+///   - It must be `repr(i8)` or `repr(u8)`.
+///
+/// [safety conditions]: trait@Unaligned#safety
+#[cfg(any(feature = "derive", test))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "derive")))]
+pub use zerocopy_derive::Unaligned;
+
+/// Types with no alignment requirement.
+///
+/// If `T: Unaligned`, then `align_of::<T>() == 1`.
+///
+/// # Implementation
+///
+/// **Do not implement this trait yourself!** Instead, use
+/// [`#[derive(Unaligned)]`][derive] (requires the `derive` Cargo feature);
+/// e.g.:
+///
+/// ```
+/// # use zerocopy_derive::Unaligned;
+/// #[derive(Unaligned)]
+/// #[repr(C)]
+/// struct MyStruct {
+/// # /*
+///     ...
+/// # */
+/// }
+///
+/// #[derive(Unaligned)]
+/// #[repr(u8)]
+/// enum MyEnum {
+/// #   Variant0,
+/// # /*
+///     ...
+/// # */
+/// }
+///
+/// #[derive(Unaligned)]
+/// #[repr(packed)]
+/// union MyUnion {
+/// #   variant: u8,
+/// # /*
+///     ...
+/// # */
+/// }
+/// ```
+///
+/// This derive performs a sophisticated, compile-time safety analysis to
+/// determine whether a type is `Unaligned`.
+///
+/// # Safety
+///
+/// *This section describes what is required in order for `T: Unaligned`, and
+/// what unsafe code may assume of such types. If you don't plan on implementing
+/// `Unaligned` manually, and you don't plan on writing unsafe code that
+/// operates on `Unaligned` types, then you don't need to read this section.*
+///
+/// If `T: Unaligned`, then unsafe code may assume that it is sound to produce a
 /// reference to `T` at any memory location regardless of alignment. If a type
 /// is marked as `Unaligned` which violates this contract, it may cause
 /// undefined behavior.
@@ -4049,7 +6448,7 @@ safety_comment! {
     ///
     /// [1] https://doc.rust-lang.org/reference/type-layout.html#tuple-layout
     unsafe_impl!((): Immutable, TryFromBytes, FromZeros, FromBytes, IntoBytes, Unaligned);
-    assert_unaligned!(());
+    assert_align_one!(());
 }
 
 safety_comment! {
@@ -4090,7 +6489,7 @@ safety_comment! {
     /// [1].
     unsafe_impl!(u8: Immutable, TryFromBytes, FromZeros, FromBytes, IntoBytes, Unaligned);
     unsafe_impl!(i8: Immutable, TryFromBytes, FromZeros, FromBytes, IntoBytes, Unaligned);
-    assert_unaligned!(u8, i8);
+    assert_align_one!(u8, i8);
     unsafe_impl!(u16: Immutable, TryFromBytes, FromZeros, FromBytes, IntoBytes);
     unsafe_impl!(i16: Immutable, TryFromBytes, FromZeros, FromBytes, IntoBytes);
     unsafe_impl!(u32: Immutable, TryFromBytes, FromZeros, FromBytes, IntoBytes);
@@ -4119,7 +6518,7 @@ safety_comment! {
     ///
     /// [1] https://doc.rust-lang.org/reference/types/boolean.html
     unsafe_impl!(bool: Immutable, FromZeros, IntoBytes, Unaligned);
-    assert_unaligned!(bool);
+    assert_align_one!(bool);
     /// SAFETY:
     /// - The safety requirements for `unsafe_impl!` with an `is_bit_valid`
     ///   closure:
@@ -4222,7 +6621,7 @@ safety_comment! {
     /// - `FromZeros`, `IntoBytes`, `Unaligned`: `[u8]` is `FromZeros`,
     ///   `IntoBytes`, and `Unaligned`.
     ///
-    /// Note that we don't `assert_unaligned!(str)` because `assert_unaligned!`
+    /// Note that we don't `assert_align_one!(str)` because `assert_align_one!`
     /// uses `align_of`, which only works for `Sized` types.
     ///
     /// TODO(#429):
@@ -4298,7 +6697,7 @@ safety_comment! {
     /// that layout is the same as primitive layout.
     unsafe_impl!(NonZeroU8: Immutable, IntoBytes, Unaligned);
     unsafe_impl!(NonZeroI8: Immutable, IntoBytes, Unaligned);
-    assert_unaligned!(NonZeroU8, NonZeroI8);
+    assert_align_one!(NonZeroU8, NonZeroI8);
     unsafe_impl!(NonZeroU16: Immutable, IntoBytes);
     unsafe_impl!(NonZeroI16: Immutable, IntoBytes);
     unsafe_impl!(NonZeroU32: Immutable, IntoBytes);
@@ -4372,7 +6771,7 @@ safety_comment! {
     /// for layout guarantees.
     unsafe_impl!(Option<NonZeroU8>: TryFromBytes, FromZeros, FromBytes, IntoBytes, Unaligned);
     unsafe_impl!(Option<NonZeroI8>: TryFromBytes, FromZeros, FromBytes, IntoBytes, Unaligned);
-    assert_unaligned!(Option<NonZeroU8>, Option<NonZeroI8>);
+    assert_align_one!(Option<NonZeroU8>, Option<NonZeroI8>);
     unsafe_impl!(Option<NonZeroU16>: TryFromBytes, FromZeros, FromBytes, IntoBytes);
     unsafe_impl!(Option<NonZeroI16>: TryFromBytes, FromZeros, FromBytes, IntoBytes);
     unsafe_impl!(Option<NonZeroU32>: TryFromBytes, FromZeros, FromBytes, IntoBytes);
@@ -4469,13 +6868,13 @@ safety_comment! {
 }
 
 macro_rules! impl_traits_for_atomics {
-    ($($atomics:ident [$inners:ident]),* $(,)?) => {
-        $(
-            impl_for_transparent_wrapper!(TryFromBytes for $atomics [UnsafeCell<$inners>]);
-            impl_for_transparent_wrapper!(FromZeros for $atomics [UnsafeCell<$inners>]);
-            impl_for_transparent_wrapper!(FromBytes for $atomics [UnsafeCell<$inners>]);
-            impl_for_transparent_wrapper!(IntoBytes for $atomics [UnsafeCell<$inners>]);
-        )*
+    ($(#[$attr:meta])* $(,)?) => {};
+    ($(#[$attr:meta])* $atomic:ident [$inner:ident] $(, $atomics:ident [$inners:ident])* $(,)?) => {
+        impl_for_transparent_wrapper!($(#[$attr])* TryFromBytes for $atomic [UnsafeCell<$inner>]);
+        impl_for_transparent_wrapper!($(#[$attr])* FromZeros for $atomic [UnsafeCell<$inner>]);
+        impl_for_transparent_wrapper!($(#[$attr])* FromBytes for $atomic [UnsafeCell<$inner>]);
+        impl_for_transparent_wrapper!($(#[$attr])* IntoBytes for $atomic [UnsafeCell<$inner>]);
+        impl_traits_for_atomics!($(#[$attr])* $($atomics [$inners]),*);
     };
 }
 
@@ -4486,6 +6885,20 @@ impl_traits_for_atomics!(
     AtomicU16 [u16], AtomicU32 [u32], AtomicU8 [u8], AtomicUsize [usize],
 );
 
+// `AtomicI64` and `AtomicU64` are only available on platforms which support
+// 64-bit atomic operations. `cfg(target_has_atomic)` is only stable from Rust
+// 1.60; on earlier versions, we fall back to excluding 32-bit PowerPC, which
+// doesn't support them.
+#[cfg(any(
+    all(zerocopy_target_has_atomic, target_has_atomic = "64"),
+    all(not(zerocopy_target_has_atomic), not(target_arch = "powerpc"))
+))]
+#[rustfmt::skip]
+impl_traits_for_atomics!(
+    #[cfg_attr(doc_cfg, doc(cfg(target_has_atomic = "64")))]
+    AtomicI64 [i64], AtomicU64 [u64],
+);
+
 safety_comment! {
     /// SAFETY:
     /// Per [1], `AtomicBool`, `AtomicU8`, and `AtomicI8` have the same size as
@@ -4507,7 +6920,7 @@ safety_comment! {
     unsafe_impl!(AtomicBool: Unaligned);
     unsafe_impl!(AtomicU8: Unaligned);
     unsafe_impl!(AtomicI8: Unaligned);
-    assert_unaligned!(AtomicBool, AtomicU8, AtomicI8);
+    assert_align_one!(AtomicBool, AtomicU8, AtomicI8);
 }
 
 safety_comment! {
@@ -4532,7 +6945,7 @@ safety_comment! {
     unsafe_impl!(T: ?Sized => FromBytes for PhantomData<T>);
     unsafe_impl!(T: ?Sized => IntoBytes for PhantomData<T>);
     unsafe_impl!(T: ?Sized => Unaligned for PhantomData<T>);
-    assert_unaligned!(PhantomData<()>, PhantomData<u8>, PhantomData<u64>);
+    assert_align_one!(PhantomData<()>, PhantomData<u8>, PhantomData<u64>);
 }
 
 impl_for_transparent_wrapper!(T: Immutable => Immutable for Wrapping<T>);
@@ -4541,7 +6954,7 @@ impl_for_transparent_wrapper!(T: FromZeros => FromZeros for Wrapping<T>);
 impl_for_transparent_wrapper!(T: FromBytes => FromBytes for Wrapping<T>);
 impl_for_transparent_wrapper!(T: IntoBytes => IntoBytes for Wrapping<T>);
 impl_for_transparent_wrapper!(T: Unaligned => Unaligned for Wrapping<T>);
-assert_unaligned!(Wrapping<()>, Wrapping<u8>);
+assert_align_one!(Wrapping<()>, Wrapping<u8>);
 
 safety_comment! {
     /// SAFETY:
@@ -4554,7 +6967,7 @@ safety_comment! {
 
 impl_for_transparent_wrapper!(T: Immutable => Immutable for MaybeUninit<T>);
 impl_for_transparent_wrapper!(T: Unaligned => Unaligned for MaybeUninit<T>);
-assert_unaligned!(MaybeUninit<()>, MaybeUninit<u8>);
+assert_align_one!(MaybeUninit<()>, MaybeUninit<u8>);
 
 impl_for_transparent_wrapper!(T: ?Sized + Immutable => Immutable for ManuallyDrop<T>);
 impl_for_transparent_wrapper!(T: ?Sized + TryFromBytes => TryFromBytes for ManuallyDrop<T>);
@@ -4562,14 +6975,14 @@ impl_for_transparent_wrapper!(T: ?Sized + FromZeros => FromZeros for ManuallyDro
 impl_for_transparent_wrapper!(T: ?Sized + FromBytes => FromBytes for ManuallyDrop<T>);
 impl_for_transparent_wrapper!(T: ?Sized + IntoBytes => IntoBytes for ManuallyDrop<T>);
 impl_for_transparent_wrapper!(T: ?Sized + Unaligned => Unaligned for ManuallyDrop<T>);
-assert_unaligned!(ManuallyDrop<()>, ManuallyDrop<u8>);
+assert_align_one!(ManuallyDrop<()>, ManuallyDrop<u8>);
 
 // TODO(#5): Implement `FromZeros` and `FromBytes` when `T: ?Sized`.
 impl_for_transparent_wrapper!(T: FromZeros => FromZeros for UnsafeCell<T>);
 impl_for_transparent_wrapper!(T: FromBytes => FromBytes for UnsafeCell<T>);
 impl_for_transparent_wrapper!(T: ?Sized + IntoBytes => IntoBytes for UnsafeCell<T>);
 impl_for_transparent_wrapper!(T: ?Sized + Unaligned => Unaligned for UnsafeCell<T>);
-assert_unaligned!(UnsafeCell<()>, UnsafeCell<u8>);
+assert_align_one!(UnsafeCell<()>, UnsafeCell<u8>);
 
 // SAFETY: See safety comment in `is_bit_valid` impl.
 //
@@ -4676,8 +7089,8 @@ safety_comment! {
     /// has "the same alignment of `T`", `[T]` and `[T; N]` are `Unaligned` if
     /// `T` is.
     ///
-    /// Note that we don't `assert_unaligned!` for slice types because
-    /// `assert_unaligned!` uses `align_of`, which only works for `Sized` types.
+    /// Note that we don't `assert_align_one!` for slice types because
+    /// `assert_align_one!` uses `align_of`, which only works for `Sized` types.
     ///
     /// [1] https://doc.rust-lang.org/reference/type-layout.html#array-layout
     unsafe_impl!(const N: usize, T: Immutable => Immutable for [T; N]);
@@ -4692,7 +7105,7 @@ safety_comment! {
     unsafe_impl!(const N: usize, T: FromBytes => FromBytes for [T; N]);
     unsafe_impl!(const N: usize, T: IntoBytes => IntoBytes for [T; N]);
     unsafe_impl!(const N: usize, T: Unaligned => Unaligned for [T; N]);
-    assert_unaligned!([(); 0], [(); 1], [u8; 0], [u8; 1]);
+    assert_align_one!([(); 0], [(); 1], [u8; 0], [u8; 1]);
     unsafe_impl!(T: Immutable => Immutable for [T]);
     unsafe_impl!(T: TryFromBytes => TryFromBytes for [T]; |c: Maybe<[T]>| {
         // SAFETY: Per the reference [1]:
@@ -4722,6 +7135,122 @@ safety_comment! {
     unsafe_impl!(T: IntoBytes => IntoBytes for [T]);
     unsafe_impl!(T: Unaligned => Unaligned for [T]);
 }
+
+safety_comment! {
+    /// SAFETY:
+    /// `IntoBytesWithPadding` only requires that a type not contain any
+    /// `UnsafeCell`s. The primitive types self-evidently do not contain any
+    /// `UnsafeCell`s, and `[T; N]` and `[T]` only contain `UnsafeCell`s if `T`
+    /// does.
+    unsafe_impl!((): IntoBytesWithPadding);
+    unsafe_impl!(u8: IntoBytesWithPadding);
+    unsafe_impl!(i8: IntoBytesWithPadding);
+    unsafe_impl!(u16: IntoBytesWithPadding);
+    unsafe_impl!(i16: IntoBytesWithPadding);
+    unsafe_impl!(u32: IntoBytesWithPadding);
+    unsafe_impl!(i32: IntoBytesWithPadding);
+    unsafe_impl!(u64: IntoBytesWithPadding);
+    unsafe_impl!(i64: IntoBytesWithPadding);
+    unsafe_impl!(u128: IntoBytesWithPadding);
+    unsafe_impl!(i128: IntoBytesWithPadding);
+    unsafe_impl!(usize: IntoBytesWithPadding);
+    unsafe_impl!(isize: IntoBytesWithPadding);
+    unsafe_impl!(f32: IntoBytesWithPadding);
+    unsafe_impl!(f64: IntoBytesWithPadding);
+    unsafe_impl!(bool: IntoBytesWithPadding);
+    unsafe_impl!(char: IntoBytesWithPadding);
+    unsafe_impl!(const N: usize, T: IntoBytesWithPadding => IntoBytesWithPadding for [T; N]);
+    unsafe_impl!(T: IntoBytesWithPadding => IntoBytesWithPadding for [T]);
+}
+
+safety_comment! {
+    /// SAFETY:
+    /// `IntoBytesWithPadding` only requires that a type not contain any
+    /// `UnsafeCell`s. `NonZeroXxx` and `Option<NonZeroXxx>` are `Immutable`
+    /// (see their impls above), and so contain no `UnsafeCell`s. `Wrapping<T>`
+    /// is `repr(transparent)` over `T` [1], and so contains `UnsafeCell`s
+    /// exactly when `T` does.
+    ///
+    /// [1] Per https://doc.rust-lang.org/1.81.0/core/num/struct.Wrapping.html#layout-1:
+    ///
+    ///   `Wrapping<T>` is guaranteed to have the same layout and ABI as `T`.
+    unsafe_impl!(NonZeroU8: IntoBytesWithPadding);
+    unsafe_impl!(NonZeroI8: IntoBytesWithPadding);
+    unsafe_impl!(NonZeroU16: IntoBytesWithPadding);
+    unsafe_impl!(NonZeroI16: IntoBytesWithPadding);
+    unsafe_impl!(NonZeroU32: IntoBytesWithPadding);
+    unsafe_impl!(NonZeroI32: IntoBytesWithPadding);
+    unsafe_impl!(NonZeroU64: IntoBytesWithPadding);
+    unsafe_impl!(NonZeroI64: IntoBytesWithPadding);
+    unsafe_impl!(NonZeroU128: IntoBytesWithPadding);
+    unsafe_impl!(NonZeroI128: IntoBytesWithPadding);
+    unsafe_impl!(NonZeroUsize: IntoBytesWithPadding);
+    unsafe_impl!(NonZeroIsize: IntoBytesWithPadding);
+    unsafe_impl!(Option<NonZeroU8>: IntoBytesWithPadding);
+    unsafe_impl!(Option<NonZeroI8>: IntoBytesWithPadding);
+    unsafe_impl!(Option<NonZeroU16>: IntoBytesWithPadding);
+    unsafe_impl!(Option<NonZeroI16>: IntoBytesWithPadding);
+    unsafe_impl!(Option<NonZeroU32>: IntoBytesWithPadding);
+    unsafe_impl!(Option<NonZeroI32>: IntoBytesWithPadding);
+    unsafe_impl!(Option<NonZeroU64>: IntoBytesWithPadding);
+    unsafe_impl!(Option<NonZeroI64>: IntoBytesWithPadding);
+    unsafe_impl!(Option<NonZeroU128>: IntoBytesWithPadding);
+    unsafe_impl!(Option<NonZeroI128>: IntoBytesWithPadding);
+    unsafe_impl!(Option<NonZeroUsize>: IntoBytesWithPadding);
+    unsafe_impl!(Option<NonZeroIsize>: IntoBytesWithPadding);
+    unsafe_impl!(T: IntoBytesWithPadding => IntoBytesWithPadding for Wrapping<T>);
+}
+
+safety_comment! {
+    /// SAFETY:
+    /// `IntoBytes`: Per [1], for `T: Sized`, `Option<&T>`, `Option<&mut T>`,
+    /// and `Option<NonNull<T>>` have the same size and alignment as `&T`, and
+    /// `None` is represented by the all-zeros (null) pointer. `Some(p)` is
+    /// represented by `p` itself. In either case, the value is a thin pointer,
+    /// which has no padding and whose bytes are all initialized.
+    ///
+    /// NOTE(#170): The bytes of a pointer do not carry its provenance. These
+    /// types are not `FromBytes`, and their `TryFromBytes` impls only accept
+    /// all-zeros bytes, so zerocopy cannot be used to reconstruct a non-null
+    /// pointer from the bytes produced by these impls. Users who do so by other
+    /// means (e.g., by transmuting the bytes to a `usize` and casting it to a
+    /// pointer) will obtain a pointer without provenance, which may not be
+    /// dereferenced.
+    ///
+    /// [1] Per https://doc.rust-lang.org/1.78.0/core/option/index.html#representation:
+    ///
+    ///   Rust guarantees to optimize the following types `T` such that
+    ///   [`Option<T>`] has the same size and alignment as `T`. In some of these
+    ///   cases, Rust further guarantees that `transmute::<_, Option<T>>([0u8;
+    ///   size_of::<T>()])` is sound and produces `Option::<T>::None`.
+    ///
+    ///   | `T`                   | `transmute::<_, Option<T>>([0u8; size_of::<T>()])` sound? |
+    ///   |-----------------------|-----------------------------------------------------------|
+    ///   | `&U`                  | when `U: Sized`                                           |
+    ///   | `&mut U`              | when `U: Sized`                                           |
+    ///   | [`ptr::NonNull<U>`]   | when `U: Sized`                                           |
+    unsafe_impl!(
+        /// The bytes of a `Some` value are the address of the pointer, and do
+        /// not carry its provenance. A pointer reconstructed from these bytes
+        /// (e.g., by casting them to a `usize` and then to a pointer) may not
+        /// be dereferenced.
+        T => IntoBytes for Option<&'_ T>
+    );
+    unsafe_impl!(
+        /// The bytes of a `Some` value are the address of the pointer, and do
+        /// not carry its provenance. A pointer reconstructed from these bytes
+        /// (e.g., by casting them to a `usize` and then to a pointer) may not
+        /// be dereferenced.
+        T => IntoBytes for Option<&'_ mut T>
+    );
+    unsafe_impl!(
+        /// The bytes of a `Some` value are the address of the pointer, and do
+        /// not carry its provenance. A pointer reconstructed from these bytes
+        /// (e.g., by casting them to a `usize` and then to a pointer) may not
+        /// be dereferenced.
+        T => IntoBytes for Option<NonNull<T>>
+    );
+}
 safety_comment! {
     /// SAFETY:
     /// - `Immutable`: Raw pointers do not contain any `UnsafeCell`s.
@@ -4775,6 +7304,73 @@ safety_comment! {
     unsafe_impl!(T: Immutable => Immutable for Option<T>);
 }
 
+/// Implements `Immutable`, `TryFromBytes`, and `FromZeros` for a tuple whose
+/// elements are the given type parameters, paired with their field indices.
+macro_rules! impl_for_tuple {
+    ($($t:ident $i:tt),+) => {
+        unsafe_impl!($($t: Immutable),+ => Immutable for ($($t,)+));
+        unsafe_impl!($($t: TryFromBytes),+ => TryFromBytes for ($($t,)+); |c: Maybe<Self>| {
+            let mut c = c;
+            true $(&& {
+                // SAFETY:
+                // - `project` is a field projection, and so it addresses a
+                //   subset of the bytes addressed by `c`
+                // - ..., and so it preserves provenance
+                // - ..., and `*c` is a tuple, so `UnsafeCell`s exist at the
+                //   same byte ranges in the returned pointer's referent as they
+                //   do in `*c`
+                let field = unsafe {
+                    c.reborrow().project(|slf: *mut Self| core::ptr::addr_of_mut!((*slf).$i))
+                };
+                // Note that this call may panic, but it would still be sound
+                // even if it did. `is_bit_valid` does not promise that it will
+                // not panic (in fact, it explicitly warns that it's a
+                // possibility), and we have not violated any safety invariants
+                // that we must fix before returning.
+                <$t as TryFromBytes>::is_bit_valid(field)
+            })+
+        });
+        unsafe_impl!($($t: FromZeros),+ => FromZeros for ($($t,)+));
+    };
+}
+
+safety_comment! {
+    /// SAFETY:
+    /// Per the reference [1], tuples have the default (`repr(Rust)`)
+    /// representation, and so the order of their fields in memory and the
+    /// amount of padding between them are unspecified. None of the following
+    /// impls depend on either.
+    /// - `Immutable`: A tuple does not contain any `UnsafeCell`s outside of its
+    ///   fields, and all of its fields are `Immutable`.
+    /// - `TryFromBytes`: The bit validity of a tuple is the composition of the
+    ///   bit validities of its fields. `is_bit_valid` locates each field using
+    ///   a field projection (rather than by assuming any particular offset),
+    ///   and only returns `true` if each field is bit-valid.
+    /// - `FromZeros`: Since each field is `FromZeros`, the all-zeros bit
+    ///   pattern is valid for each field, wherever it is located. Padding bytes
+    ///   may hold any value.
+    ///
+    /// NOTE: Implementing `FromBytes`, `IntoBytes`, or `Unaligned` for tuples
+    /// is deliberately not supported. Since a tuple's layout is unspecified,
+    /// the meaning of a given sequence of bytes as a tuple - and whether the
+    /// tuple contains padding - may differ between compiler versions. Users who
+    /// need a stable byte layout should use a `#[repr(C)]` struct instead.
+    ///
+    /// [1] https://doc.rust-lang.org/reference/type-layout.html#tuple-layout
+    impl_for_tuple!(A 0);
+    impl_for_tuple!(A 0, B 1);
+    impl_for_tuple!(A 0, B 1, C 2);
+    impl_for_tuple!(A 0, B 1, C 2, D 3);
+    impl_for_tuple!(A 0, B 1, C 2, D 3, E 4);
+    impl_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+    impl_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+    impl_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+    impl_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+    impl_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+    impl_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+    impl_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+}
+
 // SIMD support
 //
 // Per the Unsafe Code Guidelines Reference [1]:
@@ -4938,9 +7534,11 @@ mod simd {
 /// ```
 ///
 /// However, unlike a function, this macro can only be invoked when the types of
-/// `Src` and `Dst` are completely concrete. The types `Src` and `Dst` are
-/// inferred from the calling context; they cannot be explicitly specified in
-/// the macro invocation.
+/// `Src` and `Dst` are completely concrete, unless it is invoked as
+/// `transmute!(#![allow(generic)] $e)` (see [Generic
+/// types](#generic-types)). The types `Src` and `Dst` are inferred from the
+/// calling context; they cannot be explicitly specified in the macro
+/// invocation.
 ///
 /// Note that the `Src` produced by the expression `$e` will *not* be dropped.
 /// Semantically, its bits will be copied into a new value of type `Dst`, the
@@ -4961,11 +7559,130 @@ mod simd {
 /// # Use in `const` contexts
 ///
 /// This macro can be invoked in `const` contexts.
+///
+/// # Size mismatches
+///
+/// The size check is performed by [`core::mem::transmute`], so a size mismatch
+/// is reported as error [E0512], whose notes give the size of each type in
+/// bits:
+///
+/// ```text
+/// error[E0512]: cannot transmute between types of different sizes, or dependently-sized types
+///   |
+///   |     let x: u64 = transmute!([0u8; 4]);
+///   |                  ^^^^^^^^^^^^^^^^^^^^
+///   |
+///   = note: source type: `[u8; 4]` (32 bits)
+///   = note: target type: `u64` (64 bits)
+/// ```
+///
+/// The compiler can only compute these sizes when `Src` and `Dst` are
+/// concrete. If either type depends on a generic parameter (including a const
+/// generic array length), the sizes are reported as "this type does not have a
+/// fixed size", and the invocation is rejected *even if the sizes would be
+/// equal* for every instantiation:
+///
+/// ```compile_fail,E0512
+/// # use zerocopy::transmute;
+/// const fn widen<const N: usize>(bytes: [[u8; 2]; N]) -> [u16; N] {
+///     transmute!(bytes) // ⚠ Compile Error!
+/// }
+/// ```
+///
+/// In such cases, use `transmute!(#![allow(generic)] $e)`, described below.
+///
+/// # Generic types
+///
+/// `transmute!(#![allow(generic)] $e)` may be invoked when `Src` or `Dst` is
+/// generic, including in a `const fn` which is generic over an array length.
+/// Rather than relying on [`core::mem::transmute`], it checks that the sizes
+/// are equal using a constant which is evaluated separately for each
+/// instantiation. If the sizes differ, the error is reported at the
+/// invocation, and states both sizes in bytes:
+///
+/// ```text
+/// error[E0080]: attempt to compute `2_usize - 4_usize`, which would overflow
+///   |
+///   |     transmute!(#![allow(generic)] bytes)
+///   |     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ evaluation of `<[u8; 2] as truncate::TransmuteSizeEq<[u16; 2]>>::DST_SIZE_LE_SRC_SIZE` failed here
+/// ```
+///
+/// For example:
+///
+/// ```compile_fail,E0080
+/// # use zerocopy::transmute;
+/// const fn widen<const N: usize>(bytes: [[u8; 2]; N]) -> [u16; N] {
+///     transmute!(#![allow(generic)] bytes)
+/// }
+///
+/// const fn truncate<const N: usize>(bytes: [u8; N]) -> [u16; N] {
+///     transmute!(#![allow(generic)] bytes)
+/// }
+///
+/// const WIDE: [u16; 2] = widen([[1, 0], [2, 0]]); // OK
+/// const NARROW: [u16; 2] = truncate([1, 2]); // ⚠ Compile Error!
+/// ```
+///
+/// Since this check is only performed once the invocation is monomorphized, a
+/// size mismatch in a function which is never instantiated (or which is only
+/// type-checked, as by `cargo check`) is not reported. Prefer the plain form
+/// whenever `Src` and `Dst` are concrete.
+///
+/// [E0512]: https://doc.rust-lang.org/error_codes/E0512.html
 #[macro_export]
 macro_rules! transmute {
-    ($e:expr) => {{
-        // NOTE: This must be a macro (rather than a function with trait bounds)
-        // because there's no way, in a generic context, to enforce that two
+    (#![allow(generic)] $e:expr) => {{
+        let e = $e;
+        if false {
+            // This branch, though never taken, ensures that the type of `e` is
+            // `IntoBytes` and that the type of this macro invocation expression
+            // is `FromBytes`.
+
+            struct AssertIsIntoBytes<T: $crate::IntoBytes>(T);
+            let _ = AssertIsIntoBytes(e);
+
+            struct AssertIsFromBytes<U: $crate::FromBytes>(U);
+            #[allow(unused, unreachable_code)]
+            let u = AssertIsFromBytes(loop {});
+            u.0
+        } else {
+            // `core::mem::transmute` rejects generic types, so we check the
+            // sizes after monomorphization instead. Each constant subtracts one
+            // size from the other, and so fails to evaluate (with an error
+            // which states both sizes) unless `size_of::<Src>() <=
+            // size_of::<Dst>()` and vice versa. The constants are defined here,
+            // rather than in `macro_util`, so that the error is reported at the
+            // invocation of this macro.
+            #[allow(clippy::arithmetic_side_effects)]
+            trait TransmuteSizeEq<Dst>: Sized {
+                const SRC_SIZE_LE_DST_SIZE: usize =
+                    $crate::macro_util::core_reexport::mem::size_of::<Dst>()
+                        - $crate::macro_util::core_reexport::mem::size_of::<Self>();
+                const DST_SIZE_LE_SRC_SIZE: usize =
+                    $crate::macro_util::core_reexport::mem::size_of::<Self>()
+                        - $crate::macro_util::core_reexport::mem::size_of::<Dst>();
+            }
+
+            impl<Src, Dst> TransmuteSizeEq<Dst> for Src {}
+
+            const unsafe fn transmute<Src, Dst>(src: Src) -> Dst {
+                let _: usize = <Src as TransmuteSizeEq<Dst>>::SRC_SIZE_LE_DST_SIZE;
+                let _: usize = <Src as TransmuteSizeEq<Dst>>::DST_SIZE_LE_SRC_SIZE;
+                // SAFETY: Above, we asserted that `size_of::<Src>() ==
+                // size_of::<Dst>()`. The caller promises that `Src:
+                // IntoBytes` and `Dst: FromBytes`.
+                unsafe { $crate::macro_util::transmute(src) }
+            }
+
+            // SAFETY: We know this transmute is safe thanks to the `IntoBytes`
+            // and `FromBytes` bounds enforced by the `false` branch.
+            let u = unsafe { transmute(e) };
+            $crate::macro_util::must_use(u)
+        }
+    }};
+    ($e:expr) => {{
+        // NOTE: This must be a macro (rather than a function with trait bounds)
+        // because there's no way, in a generic context, to enforce that two
         // types have the same size. `core::mem::transmute` uses compiler magic
         // to enforce this so long as the types are concrete.
 
@@ -5009,6 +7726,85 @@ macro_rules! transmute {
     }}
 }
 
+/// Conditionally transmutes a value of one type to a value of another type of
+/// the same size.
+///
+/// This macro behaves like an invocation of this function:
+///
+/// ```ignore
+/// fn try_transmute<Src, Dst>(src: Src) -> Result<Dst, ValidityError<Src, Dst>>
+/// where
+///     Src: IntoBytes,
+///     Dst: TryFromBytes,
+///     size_of::<Src>() == size_of::<Dst>(),
+/// {
+/// # /*
+///     ...
+/// # */
+/// }
+/// ```
+///
+/// However, unlike a function, this macro can only be invoked when the types of
+/// `Src` and `Dst` are completely concrete. The types `Src` and `Dst` are
+/// inferred from the calling context; they cannot be explicitly specified in
+/// the macro invocation.
+///
+/// Like [`transmute!`], `try_transmute!` fails to compile if `Src` and `Dst`
+/// have different sizes. Unlike [`transmute!`], `Dst` need only be
+/// [`TryFromBytes`]: the bits of `src` are validated at runtime and, if they
+/// are not a valid `Dst`, `src` is returned in the [`ValidityError`].
+///
+/// Note that, if the transmutation succeeds, the `Src` produced by the
+/// expression `$e` will *not* be dropped. Semantically, its bits will be
+/// copied into a new value of type `Dst`, the original `Src` will be forgotten,
+/// and the value of type `Dst` will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use zerocopy::*;
+/// // 0u8 → bool = false
+/// assert_eq!(try_transmute!(0u8), Ok(false));
+///
+/// // 1u8 → bool = true
+/// assert_eq!(try_transmute!(1u8), Ok(true));
+///
+/// // 2u8 → bool = error
+/// assert!(matches!(
+///     try_transmute!(2u8),
+///     Result::<bool, _>::Err(ValidityError { .. })
+/// ));
+/// ```
+#[macro_export]
+macro_rules! try_transmute {
+    ($e:expr) => {{
+        // NOTE: This must be a macro (rather than a function with trait bounds)
+        // because there's no way, in a generic context, to enforce that two
+        // types have the same size. `core::mem::transmute` uses compiler magic
+        // to enforce this so long as the types are concrete.
+
+        let e = $e;
+        if false {
+            // This branch, though never taken, ensures that `size_of::<Src>()
+            // == size_of::<Dst>()`.
+
+            // SAFETY: This code is never executed.
+            $crate::macro_util::core_reexport::result::Result::Ok(unsafe {
+                // Clippy:
+                // - It's okay to transmute a type to itself.
+                // - We can't annotate the types; this macro is designed to
+                //   infer the types from the calling context.
+                #[allow(clippy::useless_transmute, clippy::missing_transmute_annotations)]
+                $crate::macro_util::core_reexport::mem::transmute(e)
+            })
+        } else {
+            // SAFETY: The `false` branch ensures that `Src` and `Dst` have the
+            // same size.
+            unsafe { $crate::macro_util::try_transmute::<_, _>(e) }
+        }
+    }}
+}
+
 /// Safely transmutes a mutable or immutable reference of one type to an
 /// immutable reference of another type of the same size.
 ///
@@ -5153,6 +7949,147 @@ macro_rules! transmute_ref {
     }}
 }
 
+/// Safely transmutes a slice of one element type to a slice of another element
+/// type of the same size.
+///
+/// This macro behaves like an invocation of this function:
+///
+/// ```ignore
+/// const fn transmute_slice<'src, 'dst, Src, Dst>(src: &'src [Src]) -> &'dst [Dst]
+/// where
+///     'src: 'dst,
+///     Src: IntoBytes + Immutable,
+///     Dst: FromBytes + Immutable,
+///     size_of::<Src>() == size_of::<Dst>(),
+///     align_of::<Src>() >= align_of::<Dst>(),
+/// {
+/// # /*
+///     ...
+/// # */
+/// }
+/// ```
+///
+/// The returned slice has the same length as `src`.
+///
+/// However, unlike a function, this macro can only be invoked when the types of
+/// `Src` and `Dst` are completely concrete. The types `Src` and `Dst` are
+/// inferred from the calling context; they cannot be explicitly specified in
+/// the macro invocation.
+///
+/// # Examples
+///
+/// ```
+/// # use zerocopy::transmute_slice;
+/// let words: [u32; 2] = [u32::from_ne_bytes([0, 1, 2, 3]), u32::from_ne_bytes([4, 5, 6, 7])];
+///
+/// let pixels: &[[u8; 4]] = transmute_slice!(&words[..]);
+///
+/// assert_eq!(pixels, [[0, 1, 2, 3], [4, 5, 6, 7]]);
+/// ```
+///
+/// # Use in `const` contexts
+///
+/// This macro can be invoked in `const` contexts.
+///
+/// # Size mismatch error message
+///
+/// `transmute_slice!` fails to compile if the element types do not have the
+/// same size. For example, the following code:
+///
+/// ```compile_fail
+/// const MISMATCHED_SIZE: &[[u8; 2]] = zerocopy::transmute_slice!(&[0u8; 2][..]);
+/// ```
+///
+/// ...generates the following error:
+///
+/// ```text
+/// error[E0512]: cannot transmute between types of different sizes, or dependently-sized types
+///  --> src/lib.rs:1524:38
+///   |
+/// 5 | const MISMATCHED_SIZE: &[[u8; 2]] = zerocopy::transmute_slice!(&[0u8; 2][..]);
+///   |                                     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+///   |
+///   = note: source type: `u8` (8 bits)
+///   = note: target type: `[u8; 2]` (16 bits)
+///   = note: this error originates in the macro `$crate::assert_size_eq` which comes from the expansion of the macro `transmute_slice` (in Nightly builds, run with -Z macro-backtrace for more info)
+/// ```
+///
+/// The source and target types named in the error are the element types of
+/// the source and destination slices. An alignment increase generates an error
+/// in the same style as [`transmute_ref!`]; see [its
+/// documentation](transmute_ref!#alignment-increase-error-message) for an
+/// example.
+#[macro_export]
+macro_rules! transmute_slice {
+    ($e:expr) => {{
+        // NOTE: This must be a macro (rather than a function with trait bounds)
+        // because there's no way, in a generic context, to enforce that two
+        // types have the same size or alignment.
+
+        // Ensure that the source type is a slice reference (note that mutable
+        // references are implicitly reborrowed here).
+        let e: &[_] = $e;
+
+        #[allow(unused, clippy::diverging_sub_expression)]
+        if false {
+            // This branch, though never taken, ensures that the type of `e` is
+            // `&[T]` where `T: 't + IntoBytes + Immutable`, and that the type
+            // of this macro expression is `&[U]` where `U: 'u + FromBytes +
+            // Immutable`. `[T]: IntoBytes + Immutable` if and only if `T:
+            // IntoBytes + Immutable`, and likewise for `[U]`.
+
+            struct AssertSrcIsIntoBytes<'a, T: ?::core::marker::Sized + $crate::IntoBytes>(&'a T);
+            struct AssertSrcIsImmutable<'a, T: ?::core::marker::Sized + $crate::Immutable>(&'a T);
+            struct AssertDstIsFromBytes<'a, U: ?::core::marker::Sized + $crate::FromBytes>(&'a U);
+            struct AssertDstIsImmutable<'a, T: ?::core::marker::Sized + $crate::Immutable>(&'a T);
+
+            let _ = AssertSrcIsIntoBytes(e);
+            let _ = AssertSrcIsImmutable(e);
+
+            if true {
+                #[allow(unused, unreachable_code)]
+                let u = AssertDstIsFromBytes(loop {});
+                u.0
+            } else {
+                #[allow(unused, unreachable_code)]
+                let u = AssertDstIsImmutable(loop {});
+                u.0
+            }
+        } else if false {
+            // This branch, though never taken, ensures that `size_of::<T>() ==
+            // size_of::<U>()` and that that `align_of::<T>() >=
+            // align_of::<U>()`.
+
+            // `t` is inferred to have type `T` because it's assigned to `e` (of
+            // type `&[T]`) as `&[t]`.
+            let mut t = loop {};
+            e = &[t];
+
+            // `u` is inferred to have type `U` because it's used as `&[u]` as
+            // the value returned from this branch.
+            let u;
+
+            $crate::assert_size_eq!(t, u);
+            $crate::assert_align_gt_eq!(t, u);
+
+            &[u]
+        } else {
+            // SAFETY: For source element type `Src` and destination element
+            // type `Dst`:
+            // - We know that `Src: IntoBytes + Immutable` and `Dst: FromBytes +
+            //   Immutable` thanks to the uses of `AssertSrcIsIntoBytes`,
+            //   `AssertSrcIsImmutable`, `AssertDstIsFromBytes`, and
+            //   `AssertDstIsImmutable` above.
+            // - We know that `size_of::<Src>() == size_of::<Dst>()` thanks to
+            //   the use of `assert_size_eq!` above.
+            // - We know that `align_of::<Src>() >= align_of::<Dst>()` thanks to
+            //   the use of `assert_align_gt_eq!` above.
+            let u = unsafe { $crate::macro_util::transmute_slice(e) };
+            $crate::macro_util::must_use(u)
+        }
+    }}
+}
+
 /// Safely transmutes a mutable reference of one type to a mutable reference of
 /// another type of the same size.
 ///
@@ -5305,55 +8242,788 @@ macro_rules! transmute_mut {
     }}
 }
 
-/// Includes a file and safely transmutes it to a value of an arbitrary type.
+/// Conditionally transmutes a mutable or immutable reference of one type to an
+/// immutable reference of another type of the same size.
 ///
-/// The file will be included as a byte array, `[u8; N]`, which will be
-/// transmuted to another type, `T`. `T` is inferred from the calling context,
-/// and must implement [`FromBytes`].
+/// This macro behaves like an invocation of this function:
 ///
-/// The file is located relative to the current file (similarly to how modules
-/// are found). The provided path is interpreted in a platform-specific way at
-/// compile time. So, for instance, an invocation with a Windows path containing
-/// backslashes `\` would not compile correctly on Unix.
+/// ```ignore
+/// fn try_transmute_ref<Src, Dst>(src: &Src) -> Result<&Dst, ValidityError<&Src, Dst>>
+/// where
+///     Src: IntoBytes + Immutable,
+///     Dst: TryFromBytes + Immutable,
+///     size_of::<Src>() == size_of::<Dst>(),
+///     align_of::<Src>() >= align_of::<Dst>(),
+/// {
+/// # /*
+///     ...
+/// # */
+/// }
+/// ```
 ///
-/// `include_value!` is ignorant of byte order. For byte order-aware types, see
-/// the [`byteorder`] module.
+/// However, unlike a function, this macro can only be invoked when the types of
+/// `Src` and `Dst` are completely concrete. The types `Src` and `Dst` are
+/// inferred from the calling context; they cannot be explicitly specified in
+/// the macro invocation.
+///
+/// Like [`transmute_ref!`], `try_transmute_ref!` fails to compile if `Src` and
+/// `Dst` have different sizes or if `Dst` has a greater alignment than `Src`.
+/// Unlike [`transmute_ref!`], `Dst` need only be [`TryFromBytes`]: the referent
+/// of `src` is validated at runtime and, if it is not a valid `Dst`, `src` is
+/// returned in the [`ValidityError`].
 ///
 /// # Examples
 ///
-/// Assume there are two files in the same directory with the following
-/// contents:
+/// ```
+/// # use zerocopy::*;
+/// // 0u8 → bool = false
+/// assert_eq!(try_transmute_ref!(&0u8), Ok(&false));
+///
+/// // 1u8 → bool = true
+/// assert_eq!(try_transmute_ref!(&1u8), Ok(&true));
+///
+/// // 2u8 → bool = error
+/// assert!(matches!(
+///     try_transmute_ref!(&2u8),
+///     Result::<&bool, _>::Err(ValidityError { .. })
+/// ));
+/// ```
+///
+/// # Alignment increase error message
+///
+/// The error message generated when `try_transmute_ref!` is used to transmute
+/// from a type of lower alignment to a type of higher alignment is the same as
+/// that generated by [`transmute_ref!`]; see [its
+/// documentation](transmute_ref!#alignment-increase-error-message) for an
+/// explanation.
+#[macro_export]
+macro_rules! try_transmute_ref {
+    ($e:expr) => {{
+        // NOTE: This must be a macro (rather than a function with trait bounds)
+        // because there's no way, in a generic context, to enforce that two
+        // types have the same size or alignment.
+
+        // Ensure that the source type is a reference or a mutable reference
+        // (note that mutable references are implicitly reborrowed here).
+        let e: &_ = $e;
+
+        #[allow(unreachable_code, unused, clippy::diverging_sub_expression)]
+        if false {
+            // This branch, though never taken, ensures that `size_of::<T>() ==
+            // size_of::<U>()` and that that `align_of::<T>() >=
+            // align_of::<U>()`.
+
+            // `t` is inferred to have type `T` because it's assigned to `e` (of
+            // type `&T`) as `&t`.
+            let mut t = loop {};
+            e = &t;
+
+            // `u` is inferred to have type `U` because it's used as `Ok(&u)` as
+            // the value returned from this branch.
+            let u;
+
+            $crate::assert_size_eq!(t, u);
+            $crate::assert_align_gt_eq!(t, u);
+
+            $crate::macro_util::core_reexport::result::Result::Ok(&u)
+        } else {
+            // SAFETY: For source type `Src` and destination type `Dst`:
+            // - We know that `size_of::<Src>() == size_of::<Dst>()` thanks to
+            //   the use of `assert_size_eq!` above.
+            // - We know that `align_of::<Src>() >= align_of::<Dst>()` thanks to
+            //   the use of `assert_align_gt_eq!` above.
+            unsafe { $crate::macro_util::try_transmute_ref::<_, _>(e) }
+        }
+    }}
+}
+
+/// Conditionally transmutes a mutable reference of one type to a mutable
+/// reference of another type of the same size.
+///
+/// This macro behaves like an invocation of this function:
+///
+/// ```ignore
+/// fn try_transmute_mut<Src, Dst>(src: &mut Src) -> Result<&mut Dst, ValidityError<&mut Src, Dst>>
+/// where
+///     Src: FromBytes + IntoBytes,
+///     Dst: TryFromBytes + IntoBytes,
+///     size_of::<Src>() == size_of::<Dst>(),
+///     align_of::<Src>() >= align_of::<Dst>(),
+/// {
+/// # /*
+///     ...
+/// # */
+/// }
+/// ```
+///
+/// However, unlike a function, this macro can only be invoked when the types of
+/// `Src` and `Dst` are completely concrete. The types `Src` and `Dst` are
+/// inferred from the calling context; they cannot be explicitly specified in
+/// the macro invocation.
+///
+/// Like [`transmute_mut!`], `try_transmute_mut!` fails to compile if `Src` and
+/// `Dst` have different sizes or if `Dst` has a greater alignment than `Src`.
+/// Unlike [`transmute_mut!`], `Dst` need only be [`TryFromBytes`]: the referent
+/// of `src` is validated at runtime and, if it is not a valid `Dst`, `src` is
+/// returned in the [`ValidityError`]. `Dst` must still be [`IntoBytes`] so that
+/// any value written through the returned reference is a valid `Src`.
+///
+/// # Examples
+///
+/// ```
+/// # use zerocopy::*;
+/// let mut byte = 1u8;
+/// let flag: &mut bool = try_transmute_mut!(&mut byte).unwrap();
+/// assert!(*flag);
+/// *flag = false;
+/// assert_eq!(byte, 0);
+///
+/// // 2u8 → bool = error
+/// let mut byte = 2u8;
+/// assert!(matches!(
+///     try_transmute_mut!(&mut byte),
+///     Result::<&mut bool, _>::Err(ValidityError { .. })
+/// ));
+/// ```
+///
+/// # Alignment increase error message
+///
+/// The error message generated when `try_transmute_mut!` is used to transmute
+/// from a type of lower alignment to a type of higher alignment is the same as
+/// that generated by [`transmute_mut!`]; see [its
+/// documentation](transmute_mut!#alignment-increase-error-message) for an
+/// explanation.
+#[macro_export]
+macro_rules! try_transmute_mut {
+    ($e:expr) => {{
+        // NOTE: This must be a macro (rather than a function with trait bounds)
+        // because there's no way, in a generic context, to enforce that two
+        // types have the same size or alignment.
+
+        // Ensure that the source type is a mutable reference.
+        let e: &mut _ = $e;
+
+        #[allow(unreachable_code, unused, clippy::diverging_sub_expression)]
+        if false {
+            // This branch, though never taken, ensures that `size_of::<T>() ==
+            // size_of::<U>()` and that that `align_of::<T>() >=
+            // align_of::<U>()`.
+
+            // `t` is inferred to have type `T` because it's assigned to `e` (of
+            // type `&mut T`) as `&mut t`.
+            let mut t = loop {};
+            e = &mut t;
+
+            // `u` is inferred to have type `U` because it's used as `Ok(&mut
+            // u)` as the value returned from this branch.
+            let u;
+
+            $crate::assert_size_eq!(t, u);
+            $crate::assert_align_gt_eq!(t, u);
+
+            $crate::macro_util::core_reexport::result::Result::Ok(&mut u)
+        } else {
+            // SAFETY: For source type `Src` and destination type `Dst`:
+            // - We know that `size_of::<Src>() == size_of::<Dst>()` thanks to
+            //   the use of `assert_size_eq!` above.
+            // - We know that `align_of::<Src>() >= align_of::<Dst>()` thanks to
+            //   the use of `assert_align_gt_eq!` above.
+            unsafe { $crate::macro_util::try_transmute_mut::<_, _>(e) }
+        }
+    }}
+}
+
+/// Constructs an all-zeros value of a [`FromZeros`] type.
+///
+/// `zeroed!(T)` evaluates to the value of type `T` whose bytes are all zero.
+/// It is equivalent to [`FromZeros::new_zeroed`], except that, unlike
+/// `new_zeroed`, it can be used in `const` contexts. This is useful for
+/// defining constants such as a configuration struct's default value:
+///
+/// ```
+/// # use zerocopy::zeroed;
+/// # use zerocopy_derive::*;
+/// #[derive(FromZeros)]
+/// #[repr(C)]
+/// struct Config {
+///     flags: u32,
+///     timeout: Option<core::num::NonZeroU32>,
+/// }
+///
+/// impl Config {
+///     const DEFAULT: Config = zeroed!(Config);
+/// }
+///
+/// assert_eq!(Config::DEFAULT.flags, 0);
+/// assert_eq!(Config::DEFAULT.timeout, None);
+/// ```
+///
+/// `T` must be a concrete type; `zeroed!` cannot be used with a generic type
+/// parameter.
+///
+/// # Use in `const` contexts
+///
+/// This macro can be invoked in `const` contexts on all supported toolchains.
+/// By contrast, [`core::mem::zeroed`] can only be called in `const` contexts
+/// from Rust 1.75 onwards, and is `unsafe` to call since it does not check that
+/// the all-zeros value is valid for the type.
+#[macro_export]
+macro_rules! zeroed {
+    ($ty:ty) => {{
+        if false {
+            // This branch, though never taken, ensures that `$ty: FromZeros`.
+            struct AssertIsFromZeros<T: $crate::FromZeros>(T);
+            #[allow(unused, unreachable_code)]
+            let t = AssertIsFromZeros::<$ty>(loop {});
+            t.0
+        } else {
+            const LEN: usize = $crate::macro_util::core_reexport::mem::size_of::<$ty>();
+            // SAFETY: `[u8; LEN]` and `$ty` have the same size by construction,
+            // and the all-zeros byte sequence is a valid instance of `$ty`
+            // because `$ty: FromZeros`, as enforced by the `false` branch.
+            let t = unsafe {
+                $crate::macro_util::core_reexport::mem::transmute::<[u8; LEN], $ty>([0u8; LEN])
+            };
+            $crate::macro_util::must_use(t)
+        }
+    }};
+}
+
+/// Determines whether a type is [`FromBytes`].
+///
+/// `is_from_bytes!(T)` evaluates to a `bool` constant which is `true` if `T:
+/// FromBytes` is known to hold, and `false` otherwise. It can be used in
+/// `const` contexts, and so can select between a zero-copy code path and a
+/// copying code path at compile time:
+///
+/// ```
+/// # use zerocopy::is_from_bytes;
+/// # use zerocopy_derive::*;
+/// #[derive(FromBytes)]
+/// #[repr(C)]
+/// struct Plain {
+///     a: u32,
+/// }
+///
+/// #[derive(TryFromBytes)]
+/// #[repr(C)]
+/// struct WithBool {
+///     a: u32,
+///     b: bool,
+/// }
+///
+/// const PLAIN: bool = is_from_bytes!(Plain);
+/// assert!(PLAIN);
+/// assert!(!is_from_bytes!(WithBool));
+/// ```
+///
+/// # Mechanism
+///
+/// Rust does not support specialization, and so there is no way to ask
+/// whether an arbitrary type implements a trait. Instead, `is_from_bytes!`
+/// relies on the fact that, when resolving an associated item path, an inherent
+/// associated constant takes precedence over a trait's associated constant, but
+/// only if the bounds on the inherent impl hold. zerocopy defines an inherent
+/// `IS_FROM_BYTES = true` constant which requires `T: FromBytes`, and a
+/// fallback trait constant `IS_FROM_BYTES = false` which applies to all types.
+///
+/// As a consequence, the result reflects only what is known where
+/// `is_from_bytes!` is invoked. For a concrete type, that is whether the type
+/// is `FromBytes`. For a generic type parameter, however, it is whether a `T:
+/// FromBytes` bound is in scope, *not* whether the type eventually substituted
+/// for `T` is `FromBytes`:
+///
+/// ```
+/// # use zerocopy::{is_from_bytes, FromBytes};
+/// fn unbounded<T>() -> bool {
+///     is_from_bytes!(T)
+/// }
+///
+/// fn bounded<T: FromBytes>() -> bool {
+///     is_from_bytes!(T)
+/// }
+///
+/// // `u8: FromBytes`, but `unbounded` can't know that.
+/// assert!(!unbounded::<u8>());
+/// assert!(bounded::<u8>());
+/// ```
+///
+/// Since a `false` result doesn't prove that a type is not `FromBytes`, it
+/// must only be used to choose a slower fallback path, and never to justify
+/// unsafe code.
+#[macro_export]
+macro_rules! is_from_bytes {
+    ($ty:ty) => {{
+        #[allow(unused_imports)]
+        use $crate::macro_util::IsFromBytesFallback as _;
+        <$crate::macro_util::IsFromBytes<$ty>>::IS_FROM_BYTES
+    }};
+}
+
+/// Collects the bytes of several [`IntoBytes`] values into a `Vec` of
+/// [`IoSlice`]s.
+///
+/// `io_slices!(a, b, ...)` evaluates to a `Vec<IoSlice<'_>>` containing
+/// [`a.as_io_slice()`][as_io_slice], [`b.as_io_slice()`][as_io_slice], and so
+/// on, in order. The values may have different types, and each may be passed
+/// either by value or by reference; either way, it is borrowed rather than
+/// copied. The result can be passed to vectored I/O methods such as
+/// [`Write::write_vectored`] to write several values - e.g., a header and a
+/// body - with a single call, and without first concatenating them.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use zerocopy::io_slices;
+/// # use zerocopy_derive::*;
+///
+/// #[derive(IntoBytes, Immutable)]
+/// #[repr(C)]
+/// struct Header {
+///     kind: u8,
+///     len: u8,
+/// }
+///
+/// let body = [1u8, 2, 3];
+/// let header = Header { kind: 7, len: body.len() as u8 };
+///
+/// let slices = io_slices!(header, body);
+/// assert_eq!(slices.len(), 2);
+///
+/// let mut out = Vec::new();
+/// let written = out.write_vectored(&slices).unwrap();
+/// assert_eq!(written, 5);
+/// assert_eq!(out, [7, 3, 1, 2, 3]);
+/// ```
+///
+/// [`IoSlice`]: std::io::IoSlice
+/// [as_io_slice]: IntoBytes::as_io_slice
+/// [`Write::write_vectored`]: std::io::Write::write_vectored
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+#[macro_export]
+macro_rules! io_slices {
+    ($($e:expr),* $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::IntoBytes as _;
+        $crate::macro_util::io_slices([$(($e).as_io_slice()),*])
+    }};
+}
+
+/// Includes a file and safely transmutes it to a value of an arbitrary type.
+///
+/// The file will be included as a byte array, `[u8; N]`, which will be
+/// transmuted to another type, `T`. `T` is inferred from the calling context,
+/// and must implement [`FromBytes`].
+///
+/// The file is located relative to the current file (similarly to how modules
+/// are found). The provided path is interpreted in a platform-specific way at
+/// compile time. So, for instance, an invocation with a Windows path containing
+/// backslashes `\` would not compile correctly on Unix.
+///
+/// `include_value!` is ignorant of byte order. For byte order-aware types, see
+/// the [`byteorder`] module.
+///
+/// # Examples
+///
+/// Assume there are two files in the same directory with the following
+/// contents:
+///
+/// File `data` (no trailing newline):
+///
+/// ```text
+/// abcd
+/// ```
+///
+/// File `main.rs`:
+///
+/// ```rust
+/// use zerocopy::include_value;
+/// # macro_rules! include_value {
+/// # ($file:expr) => { zerocopy::include_value!(concat!("../testdata/include_value/", $file)) };
+/// # }
+///
+/// fn main() {
+///     let as_u32: u32 = include_value!("data");
+///     assert_eq!(as_u32, u32::from_ne_bytes([b'a', b'b', b'c', b'd']));
+///     let as_i32: i32 = include_value!("data");
+///     assert_eq!(as_i32, i32::from_ne_bytes([b'a', b'b', b'c', b'd']));
+/// }
+/// ```
+///
+/// # Use in `const` contexts
+///
+/// This macro can be invoked in `const` contexts.
+#[doc(alias("include_bytes", "include_data", "include_type"))]
+#[macro_export]
+macro_rules! include_value {
+    ($file:expr $(,)?) => {
+        $crate::transmute!(*::core::include_bytes!($file))
+    };
+}
+
+/// Parses a header, and then one of several body types selected by a tag in
+/// that header.
+///
+/// `parse_tagged!` codifies a common shape of binary protocols: a fixed-size
+/// header containing a tag (or "kind", "type", "opcode", etc), followed by a
+/// body whose type depends on the value of that tag. It is invoked as:
+///
+/// ```ignore
+/// parse_tagged!(bytes, |header: &Header| tag_expr, {
+///     TAG_PATTERN => Enum::Variant(BodyType),
+///     ...
+/// })
+/// ```
+///
+/// `bytes` is a `&[u8]`. Its prefix is parsed as a `&Header` using
+/// [`FromBytes::ref_from_prefix`], and then `tag_expr` is evaluated with
+/// `header` bound to that reference. The result is matched against each
+/// `TAG_PATTERN` in turn. For the first arm which matches, the bytes following
+/// the header are parsed as a `&BodyType` (again using `ref_from_prefix`), and
+/// the result is wrapped in `Enum::Variant`.
+///
+/// On success, `parse_tagged!` evaluates to `Ok((header, body, rest))`, where
+/// `header` is the `&Header`, `body` is the `Enum` and `rest` is the bytes
+/// following the body. On failure, it evaluates to `Err` of a [`TaggedError`]
+/// describing what went wrong.
+///
+/// `Header` and each `BodyType` must implement [`FromBytes`], [`KnownLayout`],
+/// and [`Immutable`]. The type of `tag_expr` must implement [`Display`] in
+/// order for the resulting error to be displayed.
+///
+/// # Examples
+///
+/// ```
+/// use zerocopy::{parse_tagged, TaggedError};
+/// # use zerocopy_derive::*;
+///
+/// #[derive(FromBytes, KnownLayout, Immutable)]
+/// #[repr(C)]
+/// struct Header {
+///     kind: u8,
+///     len: u8,
+/// }
+///
+/// #[derive(FromBytes, KnownLayout, Immutable)]
+/// #[repr(C)]
+/// struct Ping {
+///     seq: [u8; 2],
+/// }
+///
+/// #[derive(FromBytes, KnownLayout, Immutable)]
+/// #[repr(C)]
+/// struct Data {
+///     payload: [u8],
+/// }
+///
+/// enum Body<'a> {
+///     Ping(&'a Ping),
+///     Data(&'a Data),
+/// }
+///
+/// let bytes = &[0, 2, 7, 8, 9][..];
+/// let (header, body, rest) = parse_tagged!(bytes, |header: &Header| header.kind, {
+///     0 => Body::Ping(Ping),
+///     1 => Body::Data(Data),
+/// })
+/// .unwrap();
+///
+/// assert_eq!(header.len, 2);
+/// assert!(matches!(body, Body::Ping(ping) if ping.seq == [7, 8]));
+/// assert_eq!(rest, [9]);
+///
+/// // The tag `2` does not select any body type.
+/// let bytes = &[2, 0][..];
+/// let res = parse_tagged!(bytes, |header: &Header| header.kind, {
+///     0 => Body::Ping(Ping),
+///     1 => Body::Data(Data),
+/// });
+///
+/// assert!(matches!(res, Err(TaggedError::UnknownTag(2, _))));
+/// ```
+#[macro_export]
+macro_rules! parse_tagged {
+    (
+        $bytes:expr,
+        |$header:ident: &$header_ty:ty| $tag:expr,
+        { $($($tag_pat:pat)|+ => $($variant:ident)::+ ($body_ty:ty)),+ $(,)? }
+    ) => {{
+        let bytes: &[u8] = $bytes;
+        match <$header_ty as $crate::FromBytes>::ref_from_prefix(bytes) {
+            $crate::macro_util::core_reexport::result::Result::Ok((header, rest)) => {
+                let tag = {
+                    let $header: &$header_ty = header;
+                    $tag
+                };
+                match tag {
+                    $($($tag_pat)|+ => match <$body_ty as $crate::FromBytes>::ref_from_prefix(rest) {
+                        $crate::macro_util::core_reexport::result::Result::Ok((body, rest)) => {
+                            $crate::macro_util::core_reexport::result::Result::Ok((
+                                header,
+                                $($variant)::+(body),
+                                rest,
+                            ))
+                        }
+                        $crate::macro_util::core_reexport::result::Result::Err(err) => {
+                            $crate::macro_util::core_reexport::result::Result::Err(
+                                $crate::TaggedError::Body($crate::BodyError::from(err)),
+                            )
+                        }
+                    },)+
+                    // If the tag patterns are exhaustive, this arm is
+                    // unreachable.
+                    #[allow(unreachable_patterns)]
+                    _ => $crate::macro_util::core_reexport::result::Result::Err(
+                        $crate::TaggedError::UnknownTag(tag, rest),
+                    ),
+                }
+            }
+            $crate::macro_util::core_reexport::result::Result::Err(err) => {
+                $crate::macro_util::core_reexport::result::Result::Err(
+                    $crate::TaggedError::Header(err),
+                )
+            }
+        }
+    }};
+}
+
+/// Asserts at compile time that a type has a particular size and alignment.
+///
+/// `require_layout!(T, size = SIZE, align = ALIGN)` fails compilation unless
+/// `size_of::<T>() == SIZE` and `align_of::<T>() == ALIGN`. Unlike comparing
+/// the layouts of two types, this checks `T` against absolute values, which is
+/// useful when `T` must match an externally-defined layout such as that of a
+/// hardware register or a wire format.
+///
+/// `require_layout!` expands to a `()`-typed expression, and so may be invoked
+/// as a statement in a function body. To use it in item position (e.g., at
+/// module scope), assign it to an anonymous constant:
+/// `const _: () = require_layout!(T, size = SIZE, align = ALIGN);`. It has no
+/// runtime cost.
+///
+/// # Examples
+///
+/// ```
+/// # use zerocopy::require_layout;
+/// #[repr(C, align(8))]
+/// struct Register {
+///     status: u32,
+///     control: u32,
+/// }
+///
+/// const _: () = require_layout!(Register, size = 8, align = 8);
+///
+/// fn main() {
+///     require_layout!(u16, size = 2, align = 2);
+/// }
+/// ```
+///
+/// If the layout differs, compilation fails:
+///
+/// ```compile_fail,E0080
+/// # use zerocopy::require_layout;
+/// #[repr(C)]
+/// struct Register {
+///     status: u32,
+///     control: u32,
+/// }
+///
+/// // `Register` is only 4-aligned.
+/// const _: () = require_layout!(Register, size = 8, align = 8);
+/// ```
+///
+/// # Generic types
+///
+/// `require_layout!` may refer to generic type parameters which are in scope
+/// at the invocation site. In this case, the assertion is checked when the
+/// enclosing function is monomorphized - that is, for each concrete type with
+/// which it is used - rather than when it is defined:
+///
+/// ```compile_fail,E0080
+/// # use zerocopy::require_layout;
+/// fn write_register<T>(val: T) {
+///     require_layout!(T, size = 8, align = 8);
+///     // ...
+/// #   let _ = val;
+/// }
+///
+/// write_register(0u64); // OK
+/// write_register(0u32); // ⚠ Compile Error!
+/// ```
+#[macro_export]
+macro_rules! require_layout {
+    ($ty:ty, size = $size:expr, align = $align:expr $(,)?) => {{
+        // These associated constants are evaluated after monomorphization, and
+        // so `$ty` may refer to generic type parameters.
+        let _: bool = <$ty as $crate::macro_util::RequireLayout<{ $size }, { $align }>>::SIZE_IS_REQUIRED_SIZE;
+        let _: bool = <$ty as $crate::macro_util::RequireLayout<{ $size }, { $align }>>::ALIGN_IS_REQUIRED_ALIGN;
+    }};
+}
+
+/// Asserts at compile time that types implement [`KnownLayout`].
+///
+/// `assert_known_layout!(T, U, ...)` fails compilation unless every listed type
+/// implements `KnownLayout`. It produces no runtime code. See
+/// [`assert_from_bytes!`] for more details.
+///
+/// # Examples
+///
+/// ```
+/// # use zerocopy::assert_known_layout;
+/// assert_known_layout!([u8]);
+/// ```
+#[macro_export]
+macro_rules! assert_known_layout {
+    ($($ty:ty),+ $(,)?) => {
+        $crate::assert_trait_impl!($crate::KnownLayout; $($ty),+);
+    };
+}
+
+/// Asserts at compile time that types implement [`Immutable`].
+///
+/// `assert_immutable!(T, U, ...)` fails compilation unless every listed type
+/// implements `Immutable`. It produces no runtime code. See
+/// [`assert_from_bytes!`] for more details.
+///
+/// # Examples
+///
+/// ```
+/// # use zerocopy::assert_immutable;
+/// assert_immutable!(u32);
+/// ```
+#[macro_export]
+macro_rules! assert_immutable {
+    ($($ty:ty),+ $(,)?) => {
+        $crate::assert_trait_impl!($crate::Immutable; $($ty),+);
+    };
+}
+
+/// Asserts at compile time that types implement [`TryFromBytes`].
+///
+/// `assert_try_from_bytes!(T, U, ...)` fails compilation unless every listed type
+/// implements `TryFromBytes`. It produces no runtime code. See
+/// [`assert_from_bytes!`] for more details.
+///
+/// # Examples
+///
+/// ```
+/// # use zerocopy::assert_try_from_bytes;
+/// assert_try_from_bytes!(bool);
+/// ```
+#[macro_export]
+macro_rules! assert_try_from_bytes {
+    ($($ty:ty),+ $(,)?) => {
+        $crate::assert_trait_impl!($crate::TryFromBytes; $($ty),+);
+    };
+}
+
+/// Asserts at compile time that types implement [`FromZeros`].
+///
+/// `assert_from_zeros!(T, U, ...)` fails compilation unless every listed type
+/// implements `FromZeros`. It produces no runtime code. See
+/// [`assert_from_bytes!`] for more details.
+///
+/// # Examples
+///
+/// ```
+/// # use zerocopy::assert_from_zeros;
+/// assert_from_zeros!(Option<&'static u8>);
+/// ```
+#[macro_export]
+macro_rules! assert_from_zeros {
+    ($($ty:ty),+ $(,)?) => {
+        $crate::assert_trait_impl!($crate::FromZeros; $($ty),+);
+    };
+}
+
+/// Asserts at compile time that types implement [`FromBytes`].
+///
+/// `assert_from_bytes!(T, U, ...)` fails compilation unless every listed type
+/// implements `FromBytes`. This is useful for guaranteeing that a type
+/// continues to implement `FromBytes` as it evolves - for example, in a crate
+/// which exposes the type but never itself requires the bound - without
+/// depending on a separate assertion crate. Analogous macros exist for the
+/// other traits: [`assert_known_layout!`], [`assert_immutable!`],
+/// [`assert_try_from_bytes!`], [`assert_from_zeros!`],
+/// [`assert_into_bytes!`], and [`assert_unaligned!`].
+///
+/// These macros expand to a `const` item, and so may be invoked either at item
+/// position (e.g., at module scope) or inside a function body. They produce no
+/// runtime code.
+///
+/// # Examples
+///
+/// ```
+/// # use zerocopy::assert_from_bytes;
+/// # use zerocopy_derive::*;
+/// #[derive(FromBytes)]
+/// #[repr(C)]
+/// struct PacketHeader {
+///     src_port: [u8; 2],
+///     dst_port: [u8; 2],
+/// }
+///
+/// assert_from_bytes!(PacketHeader, [PacketHeader], u64);
+/// ```
 ///
-/// File `data` (no trailing newline):
+/// If a type does not implement `FromBytes`, compilation fails:
 ///
-/// ```text
-/// abcd
+/// ```compile_fail,E0277
+/// # use zerocopy::assert_from_bytes;
+/// // `bool` is not `FromBytes`, since not every byte is a valid `bool`.
+/// assert_from_bytes!(bool);
 /// ```
 ///
-/// File `main.rs`:
+/// # Generic types
 ///
-/// ```rust
-/// use zerocopy::include_value;
-/// # macro_rules! include_value {
-/// # ($file:expr) => { zerocopy::include_value!(concat!("../testdata/include_value/", $file)) };
-/// # }
+/// Since it expands to a `const` item, `assert_from_bytes!` cannot refer to
+/// generic type parameters which are in scope at the invocation site. It can,
+/// however, be used with concrete instantiations of generic types, such as
+/// `assert_from_bytes!(Wrapper<u64>)`.
+#[macro_export]
+macro_rules! assert_from_bytes {
+    ($($ty:ty),+ $(,)?) => {
+        $crate::assert_trait_impl!($crate::FromBytes; $($ty),+);
+    };
+}
+
+/// Asserts at compile time that types implement [`IntoBytes`].
 ///
-/// fn main() {
-///     let as_u32: u32 = include_value!("data");
-///     assert_eq!(as_u32, u32::from_ne_bytes([b'a', b'b', b'c', b'd']));
-///     let as_i32: i32 = include_value!("data");
-///     assert_eq!(as_i32, i32::from_ne_bytes([b'a', b'b', b'c', b'd']));
-/// }
+/// `assert_into_bytes!(T, U, ...)` fails compilation unless every listed type
+/// implements `IntoBytes`. It produces no runtime code. See
+/// [`assert_from_bytes!`] for more details.
+///
+/// # Examples
+///
+/// ```
+/// # use zerocopy::assert_into_bytes;
+/// assert_into_bytes!([u16]);
 /// ```
+#[macro_export]
+macro_rules! assert_into_bytes {
+    ($($ty:ty),+ $(,)?) => {
+        $crate::assert_trait_impl!($crate::IntoBytes; $($ty),+);
+    };
+}
+
+/// Asserts at compile time that types implement [`Unaligned`].
 ///
-/// # Use in `const` contexts
+/// `assert_unaligned!(T, U, ...)` fails compilation unless every listed type
+/// implements `Unaligned`. It produces no runtime code. See
+/// [`assert_from_bytes!`] for more details.
 ///
-/// This macro can be invoked in `const` contexts.
-#[doc(alias("include_bytes", "include_data", "include_type"))]
+/// # Examples
+///
+/// ```
+/// # use zerocopy::assert_unaligned;
+/// assert_unaligned!([u8; 4]);
+/// ```
 #[macro_export]
-macro_rules! include_value {
-    ($file:expr $(,)?) => {
-        $crate::transmute!(*::core::include_bytes!($file))
+macro_rules! assert_unaligned {
+    ($($ty:ty),+ $(,)?) => {
+        $crate::assert_trait_impl!($crate::Unaligned; $($ty),+);
     };
 }
 
@@ -5366,6 +9036,19 @@ macro_rules! include_value {
 /// [`Ref<[u8]>`]: core::cell::Ref
 /// [`RefMut<[u8]>`]: core::cell::RefMut
 ///
+/// References to byte arrays, such as `&[u8; N]`, dereference to `[u8; N]`
+/// rather than to `[u8]`, and so do not implement `ByteSlice`. To use a byte
+/// array as the backing storage of a [`Ref`], coerce its reference to a slice
+/// reference, either explicitly or by naming the byte slice type:
+///
+/// ```
+/// use zerocopy::Ref;
+///
+/// let buf = [0u8; 8];
+/// let _ = Ref::<_, [u8; 8]>::from(&buf[..]).unwrap();
+/// let _ = Ref::<&[u8], [u8; 8]>::from(&buf).unwrap();
+/// ```
+///
 /// # Safety
 ///
 /// Implementations of `ByteSlice` must promise that their implementations of
@@ -5419,17 +9102,21 @@ pub unsafe trait CloneableByteSlice: ByteSlice + Clone {}
 ///
 /// # Safety
 ///
-/// Unsafe code may depend for its soundness on the assumption that `split_at`
-/// and `split_at_unchecked` are implemented correctly. In particular, given `B:
-/// SplitByteSlice` and `b: B`, if `b.deref()` returns a byte slice with address
-/// `addr` and length `len`, then if `split <= len`, both of these
-/// invocations:
+/// Unsafe code may depend for its soundness on the assumption that `split_at`,
+/// `split_at_unchecked`, and `try_split_at` are implemented correctly. In
+/// particular, given `B: SplitByteSlice` and `b: B`, if `b.deref()` returns a
+/// byte slice with address `addr` and length `len`, then if `split <= len`, all
+/// of these invocations:
 /// - `b.split_at(split)`
 /// - `b.split_at_unchecked(split)`
+/// - `b.try_split_at(split)`
 ///
-/// ...will return `(first, second)` such that:
+/// ...will return `(first, second)` (wrapped in `Ok` in the case of
+/// `try_split_at`) such that:
 /// - `first`'s address is `addr` and its length is `split`
 /// - `second`'s address is `addr + split` and its length is `len - split`
+///
+/// If `split > len`, then `b.try_split_at(split)` will return `Err(b)`.
 pub unsafe trait SplitByteSlice: ByteSlice {
     /// Splits the slice at the midpoint.
     ///
@@ -5441,13 +9128,57 @@ pub unsafe trait SplitByteSlice: ByteSlice {
     #[must_use]
     #[inline]
     fn split_at(self, mid: usize) -> (Self, Self) {
-        if let Ok(splits) = try_split_at(self, mid) {
+        if let Ok(splits) = self.try_split_at(mid) {
             splits
         } else {
             panic!("mid > len")
         }
     }
 
+    /// Attempts to split the slice at the midpoint.
+    ///
+    /// `x.try_split_at(mid)` returns `Ok((x[..mid], x[mid..]))` if `mid <=
+    /// x.deref().len()` and otherwise returns `Err(x)`, so that ownership of
+    /// `x` is not lost. Unlike [`split_at`], it never panics, which makes it
+    /// suitable for splitting at lengths read from untrusted input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::SplitByteSlice;
+    ///
+    /// let bytes = &[0u8, 1, 2, 3, 4][..];
+    ///
+    /// let (first, second) = bytes.try_split_at(2).unwrap();
+    /// assert_eq!(first, [0, 1]);
+    /// assert_eq!(second, [2, 3, 4]);
+    ///
+    /// // `mid` may be equal to the length of the slice...
+    /// let (first, second) = bytes.try_split_at(5).unwrap();
+    /// assert_eq!(first, [0, 1, 2, 3, 4]);
+    /// assert!(second.is_empty());
+    ///
+    /// // ...but if it is beyond the end, the original slice is returned.
+    /// let bytes = bytes.try_split_at(6).unwrap_err();
+    /// assert_eq!(bytes, [0, 1, 2, 3, 4]);
+    /// ```
+    ///
+    /// [`split_at`]: SplitByteSlice::split_at
+    #[inline]
+    fn try_split_at(self, mid: usize) -> Result<(Self, Self), Self> {
+        if mid <= self.deref().len() {
+            // SAFETY: Above, we ensure that `mid <= self.deref().len()`. By
+            // invariant on `ByteSlice`, a supertrait of `SplitByteSlice`,
+            // `.deref()` is guranteed to be "stable"; i.e., it will always
+            // dereference to a byte slice of the same address and length.
+            // Thus, we can be sure that the above precondition remains
+            // satisfied through the call to `split_at_unchecked`.
+            unsafe { Ok(self.split_at_unchecked(mid)) }
+        } else {
+            Err(self)
+        }
+    }
+
     /// Splits the slice at the midpoint, possibly omitting bounds checks.
     ///
     /// `x.split_at_unchecked(mid)` returns `x[..mid]` and `x[mid..]`.
@@ -5459,33 +9190,6 @@ pub unsafe trait SplitByteSlice: ByteSlice {
     unsafe fn split_at_unchecked(self, mid: usize) -> (Self, Self);
 }
 
-/// Attempts to split the slice at the midpoint.
-///
-/// `x.try_split_at(mid)` returns `Ok((x[..mid], x[mid..]))` if `mid <=
-/// x.deref().len()` and otherwise returns `Err(x)`.
-///
-/// # Safety
-///
-/// Unsafe code may rely on this function correctly implementing the above
-/// functionality.
-#[inline]
-fn try_split_at<S>(slice: S, mid: usize) -> Result<(S, S), S>
-where
-    S: SplitByteSlice,
-{
-    if mid <= slice.deref().len() {
-        // SAFETY: Above, we ensure that `mid <= self.deref().len()`. By
-        // invariant on `ByteSlice`, a supertrait of `SplitByteSlice`,
-        // `.deref()` is guranteed to be "stable"; i.e., it will always
-        // dereference to a byte slice of the same address and length. Thus, we
-        // can be sure that the above precondition remains satisfied through the
-        // call to `split_at_unchecked`.
-        unsafe { Ok(slice.split_at_unchecked(mid)) }
-    } else {
-        Err(slice)
-    }
-}
-
 /// A shorthand for [`SplitByteSlice`] and [`ByteSliceMut`].
 pub trait SplitByteSliceMut: SplitByteSlice + ByteSliceMut {}
 impl<B: SplitByteSlice + ByteSliceMut> SplitByteSliceMut for B {}
@@ -5649,6 +9353,65 @@ unsafe impl<'a> SplitByteSlice for RefMut<'a, [u8]> {
     }
 }
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+// SAFETY: `Box<[u8]>`'s `Deref` and `DerefMut` impls always return a reference
+// to the same heap allocation, whose address and length can only change if the
+// `Box` itself is replaced or dropped. `Box<[u8]>` uniquely owns its referent,
+// so the referent cannot be modified via any other object.
+unsafe impl ByteSlice for Box<[u8]> {}
+
+#[cfg(feature = "bytes")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bytes")))]
+// SAFETY: `Bytes`'s `Deref` impl returns a reference to the range of its
+// backing buffer which it views. That range can only change via methods which
+// take `&mut self` or `self` (e.g., `advance` or `truncate`), none of which are
+// methods on `ByteSlice` or its super-traits. The backing buffer is immutable
+// and is never moved while referenced, even if it is shared with other `Bytes`.
+unsafe impl ByteSlice for ::bytes::Bytes {}
+
+#[cfg(feature = "bytes")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bytes")))]
+// SAFETY: `Bytes::clone` produces a handle which shares `self`'s backing buffer
+// and views the same range of it, so its `Deref` impl returns a slice with the
+// same address and length as `self`'s.
+unsafe impl CloneableByteSlice for ::bytes::Bytes {}
+
+#[cfg(feature = "bytes")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bytes")))]
+// SAFETY: `Bytes::split_to(mid)` returns a handle viewing `self[..mid]` and
+// leaves `self` viewing `self[mid..]`, without moving or copying the backing
+// buffer.
+unsafe impl SplitByteSlice for ::bytes::Bytes {
+    #[inline]
+    unsafe fn split_at_unchecked(mut self, mid: usize) -> (Self, Self) {
+        let first = self.split_to(mid);
+        (first, self)
+    }
+}
+
+#[cfg(feature = "bytes")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bytes")))]
+// SAFETY: `BytesMut`'s `Deref` and `DerefMut` impls return a reference to the
+// range of its backing buffer which it uniquely views. That range can only
+// change via methods which take `&mut self` or `self` (e.g., `reserve` or
+// `truncate`), none of which are methods on `ByteSlice`, `ByteSliceMut`, or
+// their super-traits. No other `BytesMut` views the same range.
+unsafe impl ByteSlice for ::bytes::BytesMut {}
+
+#[cfg(feature = "bytes")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bytes")))]
+// SAFETY: `BytesMut::split_to(mid)` returns a handle viewing `self[..mid]` and
+// leaves `self` viewing `self[mid..]`, without moving or copying the backing
+// buffer.
+unsafe impl SplitByteSlice for ::bytes::BytesMut {
+    #[inline]
+    unsafe fn split_at_unchecked(mut self, mid: usize) -> (Self, Self) {
+        let first = self.split_to(mid);
+        (first, self)
+    }
+}
+
 #[cfg(feature = "alloc")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
 mod alloc_support {
@@ -5694,6 +9457,68 @@ mod alloc_support {
         }
     }
 
+    /// Reinterprets a `Vec<Src>` as a `Vec<Dst>` without reallocating.
+    ///
+    /// The returned vector has the same length and capacity as `src`, and
+    /// reuses its allocation. Each `Src` element is reinterpreted as a `Dst`
+    /// with the same bytes.
+    ///
+    /// A `Vec`'s allocation must be freed with the same layout with which it
+    /// was allocated, and `Vec<Dst>` computes that layout from
+    /// `size_of::<Dst>()`, `align_of::<Dst>()`, and its capacity. Thus, an
+    /// allocation can only be reused if `Src` and `Dst` have the same size
+    /// and the same alignment. If they do not, `src` is returned unmodified in
+    /// the `Err` variant so that no data is lost. In particular, this means
+    /// that a `Vec<u8>` cannot be reinterpreted as a `Vec<u32>`; to
+    /// reinterpret such bytes in place, borrow them instead (e.g., with
+    /// [`FromBytes::ref_from`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::transmute_vec;
+    ///
+    /// let src: Vec<i32> = vec![0, -1];
+    /// let capacity = src.capacity();
+    /// let dst: Vec<u32> = transmute_vec(src).unwrap();
+    /// assert_eq!(dst, [0, u32::MAX]);
+    /// assert_eq!(dst.capacity(), capacity);
+    ///
+    /// // `u32` has a different size than `u8`, so the allocation cannot be
+    /// // reused.
+    /// let src: Vec<u8> = vec![0, 1, 2, 3];
+    /// let src = transmute_vec::<u8, u32>(src).unwrap_err();
+    /// assert_eq!(src, [0, 1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn transmute_vec<Src: IntoBytes, Dst: FromBytes>(
+        src: Vec<Src>,
+    ) -> Result<Vec<Dst>, Vec<Src>> {
+        if mem::size_of::<Src>() != mem::size_of::<Dst>()
+            || mem::align_of::<Src>() != mem::align_of::<Dst>()
+        {
+            return Err(src);
+        }
+
+        let mut src = ManuallyDrop::new(src);
+        let (ptr, len, cap) = (src.as_mut_ptr(), src.len(), src.capacity());
+        // SAFETY:
+        // - `ptr` was allocated by a `Vec<Src>` with the global allocator, and
+        //   is non-null and aligned (even if `src` has not allocated).
+        // - Since `align_of::<Src>() == align_of::<Dst>()` and `size_of::<Src>()
+        //   == size_of::<Dst>()` (checked above), the layout `Vec<Dst>` will
+        //   compute from `cap` when deallocating is the same layout with which
+        //   `ptr` was allocated.
+        // - `len <= cap` by invariant on `Vec`.
+        // - The first `len` elements were initialized as `Src`s. Since `Src:
+        //   IntoBytes`, all of their bytes are initialized, and since `Dst:
+        //   FromBytes` and `Dst` has the same size as `Src`, those bytes are
+        //   valid `Dst`s.
+        // - `src` is wrapped in `ManuallyDrop`, so the allocation is owned
+        //   exclusively by the returned `Vec`.
+        Ok(unsafe { Vec::from_raw_parts(ptr.cast::<Dst>(), len, cap) })
+    }
+
     #[cfg(test)]
     mod tests {
         use core::convert::TryFrom as _;
@@ -5733,6 +9558,44 @@ mod alloc_support {
             drop(v);
         }
 
+        #[test]
+        fn test_transmute_vec() {
+            let src = vec![[0u8, 1], [2, 3]];
+            let capacity = src.capacity();
+            let dst: Vec<[i8; 2]> = transmute_vec(src).unwrap();
+            assert_eq!(dst, [[0, 1], [2, 3]]);
+            assert_eq!(dst.capacity(), capacity);
+
+            let src = vec![1.0f32, -0.0];
+            let dst: Vec<u32> = transmute_vec(src).unwrap();
+            assert_eq!(dst, [1.0f32.to_bits(), (-0.0f32).to_bits()]);
+
+            // Unused capacity is preserved.
+            let mut src = Vec::<i64>::with_capacity(8);
+            src.push(-1);
+            let dst: Vec<u64> = transmute_vec(src).unwrap();
+            assert_eq!(dst, [u64::MAX]);
+            assert_eq!(dst.capacity(), 8);
+
+            // No allocation.
+            let dst: Vec<u16> = transmute_vec(Vec::<i16>::new()).unwrap();
+            assert!(dst.is_empty());
+
+            // ZSTs.
+            let dst: Vec<()> = transmute_vec(vec![(), ()]).unwrap();
+            assert_eq!(dst.len(), 2);
+
+            // Mismatched sizes.
+            let src = transmute_vec::<u8, u16>(vec![1, 2]).unwrap_err();
+            assert_eq!(src, [1, 2]);
+            let src = transmute_vec::<u16, u8>(vec![1, 2]).unwrap_err();
+            assert_eq!(src, [1, 2]);
+
+            // Mismatched alignments.
+            let src = transmute_vec::<[u8; 2], u16>(vec![[1, 2]]).unwrap_err();
+            assert_eq!(src, [[1, 2]]);
+        }
+
         #[test]
         fn test_insert_vec_zeroed() {
             // Insert at start (no existing allocation).
@@ -5805,6 +9668,31 @@ mod alloc_support {
             drop(<[u32; 0x1000]>::new_box_zeroed());
         }
 
+        #[test]
+        fn test_read_boxed_from() {
+            let b = u64::read_boxed_from(&0x0102030405060708u64.to_ne_bytes()).unwrap();
+            assert_eq!(*b, 0x0102030405060708);
+            assert!(u64::read_boxed_from(&[0; 7]).is_err());
+            assert!(u64::read_boxed_from(&[0; 9]).is_err());
+
+            #[allow(clippy::unit_cmp)]
+            {
+                assert_eq!(*<()>::read_boxed_from(&[]).unwrap(), ());
+            }
+            assert!(<()>::read_boxed_from(&[0]).is_err());
+        }
+
+        #[test]
+        #[cfg_attr(miri, ignore)]
+        fn test_read_boxed_from_huge_array() {
+            // This array is large enough that building it on the stack (e.g.,
+            // with `read_from`) would overflow a test thread's stack.
+            const LEN: usize = 16 << 20;
+            let bytes: Vec<u8> = (0..=u8::MAX).cycle().take(LEN).collect();
+            let arr = <[u8; LEN]>::read_boxed_from(&bytes).unwrap();
+            assert_eq!(&arr[..], &bytes[..]);
+        }
+
         #[test]
         fn test_new_box_zeroed_zst() {
             // This test exists in order to exercise unsafe code, especially
@@ -5862,6 +9750,58 @@ mod alloc_support {
             let max = usize::try_from(isize::MAX).unwrap();
             let _ = u16::new_box_slice_zeroed((max / mem::size_of::<u16>()) + 1);
         }
+
+        #[derive(FromZeros, KnownLayout)]
+        #[repr(C)]
+        struct Dst {
+            header: u16,
+            trailing: [u32],
+        }
+
+        #[test]
+        fn test_new_box_zeroed_with_elems() {
+            let mut dst = Dst::new_box_zeroed_with_elems(100);
+            assert_eq!(dst.header, 0);
+            assert_eq!(dst.trailing.len(), 100);
+            assert!(dst.trailing.iter().all(|&t| t == 0));
+            assert_eq!(mem::size_of_val(&*dst), 404);
+            dst.trailing[99] = 3;
+            assert_eq!(dst.trailing[99], 3);
+
+            let dst = Dst::new_box_zeroed_with_elems(0);
+            assert!(dst.trailing.is_empty());
+            assert_eq!(mem::size_of_val(&*dst), 4);
+
+            let s = <[u64]>::new_box_zeroed_with_elems(3);
+            assert_eq!(&*s, &[0, 0, 0]);
+        }
+
+        #[test]
+        fn test_new_box_zeroed_with_elems_zero_sized() {
+            // This test exists in order to exercise unsafe code, especially
+            // when running under Miri.
+            let s = <[u64]>::new_box_zeroed_with_elems(0);
+            assert!(s.is_empty());
+            assert_eq!(s.as_ptr().align_offset(mem::align_of::<u64>()), 0);
+
+            let s = <[()]>::new_box_zeroed_with_elems(3);
+            assert_eq!(s.len(), 3);
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "size of `Self` with `count` trailing elements overflows `usize`"
+        )]
+        fn test_new_box_zeroed_with_elems_panics_usize_overflow() {
+            let _ = Dst::new_box_zeroed_with_elems(usize::MAX);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: size <= max_alloc")]
+        fn test_new_box_zeroed_with_elems_panics_isize_overflow() {
+            let max = usize::try_from(isize::MAX).unwrap();
+            let _ = <[u16]>::new_box_zeroed_with_elems((max / mem::size_of::<u16>()) + 1);
+        }
     }
 }
 
@@ -6039,6 +9979,17 @@ mod tests {
         assert_eq!(<KL01PackedN as KnownLayout>::LAYOUT, expected);
         assert_eq!(<KL01PackedN as KnownLayout>::LAYOUT, sized_layout(2, 6));
 
+        // ...with `repr(transparent)`:
+        #[allow(dead_code)]
+        #[derive(KnownLayout)]
+        #[repr(transparent)]
+        struct KL01Transparent(NotKnownLayout<AU32>);
+
+        let expected = DstLayout::for_type::<KL01Transparent>();
+
+        assert_eq!(<KL01Transparent as KnownLayout>::LAYOUT, expected);
+        assert_eq!(<KL01Transparent as KnownLayout>::LAYOUT, sized_layout(4, 4));
+
         // | `repr(C)`? | generic? | `KnownLayout`? | `Sized`? | Type Name |
         // |          N |        N |              Y |        Y |      KL03 |
         #[allow(dead_code)]
@@ -6095,6 +10046,16 @@ mod tests {
             KL05(0u8, t)
         }
 
+        // ...with `repr(transparent)`:
+        #[allow(dead_code)]
+        #[derive(KnownLayout)]
+        #[repr(transparent)]
+        struct KL05Transparent<T>(PhantomData<u8>, T);
+
+        fn _test_kl05_transparent<T>(t: T) -> impl KnownLayout {
+            KL05Transparent(PhantomData, t)
+        }
+
         // | `repr(C)`? | generic? | `KnownLayout`? | `Sized`? | Type Name |
         // |          N |        Y |              Y |        Y |      KL07 |
         #[allow(dead_code)]
@@ -6168,6 +10129,20 @@ mod tests {
         assert_eq!(<KL10PackedN as KnownLayout>::LAYOUT, expected);
         assert_eq!(<KL10PackedN as KnownLayout>::LAYOUT, unsized_layout(2, 1, 4));
 
+        // ...with `repr(transparent)` in place of `repr(C)`:
+        #[allow(dead_code)]
+        #[derive(KnownLayout)]
+        #[repr(transparent)]
+        struct KL10Transparent(PhantomData<NotKnownLayout>, [AU32]);
+
+        assert_eq!(<KL10Transparent as KnownLayout>::LAYOUT, <[AU32] as KnownLayout>::LAYOUT);
+        assert_eq!(<KL10Transparent as KnownLayout>::LAYOUT, unsized_layout(4, 4, 0));
+
+        // `KL10Transparent` has the same pointer metadata as `[AU32]`.
+        let bytes = NonNull::<AU32>::dangling().cast::<u8>();
+        let ptr = <KL10Transparent as KnownLayout>::raw_from_ptr_len(bytes, 3);
+        assert_eq!(<KL10Transparent as KnownLayout>::pointer_to_metadata(ptr), 3);
+
         // | `repr(C)`? | generic? | `KnownLayout`? | `Sized`? | Type Name |
         // |          Y |        N |              Y |        Y |      KL11 |
         #[allow(dead_code)]
@@ -6314,25 +10289,397 @@ mod tests {
         #[repr(C)]
         struct KLF1([u8]);
 
-        assert_eq!(<KLF1 as KnownLayout>::LAYOUT, unsized_layout(1, 1, 0));
+        assert_eq!(<KLF1 as KnownLayout>::LAYOUT, unsized_layout(1, 1, 0));
+
+        #[derive(KnownLayout)]
+        #[repr(C)]
+        struct KLF2(NotKnownLayout<u8>, [u8]);
+
+        assert_eq!(<KLF2 as KnownLayout>::LAYOUT, unsized_layout(1, 1, 1));
+
+        #[derive(KnownLayout)]
+        #[repr(C)]
+        struct KLF3(NotKnownLayout<u8>, NotKnownLayout<AU16>, [u8]);
+
+        assert_eq!(<KLF3 as KnownLayout>::LAYOUT, unsized_layout(2, 1, 4));
+
+        #[derive(KnownLayout)]
+        #[repr(C)]
+        struct KLF4(NotKnownLayout<u8>, NotKnownLayout<AU16>, NotKnownLayout<AU32>, [u8]);
+
+        assert_eq!(<KLF4 as KnownLayout>::LAYOUT, unsized_layout(4, 1, 8));
+    }
+
+    #[test]
+    fn test_size_of_val() {
+        assert_eq!(size_of_val(&()), 0);
+        assert_eq!(size_of_val(&0u8), 1);
+        assert_eq!(size_of_val(&AU64(0)), 8);
+        assert_eq!(size_of_val(&[0u16; 3]), 6);
+
+        let slc: &[u16] = &[0, 1, 2];
+        assert_eq!(size_of_val(slc), mem::size_of_val(slc));
+        assert_eq!(size_of_val(&slc[..0]), 0);
+        assert_eq!(size_of_val("hello"), 5);
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_size_of_val_derive() {
+        #[derive(KnownLayout, FromBytes, Immutable)]
+        #[repr(C)]
+        struct Dst {
+            a: u16,
+            trailing: [u8],
+        }
+
+        let bytes = Align::<[u8; 8], AU64>::new([0; 8]);
+        for elems in 0..=6 {
+            let (dst, _) =
+                Dst::ref_from_prefix_with_trailing_elements(&bytes.t[..], elems).unwrap();
+            assert_eq!(dst.trailing.len(), elems);
+            assert_eq!(size_of_val(dst), mem::size_of_val(dst));
+        }
+
+        // Two bytes of prefix plus three bytes of trailing slice, padded to a
+        // multiple of the alignment of 2.
+        let (dst, _) = Dst::ref_from_prefix_with_trailing_elements(&bytes.t[..], 3).unwrap();
+        assert_eq!(size_of_val(dst), 6);
+    }
+
+    #[test]
+    fn test_elems_for_bytes() {
+        assert_eq!(elems_for_bytes::<[u8]>(0), Some(0));
+        assert_eq!(elems_for_bytes::<[u32]>(15), Some(3));
+        assert_eq!(elems_for_bytes::<[()]>(4), None);
+        assert_eq!(elems_for_bytes::<str>(5), Some(5));
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_elems_for_bytes_derive() {
+        // A leading offset of 6 and a trailing element size of 4.
+        #[derive(KnownLayout)]
+        #[repr(C)]
+        struct Dst {
+            a: [u16; 3],
+            trailing: [[u16; 2]],
+        }
+
+        assert_eq!(elems_for_bytes::<Dst>(0), None);
+        assert_eq!(elems_for_bytes::<Dst>(5), None);
+        assert_eq!(elems_for_bytes::<Dst>(6), Some(0));
+        assert_eq!(elems_for_bytes::<Dst>(9), Some(0));
+        assert_eq!(elems_for_bytes::<Dst>(10), Some(1));
+        assert_eq!(elems_for_bytes::<Dst>(13), Some(1));
+        assert_eq!(elems_for_bytes::<Dst>(14), Some(2));
+
+        // `elems_for_bytes` inverts `size_for_metadata`.
+        for elems in 0..8 {
+            let size = elems.size_for_metadata(Dst::LAYOUT).unwrap();
+            assert_eq!(elems_for_bytes::<Dst>(size), Some(elems));
+        }
+
+        // Trailing padding is accounted for.
+        #[derive(KnownLayout)]
+        #[repr(C)]
+        struct Padded {
+            a: u32,
+            b: u8,
+            trailing: [u8],
+        }
+
+        // 5 bytes of prefix, plus 1 byte of trailing slice, is padded to 8.
+        assert_eq!(elems_for_bytes::<Padded>(6), None);
+        assert_eq!(elems_for_bytes::<Padded>(8), Some(3));
+        assert_eq!(elems_for_bytes::<Padded>(11), Some(3));
+        assert_eq!(elems_for_bytes::<Padded>(12), Some(7));
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_ref_from_prefix_with_trailing_elements_and_size() {
+        #[derive(KnownLayout, FromBytes, Immutable)]
+        #[repr(C)]
+        struct Dst {
+            a: u32,
+            trailing: [u8],
+        }
+
+        let bytes = Align::<[u8; 16], AU64>::new([0; 16]);
+
+        // With one trailing element, `Dst` has 5 bytes of fields, but occupies
+        // 8 bytes once padded to its alignment of 4.
+        let (dst, size, rest) =
+            Dst::ref_from_prefix_with_trailing_elements_and_size(&bytes.t[..], 1).unwrap();
+        assert_eq!(dst.trailing.len(), 1);
+        assert_eq!(size, 8);
+        assert_eq!(rest.len(), 8);
+
+        for count in 0..=12 {
+            let (dst, size, rest) =
+                Dst::ref_from_prefix_with_trailing_elements_and_size(&bytes.t[..], count).unwrap();
+            assert_eq!(size, mem::size_of_val(dst));
+            assert_eq!(size + rest.len(), bytes.t.len());
+        }
+
+        assert!(Dst::ref_from_prefix_with_trailing_elements_and_size(&bytes.t[..], 13).is_err());
+    }
+
+    #[test]
+    fn test_ref_from_prefix_with_trailing_elements_from_header() {
+        #[derive(KnownLayout, FromBytes, Immutable, Debug)]
+        #[repr(C)]
+        struct Header {
+            len: u16,
+        }
+
+        #[derive(KnownLayout, FromBytes, Immutable, Debug)]
+        #[repr(C)]
+        struct Message {
+            header: Header,
+            body: [u16],
+        }
+
+        let len = |h: &Header| usize::from(h.len);
+
+        // A length-prefixed message followed by trailing bytes.
+        let mut buf = Align::<[u8; 16], AU64>::default();
+        buf.t[..2].copy_from_slice(&3u16.to_ne_bytes());
+        let (msg, rest) =
+            Message::ref_from_prefix_with_trailing_elements_from_header(&buf.t[..], len).unwrap();
+        assert_eq!(msg.header.len, 3);
+        assert_eq!(msg.body.len(), 3);
+        assert_eq!(rest.len(), 8);
+
+        // The header's length exceeds the available bytes.
+        buf.t[..2].copy_from_slice(&8u16.to_ne_bytes());
+        let err = Message::ref_from_prefix_with_trailing_elements_from_header(&buf.t[..], len)
+            .unwrap_err();
+        assert!(matches!(err, CastError::Size(_)));
+
+        // Too short to contain the header.
+        let err = Message::ref_from_prefix_with_trailing_elements_from_header(&buf.t[..1], len)
+            .unwrap_err();
+        assert_eq!(err.into_src(), &buf.t[..1]);
+
+        // Misaligned for `Message`.
+        let err = Message::ref_from_prefix_with_trailing_elements_from_header(&buf.t[1..], len)
+            .unwrap_err();
+        assert!(matches!(err, CastError::Alignment(_)));
+
+        // A count which would overflow the size of `Message` is rejected.
+        let err = Message::ref_from_prefix_with_trailing_elements_from_header(
+            &buf.t[..],
+            |_: &Header| usize::MAX,
+        )
+        .unwrap_err();
+        assert!(matches!(err, CastError::Size(_)));
+    }
+
+    #[test]
+    fn test_ref_from_prefix_array() {
+        let bytes =
+            Align::<[u8; 16], AU64>::new([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+
+        let (arr, rest) = u8::ref_from_prefix_array::<0>(&bytes.t[..]).unwrap();
+        assert!(arr.is_empty());
+        assert_eq!(rest, &bytes.t[..]);
+
+        let (arr, rest) = <[u8; 2]>::ref_from_prefix_array::<3>(&bytes.t[..]).unwrap();
+        assert_eq!(arr, &[[0, 1], [2, 3], [4, 5]]);
+        assert_eq!(rest, &bytes.t[6..]);
+
+        let (arr, rest) = AU64::ref_from_prefix_array::<2>(&bytes.t[..]).unwrap();
+        assert_eq!(arr.as_bytes(), &bytes.t[..]);
+        assert!(ptr::eq(arr.as_bytes(), &bytes.t[..]));
+        assert!(rest.is_empty());
+
+        // Insufficient length.
+        assert!(matches!(AU64::ref_from_prefix_array::<3>(&bytes.t[..]), Err(CastError::Size(_))));
+        assert!(matches!(u8::ref_from_prefix_array::<17>(&bytes.t[..]), Err(CastError::Size(_))));
+        // Misalignment.
+        assert!(matches!(
+            AU64::ref_from_prefix_array::<1>(&bytes.t[1..]),
+            Err(CastError::Alignment(_))
+        ));
+    }
+
+    #[test]
+    fn test_ref_from_chunks() {
+        let mut buf = Align::<[u8; 20], AU64>::default();
+        buf.t[..8].copy_from_slice(&AU64(1).to_bytes());
+        buf.t[8..16].copy_from_slice(&AU64(2).to_bytes());
+        buf.t[16..].copy_from_slice(&[3, 4, 5, 6]);
+
+        // Two records, followed by a trailing partial record.
+        let chunks = AU64::ref_from_chunks(&buf.t[..]).unwrap();
+        assert_eq!(chunks.len(), 3);
+        let mut records = Vec::new();
+        let mut partial = None;
+        for chunk in chunks {
+            match chunk {
+                Ok(record) => records.push(*record),
+                Err(err) => partial = Some(err.into_src()),
+            }
+        }
+        assert_eq!(records, [AU64(1), AU64(2)]);
+        assert_eq!(partial, Some(&[3u8, 4, 5, 6][..]));
+
+        // Records are views into the original buffer.
+        let mut chunks = AU64::ref_from_chunks(&buf.t[..16]).unwrap();
+        assert_eq!(chunks.len(), 2);
+        let first: *const AU64 = chunks.next().unwrap().unwrap();
+        assert_eq!(first.cast::<u8>(), buf.t.as_ptr());
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks.next().unwrap().unwrap(), &AU64(2));
+        assert!(chunks.next().is_none());
+        assert_eq!(chunks.len(), 0);
+
+        // Empty and too-short inputs.
+        assert_eq!(AU64::ref_from_chunks(&buf.t[..0]).unwrap().count(), 0);
+        let mut chunks = AU64::ref_from_chunks(&buf.t[..7]).unwrap();
+        assert_eq!(chunks.next().unwrap().unwrap_err().into_src().len(), 7);
+        assert!(chunks.next().is_none());
+
+        // Alignment is checked up front.
+        assert!(AU64::ref_from_chunks(&buf.t[1..17]).is_err());
+    }
+
+    #[test]
+    fn test_ref_from_at_least_aligned() {
+        #[repr(C, align(64))]
+        struct CacheLine([u8; 128]);
+
+        let mut buf = CacheLine([0; 128]);
+        buf.0[64..72].copy_from_slice(&0x0102030405060708u64.to_ne_bytes());
+
+        // 64-byte-aligned inputs succeed, as do inputs which are aligned more
+        // strictly than required.
+        let x = u64::ref_from_at_least_aligned::<64>(&buf.0[64..72]).unwrap();
+        assert_eq!(*x, 0x0102030405060708);
+        assert_eq!(u64::ref_from_at_least_aligned::<8>(&buf.0[64..72]).unwrap(), x);
+        let x = <[u8]>::ref_from_at_least_aligned::<64>(&buf.0[..]).unwrap();
+        assert_eq!(x.len(), 128);
+
+        // Inputs which are aligned to `align_of::<Self>()` but not to `ALIGN`
+        // fail.
+        for offset in [8, 16, 32, 72, 96] {
+            let bytes = &buf.0[offset..offset + 8];
+            assert!(u64::ref_from(bytes).is_ok());
+            match u64::ref_from_at_least_aligned::<64>(bytes) {
+                Err(CastError::Alignment(err)) => {
+                    // The error reports `ALIGN` rather than `align_of::<u64>()`.
+                    assert_eq!(err.required_align(), 64);
+                    assert!(err.to_string().contains("is not a multiple of the alignment (64)"));
+                }
+                _ => panic!("expected an alignment error"),
+            }
+        }
+        assert!(matches!(
+            <[u8]>::ref_from_at_least_aligned::<2>(&buf.0[1..]),
+            Err(CastError::Alignment(_))
+        ));
+
+        // Size errors are still reported for well-aligned inputs.
+        assert!(matches!(
+            u64::ref_from_at_least_aligned::<64>(&buf.0[..4]),
+            Err(CastError::Size(_))
+        ));
+    }
+
+    #[test]
+    fn test_mut_from_prefix_array() {
+        let mut bytes =
+            Align::<[u8; 16], AU64>::new([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+
+        let (arr, rest) = u8::mut_from_prefix_array::<0>(&mut bytes.t[..]).unwrap();
+        assert!(arr.is_empty());
+        assert_eq!(rest.len(), 16);
+
+        let (arr, rest) = <[u8; 2]>::mut_from_prefix_array::<3>(&mut bytes.t[..]).unwrap();
+        assert_eq!(arr, &[[0, 1], [2, 3], [4, 5]]);
+        assert_eq!(rest, &[6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+        arr[1] = [0xFF, 0xFE];
+        rest[0] = 0xFD;
+        assert_eq!(bytes.t[..7], [0, 1, 0xFF, 0xFE, 4, 5, 0xFD]);
+
+        let (arr, rest) = AU64::mut_from_prefix_array::<2>(&mut bytes.t[..]).unwrap();
+        assert!(rest.is_empty());
+        arr[1] = AU64(0);
+        assert_eq!(bytes.t[8..], [0; 8]);
+
+        // Insufficient length.
+        assert!(matches!(
+            AU64::mut_from_prefix_array::<3>(&mut bytes.t[..]),
+            Err(CastError::Size(_))
+        ));
+        assert!(matches!(
+            u8::mut_from_prefix_array::<17>(&mut bytes.t[..]),
+            Err(CastError::Size(_))
+        ));
+        // Misalignment.
+        assert!(matches!(
+            AU64::mut_from_prefix_array::<1>(&mut bytes.t[1..]),
+            Err(CastError::Alignment(_))
+        ));
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_ref_from_over_aligned() {
+        // `repr(align)` raises `Aligned8`'s alignment (and thus its size, via
+        // trailing padding) beyond that of its fields. Casts must check the
+        // raised alignment and size, not those of the fields.
+        #[derive(FromBytes, KnownLayout, Immutable)]
+        #[repr(C, align(8))]
+        struct Aligned8 {
+            a: u32,
+        }
+
+        assert_eq!(mem::size_of::<Aligned8>(), 8);
+        assert_eq!(<Aligned8 as KnownLayout>::LAYOUT, DstLayout::for_type::<Aligned8>());
 
-        #[derive(KnownLayout)]
-        #[repr(C)]
-        struct KLF2(NotKnownLayout<u8>, [u8]);
+        let buf = Align::<[u8; 16], AU64>::new([0; 16]);
 
-        assert_eq!(<KLF2 as KnownLayout>::LAYOUT, unsized_layout(1, 1, 1));
+        // An 8-aligned buffer of 8 bytes is accepted.
+        assert!(Aligned8::ref_from(&buf.t[..8]).is_ok());
+        assert!(Aligned8::ref_from_prefix(&buf.t[..]).is_ok());
+        assert!(Aligned8::ref_from_suffix(&buf.t[..]).is_ok());
 
-        #[derive(KnownLayout)]
-        #[repr(C)]
-        struct KLF3(NotKnownLayout<u8>, NotKnownLayout<AU16>, [u8]);
+        // A buffer which is 4-aligned, but not 8-aligned, is rejected.
+        assert!(matches!(Aligned8::ref_from(&buf.t[4..12]), Err(CastError::Alignment(_))));
+        assert!(matches!(Aligned8::ref_from_prefix(&buf.t[4..]), Err(CastError::Alignment(_))));
+        assert!(matches!(Aligned8::ref_from_suffix(&buf.t[..12]), Err(CastError::Alignment(_))));
 
-        assert_eq!(<KLF3 as KnownLayout>::LAYOUT, unsized_layout(2, 1, 4));
+        // A buffer which only covers the fields, and not the trailing padding,
+        // is rejected.
+        assert!(matches!(Aligned8::ref_from(&buf.t[..4]), Err(CastError::Size(_))));
+        assert!(matches!(Aligned8::ref_from_prefix(&buf.t[..4]), Err(CastError::Size(_))));
 
-        #[derive(KnownLayout)]
-        #[repr(C)]
-        struct KLF4(NotKnownLayout<u8>, NotKnownLayout<AU16>, NotKnownLayout<AU32>, [u8]);
+        // The same holds for slices of over-aligned elements.
+        assert_eq!(<[Aligned8]>::ref_from(&buf.t[..]).unwrap().len(), 2);
+        assert!(matches!(<[Aligned8]>::ref_from(&buf.t[4..12]), Err(CastError::Alignment(_))));
+        assert!(matches!(<[Aligned8]>::ref_from(&buf.t[..12]), Err(CastError::Size(_))));
 
-        assert_eq!(<KLF4 as KnownLayout>::LAYOUT, unsized_layout(4, 1, 8));
+        // The same holds for over-aligned slice DSTs.
+        #[derive(FromBytes, KnownLayout, Immutable)]
+        #[repr(C, align(8))]
+        struct Aligned8Dst {
+            a: u32,
+            trailing: [u8],
+        }
+
+        let (dst, rest) =
+            Aligned8Dst::ref_from_prefix_with_trailing_elements(&buf.t[..], 1).unwrap();
+        assert_eq!(mem::size_of_val(dst), 8);
+        assert_eq!(rest.len(), 8);
+        assert!(matches!(
+            Aligned8Dst::ref_from_prefix_with_trailing_elements(&buf.t[4..], 1),
+            Err(CastError::Alignment(_))
+        ));
+        assert!(matches!(Aligned8Dst::ref_from(&buf.t[..12]), Err(CastError::Size(_))));
+        assert!(matches!(Aligned8Dst::ref_from(&buf.t[4..]), Err(CastError::Alignment(_))));
     }
 
     #[test]
@@ -6404,6 +10751,301 @@ mod tests {
         assert_eq!(bytes, want);
     }
 
+    #[test]
+    fn test_read_into_prefix() {
+        #[derive(FromBytes, IntoBytes, Debug, PartialEq)]
+        #[repr(C)]
+        struct Pair {
+            a: [u8; 2],
+            b: [u8; 2],
+        }
+
+        let bytes = [0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut pair = Pair { a: [0xFF; 2], b: [0xFF; 2] };
+
+        // Repeatedly read into the same value from a moving cursor.
+        let mut cursor = &bytes[..];
+        for i in 0..2u8 {
+            cursor = pair.read_into_prefix(cursor).unwrap();
+            let base = i * 4;
+            assert_eq!(pair, Pair { a: [base, base + 1], b: [base + 2, base + 3] });
+        }
+        assert_eq!(cursor, [8, 9]);
+
+        // A short read leaves the value unmodified.
+        let err = pair.read_into_prefix(cursor).unwrap_err();
+        assert_eq!(err.into_src(), [8, 9]);
+        assert_eq!(pair, Pair { a: [4, 5], b: [6, 7] });
+
+        // Unsized types are supported.
+        let mut arr = [0u16; 3];
+        let slc = &mut arr[..2];
+        let rest = slc.read_into_prefix(&[1, 0, 2, 0, 3][..]).unwrap();
+        assert_eq!(rest, [3]);
+        assert_eq!(slc, [u16::from_ne_bytes([1, 0]), u16::from_ne_bytes([2, 0])]);
+    }
+
+    #[test]
+    fn test_write_to_prefix_rest() {
+        let mut bytes = [0xFFu8; 8];
+
+        // Chain two writes into a single buffer.
+        let rest = 0x0102u16.to_be().write_to_prefix_rest(&mut bytes[..]).unwrap();
+        let rest = [3u8, 4, 5].write_to_prefix_rest(rest).unwrap();
+        assert_eq!(rest, [0xFF; 3]);
+
+        // A short write leaves the remaining bytes unmodified.
+        let err = 0u32.write_to_prefix_rest(rest).unwrap_err();
+        assert_eq!(err.into_src(), &0);
+        assert_eq!(bytes, [1, 2, 3, 4, 5, 0xFF, 0xFF, 0xFF]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_io_slices() {
+        use std::io::Write as _;
+
+        #[derive(IntoBytes, Immutable)]
+        #[repr(C)]
+        struct Header {
+            kind: u8,
+            flags: u8,
+            len: [u8; 2],
+        }
+
+        let body = [0xAAu8; 5];
+        let header = Header { kind: 1, flags: 2, len: 5u16.to_be_bytes() };
+
+        assert_eq!(&*header.as_io_slice(), [1, 2, 0, 5]);
+        assert_eq!(&*body[..2].as_io_slice(), [0xAA, 0xAA]);
+
+        let trailer = 0x0A0Bu16.to_be();
+        let slices = io_slices!(header, &body[..], trailer);
+        assert_eq!(slices.len(), 3);
+        assert_eq!(slices.iter().map(|s| s.len()).sum::<usize>(), 11);
+        // The slices borrow, rather than copy, their values.
+        assert_eq!(slices[1].as_ptr(), body.as_ptr());
+
+        let mut out = Vec::new();
+        assert_eq!(out.write_vectored(&slices).unwrap(), 11);
+        assert_eq!(out, [1, 2, 0, 5, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x0A, 0x0B]);
+
+        assert!(io_slices!().is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_ref_from_or_copy() {
+        let buf = Align::<[u8; 9], AU64>::new([0, 1, 2, 3, 4, 5, 6, 7, 8]);
+        let want = AU64::read_from(&buf.t[..8]).unwrap();
+
+        // An aligned slice is borrowed.
+        match AU64::ref_from_or_copy(&buf.t[..8]).unwrap() {
+            Cow::Borrowed(r) => assert!(ptr::eq(r, buf.t.as_ptr().cast::<AU64>())),
+            Cow::Owned(_) => panic!("expected a borrowed value"),
+        }
+
+        // A misaligned slice of the same data is copied.
+        let misaligned = Align::<[u8; 9], AU64>::new([0xFF, 0, 1, 2, 3, 4, 5, 6, 7]);
+        match AU64::ref_from_or_copy(&misaligned.t[1..]).unwrap() {
+            Cow::Borrowed(_) => panic!("expected an owned value"),
+            Cow::Owned(val) => assert_eq!(val, want),
+        }
+
+        // A slice of the wrong size is rejected, regardless of alignment.
+        assert_eq!(AU64::ref_from_or_copy(&buf.t[..]).unwrap_err().into_src(), &buf.t[..]);
+        assert_eq!(AU64::ref_from_or_copy(&buf.t[1..5]).unwrap_err().into_src(), &buf.t[1..5]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_read_vec_from_prefix() {
+        // Reads succeed from misaligned buffers.
+        let buf = Align::<[u8; 20], AU64>::new([
+            0xFF, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18,
+        ]);
+        let (vec, rest) = AU64::read_vec_from_prefix(&buf.t[1..], 2).unwrap();
+        assert_eq!(
+            vec,
+            [AU64::read_from(&buf.t[1..9]).unwrap(), AU64::read_from(&buf.t[9..17]).unwrap()]
+        );
+        assert_eq!(rest, [16, 17, 18]);
+
+        let (vec, rest) = u8::read_vec_from_prefix(&buf.t[..], 0).unwrap();
+        assert!(vec.is_empty());
+        assert_eq!(rest, &buf.t[..]);
+
+        // Zero-sized types don't consume any bytes.
+        let (vec, rest) = <()>::read_vec_from_prefix(&[1, 2][..], usize::MAX).unwrap();
+        assert_eq!(vec.len(), usize::MAX);
+        assert_eq!(rest, [1, 2]);
+
+        // Too few bytes.
+        let err = AU64::read_vec_from_prefix(&buf.t[..], 3).unwrap_err();
+        assert_eq!(err.into_src(), &buf.t[..]);
+
+        // `n * size_of::<Self>()` overflows.
+        let err = AU64::read_vec_from_prefix(&buf.t[..], usize::MAX / 4).unwrap_err();
+        assert_eq!(err.into_src(), &buf.t[..]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_from_boxed_bytes() {
+        #[derive(FromBytes, KnownLayout, Unaligned)]
+        #[repr(C)]
+        struct Dst {
+            header: [u8; 2],
+            trailing: [[u8; 3]],
+        }
+
+        // Slice DSTs take as many trailing elements as fit, and reuse the
+        // original allocation.
+        let bytes = vec![0u8, 1, 2, 3, 4, 5, 6, 7].into_boxed_slice();
+        let addr = bytes.as_ptr();
+        let dst = Dst::from_boxed_bytes(bytes).unwrap();
+        assert_eq!(dst.header, [0, 1]);
+        assert_eq!(dst.trailing, [[2, 3, 4], [5, 6, 7]]);
+        assert!(ptr::eq(dst.header.as_ptr(), addr));
+
+        let dst = Dst::from_boxed_bytes(vec![0u8, 1].into_boxed_slice()).unwrap();
+        assert!(dst.trailing.is_empty());
+
+        // Sized types must match the length exactly.
+        let arr = <[u8; 4]>::from_boxed_bytes(vec![0u8, 1, 2, 3].into_boxed_slice()).unwrap();
+        assert_eq!(*arr, [0, 1, 2, 3]);
+        let _: Box<()> = <()>::from_boxed_bytes(Box::new([])).unwrap();
+
+        // On failure, the original box is returned.
+        let bytes = vec![0u8, 1, 2, 3, 4, 5].into_boxed_slice();
+        let addr = bytes.as_ptr();
+        let bytes = Dst::from_boxed_bytes(bytes).err().unwrap().into_src();
+        assert_eq!(*bytes, [0, 1, 2, 3, 4, 5]);
+        assert!(ptr::eq(bytes.as_ptr(), addr));
+        let bytes = <[u8; 4]>::from_boxed_bytes(bytes).unwrap_err().into_src();
+        let bytes = Dst::from_boxed_bytes(bytes[..1].into()).err().unwrap().into_src();
+        assert_eq!(*bytes, [0]);
+    }
+
+    #[test]
+    fn test_slice_as_bytes() {
+        let words = [1u16, 2u16];
+        let bytes = slice_as_bytes(&words);
+        #[cfg(target_endian = "little")]
+        assert_eq!(bytes, [1, 0, 2, 0]);
+        #[cfg(target_endian = "big")]
+        assert_eq!(bytes, [0, 1, 0, 2]);
+        assert!(ptr::eq(bytes.as_ptr().cast::<u16>(), words.as_ptr()));
+
+        let empty: [AU64; 0] = [];
+        assert!(slice_as_bytes(&empty).is_empty());
+
+        let mut words = [0u32; 2];
+        let bytes = slice_as_mut_bytes(&mut words);
+        assert_eq!(bytes.len(), 8);
+        bytes[4..].copy_from_slice(&7u32.to_ne_bytes());
+        assert_eq!(words, [0, 7]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_hash_bytes() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash<T: IntoBytes + Immutable + ?Sized>(value: &T) -> u64 {
+            let mut state = DefaultHasher::new();
+            hash_bytes(value, &mut state);
+            state.finish()
+        }
+
+        // Values with identical bytes hash identically, regardless of type.
+        let words = [0x0102u16, 0x0304];
+        assert_eq!(hash(&words), hash(&words));
+        assert_eq!(hash(&words), hash(words.as_bytes()));
+        assert_eq!(hash(&words[..]), hash(&[words[0], words[1]]));
+
+        // `hash_bytes` is equivalent to a single call to `Hasher::write`.
+        let mut state = DefaultHasher::new();
+        state.write(words.as_bytes());
+        assert_eq!(hash(&words), state.finish());
+
+        // Values with different bytes (almost certainly) hash differently.
+        assert_ne!(hash(&words), hash(&[0x0102u16, 0x0305]));
+    }
+
+    #[test]
+    fn test_unchecked_methods() {
+        // This test exists in order to exercise the unchecked methods' unsafe
+        // code with valid inputs, especially when running under Miri.
+
+        let mut buf = Align::<[u8; 16], AU64>::default();
+        buf.t = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+        // SAFETY: `buf.t` is 8-aligned and 8 bytes long.
+        let x = unsafe { AU64::ref_from_unchecked(&buf.t[..8]) };
+        assert_eq!(x.as_bytes(), &buf.t[..8]);
+
+        // SAFETY: `buf.t[2..]` is 2-aligned, and its length is a multiple of 2.
+        let x = unsafe { <[u16]>::ref_from_unchecked(&buf.t[2..]) };
+        assert_eq!(x.len(), 7);
+        assert_eq!(x.as_bytes(), &buf.t[2..]);
+
+        // SAFETY: `buf.t` is 4-aligned, and 3 `u32`s occupy 12 bytes.
+        let x = unsafe { <[u32]>::ref_from_with_trailing_elements_unchecked(&buf.t[..12], 3) };
+        assert_eq!(x.as_bytes(), &buf.t[..12]);
+
+        // SAFETY: `buf.t` is 8-aligned and 8 bytes long.
+        let x = unsafe { AU64::mut_from_unchecked(&mut buf.t[8..]) };
+        *x = AU64(0);
+        assert_eq!(buf.t[8..], [0; 8]);
+
+        // SAFETY: `buf.t` is 4-aligned, and 2 `u32`s occupy 8 bytes.
+        let x = unsafe { <[u32]>::mut_from_with_trailing_elements_unchecked(&mut buf.t[..8], 2) };
+        x[1] = u32::MAX;
+        assert_eq!(buf.t[..8], [0, 1, 2, 3, 0xFF, 0xFF, 0xFF, 0xFF]);
+
+        #[derive(FromBytes, KnownLayout, Immutable)]
+        #[repr(C)]
+        struct Dst {
+            a: u16,
+            trailing: [u8],
+        }
+
+        // `Dst` has trailing padding when its trailing slice has an odd
+        // length, so each valid size corresponds to the largest number of
+        // elements which fits.
+        for len in (2..=16).step_by(2) {
+            // SAFETY: `buf.t` is 2-aligned, and `len` is a valid size for
+            // `Dst`.
+            let dst = unsafe { Dst::ref_from_unchecked(&buf.t[..len]) };
+            assert_eq!(dst.trailing.len(), len - 2);
+            assert_eq!(size_of_val(dst), len);
+
+            let checked = Dst::ref_from(&buf.t[..len]).unwrap();
+            assert!(ptr::eq(dst, checked));
+        }
+
+        #[derive(FromBytes, IntoBytes, KnownLayout, Immutable)]
+        #[repr(C, packed)]
+        struct DstMut {
+            a: [u8; 2],
+            trailing: [[u8; 2]],
+        }
+
+        // SAFETY: `DstMut` has alignment 1, and a `DstMut` with 3 trailing
+        // elements has size 8.
+        let dst = unsafe { DstMut::mut_from_with_trailing_elements_unchecked(&mut buf.t[..8], 3) };
+        dst.trailing[2] = [0x2A, 0x2A];
+        assert_eq!(buf.t[6..8], [0x2A, 0x2A]);
+
+        // SAFETY: `DstMut` has alignment 1, and 6 is a valid size for
+        // `DstMut`.
+        let dst = unsafe { DstMut::mut_from_unchecked(&mut buf.t[..6]) };
+        assert_eq!(dst.trailing.len(), 2);
+        dst.a = [0, 0];
+        assert_eq!(buf.t[..2], [0, 0]);
+    }
+
     #[test]
     fn test_try_from_bytes_try_read_from() {
         assert_eq!(<bool as TryFromBytes>::try_read_from(&[0]), Ok(false));
@@ -6430,6 +11072,127 @@ mod tests {
         assert_eq!(<AU64 as TryFromBytes>::try_read_from(&bytes[1..9]), Ok(AU64(0)));
     }
 
+    #[test]
+    fn test_try_ref_from_with_trailing_elements() {
+        #[derive(TryFromBytes, KnownLayout, Immutable, Debug)]
+        #[repr(C)]
+        struct Flags {
+            header: u16,
+            flags: [bool],
+        }
+
+        let buf = Align::<[u8; 9], AU64>::new([0, 0, 1, 0, 1, 1, 2, 0, 0]);
+
+        let flags = Flags::try_ref_from_with_trailing_elements(&buf.t[..6], 4).unwrap();
+        assert_eq!(flags.header, 0);
+        assert_eq!(flags.flags, [true, false, true, true]);
+
+        // The length must match `count` exactly.
+        let err = Flags::try_ref_from_with_trailing_elements(&buf.t[..6], 5).unwrap_err();
+        assert!(matches!(err, TryCastError::Size(_)));
+        let err = Flags::try_ref_from_with_trailing_elements(&buf.t[..6], 2).unwrap_err();
+        assert!(matches!(err, TryCastError::Size(_)));
+
+        // The bytes must be aligned.
+        let err = Flags::try_ref_from_with_trailing_elements(&buf.t[1..7], 4).unwrap_err();
+        assert!(matches!(err, TryCastError::Alignment(_)));
+
+        // Every trailing element must be valid.
+        let err = Flags::try_ref_from_with_trailing_elements(&buf.t[..8], 6).unwrap_err();
+        match err {
+            TryCastError::Validity(err) => assert_eq!(err.into_src(), &buf.t[..8]),
+            _ => panic!("expected a validity error"),
+        }
+    }
+
+    #[test]
+    fn test_try_ref_from_all() {
+        let buf = Align::<[u8; 9], AU64>::new([1, 0, 2, 0, 0, 0, 3, 0, 4]);
+
+        let elems = NonZeroU16::try_ref_from_all(&buf.t[..2]).unwrap();
+        assert_eq!(elems, [NonZeroU16::new(u16::from_ne_bytes([1, 0])).unwrap()]);
+        assert!(NonZeroU16::try_ref_from_all(&buf.t[..0]).unwrap().is_empty());
+
+        // A single bad element in the middle is located.
+        let err = NonZeroU16::try_ref_from_all(&buf.t[..8]).unwrap_err();
+        match err {
+            TryCastError::Validity(err) => {
+                assert_eq!(err.invalid_index(), Some(2));
+                assert!(err.to_string().ends_with(" (the first invalid element is at index 2)"));
+                assert_eq!(err.into_src(), &buf.t[..8]);
+            }
+            _ => panic!("expected a validity error"),
+        }
+
+        // The length must be an exact multiple of the element size.
+        let err = NonZeroU16::try_ref_from_all(&buf.t[..3]).unwrap_err();
+        assert!(matches!(err, TryCastError::Size(_)));
+
+        // The bytes must be aligned.
+        let err = NonZeroU16::try_ref_from_all(&buf.t[1..9]).unwrap_err();
+        assert!(matches!(err, TryCastError::Alignment(_)));
+
+        // Other validity errors don't carry an index.
+        match <[NonZeroU16]>::try_ref_from(&buf.t[..8]).unwrap_err() {
+            TryCastError::Validity(err) => assert_eq!(err.invalid_index(), None),
+            _ => panic!("expected a validity error"),
+        }
+    }
+
+    #[test]
+    fn test_try_mut_from_with_trailing_elements() {
+        #[derive(TryFromBytes, KnownLayout, Debug)]
+        #[repr(C)]
+        struct Flags {
+            header: u16,
+            flags: [bool],
+        }
+
+        let mut buf = Align::<[u8; 9], AU64>::new([0, 0, 1, 0, 1, 1, 2, 0, 0]);
+
+        let flags = Flags::try_mut_from_with_trailing_elements(&mut buf.t[..6], 4).unwrap();
+        assert_eq!(flags.flags, [true, false, true, true]);
+        flags.flags[1] = true;
+        assert_eq!(buf.t[..6], [0, 0, 1, 1, 1, 1]);
+
+        // The length must match `count` exactly.
+        let err = Flags::try_mut_from_with_trailing_elements(&mut buf.t[..6], 5).unwrap_err();
+        assert!(matches!(err, TryCastError::Size(_)));
+
+        // The prefix variant returns the leftover bytes. Note that, with an odd
+        // number of elements, `Flags` has a trailing padding byte.
+        let (flags, suffix) =
+            Flags::try_mut_from_prefix_with_trailing_elements(&mut buf.t[..], 3).unwrap();
+        assert_eq!(flags.flags, [true, true, true]);
+        assert_eq!(suffix, [2, 0, 0]);
+        suffix[0] = 0;
+        assert_eq!(buf.t, [0, 0, 1, 1, 1, 1, 0, 0, 0]);
+
+        // Every trailing element must be valid.
+        buf.t[6] = 2;
+        let err = Flags::try_mut_from_prefix_with_trailing_elements(&mut buf.t[..], 6).unwrap_err();
+        match err {
+            TryCastError::Validity(err) => assert_eq!(err.into_src(), [0, 0, 1, 1, 1, 1, 2, 0]),
+            _ => panic!("expected a validity error"),
+        }
+        let err =
+            Flags::try_mut_from_prefix_with_trailing_elements(&mut buf.t[1..], 2).unwrap_err();
+        assert!(matches!(err, TryCastError::Alignment(_)));
+
+        // The suffix variant returns the preceding bytes.
+        let (prefix, flags) =
+            Flags::try_mut_from_suffix_with_trailing_elements(&mut buf.t[..6], 2).unwrap();
+        assert_eq!(prefix, [0, 0]);
+        assert_eq!(flags.header, u16::from_ne_bytes([1, 1]));
+        assert_eq!(flags.flags, [true, true]);
+        let err =
+            Flags::try_mut_from_suffix_with_trailing_elements(&mut buf.t[..8], 2).unwrap_err();
+        assert!(matches!(err, TryCastError::Validity(_)));
+        let err =
+            Flags::try_mut_from_suffix_with_trailing_elements(&mut buf.t[..7], 2).unwrap_err();
+        assert!(matches!(err, TryCastError::Alignment(_)));
+    }
+
     #[test]
     fn test_transmute() {
         // Test that memory is transmuted as expected.
@@ -6459,6 +11222,15 @@ mod tests {
         const X: [[u8; 2]; 4] = transmute!(ARRAY_OF_U8S);
         assert_eq!(X, ARRAY_OF_ARRAYS);
 
+        // Test that `transmute!` is legal in a generic `const fn`.
+        const fn flatten<const N: usize>(arrays: [[u8; 2]; N]) -> [u16; N] {
+            transmute!(#![allow(generic)] arrays)
+        }
+        const FLAT: [u16; 4] = flatten(ARRAY_OF_ARRAYS);
+        let x: [u16; 4] = transmute!(ARRAY_OF_U8S);
+        assert_eq!(FLAT, x);
+        assert_eq!(flatten([[1, 2]]), [u16::from_ne_bytes([1, 2])]);
+
         // Test that `transmute!` works with `!Immutable` types.
         let x: usize = transmute!(UnsafeCell::new(1usize));
         assert_eq!(x, 1);
@@ -6468,6 +11240,44 @@ mod tests {
         assert_eq!(x.into_inner(), 1);
     }
 
+    #[test]
+    fn test_try_transmute() {
+        // Test that memory is transmuted as expected.
+        let array_of_bools = [false, true, false, true, false, true, false, true];
+        let array_of_arrays = [[0, 1], [0, 1], [0, 1], [0, 1]];
+        let x: Result<[[u8; 2]; 4], _> = try_transmute!(array_of_bools);
+        assert_eq!(x, Ok(array_of_arrays));
+        let x: Result<[bool; 8], _> = try_transmute!(array_of_arrays);
+        assert_eq!(x, Ok(array_of_bools));
+
+        // Test that invalid bits are rejected, and that the source is returned.
+        let x: Result<[bool; 8], _> = try_transmute!([[0u8, 2], [0, 1], [0, 1], [0, 1]]);
+        assert_eq!(x.unwrap_err().into_src(), [[0u8, 2], [0, 1], [0, 1], [0, 1]]);
+
+        // Test that the source expression's value is forgotten rather than
+        // dropped on success, and returned on failure.
+        #[derive(IntoBytes)]
+        #[repr(transparent)]
+        struct PanicOnDrop(u8);
+        impl Drop for PanicOnDrop {
+            fn drop(&mut self) {
+                panic!("PanicOnDrop::drop");
+            }
+        }
+        let x: Result<bool, _> = try_transmute!(PanicOnDrop(1));
+        assert_eq!(x.map_err(|_| ()), Ok(true));
+        let x: Result<bool, _> = try_transmute!(PanicOnDrop(2));
+        mem::forget(x.unwrap_err().into_src());
+
+        // Test that `try_transmute!` works with `!Immutable` types.
+        let x: Result<usize, _> = try_transmute!(UnsafeCell::new(1usize));
+        assert_eq!(x.unwrap(), 1);
+        let x: Result<UnsafeCell<usize>, _> = try_transmute!(1usize);
+        assert_eq!(x.unwrap().into_inner(), 1);
+        let x: Result<UnsafeCell<bool>, _> = try_transmute!(UnsafeCell::new(1u8));
+        assert!(x.unwrap().into_inner());
+    }
+
     #[test]
     fn test_transmute_ref() {
         // Test that memory is transmuted as expected.
@@ -6503,6 +11313,42 @@ mod tests {
         assert_eq!(*y, 0);
     }
 
+    #[test]
+    fn test_transmute_slice() {
+        // Test that memory is transmuted as expected.
+        let array_of_u8s = [0u8, 1, 2, 3, 4, 5, 6, 7];
+        let array_of_arrays = [[0, 1], [2, 3], [4, 5], [6, 7]];
+        let x: &[[u8; 1]] = transmute_slice!(&array_of_u8s[..]);
+        assert_eq!(x.len(), 8);
+        assert_eq!(x.as_bytes(), array_of_u8s);
+        let x: &[[u8; 2]] = transmute_slice!(&array_of_arrays[..]);
+        assert_eq!(x, array_of_arrays);
+        let x: &[[i8; 2]] = transmute_slice!(&array_of_arrays[..]);
+        assert_eq!(x.len(), 4);
+        assert_eq!(x.as_bytes(), array_of_u8s);
+
+        // Test that `transmute_slice!` is legal in a const context.
+        const ARRAY_OF_U8S: [u8; 8] = [0u8, 1, 2, 3, 4, 5, 6, 7];
+        #[allow(clippy::redundant_static_lifetimes)]
+        const X: &'static [[u8; 1]] = transmute_slice!(&ARRAY_OF_U8S);
+        assert_eq!(X.len(), 8);
+        assert_eq!(X.as_bytes(), ARRAY_OF_U8S);
+
+        // Test that `transmute_slice!` supports decreasing alignment.
+        let u = [AU64(0), AU64(u64::MAX)];
+        let x: &[[u8; 8]] = transmute_slice!(&u[..]);
+        assert_eq!(x, [[0; 8], [0xFF; 8]]);
+
+        // Test that empty slices are supported.
+        let x: &[[u8; 2]] = transmute_slice!(&[0u16; 0][..]);
+        assert!(x.is_empty());
+
+        // Test that a mutable slice can be turned into an immutable one.
+        let mut x = [0u8; 4];
+        let y: &[i8] = transmute_slice!(&mut x[..]);
+        assert_eq!(y, [0; 4]);
+    }
+
     #[test]
     fn test_transmute_mut() {
         // Test that memory is transmuted as expected.
@@ -6532,6 +11378,59 @@ mod tests {
         assert_eq!(*y, 0);
     }
 
+    #[test]
+    fn test_try_transmute_ref() {
+        // Test that memory is transmuted as expected.
+        let array_of_bools = [false, true, false, true, false, true, false, true];
+        let array_of_arrays = [[0, 1], [0, 1], [0, 1], [0, 1]];
+        let x: Result<&[[u8; 2]; 4], _> = try_transmute_ref!(&array_of_bools);
+        assert_eq!(x, Ok(&array_of_arrays));
+        let x: Result<&[bool; 8], _> = try_transmute_ref!(&array_of_arrays);
+        assert_eq!(x, Ok(&array_of_bools));
+
+        // Test that invalid referents are rejected, and that the source is
+        // returned.
+        let invalid = [[0u8, 2], [0, 1], [0, 1], [0, 1]];
+        let x: Result<&[bool; 8], _> = try_transmute_ref!(&invalid);
+        assert!(ptr::eq(x.unwrap_err().into_src(), &invalid));
+
+        // Test that `try_transmute_ref!` supports decreasing alignment.
+        let u = AU64(0);
+        let x: Result<&[bool; 8], _> = try_transmute_ref!(&u);
+        assert_eq!(x, Ok(&[false; 8]));
+
+        // Test that a mutable reference can be turned into an immutable one.
+        let mut x = [1u8];
+        let y: Result<&[bool; 1], _> = try_transmute_ref!(&mut x);
+        assert_eq!(y, Ok(&[true]));
+    }
+
+    #[test]
+    fn test_try_transmute_mut() {
+        // Test that memory is transmuted as expected.
+        let mut array_of_u8s = [0u8, 1, 0, 1, 0, 1, 0, 1];
+        let x: Result<&mut [bool; 8], _> = try_transmute_mut!(&mut array_of_u8s);
+        let x = x.unwrap();
+        assert_eq!(*x, [false, true, false, true, false, true, false, true]);
+
+        // Test that writes through the returned reference are visible in the
+        // source.
+        x[0] = true;
+        assert_eq!(array_of_u8s, [1, 1, 0, 1, 0, 1, 0, 1]);
+
+        // Test that invalid referents are rejected, and that the source is
+        // returned.
+        let mut invalid = [[0u8, 2], [0, 1], [0, 1], [0, 1]];
+        let x: Result<&mut [bool; 8], _> = try_transmute_mut!(&mut invalid);
+        x.unwrap_err().into_src()[0][1] = 1;
+        assert_eq!(invalid, [[0, 1], [0, 1], [0, 1], [0, 1]]);
+
+        // Test that `try_transmute_mut!` supports decreasing alignment.
+        let mut u = AU64(0);
+        let x: Result<&mut [bool; 8], _> = try_transmute_mut!(&mut u);
+        assert_eq!(x.map(|x| *x), Ok([false; 8]));
+    }
+
     #[test]
     fn test_macros_evaluate_args_once() {
         let mut ctr = 0;
@@ -6547,6 +11446,73 @@ mod tests {
             &0usize
         });
         assert_eq!(ctr, 1);
+
+        let mut ctr = 0;
+        let _: Result<&[bool; 1], _> = try_transmute_ref!({
+            ctr += 1;
+            &[0u8]
+        });
+        assert_eq!(ctr, 1);
+    }
+
+    #[test]
+    fn test_zeroed() {
+        #[derive(FromZeros, Debug, Eq, PartialEq)]
+        #[repr(C)]
+        struct MyStruct {
+            a: u32,
+            b: bool,
+            c: Option<NonZeroU64>,
+        }
+
+        const C: MyStruct = zeroed!(MyStruct);
+        assert_eq!(C, MyStruct { a: 0, b: false, c: None });
+        assert_eq!(C, MyStruct::new_zeroed());
+
+        const ARR: [AU64; 3] = zeroed!([AU64; 3]);
+        assert_eq!(ARR, [AU64(0); 3]);
+
+        let x: u16 = zeroed!(u16);
+        assert_eq!(x, 0);
+        let () = zeroed!(());
+    }
+
+    #[test]
+    fn test_is_from_bytes() {
+        #[derive(TryFromBytes)]
+        #[repr(C)]
+        struct WithBool {
+            a: u8,
+            b: bool,
+        }
+
+        #[derive(FromBytes)]
+        #[repr(C)]
+        struct Plain {
+            a: u8,
+            b: u16,
+        }
+
+        const U8: bool = is_from_bytes!(u8);
+        const WITH_BOOL: bool = is_from_bytes!(WithBool);
+        assert!(U8);
+        assert!(!WITH_BOOL);
+        assert!(is_from_bytes!(Plain));
+        assert!(is_from_bytes!([AU64; 2]));
+        assert!(is_from_bytes!([u8]));
+        assert!(!is_from_bytes!(bool));
+        assert!(!is_from_bytes!(char));
+
+        fn unbounded<T>() -> bool {
+            is_from_bytes!(T)
+        }
+
+        fn bounded<T: FromBytes>() -> bool {
+            is_from_bytes!(T)
+        }
+
+        assert!(!unbounded::<u8>());
+        assert!(bounded::<u8>());
     }
 
     #[test]
@@ -6557,6 +11523,59 @@ mod tests {
         assert_eq!(AS_I32, i32::from_ne_bytes([b'a', b'b', b'c', b'd']));
     }
 
+    #[test]
+    fn test_parse_tagged() {
+        #[derive(Debug, PartialEq)]
+        enum Body<'a> {
+            One(&'a [u8; 1]),
+            Two(&'a [u8; 2]),
+            Aligned(&'a AU64),
+        }
+
+        fn parse(
+            bytes: &[u8],
+        ) -> Result<(&[u8; 2], Body<'_>, &[u8]), TaggedError<&[u8], [u8; 2], u8>> {
+            parse_tagged!(bytes, |header: &[u8; 2]| header[0], {
+                1 => Body::One([u8; 1]),
+                2 | 3 => Body::Two([u8; 2]),
+                8 => Body::Aligned(AU64),
+            })
+        }
+
+        // Succeed, with and without trailing bytes.
+        assert_eq!(parse(&[1, 0, 7]), Ok((&[1, 0], Body::One(&[7]), &[][..])));
+        assert_eq!(parse(&[2, 0, 7, 8, 9]), Ok((&[2, 0], Body::Two(&[7, 8]), &[9][..])));
+        assert_eq!(parse(&[3, 0, 7, 8]), Ok((&[3, 0], Body::Two(&[7, 8]), &[][..])));
+
+        // Fail because the header is too short.
+        let bytes = &[1][..];
+        let err = parse(bytes).unwrap_err();
+        assert!(matches!(err, TaggedError::Header(CastError::Size(_))));
+        assert_eq!(err.into_src(), bytes);
+
+        // Fail because the tag doesn't select a body type.
+        let err = parse(&[4, 0, 7]).unwrap_err();
+        assert_eq!(err, TaggedError::UnknownTag(4, &[7][..]));
+        assert_eq!(err.to_string(), "the header's tag (4) did not select any body type");
+        assert_eq!(err.into_src(), [7]);
+
+        // Fail because the body is too short.
+        let err = parse(&[2, 0, 7]).unwrap_err();
+        assert!(matches!(err, TaggedError::Body(ref e) if !e.is_alignment_error()));
+        assert_eq!(
+            err.to_string(),
+            "the conversion failed because the size of the source (1 byte) is incorrect for the size of the destination type (2 bytes): [u8; 2]"
+        );
+        assert_eq!(err.into_src(), [7]);
+
+        // Fail because the body is misaligned.
+        let buf = Align::<[u8; 10], AU64>::new([8, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        let err = parse(&buf.t[..]).unwrap_err();
+        assert!(matches!(err, TaggedError::Body(ref e) if e.is_alignment_error()));
+        assert_eq!(err.to_string(), AlignmentError::<_, AU64>::new(&buf.t[2..]).to_string());
+        assert_eq!(err.into_src(), &buf.t[2..]);
+    }
+
     #[test]
     fn test_ref_from_mut_from_error() {
         // Test `FromBytes::{ref_from, mut_from}{,_prefix,Suffix}` error cases.
@@ -6690,6 +11709,182 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_maybe_uninit_bytes() {
+        // A shim with the shape of `Read::read_buf`: it fills a prefix of `buf`
+        // with initialized bytes and returns the number of bytes written.
+        fn read_buf(src: &[u8], buf: &mut [MaybeUninit<u8>]) -> usize {
+            for (dst, src) in buf.iter_mut().zip(src) {
+                *dst = MaybeUninit::new(*src);
+            }
+            core::cmp::min(src.len(), buf.len())
+        }
+
+        let val = [1u8, 2, 3, 4];
+        let bytes = val.as_maybe_uninit_bytes();
+        assert_eq!(bytes.len(), 4);
+        assert!(ptr::eq(bytes.as_ptr().cast::<u8>(), val.as_ptr()));
+        // SAFETY: All of the bytes of `val` are initialized.
+        assert_eq!(unsafe { bytes[2].assume_init() }, 3);
+
+        let mut val = 0u32;
+        // SAFETY: `read_buf` only writes initialized bytes.
+        let n = read_buf(&[0xFF; 4], unsafe { val.as_mut_maybe_uninit_bytes() });
+        assert_eq!(n, 4);
+        assert_eq!(val, u32::MAX);
+
+        // A short read leaves the remaining bytes untouched.
+        let mut val = [0u16; 2];
+        // SAFETY: `read_buf` only writes initialized bytes.
+        let n = read_buf(&[0xFF; 3], unsafe { val.as_mut_maybe_uninit_bytes() });
+        assert_eq!(n, 3);
+        assert_eq!(val.as_bytes(), [0xFF, 0xFF, 0xFF, 0]);
+
+        // Unsized types are supported.
+        let mut arr = [0, 0, 0];
+        let val = Unsized::from_mut_slice(&mut arr);
+        // SAFETY: `read_buf` only writes initialized bytes.
+        let n = read_buf(&[1, 2], unsafe { val.as_mut_maybe_uninit_bytes() });
+        assert_eq!(n, 2);
+        assert_eq!(val.as_bytes(), [1, 2, 0]);
+    }
+
+    #[test]
+    fn test_as_bytes_large_array() {
+        // `as_bytes` on a large array must cover the whole array without
+        // requiring a runtime size computation (see the comment in
+        // `IntoBytes::as_bytes`).
+        let arr = [0xAAu8; 4096];
+        let bytes = arr.as_bytes();
+        assert_eq!(bytes.len(), 4096);
+        assert!(ptr::eq(bytes.as_ptr(), arr.as_ptr()));
+        assert!(bytes.iter().all(|&b| b == 0xAA));
+
+        let arr = [AU64(1); 512];
+        let bytes = arr.as_bytes();
+        assert_eq!(bytes.len(), mem::size_of::<[AU64; 512]>());
+        assert!(ptr::eq(bytes.as_ptr().cast::<AU64>(), arr.as_ptr()));
+        assert_eq!(&bytes[..8], 1u64.to_ne_bytes());
+    }
+
+    #[test]
+    fn test_to_bytes_array() {
+        let bytes: [u8; 4] = 0x01020304u32.to_bytes_array();
+        assert_eq!(bytes, 0x01020304u32.to_ne_bytes());
+
+        let bytes: [u8; 16] = [AU64(1), AU64(u64::MAX)].to_bytes_array();
+        assert_eq!(bytes[..8], 1u64.to_ne_bytes());
+        assert_eq!(bytes[8..], [0xFF; 8]);
+
+        let bytes: [u8; 0] = ().to_bytes_array();
+        assert_eq!(bytes, [0u8; 0]);
+    }
+
+    #[test]
+    fn test_as_bytes_including_padding() {
+        #[derive(IntoBytesWithPadding)]
+        #[repr(C)]
+        struct Padded {
+            a: u8,
+            b: u16,
+            c: u32,
+        }
+
+        let val = Padded { a: 1, b: 2, c: 3 };
+        let bytes = val.as_bytes_including_padding();
+        assert_eq!(bytes.len(), 8);
+        let val_ptr: *const Padded = &val;
+        assert!(ptr::eq(bytes.as_ptr().cast::<Padded>(), val_ptr));
+        // SAFETY: Byte 0 is the initialized field `a`, and bytes 2..8 are the
+        // initialized fields `b` and `c`; only byte 1 is padding.
+        let init = |i: usize| unsafe { bytes[i].assume_init() };
+        assert_eq!(init(0), 1);
+        assert_eq!([init(2), init(3)], 2u16.to_ne_bytes());
+        assert_eq!([init(4), init(5), init(6), init(7)], 3u32.to_ne_bytes());
+
+        // Primitives, arrays, and slices have no padding, so all of their bytes
+        // are initialized.
+        let assert_bytes_eq = |bytes: &[MaybeUninit<u8>], expected: &[u8]| {
+            assert_eq!(bytes.len(), expected.len());
+            for (byte, expected) in bytes.iter().zip(expected) {
+                // SAFETY: `bytes` has no padding, so every byte is initialized.
+                assert_eq!(unsafe { byte.assume_init() }, *expected);
+            }
+        };
+        assert_bytes_eq(3u32.as_bytes_including_padding(), &3u32.to_ne_bytes());
+        assert_bytes_eq([true, false].as_bytes_including_padding(), &[1, 0]);
+        assert_bytes_eq([1u8, 2, 3][..].as_bytes_including_padding(), &[1, 2, 3]);
+        assert_bytes_eq(
+            NonZeroU16::new(0x0102).as_bytes_including_padding(),
+            &0x0102u16.to_ne_bytes(),
+        );
+        assert_bytes_eq(Wrapping(3u32).as_bytes_including_padding(), &3u32.to_ne_bytes());
+        assert_bytes_eq(Unalign::new(4u16).as_bytes_including_padding(), &4u16.to_ne_bytes());
+        assert_bytes_eq(U16::<BigEndian>::new(5).as_bytes_including_padding(), &[0, 5]);
+    }
+
+    #[test]
+    fn test_option_nonzero() {
+        // `None` is represented by zero, and `Some` by the non-zero value.
+        assert_eq!(Option::<NonZeroU32>::read_from(&[0u8; 4][..]), Ok(None));
+        assert_eq!(
+            Option::<NonZeroU32>::read_from(&7u32.to_ne_bytes()[..]),
+            Ok(NonZeroU32::new(7))
+        );
+        assert_eq!(Option::<NonZeroI8>::new_zeroed(), None);
+        assert_eq!(NonZeroU16::new(0x0102).as_bytes(), 0x0102u16.to_ne_bytes());
+        assert_eq!(None::<NonZeroU64>.as_bytes(), [0u8; 8]);
+
+        // Since all bit patterns are valid, `Option<NonZeroXxx>` fields don't
+        // constrain the validity of the types that contain them.
+        #[derive(TryFromBytes, KnownLayout, Immutable)]
+        #[repr(u8)]
+        #[allow(dead_code)]
+        enum Message {
+            Ping(Option<NonZeroU8>),
+            Close(NonZeroU8),
+        }
+
+        let ping = Message::try_ref_from(&[0u8, 0][..]).unwrap();
+        assert!(matches!(ping, Message::Ping(None)));
+        let ping = Message::try_ref_from(&[0u8, 3][..]).unwrap();
+        assert!(matches!(ping, Message::Ping(Some(n)) if n.get() == 3));
+        let close = Message::try_ref_from(&[1u8, 3][..]).unwrap();
+        assert!(matches!(close, Message::Close(n) if n.get() == 3));
+        assert!(Message::try_ref_from(&[1u8, 0][..]).is_err());
+    }
+
+    #[test]
+    fn test_option_pointer_into_bytes() {
+        const PTR_SIZE: usize = mem::size_of::<usize>();
+
+        let none: Option<&u32> = None;
+        assert_eq!(none.as_bytes(), [0u8; PTR_SIZE]);
+        let none: Option<&mut u32> = None;
+        assert_eq!(none.as_bytes(), [0u8; PTR_SIZE]);
+        let none: Option<NonNull<u32>> = None;
+        assert_eq!(none.as_bytes(), [0u8; PTR_SIZE]);
+
+        // `Some` serializes to the address of the referent.
+        let x = 0u32;
+        let some = Some(&x);
+        let addr = util::AsAddress::addr(&x);
+        assert_eq!(some.as_bytes(), addr.as_bytes());
+        let some = Some(NonNull::from(&x));
+        assert_eq!(some.as_bytes(), addr.as_bytes());
+
+        // An FFI struct with an optional pointer field.
+        #[derive(IntoBytes, Immutable)]
+        #[repr(C)]
+        struct Ffi {
+            next: Option<&'static Ffi>,
+            len: usize,
+        }
+
+        let ffi = Ffi { next: None, len: 0 };
+        assert_eq!(ffi.as_bytes(), [0u8; 2 * PTR_SIZE]);
+    }
+
     #[test]
     fn test_array() {
         #[derive(FromBytes, IntoBytes, Immutable)]
@@ -6715,6 +11910,89 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_zero_secure() {
+        // Whether the zeroing is elided can't be tested portably, so this only
+        // tests that the value is zeroed. This test exists in order to exercise
+        // unsafe code, especially when running under Miri.
+        let mut x = [0xFFu8; 33];
+        x.zero_secure();
+        assert_eq!(x, [0; 33]);
+
+        let mut x = AU64(u64::MAX);
+        x.zero_secure();
+        assert_eq!(x, AU64(0));
+
+        let mut x = [u16::MAX; 5];
+        x[1..3].zero_secure();
+        assert_eq!(x, [u16::MAX, 0, 0, u16::MAX, u16::MAX]);
+
+        let mut x = true;
+        x.zero_secure();
+        assert!(!x);
+
+        let mut x = ();
+        x.zero_secure();
+    }
+
+    #[test]
+    #[cfg(any(
+        all(zerocopy_target_has_atomic, target_has_atomic = "64"),
+        all(not(zerocopy_target_has_atomic), not(target_arch = "powerpc"))
+    ))]
+    fn test_atomic_64() {
+        use core::sync::atomic::Ordering;
+
+        assert_impl_all!(AtomicU64: KnownLayout, TryFromBytes, FromZeros, FromBytes, IntoBytes);
+        assert_impl_all!(AtomicI64: KnownLayout, TryFromBytes, FromZeros, FromBytes, IntoBytes);
+        static_assertions::assert_not_impl_any!(AtomicU64: Immutable, Unaligned);
+        static_assertions::assert_not_impl_any!(AtomicI64: Immutable, Unaligned);
+
+        // A structure which might be placed in memory shared between
+        // processes.
+        #[derive(KnownLayout, FromBytes, IntoBytes)]
+        #[repr(C)]
+        struct Shared {
+            seq: AtomicU64,
+            offset: AtomicI64,
+        }
+
+        let mut buf = Align::<[u8; 16], AU64>::default();
+        let shared = Shared::mut_from(&mut buf.t[..]).unwrap();
+        assert_eq!(shared.seq.fetch_add(1, Ordering::Relaxed), 0);
+        shared.offset.store(-1, Ordering::Relaxed);
+        assert_eq!(shared.as_mut_bytes()[..8], 1u64.to_ne_bytes());
+        assert_eq!(buf.t[8..], (-1i64).to_ne_bytes());
+
+        let mut x = AtomicU64::new_zeroed();
+        x.as_mut_bytes().copy_from_slice(&u64::MAX.to_ne_bytes());
+        assert_eq!(x.load(Ordering::Relaxed), u64::MAX);
+    }
+
+    #[test]
+    fn test_tuple() {
+        type Tuple = (u32, bool, u16);
+
+        assert_impl_all!(Tuple: Immutable, TryFromBytes, FromZeros);
+        static_assertions::assert_not_impl_any!(Tuple: FromBytes, IntoBytes, Unaligned);
+        static_assertions::assert_not_impl_any!((u8, UnsafeCell<u8>, u8): Immutable);
+        static_assertions::assert_not_impl_any!((u8, NonNull<u8>, u8): TryFromBytes);
+
+        assert_eq!(Tuple::new_zeroed(), (0, false, 0));
+
+        // The layout of a tuple is unspecified, so we locate the `bool` field
+        // using the address of that field in a real value.
+        let t: Tuple = (0, false, 0);
+        #[allow(clippy::as_conversions)]
+        let offset = ptr::addr_of!(t.1) as usize - ptr::addr_of!(t) as usize;
+
+        let mut bytes = [0u8; mem::size_of::<Tuple>()];
+        bytes[offset] = 1;
+        assert_eq!(Tuple::try_read_from(&bytes[..]).ok(), Some((0, true, 0)));
+        bytes[offset] = 2;
+        assert!(matches!(Tuple::try_read_from(&bytes[..]), Err(TryReadError::Validity(_))));
+    }
+
     #[test]
     fn test_transparent_packed_generic_struct() {
         #[derive(IntoBytes, FromBytes, Unaligned)]
@@ -6896,6 +12174,18 @@ mod tests {
             Wrapping<bool>
                 => @success Wrapping(false), Wrapping(true),
                     @failure 2u8, 0xFFu8;
+            Wrapping<NonZeroU16>
+                => @success Wrapping(NonZeroU16::new(1).unwrap()), Wrapping(NonZeroU16::new(u16::MAX).unwrap()),
+                   @failure 0u16;
+            ManuallyDrop<NonZeroU16>
+                => @success ManuallyDrop::new(NonZeroU16::new(1).unwrap()), ManuallyDrop::new(NonZeroU16::new(u16::MAX).unwrap()),
+                   @failure 0u16;
+            Wrapping<ManuallyDrop<bool>>
+                => @success Wrapping(ManuallyDrop::new(false)), Wrapping(ManuallyDrop::new(true)),
+                   @failure 2u8, 0xFFu8;
+            ManuallyDrop<Wrapping<NonZeroU32>>
+                => @success ManuallyDrop::new(Wrapping(NonZeroU32::new(1).unwrap())),
+                   @failure 0u32;
             *const NotZerocopy
                 => @success ptr::null::<NotZerocopy>(),
                    @failure [0x01; mem::size_of::<*const NotZerocopy>()];
@@ -7104,6 +12394,8 @@ mod tests {
                             MaybeUninit<u8>,
                             MaybeUninit<NotZerocopy>,
                             MaybeUninit<UnsafeCell<()>>,
+                            MaybeUninit<NonZeroU16>,
+                            MaybeUninit<Wrapping<bool>>,
                             ManuallyDrop<UnsafeCell<()>>,
                             ManuallyDrop<[UnsafeCell<u8>]>,
                             ManuallyDrop<[UnsafeCell<bool>]>,
@@ -7540,11 +12832,11 @@ mod tests {
         #[cfg(feature = "alloc")]
         assert_impls!(Option<Box<UnsafeCell<NotZerocopy>>>: KnownLayout, Immutable, TryFromBytes, FromZeros, !FromBytes, !IntoBytes, !Unaligned);
         assert_impls!(Option<Box<[UnsafeCell<NotZerocopy>]>>: KnownLayout, !Immutable, !TryFromBytes, !FromZeros, !FromBytes, !IntoBytes, !Unaligned);
-        assert_impls!(Option<&'static UnsafeCell<NotZerocopy>>: KnownLayout, Immutable, TryFromBytes, FromZeros, !FromBytes, !IntoBytes, !Unaligned);
+        assert_impls!(Option<&'static UnsafeCell<NotZerocopy>>: KnownLayout, Immutable, TryFromBytes, FromZeros, IntoBytes, !FromBytes, !Unaligned);
         assert_impls!(Option<&'static [UnsafeCell<NotZerocopy>]>: KnownLayout, Immutable, !TryFromBytes, !FromZeros, !FromBytes, !IntoBytes, !Unaligned);
-        assert_impls!(Option<&'static mut UnsafeCell<NotZerocopy>>: KnownLayout, Immutable, TryFromBytes, FromZeros, !FromBytes, !IntoBytes, !Unaligned);
+        assert_impls!(Option<&'static mut UnsafeCell<NotZerocopy>>: KnownLayout, Immutable, TryFromBytes, FromZeros, IntoBytes, !FromBytes, !Unaligned);
         assert_impls!(Option<&'static mut [UnsafeCell<NotZerocopy>]>: KnownLayout, Immutable, !TryFromBytes, !FromZeros, !FromBytes, !IntoBytes, !Unaligned);
-        assert_impls!(Option<NonNull<UnsafeCell<NotZerocopy>>>: KnownLayout, TryFromBytes, FromZeros, Immutable, !FromBytes, !IntoBytes, !Unaligned);
+        assert_impls!(Option<NonNull<UnsafeCell<NotZerocopy>>>: KnownLayout, TryFromBytes, FromZeros, Immutable, IntoBytes, !FromBytes, !Unaligned);
         assert_impls!(Option<NonNull<[UnsafeCell<NotZerocopy>]>>: KnownLayout, Immutable, !TryFromBytes, !FromZeros, !FromBytes, !IntoBytes, !Unaligned);
         assert_impls!(Option<fn()>: KnownLayout, Immutable, TryFromBytes, FromZeros, !FromBytes, !IntoBytes, !Unaligned);
         assert_impls!(Option<FnManyArgs>: KnownLayout, Immutable, TryFromBytes, FromZeros, !FromBytes, !IntoBytes, !Unaligned);
@@ -7572,6 +12864,10 @@ mod tests {
         assert_impls!(MaybeUninit<u8>: KnownLayout, Immutable, TryFromBytes, FromZeros, FromBytes, Unaligned, !IntoBytes);
         assert_impls!(MaybeUninit<NotZerocopy>: KnownLayout, TryFromBytes, FromZeros, FromBytes, !Immutable, !IntoBytes, !Unaligned);
         assert_impls!(MaybeUninit<UnsafeCell<()>>: KnownLayout, TryFromBytes, FromZeros, FromBytes, Unaligned, !Immutable, !IntoBytes);
+        // `MaybeUninit<T>` places no restrictions on its contents, so it is
+        // `FromBytes` even when `T` is only `TryFromBytes`.
+        assert_impls!(MaybeUninit<NonZeroU16>: KnownLayout, Immutable, TryFromBytes, FromZeros, FromBytes, !IntoBytes, !Unaligned);
+        assert_impls!(MaybeUninit<Wrapping<bool>>: KnownLayout, Immutable, TryFromBytes, FromZeros, FromBytes, Unaligned, !IntoBytes);
 
         assert_impls!(Wrapping<u8>: KnownLayout, Immutable, TryFromBytes, FromZeros, FromBytes, IntoBytes, Unaligned);
         // This test is important because it allows us to test our hand-rolled
@@ -7579,6 +12875,13 @@ mod tests {
         assert_impls!(Wrapping<bool>: KnownLayout, Immutable, TryFromBytes, FromZeros, IntoBytes, Unaligned, !FromBytes);
         assert_impls!(Wrapping<NotZerocopy>: KnownLayout, !Immutable, !TryFromBytes, !FromZeros, !FromBytes, !IntoBytes, !Unaligned);
         assert_impls!(Wrapping<UnsafeCell<()>>: KnownLayout, TryFromBytes, FromZeros, FromBytes, IntoBytes, Unaligned, !Immutable);
+        // These tests ensure that `Wrapping` and `ManuallyDrop` forward
+        // `is_bit_valid` to validated inner types, including through each
+        // other.
+        assert_impls!(Wrapping<NonZeroU16>: KnownLayout, Immutable, TryFromBytes, IntoBytes, !FromZeros, !FromBytes, !Unaligned);
+        assert_impls!(ManuallyDrop<NonZeroU16>: KnownLayout, Immutable, TryFromBytes, IntoBytes, !FromZeros, !FromBytes, !Unaligned);
+        assert_impls!(Wrapping<ManuallyDrop<bool>>: KnownLayout, Immutable, TryFromBytes, FromZeros, IntoBytes, Unaligned, !FromBytes);
+        assert_impls!(ManuallyDrop<Wrapping<NonZeroU32>>: KnownLayout, Immutable, TryFromBytes, IntoBytes, !FromZeros, !FromBytes, !Unaligned);
 
         assert_impls!(Unalign<u8>: KnownLayout, Immutable, TryFromBytes, FromZeros, FromBytes, IntoBytes, Unaligned);
         // This test is important because it allows us to test our hand-rolled
@@ -17,12 +17,21 @@
 
 #![allow(missing_debug_implementations)]
 
-use core::{marker::PhantomData, mem::ManuallyDrop};
+use core::{
+    marker::PhantomData,
+    mem::{self, ManuallyDrop, MaybeUninit},
+    ptr,
+};
+
+use crate::{
+    pointer::{invariant, Maybe, Ptr},
+    FromBytes, Immutable, IntoBytes, TryFromBytes, Unaligned, ValidityError,
+};
 
 // TODO(#29), TODO(https://github.com/rust-lang/rust/issues/69835): Remove this
 // `cfg` when `size_of_val_raw` is stabilized.
 #[cfg(__INTERNAL_USE_ONLY_NIGHTLY_FEATURES_IN_TESTS)]
-use core::ptr::{self, NonNull};
+use core::ptr::NonNull;
 
 /// A compile-time check that should be one particular value.
 pub trait ShouldBe<const VALUE: bool> {}
@@ -32,6 +41,29 @@ pub struct HasPadding<T: ?Sized, const VALUE: bool>(PhantomData<T>);
 
 impl<T: ?Sized, const VALUE: bool> ShouldBe<VALUE> for HasPadding<T, VALUE> {}
 
+/// Compile-time assertions about the layout of `Self`, used by
+/// `require_layout!`.
+///
+/// Since these are associated constants, they are evaluated after
+/// monomorphization, and so `require_layout!` may be used with generic types.
+pub trait RequireLayout<const SIZE: usize, const ALIGN: usize>: Sized {
+    /// Asserts that `size_of::<Self>() == SIZE`.
+    const SIZE_IS_REQUIRED_SIZE: bool = {
+        let size_is_required_size = mem::size_of::<Self>() == SIZE;
+        const_assert!(size_is_required_size);
+        size_is_required_size
+    };
+
+    /// Asserts that `align_of::<Self>() == ALIGN`.
+    const ALIGN_IS_REQUIRED_ALIGN: bool = {
+        let align_is_required_align = mem::align_of::<Self>() == ALIGN;
+        const_assert!(align_is_required_align);
+        align_is_required_align
+    };
+}
+
+impl<T, const SIZE: usize, const ALIGN: usize> RequireLayout<SIZE, ALIGN> for T {}
+
 /// A type whose size is equal to `align_of::<T>()`.
 #[repr(C)]
 pub struct AlignOf<T> {
@@ -105,6 +137,22 @@ pub const ALIGNED_64K_ALLOCATION: NonNull<[u8]> = {
     }
 };
 
+/// Asserts at compile time that each of `$ty` implements `$trait`.
+///
+/// This is the implementation of `assert_from_bytes!` and friends. It expands
+/// to a `const` item whose initializer is a closure which is never called, and
+/// so it produces no runtime code.
+#[doc(hidden)] // `#[macro_export]` bypasses this module's `#[doc(hidden)]`.
+#[macro_export]
+macro_rules! assert_trait_impl {
+    ($trait:path; $($ty:ty),+) => {
+        const _: fn() = || {
+            fn assert_impl<T: ?$crate::macro_util::core_reexport::marker::Sized + $trait>() {}
+            $(assert_impl::<$ty>();)+
+        };
+    };
+}
+
 /// Computes the offset of the base of the field `$trailing_field_name` within
 /// the type `$ty`.
 ///
@@ -262,10 +310,44 @@ macro_rules! align_of {
 #[macro_export]
 macro_rules! struct_has_padding {
     ($t:ty, $($ts:ty),*) => {
-        ::zerocopy::macro_util::core_reexport::mem::size_of::<$t>() > 0 $(+ ::zerocopy::macro_util::core_reexport::mem::size_of::<$ts>())*
+        $crate::macro_util::core_reexport::mem::size_of::<$t>() > 0 $(+ $crate::macro_util::core_reexport::mem::size_of::<$ts>())*
     };
 }
 
+/// Does the `repr(C)` slice DST `$t` have padding?
+///
+/// `$attr` is the list of `$t`'s `repr` attributes, `$ts` is the list of the
+/// types of every field in `$t` except for the trailing slice field, and
+/// `$elem` is the element type of that slice. `$t` must be a `repr(C)` struct
+/// type whose last field is a `[$elem]`, or else
+/// `repr_c_slice_dst_has_padding!`'s result may be meaningless.
+///
+/// `$t` has no padding for any number of trailing elements if and only if:
+/// - its leading fields are laid out back-to-back with no padding, and the
+///   trailing slice begins immediately after them, and
+/// - the size of the struct without any trailing elements, and the size of
+///   `$elem`, are both multiples of the struct's alignment, so that no
+///   trailing padding is ever required.
+///
+/// Since `size_of` can't be used on `$t`, we compute its layout using a sized
+/// struct with the same `repr` attributes (including any `align(N)` or
+/// `packed(N)` modifiers) and the same leading fields, followed by a
+/// `[$elem; 0]`. This struct has the same alignment as `$t`, and its trailing
+/// array has the same offset as `$t`'s trailing slice.
+#[doc(hidden)] // `#[macro_export]` bypasses this module's `#[doc(hidden)]`.
+#[macro_export]
+macro_rules! repr_c_slice_dst_has_padding {
+    ($(#[$attr:meta])* $t:ty, [$($ts:ty),*], $elem:ty) => {{
+        $(#[$attr])*
+        #[allow(dead_code)]
+        struct Prefix($($ts,)* [$elem; 0]);
+
+        $crate::macro_util::core_reexport::mem::size_of::<Prefix>() != 0 $(+ $crate::macro_util::core_reexport::mem::size_of::<$ts>())*
+            || $crate::macro_util::core_reexport::mem::size_of::<$elem>()
+                % $crate::macro_util::core_reexport::mem::align_of::<Prefix>() != 0
+    }};
+}
+
 /// Does the union type `$t` have padding?
 ///
 /// `$ts` is the list of the type of every field in `$t`. `$t` must be a
@@ -282,7 +364,7 @@ macro_rules! struct_has_padding {
 #[macro_export]
 macro_rules! union_has_padding {
     ($t:ty, $($ts:ty),*) => {
-        false $(|| ::zerocopy::macro_util::core_reexport::mem::size_of::<$t>() != ::zerocopy::macro_util::core_reexport::mem::size_of::<$ts>())*
+        false $(|| $crate::macro_util::core_reexport::mem::size_of::<$t>() != $crate::macro_util::core_reexport::mem::size_of::<$ts>())*
     };
 }
 
@@ -294,7 +376,8 @@ macro_rules! union_has_padding {
 macro_rules! assert_align_gt_eq {
     ($t:ident, $u: ident) => {{
         // The comments here should be read in the context of this macro's
-        // invocations in `transmute_ref!` and `transmute_mut!`.
+        // invocations in `transmute_ref!` and `transmute_mut!` (and their
+        // `try_` counterparts).
         if false {
             // The type wildcard in this bound is inferred to be `T` because
             // `align_of.into_t()` is assigned to `t` (which has type `T`).
@@ -329,7 +412,8 @@ macro_rules! assert_align_gt_eq {
 macro_rules! assert_size_eq {
     ($t:ident, $u: ident) => {{
         // The comments here should be read in the context of this macro's
-        // invocations in `transmute_ref!` and `transmute_mut!`.
+        // invocations in `transmute_ref!` and `transmute_mut!` (and their
+        // `try_` counterparts).
         if false {
             // SAFETY: This code is never run.
             $u = unsafe {
@@ -337,7 +421,13 @@ macro_rules! assert_size_eq {
                 // - It's okay to transmute a type to itself.
                 // - We can't annotate the types; this macro is designed to
                 //   infer the types from the calling context.
-                #[allow(clippy::useless_transmute, clippy::missing_transmute_annotations)]
+                // - This code is never run, so suggestions for replacing the
+                //   transmute (e.g., with `to_ne_bytes`) are irrelevant.
+                #[allow(
+                    clippy::useless_transmute,
+                    clippy::missing_transmute_annotations,
+                    clippy::transmute_num_to_bytes
+                )]
                 $crate::macro_util::core_reexport::mem::transmute($t)
             };
         } else {
@@ -346,6 +436,34 @@ macro_rules! assert_size_eq {
     }};
 }
 
+/// A union used by `transmute` to reinterpret the bytes of a `Src` as a `Dst`.
+union Transmute<Src, Dst> {
+    src: ManuallyDrop<Src>,
+    dst: ManuallyDrop<Dst>,
+}
+
+/// Transmutes a value of one type to a value of another type.
+///
+/// Unlike `core::mem::transmute`, `transmute` may be called with generic
+/// types. It is used by `transmute!(#![allow(generic)] ...)`, which checks the
+/// sizes of `Src` and `Dst` itself.
+///
+/// # Safety
+///
+/// The caller must guarantee that:
+/// - `size_of::<Src>() == size_of::<Dst>()`
+/// - `Src: IntoBytes`
+/// - `Dst: FromBytes`
+#[inline(always)]
+pub const unsafe fn transmute<Src, Dst>(src: Src) -> Dst {
+    let src = Transmute { src: ManuallyDrop::new(src) };
+    // SAFETY: The caller promises that `size_of::<Src>() == size_of::<Dst>()`,
+    // and so `src.dst` consists of exactly the bytes of `src.src`. The caller
+    // promises that `Src: IntoBytes`, so those bytes are initialized, and that
+    // `Dst: FromBytes`, so any initialized bytes are a valid `Dst`.
+    unsafe { ManuallyDrop::into_inner(src.dst) }
+}
+
 /// Transmutes a reference of one type to a reference of another type.
 ///
 /// # Safety
@@ -376,7 +494,46 @@ pub const unsafe fn transmute_ref<'dst, 'src: 'dst, Src: 'src, Dst: 'dst>(
     // TODO(#67): Once our MSRV is 1.58, replace this `transmute` with `&*dst`.
     #[allow(clippy::transmute_ptr_to_ref)]
     unsafe {
-        core::mem::transmute(dst)
+        mem::transmute(dst)
+    }
+}
+
+/// Transmutes a slice of one element type to a slice of another element type.
+///
+/// The returned slice has the same length as `src`.
+///
+/// # Safety
+///
+/// The caller must guarantee that:
+/// - `Src: IntoBytes + Immutable`
+/// - `Dst: FromBytes + Immutable`
+/// - `size_of::<Src>() == size_of::<Dst>()`
+/// - `align_of::<Src>() >= align_of::<Dst>()`
+#[inline(always)]
+pub const unsafe fn transmute_slice<'dst, 'src: 'dst, Src: 'src, Dst: 'dst>(
+    src: &'src [Src],
+) -> &'dst [Dst] {
+    let src: *const [Src] = src;
+    // Slice pointer casts preserve the number of elements.
+    #[allow(clippy::as_conversions)]
+    let dst = src as *const [Dst];
+    // SAFETY:
+    // - We know that it is sound to view the target type of the input reference
+    //   (`[Src]`) as the target type of the output reference (`[Dst]`) because
+    //   the caller has guaranteed that `Src: IntoBytes`, `Dst: FromBytes`, and
+    //   `size_of::<Src>() == size_of::<Dst>()`. Since the cast preserves the
+    //   number of elements, `[Src]` and `[Dst]` have the same size.
+    // - We know that there are no `UnsafeCell`s, and thus we don't have to
+    //   worry about `UnsafeCell` overlap, because `Src: Immutable` and `Dst:
+    //   Immutable`.
+    // - The caller has guaranteed that alignment is not increased.
+    // - We know that the returned lifetime will not outlive the input lifetime
+    //   thanks to the lifetime bounds on this function.
+    //
+    // TODO(#67): Once our MSRV is 1.58, replace this `transmute` with `&*dst`.
+    #[allow(clippy::transmute_ptr_to_ref)]
+    unsafe {
+        mem::transmute(dst)
     }
 }
 
@@ -409,6 +566,266 @@ pub unsafe fn transmute_mut<'dst, 'src: 'dst, Src: 'src, Dst: 'dst>(
     unsafe { &mut *dst }
 }
 
+/// Attempts to transmute a value of type `Src` into a value of type `Dst`.
+///
+/// # Safety
+///
+/// The caller must guarantee that `size_of::<Src>() == size_of::<Dst>()`.
+#[inline(always)]
+pub unsafe fn try_transmute<Src, Dst>(src: Src) -> Result<Dst, ValidityError<Src, Dst>>
+where
+    Src: IntoBytes,
+    Dst: TryFromBytes,
+{
+    // SAFETY: The caller has guaranteed that `Src` and `Dst` - and thus
+    // `MaybeUninit<Dst>` - have the same size, so `transmute_copy` reads only
+    // bytes of `src`. `MaybeUninit<Dst>` has no validity requirements.
+    let mut candidate: MaybeUninit<Dst> = unsafe { mem::transmute_copy(&src) };
+
+    // Note that we have to call `is_bit_valid` on an exclusive-aliased pointer
+    // since we don't require `Dst: Immutable`. See `TryFromBytes::try_read_from`
+    // for more details.
+    let c_ptr = Ptr::from_mut(&mut candidate);
+    let c_ptr = c_ptr.transparent_wrapper_into_inner();
+    // SAFETY: `c_ptr` has no uninitialized sub-ranges because it was copied
+    // from `src`, which is `IntoBytes` and so has no uninitialized bytes.
+    let c_ptr = unsafe { c_ptr.assume_validity::<invariant::Initialized>() };
+
+    if !Dst::is_bit_valid(c_ptr.forget_aligned()) {
+        return Err(ValidityError::new(src));
+    }
+
+    // The bits of `src` now belong to `candidate`; like `transmute!`, we don't
+    // drop `src`.
+    mem::forget(src);
+
+    // SAFETY: We just validated that `candidate` contains a valid `Dst`.
+    Ok(unsafe { candidate.assume_init() })
+}
+
+/// Attempts to transmute a reference of one type to a reference of another
+/// type.
+///
+/// # Safety
+///
+/// The caller must guarantee that:
+/// - `size_of::<Src>() == size_of::<Dst>()`
+/// - `align_of::<Src>() >= align_of::<Dst>()`
+#[inline(always)]
+pub unsafe fn try_transmute_ref<Src, Dst>(src: &Src) -> Result<&Dst, ValidityError<&Src, Dst>>
+where
+    Src: IntoBytes + Immutable,
+    Dst: TryFromBytes + Immutable,
+{
+    let ptr = Ptr::from_ref(src);
+    // SAFETY:
+    // - The caller has guaranteed that `Src` and `Dst` have the same size, so
+    //   `cast` addresses the same bytes as `p`
+    // - `cast` preserves provenance
+    // - Since `Src: Immutable` and `Dst: Immutable`, neither `*p` nor the
+    //   returned pointer's referent contain any `UnsafeCell`s
+    let ptr = unsafe { ptr.cast_unsized(|p: *mut Src| p.cast::<Dst>()) };
+    // SAFETY: `Src: IntoBytes`, and so all of the bytes of `src` are
+    // initialized.
+    let ptr = unsafe { ptr.assume_initialized() };
+
+    if !Dst::is_bit_valid(ptr) {
+        return Err(ValidityError::new(src));
+    }
+
+    let src: *const Src = src;
+    let dst = src.cast::<Dst>();
+    // SAFETY:
+    // - We just validated that `src`'s referent is a bit-valid `Dst`.
+    // - The caller has guaranteed that `Src` and `Dst` have the same size, and
+    //   that alignment is not increased.
+    // - We know that there are no `UnsafeCell`s, and thus we don't have to
+    //   worry about `UnsafeCell` overlap, because `Src: Immutable` and `Dst:
+    //   Immutable`.
+    // - We know that the returned lifetime will not outlive the input lifetime
+    //   thanks to the lifetime elision on this function.
+    Ok(unsafe { &*dst })
+}
+
+/// Attempts to transmute a mutable reference of one type to a mutable
+/// reference of another type.
+///
+/// # Safety
+///
+/// The caller must guarantee that:
+/// - `size_of::<Src>() == size_of::<Dst>()`
+/// - `align_of::<Src>() >= align_of::<Dst>()`
+#[inline(always)]
+pub unsafe fn try_transmute_mut<Src, Dst>(
+    src: &mut Src,
+) -> Result<&mut Dst, ValidityError<&mut Src, Dst>>
+where
+    Src: FromBytes + IntoBytes,
+    Dst: TryFromBytes + IntoBytes,
+{
+    let is_bit_valid = {
+        let ptr = Ptr::from_mut(&mut *src);
+        // SAFETY:
+        // - The caller has guaranteed that `Src` and `Dst` have the same size,
+        //   so `cast` addresses the same bytes as `p`
+        // - `cast` preserves provenance
+        // - `ptr` is `Exclusive`, so there are no requirements on the
+        //   positions of `UnsafeCell`s
+        let ptr = unsafe { ptr.cast_unsized(|p: *mut Src| p.cast::<Dst>()) };
+        // SAFETY: `Src: IntoBytes`, and so all of the bytes of `src` are
+        // initialized.
+        let ptr = unsafe { ptr.assume_initialized() };
+
+        // Note that we call `is_bit_valid` on an exclusive-aliased pointer
+        // since we don't require `Dst: Immutable`. See
+        // `TryFromBytes::try_read_from` for more details.
+        Dst::is_bit_valid(ptr)
+    };
+
+    if !is_bit_valid {
+        return Err(ValidityError::new(src));
+    }
+
+    let src: *mut Src = src;
+    let dst = src.cast::<Dst>();
+    // SAFETY:
+    // - We just validated that `src`'s referent is a bit-valid `Dst`.
+    // - Since `Src: FromBytes + IntoBytes` and `Dst: IntoBytes`, any `Dst`
+    //   written through the returned reference is a bit-valid `Src`.
+    // - The caller has guaranteed that `Src` and `Dst` have the same size, and
+    //   that alignment is not increased.
+    // - We know that the returned lifetime will not outlive the input lifetime
+    //   thanks to the lifetime elision on this function.
+    Ok(unsafe { &mut *dst })
+}
+
+/// Implemented for `T` if a field of type `T` may appear in a type which
+/// derives `FromBytes`.
+///
+/// `#[derive(FromBytes)]` emits a `FieldIsFromBytes` check for each field in
+/// addition to its `FromBytes` bounds. `Ty` is the deriving type, and `Field`
+/// is a marker type named after the field. Since both appear in the error
+/// emitted when the check fails, this turns a generic "trait bound not
+/// satisfied" error into one which names the offending field.
+#[cfg_attr(
+    zerocopy_diagnostic_on_unimplemented,
+    diagnostic::on_unimplemented(
+        message = "field `{Field}: {Self}` is not `FromBytes` because not all byte patterns are valid",
+        label = "required by `#[derive(FromBytes)]` on `{Ty}`",
+        note = "every field of a `FromBytes` type must itself be `FromBytes`"
+    )
+)]
+pub trait FieldIsFromBytes<Ty: ?Sized, Field> {}
+
+impl<T: FromBytes + ?Sized, Ty: ?Sized, Field> FieldIsFromBytes<Ty, Field> for T {}
+
+/// Fails to compile unless `T: FromBytes`, naming the field `Field` of `Ty`.
+///
+/// See [`FieldIsFromBytes`] for more details.
+#[inline(always)]
+pub fn assert_field_is_from_bytes<T, Ty, Field>()
+where
+    T: FieldIsFromBytes<Ty, Field> + ?Sized,
+    Ty: ?Sized,
+{
+}
+
+/// Implemented for `T` if a field of type `T` may appear in a type which
+/// derives `Unaligned` without `repr(packed)`.
+///
+/// This is the `Unaligned` analogue of [`FieldIsFromBytes`]. It is commonly
+/// hit when a field's type has no alignment requirement in practice (e.g., a
+/// `repr(packed)` struct), but does not itself implement `Unaligned`.
+#[cfg_attr(
+    zerocopy_diagnostic_on_unimplemented,
+    diagnostic::on_unimplemented(
+        message = "field `{Field}: {Self}` is not `Unaligned`",
+        label = "required by `#[derive(Unaligned)]` on `{Ty}`",
+        note = "every field of an `Unaligned` type which is not `repr(packed)` must itself be `Unaligned`",
+        note = "if `{Self}` has an alignment of 1, consider deriving `Unaligned` on it"
+    )
+)]
+pub trait FieldIsUnaligned<Ty: ?Sized, Field> {}
+
+impl<T: Unaligned + ?Sized, Ty: ?Sized, Field> FieldIsUnaligned<Ty, Field> for T {}
+
+/// Fails to compile unless `T: Unaligned`, naming the field `Field` of `Ty`.
+///
+/// See [`FieldIsUnaligned`] for more details.
+#[inline(always)]
+pub fn assert_field_is_unaligned<T, Ty, Field>()
+where
+    T: FieldIsUnaligned<Ty, Field> + ?Sized,
+    Ty: ?Sized,
+{
+}
+
+/// Runs a user-supplied validator on a bit-valid `candidate`.
+///
+/// This is used by `#[derive(TryFromBytes)]` to implement
+/// `#[zerocopy(validate = ...)]`. If `candidate` is well-aligned, `validate`
+/// is passed a reference to its referent; otherwise, it is passed a reference
+/// to a bitwise copy which is never dropped.
+///
+/// # Safety
+///
+/// The caller must guarantee that `candidate`'s referent is a bit-valid `T`.
+#[inline(always)]
+pub unsafe fn run_validator<T, A, F>(candidate: Maybe<'_, T, A>, validate: F) -> bool
+where
+    T: Immutable,
+    A: invariant::Aliasing + invariant::AtLeast<invariant::Shared>,
+    F: FnOnce(&T) -> bool,
+{
+    // SAFETY: The caller has guaranteed that `candidate`'s referent is a
+    // bit-valid `T`.
+    let candidate = unsafe { candidate.assume_valid() };
+    match candidate.bikeshed_try_into_aligned() {
+        Ok(aligned) => validate(aligned.as_ref()),
+        Err(err) => {
+            let raw = err.into_src().as_non_null().as_ptr();
+            // SAFETY: `raw` is valid for reads of `size_of::<T>()` bytes, and
+            // those bytes are a bit-valid `T`. Since `T: Immutable`, no other
+            // party can mutate them concurrently. We wrap the copy in
+            // `ManuallyDrop` so that it is never dropped, since it does not own
+            // its referent; this holds even if `validate` panics.
+            let copy = ManuallyDrop::new(unsafe { ptr::read_unaligned(raw) });
+            validate(&copy)
+        }
+    }
+}
+
+/// Used by `is_from_bytes!` to determine whether `T: FromBytes`.
+///
+/// `IsFromBytes<T>` has an inherent `IS_FROM_BYTES` constant, equal to `true`,
+/// only if `T: FromBytes`. Otherwise, the path `IsFromBytes::<T>::IS_FROM_BYTES`
+/// resolves to the constant provided by [`IsFromBytesFallback`], which is
+/// `false`. Inherent items take precedence over trait items, but only when the
+/// inherent impl's bounds are known to hold.
+pub struct IsFromBytes<T: ?Sized>(PhantomData<T>);
+
+impl<T: FromBytes + ?Sized> IsFromBytes<T> {
+    pub const IS_FROM_BYTES: bool = true;
+}
+
+/// The fallback for [`IsFromBytes`] when `T: FromBytes` is not known to hold.
+pub trait IsFromBytesFallback {
+    const IS_FROM_BYTES: bool = false;
+}
+
+impl<T: ?Sized> IsFromBytesFallback for IsFromBytes<T> {}
+
+/// Collects `slices` into a `Vec`.
+///
+/// This is used by `io_slices!`.
+#[cfg(feature = "std")]
+#[inline(always)]
+pub fn io_slices<const N: usize>(
+    slices: [std::io::IoSlice<'_>; N],
+) -> std::vec::Vec<std::io::IoSlice<'_>> {
+    std::vec::Vec::from(slices)
+}
+
 /// A function which emits a warning if its return value is not used.
 #[must_use]
 #[inline(always)]
@@ -659,6 +1076,48 @@ mod tests {
         test!(#[repr(packed)] (u8, u64) => false);
     }
 
+    #[test]
+    fn test_repr_c_slice_dst_has_padding() {
+        // Test that `repr_c_slice_dst_has_padding!` reports the expected value.
+        macro_rules! test {
+            ($(#[$attr:meta])* ($($ts:ty),*), [$elem:ty] => $expect:expr) => {{
+                $(#[$attr])*
+                #[allow(dead_code)]
+                struct Test($($ts,)* [$elem]);
+                assert_eq!(
+                    repr_c_slice_dst_has_padding!($(#[$attr])* Test, [$($ts),*], $elem),
+                    $expect
+                );
+            }};
+            (($($ts:ty),*), [$elem:ty] => $expect:expr) => {
+                test!(#[repr(C)] ($($ts),*), [$elem] => $expect)
+            };
+        }
+
+        test!((u8), [u8] => false);
+        test!((u8, u8), [[u8; 3]] => false);
+        test!((AU64), [AU64] => false);
+        test!((AU64), [[u8; 8]] => false);
+        test!((AU64, [u8; 4], [u8; 4]), [AU64] => false);
+
+        // Padding between the leading fields.
+        test!((u8, AU64), [AU64] => true);
+        // Padding between the leading fields and the trailing slice.
+        test!((u8), [AU64] => true);
+        // Trailing padding after an odd number of elements.
+        test!((AU64), [u8] => true);
+        test!((AU64), [[u8; 4]] => true);
+
+        // `align(N)` raises the struct's alignment, which can require trailing
+        // padding even if the fields are laid out back-to-back.
+        test!(#[repr(C, align(8))] (u8), [u8] => true);
+        test!(#[repr(C, align(8))] (), [u8] => true);
+        test!(#[repr(C, align(8))] (AU64), [AU64] => false);
+        test!(#[repr(C, align(2))] ([u8; 2]), [[u8; 2]] => false);
+        // `packed(N)` removes padding between the leading fields.
+        test!(#[repr(C, packed(1))] (u8, u64), [u64] => false);
+    }
+
     #[test]
     fn test_union_has_padding() {
         // Test that, for each provided repr, `union_has_padding!` reports the
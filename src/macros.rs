@@ -130,7 +130,7 @@ macro_rules! unsafe_impl {
     ) => {
         $(#[$attr])*
         #[allow(non_local_definitions)]
-        unsafe impl<$($tyvar $(: $(? $optbound +)* $($bound +)*)?),* $(, const $constname: $constty,)*> $trait for $ty {
+        unsafe impl<$($tyvar $(: $(? $optbound +)* $($bound +)*)?,)* $(const $constname: $constty,)*> $trait for $ty {
             unsafe_impl!(@method $trait $(; |$candidate: $(MaybeAligned<$ref_repr>)? $(Maybe<$ptr_repr>)?| $is_bit_valid)?);
         }
     };
@@ -534,13 +534,16 @@ macro_rules! impl_known_layout {
     ($($tyvar:ident $(: ?$optbound:ident)? => $ty:ty),* $(,)?) => {
         $(impl_known_layout!(@inner , $tyvar $(: ?$optbound)? => $ty);)*
     };
+    ($(const $constvar:ident : $constty:ty => $ty:ty),* $(,)?) => {
+        $(impl_known_layout!(@inner const $constvar: $constty, => $ty);)*
+    };
     ($($ty:ty),*) => { $(impl_known_layout!(@inner , => $ty);)* };
     (@inner $(const $constvar:ident : $constty:ty)? , $($tyvar:ident $(: ?$optbound:ident)?)? => $ty:ty) => {
         const _: () = {
             use core::ptr::NonNull;
 
             // SAFETY: Delegates safety to `DstLayout::for_type`.
-            unsafe impl<$($tyvar $(: ?$optbound)?)? $(, const $constvar : $constty)?> KnownLayout for $ty {
+            unsafe impl<$($tyvar $(: ?$optbound)?,)? $(const $constvar : $constty)?> KnownLayout for $ty {
                 #[allow(clippy::missing_inline_in_public_items)]
                 fn only_derive_is_allowed_to_implement_this_trait() where Self: Sized {}
 
@@ -621,7 +624,7 @@ macro_rules! unsafe_impl_known_layout {
 ///
 /// Note that `align_of<T>` requires `T: Sized`, so this macro doesn't work for
 /// unsized types.
-macro_rules! assert_unaligned {
+macro_rules! assert_align_one {
     ($($tys:ty),*) => {
         $(
             // We only compile this assertion under `cfg(test)` to avoid taking
@@ -0,0 +1,291 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+//! Interpreting [`memmap2`] memory maps as zerocopy types.
+//!
+//! The functions in this module view the bytes of a [`Mmap`] or [`MmapMut`]
+//! as a `T` without copying, by applying [`FromBytes::ref_from`] (or a related
+//! method) to the entire mapped region.
+//!
+//! # Alignment
+//!
+//! Memory maps created with an offset of zero (the default) begin on a page
+//! boundary. Since pages are at least 4096 bytes on every platform supported
+//! by `memmap2`, a mapping created this way is suitably aligned for any type
+//! whose alignment is no greater than that, which includes every primitive
+//! type.
+//!
+//! However, [`MmapOptions::offset`] permits mapping a file starting at an
+//! arbitrary byte offset. In that case, the mapping begins at the page
+//! boundary preceding `offset`, and the returned `Mmap` points `offset % page
+//! size` bytes into the first page. Its address is then only as aligned as
+//! `offset` is. If the resulting address does not satisfy `T`'s alignment
+//! requirement, the functions in this module return an [`AlignmentError`].
+//! Types which are [`Unaligned`] can be read from any mapping.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::{fs::File, io::Write};
+//!
+//! use memmap2::Mmap;
+//! use zerocopy::{byteorder::little_endian::U32, memmap};
+//! # use zerocopy_derive::*;
+//!
+//! #[derive(FromBytes, KnownLayout, Immutable)]
+//! #[repr(C)]
+//! struct Header {
+//!     magic: [u8; 4],
+//!     version: U32,
+//!     len: U32,
+//! }
+//!
+//! # let path = std::env::temp_dir().join(format!("zerocopy-memmap-doctest-{}", std::process::id()));
+//! let mut file = File::create(&path)?;
+//! file.write_all(b"ZCPY\x02\x00\x00\x00\x10\x00\x00\x00")?;
+//!
+//! let file = File::open(&path)?;
+//! // SAFETY: This file is not modified while it is mapped.
+//! let mmap = unsafe { Mmap::map(&file)? };
+//!
+//! let header: &Header = memmap::map_ref(&mmap).unwrap();
+//! assert_eq!(header.magic, *b"ZCPY");
+//! assert_eq!(header.version.get(), 2);
+//! assert_eq!(header.len.get(), 16);
+//! # drop(mmap);
+//! # std::fs::remove_file(&path)?;
+//! # Ok::<(), std::io::Error>(())
+//! ```
+//!
+//! [`MmapOptions::offset`]: memmap2::MmapOptions::offset
+//! [`AlignmentError`]: crate::error::AlignmentError
+//! [`Unaligned`]: crate::Unaligned
+
+use memmap2::{Mmap, MmapMut};
+
+use crate::{
+    error::CastError,
+    pointer::{BecauseExclusive, Ptr},
+    FromBytes, Immutable, IntoBytes, KnownLayout,
+};
+
+/// Interprets the bytes of `mmap` as a `&T` without copying.
+///
+/// This is equivalent to `T::ref_from(&mmap[..])`; see
+/// [`FromBytes::ref_from`]. If the length of `mmap` is not a valid size for
+/// `T`, or if `mmap` is not suitably aligned for `T` (see the [module
+/// documentation](self#alignment)), this returns `Err`.
+///
+/// `T` may be a slice or slice DST, in which case its trailing slice has as
+/// many elements as fit in `mmap`. Use [`map_ref_with_trailing_elements`] to
+/// specify the number of elements explicitly.
+///
+/// # Examples
+///
+/// ```
+/// use memmap2::MmapMut;
+/// use zerocopy::memmap;
+///
+/// let mut mmap = MmapMut::map_anon(16)?;
+/// mmap[..4].copy_from_slice(&[1, 2, 3, 4]);
+/// let mmap = mmap.make_read_only()?;
+///
+/// let words: &[u32] = memmap::map_ref(&mmap).unwrap();
+/// assert_eq!(words, [u32::from_ne_bytes([1, 2, 3, 4]), 0, 0, 0]);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[must_use = "has no side effects"]
+#[inline]
+pub fn map_ref<T>(mmap: &Mmap) -> Result<&T, CastError<&[u8], T>>
+where
+    T: FromBytes + KnownLayout + Immutable + ?Sized,
+{
+    T::ref_from(mmap)
+}
+
+/// Interprets the bytes of `mmap` as a `&T` with `count` trailing elements
+/// without copying.
+///
+/// This is equivalent to `T::ref_from_with_trailing_elements(&mmap[..],
+/// count)`; see [`FromBytes::ref_from_with_trailing_elements`]. If the length
+/// of `mmap` is not exactly the size of a `T` with `count` trailing elements,
+/// or if `mmap` is not suitably aligned for `T` (see the [module
+/// documentation](self#alignment)), this returns `Err`.
+#[must_use = "has no side effects"]
+#[inline]
+pub fn map_ref_with_trailing_elements<T>(
+    mmap: &Mmap,
+    count: usize,
+) -> Result<&T, CastError<&[u8], T>>
+where
+    T: FromBytes + KnownLayout<PointerMetadata = usize> + Immutable + ?Sized,
+{
+    T::ref_from_with_trailing_elements(mmap, count)
+}
+
+/// Interprets the bytes of `mmap` as a `&mut T` without copying.
+///
+/// This is equivalent to `T::mut_from(&mut mmap[..])`; see
+/// [`FromBytes::mut_from`]. If the length of `mmap` is not a valid size for
+/// `T`, or if `mmap` is not suitably aligned for `T` (see the [module
+/// documentation](self#alignment)), this returns `Err`.
+///
+/// # Examples
+///
+/// ```
+/// use memmap2::MmapMut;
+/// use zerocopy::memmap;
+///
+/// let mut mmap = MmapMut::map_anon(8)?;
+/// let word: &mut u64 = memmap::map_mut(&mut mmap).unwrap();
+/// *word = u64::MAX;
+/// assert_eq!(mmap[..], [0xFF; 8]);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[must_use = "has no side effects"]
+#[inline]
+pub fn map_mut<T>(mmap: &mut MmapMut) -> Result<&mut T, CastError<&mut [u8], T>>
+where
+    T: FromBytes + IntoBytes + KnownLayout + ?Sized,
+{
+    T::mut_from(mmap)
+}
+
+/// Interprets the bytes of `mmap` as a `&mut T` with `count` trailing elements
+/// without copying.
+///
+/// This is equivalent to `T::mut_from_with_trailing_elements(&mut mmap[..],
+/// count)`, except that `T` need not be [`Immutable`]; see
+/// [`FromBytes::mut_from_with_trailing_elements`]. If the length of `mmap` is
+/// not exactly the size of a `T` with `count` trailing elements, or if `mmap`
+/// is not suitably aligned for `T` (see the [module
+/// documentation](self#alignment)), this returns `Err`.
+#[must_use = "has no side effects"]
+#[inline]
+pub fn map_mut_with_trailing_elements<T>(
+    mmap: &mut MmapMut,
+    count: usize,
+) -> Result<&mut T, CastError<&mut [u8], T>>
+where
+    T: FromBytes + IntoBytes + KnownLayout<PointerMetadata = usize> + ?Sized,
+{
+    // `FromBytes::mut_from_with_trailing_elements` requires `T: Immutable`, but
+    // since `mmap` is borrowed exclusively, `T` may contain `UnsafeCell`s.
+    let bytes = Ptr::from_mut(&mut mmap[..]);
+    match bytes.try_cast_into_no_leftover::<T, BecauseExclusive>(Some(count)) {
+        Ok(slf) => Ok(slf.bikeshed_recall_valid().as_mut()),
+        Err(err) => Err(err.map_src(|src| src.as_mut())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::UnsafeCell, fs, io::Write as _, path::PathBuf};
+
+    use memmap2::MmapOptions;
+
+    use super::*;
+    use crate::util::{testutil::AU64, AsAddress};
+
+    // Creates a uniquely-named file in the temporary directory containing
+    // `bytes`, and returns its path.
+    fn temp_file(name: &str, bytes: &[u8]) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("zerocopy-memmap-{}-{}", name, std::process::id()));
+        fs::File::create(&path).unwrap().write_all(bytes).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_map_ref() {
+        let bytes: Vec<u8> = (0..16).collect();
+        let path = temp_file("map-ref", &bytes);
+        let file = fs::File::open(&path).unwrap();
+        // SAFETY: The file is not modified while it is mapped.
+        let mmap = unsafe { Mmap::map(&file) }.unwrap();
+
+        let arr: &[u8; 16] = map_ref(&mmap).unwrap();
+        assert_eq!(arr[..], bytes[..]);
+        let slc: &[[u8; 4]] = map_ref(&mmap).unwrap();
+        assert_eq!(slc, [[0, 1, 2, 3], [4, 5, 6, 7], [8, 9, 10, 11], [12, 13, 14, 15]]);
+        let slc: &[[u8; 4]] = map_ref_with_trailing_elements(&mmap, 4).unwrap();
+        assert_eq!(slc.len(), 4);
+
+        // Size mismatches are reported.
+        assert!(matches!(map_ref::<[u8; 15]>(&mmap), Err(CastError::Size(_))));
+        assert!(matches!(map_ref::<[[u8; 3]]>(&mmap), Err(CastError::Size(_))));
+        assert!(matches!(
+            map_ref_with_trailing_elements::<[u8]>(&mmap, 15),
+            Err(CastError::Size(_))
+        ));
+
+        drop(mmap);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_map_mut() {
+        let mut mmap = MmapMut::map_anon(16).unwrap();
+        let words: &mut [AU64] = map_mut(&mut mmap).unwrap();
+        assert_eq!(words.len(), 2);
+        words[1] = AU64(u64::MAX);
+        assert_eq!(mmap[..], [[0; 8], [0xFF; 8]].concat()[..]);
+
+        let words: &mut [AU64] = map_mut_with_trailing_elements(&mut mmap, 2).unwrap();
+        words[0] = AU64(u64::MAX);
+        assert_eq!(mmap[..], [0xFF; 16]);
+
+        // Mutable mappings don't require `Immutable`.
+        let cells: &mut [UnsafeCell<u8>] = map_mut(&mut mmap).unwrap();
+        *cells[0].get_mut() = 0;
+        let cells: &mut [UnsafeCell<u8>] = map_mut_with_trailing_elements(&mut mmap, 16).unwrap();
+        *cells[15].get_mut() = 0;
+        assert_eq!(mmap[..], [&[0][..], &[0xFF; 14], &[0]].concat()[..]);
+
+        assert!(matches!(map_mut::<[u8; 17]>(&mut mmap), Err(CastError::Size(_))));
+        assert!(matches!(
+            map_mut_with_trailing_elements::<[AU64]>(&mut mmap, 1),
+            Err(CastError::Size(_))
+        ));
+    }
+
+    #[test]
+    fn test_alignment() {
+        let path = temp_file("alignment", &[0u8; 24]);
+        let file = fs::File::open(&path).unwrap();
+
+        // A mapping with offset zero is page-aligned, and so is aligned for
+        // `AU64`.
+        //
+        // SAFETY: The file is not modified while it is mapped.
+        let mmap = unsafe { Mmap::map(&file) }.unwrap();
+        assert_eq!(AsAddress::addr(mmap.as_ptr()) % 4096, 0);
+        map_ref::<[AU64; 3]>(&mmap).unwrap();
+        drop(mmap);
+
+        // A mapping whose offset is a multiple of `AU64`'s alignment is still
+        // aligned for `AU64`.
+        //
+        // SAFETY: The file is not modified while it is mapped.
+        let mmap = unsafe { MmapOptions::new().offset(8).map(&file) }.unwrap();
+        map_ref::<[AU64; 2]>(&mmap).unwrap();
+        drop(mmap);
+
+        // A mapping with any other offset is not aligned for `AU64`, but is
+        // still aligned for unaligned types.
+        //
+        // SAFETY: The file is not modified while it is mapped.
+        let mmap = unsafe { MmapOptions::new().offset(4).len(16).map(&file) }.unwrap();
+        assert!(matches!(map_ref::<[AU64; 2]>(&mmap), Err(CastError::Alignment(_))));
+        assert!(matches!(map_ref::<[AU64]>(&mmap), Err(CastError::Alignment(_))));
+        map_ref::<[u8; 16]>(&mmap).unwrap();
+        drop(mmap);
+
+        fs::remove_file(&path).unwrap();
+    }
+}
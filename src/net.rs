@@ -0,0 +1,191 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+//! IP addresses which can be parsed from and serialized to bytes.
+//!
+//! The standard library's [`core::net::Ipv4Addr`] and [`core::net::Ipv6Addr`]
+//! are stored as `[u8; 4]` and `[u8; 16]` respectively, but their layout is not
+//! guaranteed, and so zerocopy cannot soundly implement [`FromBytes`] or
+//! [`IntoBytes`] for them. Instead, this module provides [`Ipv4Addr`] and
+//! [`Ipv6Addr`], which have a guaranteed layout of their octets in network
+//! byte order, and which convert to and from their standard library
+//! counterparts using [`octets`] and [`From<[u8; N]>`][from].
+//!
+//! # Example
+//!
+//! ```rust
+//! use core::net;
+//! use zerocopy::{net::Ipv4Addr, FromBytes, IntoBytes, Immutable, KnownLayout, Unaligned};
+//! # use zerocopy_derive::*;
+//!
+//! #[derive(FromBytes, IntoBytes, KnownLayout, Immutable, Unaligned)]
+//! #[repr(C)]
+//! struct Ipv4Header {
+//!     // ...
+//! #   _fields: [u8; 12],
+//!     src: Ipv4Addr,
+//!     dst: Ipv4Addr,
+//! }
+//!
+//! let bytes = [
+//! #   0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+//!     // ...
+//!     192, 168, 0, 1,
+//!     10, 0, 0, 1,
+//! ];
+//! let header = Ipv4Header::ref_from(&bytes[..]).unwrap();
+//! assert_eq!(header.src.get(), net::Ipv4Addr::new(192, 168, 0, 1));
+//! assert_eq!(header.dst.get(), net::Ipv4Addr::new(10, 0, 0, 1));
+//! ```
+//!
+//! [`octets`]: core::net::Ipv4Addr::octets
+//! [from]: core::net::Ipv4Addr#impl-From<[u8;+4]>-for-Ipv4Addr
+
+use core::{
+    fmt::{self, Debug, Display, Formatter},
+    net,
+};
+
+use super::*;
+
+macro_rules! define_addr_type {
+    ($name:ident, $bytes:expr, $version:expr) => {
+        #[doc = concat!("An ", $version, " address stored as its octets in network byte order.
+
+`", stringify!($name), "` has the same representation as `[u8; ", stringify!($bytes), "]`,
+and implements [`FromBytes`], [`IntoBytes`], and [`Unaligned`], making it
+suitable for use as a field in a packet header. It can be constructed from a
+[`core::net::", stringify!($name), "`] using the [`new`] method, and converted
+back using the [`get`] method.
+
+[`new`]: crate::net::", stringify!($name), "::new
+[`get`]: crate::net::", stringify!($name), "::get
+[`FromBytes`]: crate::FromBytes
+[`IntoBytes`]: crate::IntoBytes
+[`Unaligned`]: crate::Unaligned")]
+        #[derive(Copy, Clone, Eq, PartialEq, Hash)]
+        #[cfg_attr(any(feature = "derive", test), derive(KnownLayout, Immutable, FromBytes, IntoBytes, Unaligned))]
+        #[repr(transparent)]
+        pub struct $name([u8; $bytes]);
+
+        #[cfg(not(any(feature = "derive", test)))]
+        impl_known_layout!($name);
+
+        safety_comment! {
+            /// SAFETY:
+            /// `$name` is `repr(transparent)`, and so it has the same layout
+            /// as its only field, which is a `u8` array. `u8` arrays are
+            /// `Immutable`, `TryFromBytes`, `FromZeros`, `FromBytes`,
+            /// `IntoBytes`, and `Unaligned`.
+            impl_or_verify!(=> Immutable for $name);
+            impl_or_verify!(=> TryFromBytes for $name);
+            impl_or_verify!(=> FromZeros for $name);
+            impl_or_verify!(=> FromBytes for $name);
+            impl_or_verify!(=> IntoBytes for $name);
+            impl_or_verify!(=> Unaligned for $name);
+        }
+
+        impl $name {
+            #[doc = concat!("Constructs a new `", stringify!($name), "` from a [`core::net::", stringify!($name), "`].")]
+            #[must_use = "has no side effects"]
+            #[inline(always)]
+            pub const fn new(addr: net::$name) -> $name {
+                $name(addr.octets())
+            }
+
+            #[doc = concat!("Returns the address as a [`core::net::", stringify!($name), "`].")]
+            #[must_use = "has no side effects"]
+            #[inline(always)]
+            pub fn get(self) -> net::$name {
+                net::$name::from(self.0)
+            }
+
+            /// Returns the octets of the address in network byte order.
+            #[must_use = "has no side effects"]
+            #[inline(always)]
+            pub const fn octets(self) -> [u8; $bytes] {
+                self.0
+            }
+        }
+
+        impl From<net::$name> for $name {
+            #[inline(always)]
+            fn from(addr: net::$name) -> $name {
+                $name::new(addr)
+            }
+        }
+
+        impl From<$name> for net::$name {
+            #[inline(always)]
+            fn from(addr: $name) -> net::$name {
+                addr.get()
+            }
+        }
+
+        impl From<[u8; $bytes]> for $name {
+            #[inline(always)]
+            fn from(octets: [u8; $bytes]) -> $name {
+                $name(octets)
+            }
+        }
+
+        impl Debug for $name {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                Debug::fmt(&self.get(), f)
+            }
+        }
+
+        impl Display for $name {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                Display::fmt(&self.get(), f)
+            }
+        }
+    };
+}
+
+define_addr_type!(Ipv4Addr, 4, "IPv4");
+define_addr_type!(Ipv6Addr, 16, "IPv6");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ipv4_addr() {
+        let bytes = [192u8, 0, 2, 1];
+        let addr = Ipv4Addr::read_from(&bytes[..]).unwrap();
+        assert_eq!(addr.get(), net::Ipv4Addr::new(192, 0, 2, 1));
+        assert_eq!(addr.as_bytes(), bytes);
+
+        let addr = Ipv4Addr::new(net::Ipv4Addr::LOCALHOST);
+        assert_eq!(addr.as_bytes(), [127, 0, 0, 1]);
+        assert_eq!(net::Ipv4Addr::from(addr), net::Ipv4Addr::LOCALHOST);
+        assert_eq!(format!("{}", addr), "127.0.0.1");
+        assert_eq!(format!("{:?}", addr), "127.0.0.1");
+    }
+
+    #[test]
+    fn test_ipv6_addr() {
+        let want = net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+        let bytes = want.octets();
+        let addr = Ipv6Addr::read_from(&bytes[..]).unwrap();
+        assert_eq!(addr.get(), want);
+        assert_eq!(addr.as_bytes(), bytes);
+        assert_eq!(addr.as_bytes()[..4], [0x20, 0x01, 0x0d, 0xb8]);
+
+        let addr = Ipv6Addr::from(net::Ipv6Addr::LOCALHOST);
+        assert_eq!(addr.octets(), net::Ipv6Addr::LOCALHOST.octets());
+        assert_eq!(format!("{}", addr), "::1");
+
+        // References can be taken into a byte slice without copying.
+        let addr = Ipv6Addr::ref_from(&bytes[..]).unwrap();
+        assert_eq!(addr.as_bytes().as_ptr(), bytes.as_ptr());
+    }
+}
@@ -1249,6 +1249,11 @@ mod _casts {
             U: 'a + ?Sized + KnownLayout + AliasingSafe<[u8], I::Aliasing, R>,
             R: AliasingSafeReason,
         {
+            // Retain the original pointer so that, if the cast leaves a
+            // remainder, the error can refer to all of the original bytes
+            // rather than only to the prefix.
+            let original = self.as_non_null();
+
             // TODO(#67): Remove this allow. See NonNulSlicelExt for more
             // details.
             #[allow(unstable_name_collisions)]
@@ -1258,16 +1263,15 @@ mod _casts {
                         Ok(slf)
                     } else {
                         // Undo the cast so we can return the original bytes.
-                        let slf = slf.as_bytes();
-                        // Restore the initial invariants of `self`.
                         //
-                        // SAFETY: The referent type of `slf` is now equal to
-                        // that of `self`, but the invariants nominally differ.
-                        // Since `slf` and `self` refer to the same memory and
-                        // no actions have been taken that would violate the
-                        // original invariants on `self`, it is sound to apply
-                        // the invariants of `self` onto `slf`.
-                        let slf = unsafe { slf.assume_invariants() };
+                        // SAFETY: `original` was obtained from `self`, and so
+                        // it satisfies all of `self`'s invariants. `self` was
+                        // consumed by `try_cast_into`, and `slf` and
+                        // `remainder` - the only `Ptr`s derived from it - are
+                        // not used after this point. Thus, reconstituting a
+                        // `Ptr` with `self`'s invariants does not violate its
+                        // aliasing invariant.
+                        let slf = unsafe { Ptr::new(original) };
                         Err(CastError::Size(SizeError::<_, U>::new(slf)))
                     }
                 }
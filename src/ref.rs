@@ -7,14 +7,19 @@
 // This file may not be copied, modified, or distributed except according to
 // those terms.
 
+use core::ops::Range;
+
 use super::*;
 
 mod def {
     use core::marker::PhantomData;
 
+    #[cfg(feature = "alloc")]
+    use alloc::boxed::Box;
+
     use crate::{
         ByteSlice, ByteSliceMut, CloneableByteSlice, CopyableByteSlice, IntoByteSlice,
-        IntoByteSliceMut,
+        IntoByteSliceMut, SplitByteSlice,
     };
 
     /// A typed reference derived from a byte slice.
@@ -65,6 +70,30 @@ mod def {
     /// }
     /// # }
     /// ```
+    ///
+    /// # Passing `Ref`s to generic code
+    ///
+    /// `Ref<B, T>` does not implement [`IntoBytes`](crate::IntoBytes), even
+    /// when `T` does: `IntoBytes` describes the bytes of a value's own
+    /// representation, and the representation of a `Ref` is its byte slice
+    /// `B` (e.g., a pointer and a length), not the bytes it refers to. Instead,
+    /// generic code bounded by `IntoBytes` can accept a `Ref`'s referent by
+    /// taking `&T` and having callers dereference the `Ref`. Code which only
+    /// needs the bytes can use [`Ref::bytes`], which does not require `T:
+    /// IntoBytes`.
+    ///
+    /// ```rust
+    /// use zerocopy::{Immutable, IntoBytes, Ref};
+    ///
+    /// fn checksum<T: IntoBytes + Immutable + ?Sized>(t: &T) -> u8 {
+    ///     t.as_bytes().iter().fold(0, |sum, b| sum.wrapping_add(*b))
+    /// }
+    ///
+    /// let bytes = &[1, 2, 3, 4, 5][..];
+    /// let (header, _body) = Ref::<_, [u8; 2]>::from_prefix(bytes).unwrap();
+    /// assert_eq!(checksum(&*header), 3);
+    /// assert_eq!(header.bytes(), [1, 2]);
+    /// ```
     pub struct Ref<B, T: ?Sized>(
         // INVARIANTS: The referent (via `.deref`, `.deref_mut`, `.into`) byte
         // slice is aligned to `T`'s alignment and its size corresponds to a
@@ -184,6 +213,55 @@ mod def {
         }
     }
 
+    impl<B: ByteSlice, T: ?Sized> Ref<B, T> {
+        /// Converts `self` into a `Ref` of another type.
+        ///
+        /// # Safety
+        ///
+        /// The caller promises that `self`'s referent byte slice is aligned to
+        /// `U`'s alignment and that its size is a valid size for `U`.
+        pub(crate) unsafe fn cast_unchecked<U: ?Sized>(self) -> Ref<B, U> {
+            // INVARIANTS: Since `B: ByteSlice`, dereference stability
+            // guarantees that `self.0`'s referent is unchanged, and the caller
+            // promises that it is validly-aligned for `U` and has a valid size
+            // for `U`.
+            Ref(self.0, PhantomData)
+        }
+    }
+
+    impl<B: SplitByteSlice, T: ?Sized> Ref<B, T> {
+        /// Access the byte slice as a [`SplitByteSlice`].
+        ///
+        /// # Safety
+        ///
+        /// The caller promises not to call methods on the returned byte slice
+        /// other than `SplitByteSlice` methods (for example, via
+        /// `Any::downcast_ref`).
+        ///
+        /// `into_split_byte_slice` promises to return a byte slice whose
+        /// referent is validly-aligned for `T` and has a valid size for `T`.
+        pub(crate) unsafe fn into_split_byte_slice(self) -> B {
+            // INVARIANTS: The caller promises not to call methods other than
+            // those on `SplitByteSlice`. Since `B: ByteSlice`, dereference
+            // stability guarantees that calling `ByteSlice` methods will not
+            // change the address or length of `self.0`'s referent.
+            //
+            // SAFETY: By invariant on `self.0`, the alignment and size
+            // post-conditions are upheld.
+            self.0
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<T: ?Sized> Ref<Box<[u8]>, T> {
+        /// Consumes the `Ref`, returning the underlying boxed byte slice.
+        pub(crate) fn into_box(self) -> Box<[u8]> {
+            // Since `self` is consumed, no `Ref` remains whose invariants
+            // could be violated by modifying the returned `Box`.
+            self.0
+        }
+    }
+
     impl<B: CloneableByteSlice + Clone, T: ?Sized> Clone for Ref<B, T> {
         #[inline]
         fn clone(&self) -> Ref<B, T> {
@@ -234,9 +312,9 @@ where
             return Err(AlignmentError::new(bytes).into());
         }
         let (bytes, suffix) =
-            try_split_at(bytes, mem::size_of::<T>()).map_err(|b| SizeError::new(b).into())?;
+            bytes.try_split_at(mem::size_of::<T>()).map_err(|b| SizeError::new(b).into())?;
         // SAFETY: We just validated alignment and that `bytes` is at least as
-        // large as `T`. `try_split_at(bytes, mem::size_of::<T>())?` ensures
+        // large as `T`. `bytes.try_split_at(mem::size_of::<T>())?` ensures
         // that the new `bytes` is exactly the size of `T`. By safety
         // postcondition on `SplitByteSlice::try_split_at` we can rely on
         // `try_split_at` to produce the correct `bytes` and `suffix`.
@@ -252,14 +330,13 @@ where
         } else {
             return Err(SizeError::new(bytes).into());
         };
-        let (prefix, bytes) =
-            try_split_at(bytes, split_at).map_err(|b| SizeError::new(b).into())?;
+        let (prefix, bytes) = bytes.try_split_at(split_at).map_err(|b| SizeError::new(b).into())?;
         if !util::aligned_to::<_, T>(bytes.deref()) {
             return Err(AlignmentError::new(bytes).into());
         }
         // SAFETY: Since `split_at` is defined as `bytes_len - size_of::<T>()`,
         // the `bytes` which results from `let (prefix, bytes) =
-        // try_split_at(bytes, split_at)?` has length `size_of::<T>()`. After
+        // bytes.try_split_at(split_at)?` has length `size_of::<T>()`. After
         // constructing `bytes`, we validate that it has the proper alignment.
         // By safety postcondition on `SplitByteSlice::try_split_at` we can rely
         // on `try_split_at` to produce the correct `prefix` and `bytes`.
@@ -279,6 +356,12 @@ where
     /// aligned to `align_of::<T>()`, and constructs a new `Ref`. If either of
     /// these checks fail, it returns `None`.
     ///
+    /// If `T` is a slice DST, such as `[U]`, `from` instead verifies that
+    /// `bytes.len()` is a valid size for some number of trailing slice
+    /// elements, and the returned `Ref` has that many elements. To construct a
+    /// `Ref` with a particular number of elements from part of `bytes`, use
+    /// [`from_prefix_with_elems`] or [`from_suffix_with_elems`].
+    ///
     /// # Compile-Time Assertions
     ///
     /// This method cannot yet be used on unsized types whose dynamically-sized
@@ -298,6 +381,19 @@ where
     ///
     /// let _ = Ref::<_, ZSTy>::from(&b"UU"[..]); // ⚠ Compile Error!
     /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::Ref;
+    ///
+    /// let bytes = &[1u8, 2, 3, 4, 5, 6][..];
+    /// let pairs = Ref::<_, [[u8; 2]]>::from(bytes).unwrap();
+    /// assert_eq!(*pairs, [[1, 2], [3, 4], [5, 6]]);
+    /// ```
+    ///
+    /// [`from_prefix_with_elems`]: Ref::from_prefix_with_elems
+    /// [`from_suffix_with_elems`]: Ref::from_suffix_with_elems
     #[must_use = "has no side effects"]
     #[inline]
     pub fn from(bytes: B) -> Result<Ref<B, T>, CastError<B, T>> {
@@ -312,6 +408,259 @@ where
     }
 }
 
+impl<B, T> Ref<B, T>
+where
+    B: ByteSlice,
+    T: TryFromBytes + KnownLayout + Immutable + ?Sized,
+{
+    /// Attempts to construct a new `Ref` from a byte slice.
+    ///
+    /// `try_from_bytes` verifies that `bytes` has a valid size and alignment
+    /// for `T` (as in [`from`]) and, using [`TryFromBytes`]'s validity check,
+    /// that `bytes` contains a valid `T`. If any of these checks fail, it
+    /// returns `Err`.
+    ///
+    /// Note that the validity check only applies to the bytes as they are at
+    /// the time of the call. Thus, as with any `Ref`, the returned `Ref<B, T>`
+    /// can only be dereferenced when `T: FromBytes`; otherwise, use
+    /// [`try_into_ref`] to access the `T`.
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// This method cannot yet be used on unsized types whose dynamically-sized
+    /// component is zero-sized. Attempting to use this method on such types
+    /// results in a compile-time assertion error; e.g.:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerocopy::*;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(TryFromBytes, Immutable, KnownLayout)]
+    /// #[repr(C)]
+    /// struct ZSTy {
+    ///     leading_sized: u16,
+    ///     trailing_dst: [()],
+    /// }
+    ///
+    /// let _ = Ref::<_, ZSTy>::try_from_bytes(&b"UU"[..]); // ⚠ Compile Error!
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::{error::TryCastError, Ref};
+    ///
+    /// let r = Ref::<_, [bool]>::try_from_bytes(&[0, 1, 0][..]).unwrap();
+    /// assert_eq!(r.try_into_ref().ok(), Some(&[false, true, false][..]));
+    ///
+    /// // `2` is not a valid `bool`.
+    /// let r = Ref::<_, [bool]>::try_from_bytes(&[0, 2, 0][..]);
+    /// assert!(matches!(r, Err(TryCastError::Validity(_))));
+    /// ```
+    ///
+    /// [`from`]: Ref::from
+    /// [`try_into_ref`]: Ref::try_into_ref
+    #[must_use = "has no side effects"]
+    #[inline]
+    pub fn try_from_bytes(bytes: B) -> Result<Ref<B, T>, TryCastError<B, T>> {
+        util::assert_dst_is_not_zst::<T>();
+        let candidate = match Ptr::from_ref(bytes.deref())
+            .try_cast_into_no_leftover::<T, BecauseImmutable>(None)
+        {
+            Ok(candidate) => candidate,
+            Err(e) => return Err(e.with_src(()).with_src(bytes).into()),
+        };
+        // This call may panic. If that happens, it doesn't cause any soundness
+        // issues, as we have not generated any invalid state which we need to
+        // fix before returning.
+        //
+        // Note that one panic or post-monomorphization error condition is
+        // calling `try_into_valid` (and thus `is_bit_valid`) with a shared
+        // pointer when `T: !Immutable`. Since `T: Immutable`, this panic
+        // condition will not happen.
+        if candidate.try_into_valid().is_err() {
+            return Err(ValidityError::new(bytes).into());
+        }
+        // SAFETY: `try_cast_into_no_leftover` validates size and alignment.
+        Ok(unsafe { Ref::new_unchecked(bytes) })
+    }
+}
+
+impl<B, T> Ref<B, T>
+where
+    B: ByteSliceMut,
+    T: TryFromBytes + KnownLayout + ?Sized,
+{
+    /// Attempts to construct a new mutable `Ref` from a byte slice.
+    ///
+    /// `try_from_bytes_mut` behaves like [`try_from_bytes`], except that,
+    /// since `bytes` is exclusively borrowed, it does not require that `T:
+    /// Immutable`.
+    ///
+    /// Note that the validity check only applies to the bytes as they are at
+    /// the time of the call. Thus, as with any `Ref`, the returned `Ref<B, T>`
+    /// can only be dereferenced when `T: FromBytes`.
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// This method cannot yet be used on unsized types whose dynamically-sized
+    /// component is zero-sized. Attempting to use this method on such types
+    /// results in a compile-time assertion error; e.g.:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerocopy::*;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(TryFromBytes, KnownLayout)]
+    /// #[repr(C)]
+    /// struct ZSTy {
+    ///     leading_sized: u16,
+    ///     trailing_dst: [()],
+    /// }
+    ///
+    /// let mut bytes = *b"UU";
+    /// let _ = Ref::<_, ZSTy>::try_from_bytes_mut(&mut bytes[..]); // ⚠ Compile Error!
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::Ref;
+    ///
+    /// let mut bytes = [1u8, 2];
+    /// let mut r = Ref::<_, [u8; 2]>::try_from_bytes_mut(&mut bytes[..]).unwrap();
+    /// r[1] = 3;
+    /// assert_eq!(bytes, [1, 3]);
+    ///
+    /// // `2` is not a valid `bool`.
+    /// let mut bytes = [1u8, 2];
+    /// assert!(Ref::<_, [bool; 2]>::try_from_bytes_mut(&mut bytes[..]).is_err());
+    /// ```
+    ///
+    /// [`try_from_bytes`]: Ref::try_from_bytes
+    #[must_use = "has no side effects"]
+    #[inline]
+    pub fn try_from_bytes_mut(mut bytes: B) -> Result<Ref<B, T>, TryCastError<B, T>> {
+        util::assert_dst_is_not_zst::<T>();
+        let candidate = match Ptr::from_mut(bytes.deref_mut())
+            .try_cast_into_no_leftover::<T, BecauseExclusive>(None)
+        {
+            Ok(candidate) => candidate,
+            Err(e) => return Err(e.with_src(()).with_src(bytes).into()),
+        };
+        // This call may panic. If that happens, it doesn't cause any soundness
+        // issues, as we have not generated any invalid state which we need to
+        // fix before returning.
+        //
+        // Note that one panic or post-monomorphization error condition is
+        // calling `is_bit_valid` with a shared pointer when `T` contains an
+        // `UnsafeCell`. `try_into_valid` downgrades `candidate` to a shared
+        // pointer, so, as with `TryFromBytes::try_mut_from`, such a `T` will
+        // fail to monomorphize rather than causing unsoundness.
+        if candidate.try_into_valid().is_err() {
+            return Err(ValidityError::new(bytes).into());
+        }
+        // SAFETY: `try_cast_into_no_leftover` validates size and alignment.
+        Ok(unsafe { Ref::new_unchecked(bytes) })
+    }
+}
+
+impl<B, T> Ref<B, T>
+where
+    B: ByteSlice,
+    T: TryFromBytes + KnownLayout<PointerMetadata = usize> + Immutable + ?Sized,
+{
+    /// Attempts to construct a new `Ref` of a slice DST from a byte slice.
+    ///
+    /// `try_from_with_trailing_elements` verifies that `bytes` is exactly the
+    /// size of a `T` with `count` trailing slice elements, that `bytes` is
+    /// aligned to `align_of::<T>()`, and, using [`TryFromBytes`]'s validity
+    /// check, that `bytes` contains a valid `T`. If any of these checks fail,
+    /// it returns `Err`.
+    ///
+    /// As with [`try_from_bytes`], the returned `Ref<B, T>` can only be
+    /// dereferenced when `T: FromBytes`; otherwise, use [`try_into_ref`] to
+    /// access the `T`.
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// This method cannot yet be used on unsized types whose dynamically-sized
+    /// component is zero-sized. Attempting to use this method on such types
+    /// results in a compile-time assertion error; e.g.:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerocopy::*;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(TryFromBytes, Immutable, KnownLayout)]
+    /// #[repr(C)]
+    /// struct ZSTy {
+    ///     leading_sized: u16,
+    ///     trailing_dst: [()],
+    /// }
+    ///
+    /// let bytes = &b"UU"[..];
+    /// let _ = Ref::<_, ZSTy>::try_from_with_trailing_elements(bytes, 42); // ⚠ Compile Error!
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::{error::TryCastError, Ref};
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(TryFromBytes, KnownLayout, Immutable)]
+    /// #[repr(C)]
+    /// struct Flags {
+    ///     len: u8,
+    ///     flags: [bool],
+    /// }
+    ///
+    /// let r = Ref::<_, Flags>::try_from_with_trailing_elements(&[2, 1, 0][..], 2).unwrap();
+    /// let flags = r.try_into_ref().unwrap();
+    /// assert_eq!(flags.len, 2);
+    /// assert_eq!(flags.flags, [true, false]);
+    ///
+    /// // `2` is not a valid `bool`.
+    /// let r = Ref::<_, Flags>::try_from_with_trailing_elements(&[2, 1, 2][..], 2);
+    /// assert!(matches!(r, Err(TryCastError::Validity(_))));
+    ///
+    /// // `bytes` must be exactly the size of a `Flags` with `count` flags.
+    /// let r = Ref::<_, Flags>::try_from_with_trailing_elements(&[2, 1, 0][..], 1);
+    /// assert!(matches!(r, Err(TryCastError::Size(_))));
+    /// ```
+    ///
+    /// [`try_from_bytes`]: Ref::try_from_bytes
+    /// [`try_into_ref`]: Ref::try_into_ref
+    #[must_use = "has no side effects"]
+    #[inline]
+    pub fn try_from_with_trailing_elements(
+        bytes: B,
+        count: usize,
+    ) -> Result<Ref<B, T>, TryCastError<B, T>> {
+        util::assert_dst_is_not_zst::<T>();
+        let candidate = match Ptr::from_ref(bytes.deref())
+            .try_cast_into_no_leftover::<T, BecauseImmutable>(Some(count))
+        {
+            Ok(candidate) => candidate,
+            Err(e) => return Err(e.with_src(()).with_src(bytes).into()),
+        };
+        // This call may panic. If that happens, it doesn't cause any soundness
+        // issues, as we have not generated any invalid state which we need to
+        // fix before returning.
+        //
+        // Note that one panic or post-monomorphization error condition is
+        // calling `try_into_valid` (and thus `is_bit_valid`) with a shared
+        // pointer when `T: !Immutable`. Since `T: Immutable`, this panic
+        // condition will not happen.
+        if candidate.try_into_valid().is_err() {
+            return Err(ValidityError::new(bytes).into());
+        }
+        // SAFETY: `try_cast_into_no_leftover` validates size and alignment.
+        Ok(unsafe { Ref::new_unchecked(bytes) })
+    }
+}
+
 impl<B, T> Ref<B, T>
 where
     B: SplitByteSlice,
@@ -364,8 +713,7 @@ where
         // underflow.
         #[allow(unstable_name_collisions, clippy::incompatible_msrv)]
         let split_at = unsafe { bytes.len().unchecked_sub(remainder.len()) };
-        let (bytes, suffix) =
-            try_split_at(bytes, split_at).map_err(|b| SizeError::new(b).into())?;
+        let (bytes, suffix) = bytes.try_split_at(split_at).map_err(|b| SizeError::new(b).into())?;
         // SAFETY: `try_cast_into` validates size and alignment, and returns a
         // `split_at` that indicates how many bytes of `bytes` correspond to a
         // valid `T`. By safety postcondition on `SplitByteSlice::try_split_at`
@@ -417,8 +765,7 @@ where
         };
 
         let split_at = remainder.len();
-        let (prefix, bytes) =
-            try_split_at(bytes, split_at).map_err(|b| SizeError::new(b).into())?;
+        let (prefix, bytes) = bytes.try_split_at(split_at).map_err(|b| SizeError::new(b).into())?;
         // SAFETY: `try_cast_into` validates size and alignment, and returns a
         // `try_split_at` that indicates how many bytes of `bytes` correspond to
         // a valid `T`. By safety postcondition on
@@ -427,6 +774,80 @@ where
         let r = unsafe { Ref::new_unchecked(bytes) };
         Ok((prefix, r))
     }
+
+    /// Constructs a new `Ref` from the first aligned prefix of a byte slice.
+    ///
+    /// `from_prefix_aligned` skips the minimum number of leading bytes needed
+    /// to reach a `T`-aligned address, then constructs a `Ref` from the prefix
+    /// of the remaining bytes as [`from_prefix`] does. It returns the skipped
+    /// bytes, the `Ref`, and the bytes following the `Ref`, in that order.
+    /// This saves callers from manually computing the alignment offset of
+    /// buffers which may be misaligned. If, after skipping to an aligned
+    /// address, too few bytes remain to construct a `T`, it returns `Err`.
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// This method cannot yet be used on unsized types whose dynamically-sized
+    /// component is zero-sized. Attempting to use this method on such types
+    /// results in a compile-time assertion error; e.g.:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerocopy::*;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(Immutable, KnownLayout)]
+    /// #[repr(C)]
+    /// struct ZSTy {
+    ///     leading_sized: u16,
+    ///     trailing_dst: [()],
+    /// }
+    ///
+    /// let _ = Ref::<_, ZSTy>::from_prefix_aligned(&b"UU"[..]); // ⚠ Compile Error!
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::Ref;
+    ///
+    /// let buf = [0u8; 16];
+    /// // A sub-slice of `buf` which may or may not be aligned to `u32`.
+    /// let bytes = &buf[1..];
+    ///
+    /// let (skipped, r, suffix) = Ref::<_, u32>::from_prefix_aligned(bytes).unwrap();
+    /// assert!(skipped.len() < 4);
+    /// assert_eq!(skipped.len() + 4 + suffix.len(), bytes.len());
+    /// assert_eq!(*r, 0);
+    /// ```
+    ///
+    /// [`from_prefix`]: Ref::from_prefix
+    #[must_use = "has no side effects"]
+    #[inline]
+    pub fn from_prefix_aligned(bytes: B) -> Result<(B, Ref<B, T>, B), SizeError<B, T>> {
+        util::assert_dst_is_not_zst::<T>();
+        let offset = bytes.as_ptr().align_offset(T::LAYOUT.align.get());
+        // `align_offset` returns `usize::MAX` if the pointer cannot be aligned,
+        // in which case `get` returns `None`.
+        let fits = bytes.get(offset..).map_or(false, |aligned| {
+            Ptr::from_ref(aligned)
+                .try_cast_into::<T, BecauseImmutable>(CastType::Prefix, None)
+                .is_ok()
+        });
+        if !fits {
+            return Err(SizeError::new(bytes));
+        }
+
+        let (skipped, aligned) = bytes.try_split_at(offset).map_err(SizeError::new)?;
+        match Ref::from_prefix(aligned) {
+            Ok((r, suffix)) => Ok((skipped, r, suffix)),
+            // We validated above that `aligned` is aligned and long enough for
+            // `T`, so this should never happen. We return a `SizeError` rather
+            // than panicking because, in the event the branch is not optimized
+            // away, returning a value is generally lighter-weight than
+            // panicking.
+            Err(e) => Err(SizeError::new(e.into_src())),
+        }
+    }
 }
 
 impl<B, T> Ref<B, T>
@@ -434,9 +855,55 @@ where
     B: SplitByteSlice,
     T: KnownLayout<PointerMetadata = usize> + Immutable + ?Sized,
 {
-    // TODO(#29), TODO(#871): Pick a name and make this public. Make sure to
-    // update references to this name in `#[deprecated]` attributes elsewhere.
-    #[doc(hidden)]
+    /// Constructs a new `Ref` of a slice DST from the prefix of a byte slice.
+    ///
+    /// `from_prefix_with_elems` verifies that `bytes` is long enough to hold a
+    /// `T` with `count` trailing slice elements, and that `bytes` is aligned to
+    /// `align_of::<T>()`. It consumes the bytes of that `T` from the start of
+    /// `bytes` to construct a `Ref`, and returns the remaining bytes to the
+    /// caller. If either the length or alignment checks fail, or if the size
+    /// of a `T` with `count` elements overflows `usize`, it returns `Err`.
+    ///
+    /// Since `bytes` may be any [`SplitByteSlice`], this can be used to parse
+    /// slices out of owned or guarded buffers, such as [`cell::Ref<[u8]>`],
+    /// without first borrowing them as `&[u8]`.
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// This method cannot yet be used on unsized types whose dynamically-sized
+    /// component is zero-sized. Attempting to use this method on such types
+    /// results in a compile-time assertion error; e.g.:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerocopy::*;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(Immutable, KnownLayout)]
+    /// #[repr(C)]
+    /// struct ZSTy {
+    ///     leading_sized: u16,
+    ///     trailing_dst: [()],
+    /// }
+    ///
+    /// let _ = Ref::<_, ZSTy>::from_prefix_with_elems(&b"UU"[..], 42); // ⚠ Compile Error!
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cell::RefCell;
+    /// use zerocopy::Ref;
+    ///
+    /// let cell = RefCell::new([1u8, 2, 3, 4, 5, 6, 7]);
+    /// let bytes = core::cell::Ref::map(cell.borrow(), |b| &b[..]);
+    ///
+    /// let (pairs, rest) = Ref::<_, [[u8; 2]]>::from_prefix_with_elems(bytes, 3).unwrap();
+    /// assert_eq!(*pairs, [[1, 2], [3, 4], [5, 6]]);
+    /// assert_eq!(*rest, [7]);
+    /// ```
+    ///
+    /// [`cell::Ref<[u8]>`]: core::cell::Ref
+    #[must_use = "has no side effects"]
     #[inline]
     pub fn from_prefix_with_elems(
         bytes: B,
@@ -460,10 +927,57 @@ where
     B: SplitByteSlice,
     T: KnownLayout<PointerMetadata = usize> + Immutable + ?Sized,
 {
-    // TODO(#29), TODO(#871): Pick a name and make this public. Make sure to
-    // update references to this name in `#[deprecated]` attributes elsewhere.
-    #[doc(hidden)]
-    #[inline]
+    /// Constructs a new `Ref` of a slice DST from the suffix of a byte slice.
+    ///
+    /// `from_suffix_with_elems` verifies that `bytes` is long enough to hold a
+    /// `T` with `count` trailing slice elements, and that the last bytes of
+    /// `bytes` which make up that `T` are aligned to `align_of::<T>()`. It
+    /// consumes those bytes from the end of `bytes` to construct a `Ref`, and
+    /// returns the preceding bytes to the caller. If either the length or
+    /// alignment checks fail, or if the size of a `T` with `count` elements
+    /// overflows `usize`, it returns `Err`.
+    ///
+    /// Since `bytes` may be any [`SplitByteSlice`], this can be used to parse
+    /// slices out of owned or guarded buffers, such as [`cell::Ref<[u8]>`],
+    /// without first borrowing them as `&[u8]`.
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// This method cannot yet be used on unsized types whose dynamically-sized
+    /// component is zero-sized. Attempting to use this method on such types
+    /// results in a compile-time assertion error; e.g.:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerocopy::*;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(Immutable, KnownLayout)]
+    /// #[repr(C)]
+    /// struct ZSTy {
+    ///     leading_sized: u16,
+    ///     trailing_dst: [()],
+    /// }
+    ///
+    /// let _ = Ref::<_, ZSTy>::from_suffix_with_elems(&b"UU"[..], 42); // ⚠ Compile Error!
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cell::RefCell;
+    /// use zerocopy::Ref;
+    ///
+    /// let cell = RefCell::new([1u8, 2, 3, 4, 5, 6, 7]);
+    /// let bytes = core::cell::Ref::map(cell.borrow(), |b| &b[..]);
+    ///
+    /// let (rest, pairs) = Ref::<_, [[u8; 2]]>::from_suffix_with_elems(bytes, 3).unwrap();
+    /// assert_eq!(*rest, [1]);
+    /// assert_eq!(*pairs, [[2, 3], [4, 5], [6, 7]]);
+    /// ```
+    ///
+    /// [`cell::Ref<[u8]>`]: core::cell::Ref
+    #[must_use = "has no side effects"]
+    #[inline]
     pub fn from_suffix_with_elems(
         bytes: B,
         count: usize,
@@ -682,6 +1196,173 @@ where
     }
 }
 
+impl<'a, B, T> Ref<B, T>
+where
+    B: 'a + IntoByteSlice<'a>,
+    T: TryFromBytes + KnownLayout + Immutable + ?Sized,
+{
+    /// Attempts to convert this `Ref` into a reference.
+    ///
+    /// Unlike [`into_ref`], `try_into_ref` does not require that `T:
+    /// FromBytes`. Instead, it checks that the referenced bytes are a valid
+    /// `T` (using [`TryFromBytes`]'s validity check) and, if they are, consumes
+    /// the `Ref` and returns a reference to `T`. If they are not, it returns
+    /// `self` in the `Err` variant.
+    ///
+    /// Since a `Ref` only guarantees that its bytes have a valid size and
+    /// alignment for `T`, and not that they are a valid `T`, a `Ref<B, T>`
+    /// can only be dereferenced when `T: FromBytes`. `try_into_ref` allows
+    /// types such as `bool` or field-less enums, which are `TryFromBytes` but
+    /// not `FromBytes`, to be accessed after being parsed into a `Ref`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::Ref;
+    ///
+    /// let r = Ref::<_, [bool]>::from(&[0, 1, 0][..]).unwrap();
+    /// assert_eq!(r.try_into_ref().ok(), Some(&[false, true, false][..]));
+    ///
+    /// let r = Ref::<_, [bool]>::from(&[0, 2, 0][..]).unwrap();
+    /// assert!(r.try_into_ref().is_err());
+    /// ```
+    ///
+    /// [`into_ref`]: Ref::into_ref
+    #[must_use = "has no side effects"]
+    #[inline]
+    pub fn try_into_ref(self) -> Result<&'a T, ValidityError<Self, T>> {
+        // Presumably unreachable, since we've guarded each constructor of `Ref`.
+        util::assert_dst_is_not_zst::<T>();
+
+        // PANICS: By invariant on `Ref`, `self.bytes()`'s size and alignment
+        // are valid for `T`.
+        let candidate = Ptr::from_ref(self.bytes())
+            .try_cast_into_no_leftover::<T, BecauseImmutable>(None)
+            .expect("zerocopy internal error: try_into_ref should be infallible");
+        // This call may panic. If that happens, it doesn't cause any soundness
+        // issues, as we have not generated any invalid state which we need to
+        // fix before returning.
+        //
+        // Note that one panic or post-monomorphization error condition is
+        // calling `try_into_valid` (and thus `is_bit_valid`) with a shared
+        // pointer when `T: !Immutable`. Since `T: Immutable`, this panic
+        // condition will not happen.
+        if candidate.try_into_valid().is_err() {
+            return Err(ValidityError::new(self));
+        }
+
+        // SAFETY: We don't call any methods on `b` other than those provided by
+        // `IntoByteSlice`.
+        let b = unsafe { self.into_byte_slice() };
+
+        // PANICS: By post-condition on `into_byte_slice`, `b`'s size and
+        // alignment are valid for `T`. By invariant on `IntoByteSlice`,
+        // `b.into()` produces a byte slice with identical address and length to
+        // that produced by `b.deref()`.
+        let ptr = Ptr::from_ref(b.into())
+            .try_cast_into_no_leftover::<T, BecauseImmutable>(None)
+            .expect("zerocopy internal error: try_into_ref should be infallible");
+        // SAFETY: Above, we validated that the bytes referenced by
+        // `self.bytes()` are a valid `T`. By invariant on `IntoByteSlice`,
+        // `b.into()` references the same bytes. Since `B: IntoByteSlice`, those
+        // bytes are immutably borrowed, and since `T: Immutable`, `T` contains
+        // no `UnsafeCell`s through which they could be mutated. Thus, they
+        // remain a valid `T`.
+        let ptr = unsafe { ptr.assume_valid() };
+        Ok(ptr.as_ref())
+    }
+}
+
+impl<B, T> Ref<B, T>
+where
+    B: ByteSlice,
+    T: ?Sized,
+{
+    /// Attempts to convert this `Ref` into a `Ref` of another type.
+    ///
+    /// `try_cast` reinterprets all of the bytes referenced by `self` as a `U`,
+    /// sharing `self`'s backing storage. It checks that those bytes have a
+    /// valid size and alignment for `U` and, using [`TryFromBytes`]'s
+    /// validity check, that they are a valid `U`. If any check fails, it
+    /// returns `Err`, from which `self` can be recovered unmodified via the
+    /// error's `into_src` method. This makes it possible to parse a buffer as
+    /// a plain byte array and later refine it into a type with validity
+    /// constraints, such as a field-less enum.
+    ///
+    /// Note that the validity check only applies to the bytes as they are at
+    /// the time of the call: since the bytes may later be modified via `B`, a
+    /// `Ref<B, U>` does not guarantee that they remain a valid `U`. Thus, as
+    /// with any `Ref`, a `Ref<B, U>` can only be dereferenced when `U:
+    /// FromBytes`; otherwise, use [`try_into_ref`] to access the `U`.
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// This method cannot yet be used on unsized types whose dynamically-sized
+    /// component is zero-sized. Attempting to use this method on such types
+    /// results in a compile-time assertion error; e.g.:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerocopy::*;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(TryFromBytes, Immutable, KnownLayout)]
+    /// #[repr(C)]
+    /// struct ZSTy {
+    ///     leading_sized: u16,
+    ///     trailing_dst: [()],
+    /// }
+    ///
+    /// let r = Ref::<_, [u8; 2]>::from(&b"UU"[..]).unwrap();
+    /// let _ = r.try_cast::<ZSTy>(); // ⚠ Compile Error!
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::Ref;
+    ///
+    /// let r = Ref::<_, [u8; 2]>::from(&[1, 0][..]).unwrap();
+    /// let r = r.try_cast::<[bool; 2]>().unwrap();
+    /// assert_eq!(r.try_into_ref().ok(), Some(&[true, false]));
+    ///
+    /// // `2` is not a valid `bool`.
+    /// let r = Ref::<_, [u8; 2]>::from(&[1, 2][..]).unwrap();
+    /// let r = r.try_cast::<[bool; 2]>().err().unwrap().into_src();
+    /// assert_eq!(*r, [1, 2]);
+    /// ```
+    ///
+    /// [`try_into_ref`]: Ref::try_into_ref
+    #[must_use = "has no side effects"]
+    #[inline]
+    pub fn try_cast<U>(self) -> Result<Ref<B, U>, TryCastError<Self, U>>
+    where
+        U: TryFromBytes + KnownLayout + Immutable + ?Sized,
+    {
+        util::assert_dst_is_not_zst::<U>();
+        let candidate = match Ptr::from_ref(self.bytes())
+            .try_cast_into_no_leftover::<U, BecauseImmutable>(None)
+        {
+            Ok(candidate) => candidate,
+            Err(e) => return Err(e.with_src(()).with_src(self).into()),
+        };
+        // This call may panic. If that happens, it doesn't cause any soundness
+        // issues, as we have not generated any invalid state which we need to
+        // fix before returning.
+        //
+        // Note that one panic or post-monomorphization error condition is
+        // calling `try_into_valid` (and thus `is_bit_valid`) with a shared
+        // pointer when `U: !Immutable`. Since `U: Immutable`, this panic
+        // condition will not happen.
+        if candidate.try_into_valid().is_err() {
+            return Err(ValidityError::new(self).into());
+        }
+        // SAFETY: `try_cast_into_no_leftover` validated that `self.bytes()` is
+        // aligned to `U`'s alignment and has a valid size for `U`. By
+        // invariant on `ByteSlice`, `self`'s referent is unchanged.
+        Ok(unsafe { self.cast_unchecked() })
+    }
+}
+
 impl<'a, B, T> Ref<B, T>
 where
     B: 'a + IntoByteSliceMut<'a>,
@@ -689,54 +1370,481 @@ where
 {
     /// Converts this `Ref` into a mutable reference.
     ///
-    /// `into_mut` consumes the `Ref`, and returns a mutable reference to `T`.
+    /// `into_mut` consumes the `Ref`, and returns a mutable reference to `T`.
+    #[must_use = "has no side effects"]
+    #[inline(always)]
+    pub fn into_mut(self) -> &'a mut T {
+        // Presumably unreachable, since we've guarded each constructor of `Ref`.
+        util::assert_dst_is_not_zst::<T>();
+
+        // SAFETY: We don't call any methods on `b` other than those provided by
+        // `IntoByteSliceMut`.
+        let b = unsafe { self.into_byte_slice_mut() };
+
+        // PANICS: By post-condition on `into_byte_slice_mut`, `b`'s size and
+        // alignment are valid for `T`. By invariant on `IntoByteSliceMut`,
+        // `b.into()` produces a byte slice with identical address and length to
+        // that produced by `b.deref_mut()`.
+        let ptr = Ptr::from_mut(b.into())
+            .try_cast_into_no_leftover::<T, BecauseExclusive>(None)
+            .expect("zerocopy internal error: into_ref should be infallible");
+        let ptr = ptr.bikeshed_recall_valid();
+        ptr.as_mut()
+    }
+}
+
+impl<B, T> Ref<B, T>
+where
+    B: ByteSlice,
+    T: ?Sized,
+{
+    /// Gets the underlying bytes.
+    ///
+    /// The returned slice covers exactly the bytes of the referenced `T`: its
+    /// length is equal to `size_of_val` of the referent, and it excludes any
+    /// bytes which were split off when constructing this `Ref` (e.g., by
+    /// [`Ref::from_prefix`]). This is useful for logging or checksumming the
+    /// region that a `Ref` covers, and does not require `T: IntoBytes`.
+    ///
+    /// The returned slice borrows from `self`, and so this works for any
+    /// [`ByteSlice`] backing - including those, such as [`cell::Ref<[u8]>`],
+    /// which don't implement [`IntoByteSlice`].
+    ///
+    /// [`cell::Ref<[u8]>`]: core::cell::Ref
+    #[inline]
+    pub fn bytes(&self) -> &[u8] {
+        // SAFETY: We don't call any methods on `b` other than those provided by
+        // `ByteSlice`.
+        unsafe { self.as_byte_slice().deref() }
+    }
+}
+
+impl<B, T> Ref<B, T>
+where
+    B: ByteSliceMut,
+    T: ?Sized,
+{
+    /// Gets the underlying bytes mutably.
+    ///
+    /// Like [`Ref::bytes`], the returned slice covers exactly the bytes of the
+    /// referenced `T`.
+    #[inline]
+    pub fn bytes_mut(&mut self) -> &mut [u8] {
+        // SAFETY: We don't call any methods on `b` other than those provided by
+        // `ByteSliceMut`.
+        unsafe { self.as_byte_slice_mut().deref_mut() }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+impl<T> Ref<Box<[u8]>, T>
+where
+    T: KnownLayout + Immutable + ?Sized,
+{
+    /// Constructs a new `Ref` which owns its bytes.
+    ///
+    /// `from_boxed_bytes` is like [`Ref::from`], but takes ownership of a
+    /// heap-allocated byte slice. The resulting `Ref` is not tied to the
+    /// lifetime of a borrow, and so can be stored or returned freely. The box
+    /// can be recovered with [`into_boxed_slice`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::Ref;
+    ///
+    /// let bytes = vec![0u8; 8].into_boxed_slice();
+    /// let mut r = Ref::<_, [[u8; 2]]>::from_boxed_bytes(bytes).unwrap();
+    /// r[1] = [1, 2];
+    /// assert_eq!(&*r.into_boxed_slice(), [0, 0, 1, 2, 0, 0, 0, 0]);
+    /// ```
+    ///
+    /// [`into_boxed_slice`]: Ref::into_boxed_slice
+    #[must_use = "has no side effects"]
+    #[inline]
+    pub fn from_boxed_bytes(
+        bytes: Box<[u8]>,
+    ) -> Result<Ref<Box<[u8]>, T>, CastError<Box<[u8]>, T>> {
+        Ref::from(bytes)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+impl<T> Ref<Box<[u8]>, T>
+where
+    T: ?Sized,
+{
+    /// Consumes the `Ref`, returning the boxed byte slice which backs it.
+    ///
+    /// This is the inverse of [`Ref::from_boxed_bytes`]. Any modifications
+    /// made through the `Ref` are reflected in the returned bytes.
+    #[must_use = "has no side effects"]
+    #[inline]
+    pub fn into_boxed_slice(self) -> Box<[u8]> {
+        self.into_box()
+    }
+}
+
+impl<B> Ref<B, [u8]>
+where
+    B: SplitByteSlice,
+{
+    /// Carves a typed `Ref` out of a range of bytes.
+    ///
+    /// `carve` splits `self` into the bytes before `byte_range`, the bytes in
+    /// `byte_range`, and the bytes after `byte_range`, and reinterprets the
+    /// bytes in `byte_range` as a `Ref<B, U>`. All three results share the
+    /// original byte slice's backing storage.
+    ///
+    /// `carve` verifies that `byte_range` is in bounds and that the bytes it
+    /// covers are of a valid size and alignment for `U`. If any of these
+    /// checks fail, it returns `Err`, which contains `self` unmodified.
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// This method cannot yet be used on unsized types whose dynamically-sized
+    /// component is zero-sized. Attempting to use this method on such types
+    /// results in a compile-time assertion error; e.g.:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerocopy::*;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(Immutable, KnownLayout)]
+    /// #[repr(C)]
+    /// struct ZSTy {
+    ///     leading_sized: u16,
+    ///     trailing_dst: [()],
+    /// }
+    ///
+    /// let bytes = Ref::<_, [u8]>::from(&b"UUUU"[..]).unwrap();
+    /// let _ = bytes.carve::<ZSTy>(1..3); // ⚠ Compile Error!
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "derive")] { // This example uses derives, and won't compile without them
+    /// use zerocopy::{FromBytes, Immutable, KnownLayout, Ref, Unaligned};
+    ///
+    /// #[derive(FromBytes, KnownLayout, Immutable, Unaligned)]
+    /// #[repr(C)]
+    /// struct Port([u8; 2]);
+    ///
+    /// let bytes = &[0, 1, 2, 3, 4, 5][..];
+    /// let bytes = Ref::<_, [u8]>::unaligned_from(bytes).unwrap();
+    ///
+    /// let (prefix, port, suffix) = bytes.carve::<Port>(1..3).unwrap();
+    /// assert_eq!(prefix.bytes(), [0]);
+    /// assert_eq!(port.0, [1, 2]);
+    /// assert_eq!(suffix.bytes(), [3, 4, 5]);
+    /// # }
+    /// ```
+    #[must_use = "has no side effects"]
+    #[inline]
+    pub fn carve<U>(
+        self,
+        byte_range: Range<usize>,
+    ) -> Result<(Ref<B, [u8]>, Ref<B, U>, Ref<B, [u8]>), CastError<Self, U>>
+    where
+        U: KnownLayout + Immutable + ?Sized,
+    {
+        util::assert_dst_is_not_zst::<U>();
+        let Range { start, end } = byte_range;
+        let middle = match self.bytes().get(start..end) {
+            Some(middle) => middle,
+            None => return Err(SizeError::new(self).into()),
+        };
+        if let Err(e) = Ptr::from_ref(middle).try_cast_into_no_leftover::<U, BecauseImmutable>(None)
+        {
+            return Err(e.with_src(()).with_src(self));
+        }
+
+        // SAFETY: We don't call any methods on `bytes` other than those
+        // provided by `SplitByteSlice`.
+        let bytes = unsafe { self.into_split_byte_slice() };
+        // SAFETY: `self.bytes().get(start..end)` returned `Some` above, so
+        // `start <= end <= bytes.len()`. By invariant on `ByteSlice`, a
+        // supertrait of `SplitByteSlice`, `bytes.deref()` has the same length
+        // as `self.bytes()` did.
+        let (prefix, rest) = unsafe { bytes.split_at_unchecked(start) };
+        // SAFETY: As above, `start <= end <= bytes.len()`. By safety
+        // postcondition on `SplitByteSlice`, `rest` has length `bytes.len() -
+        // start`, which is at least `end - start`.
+        #[allow(clippy::arithmetic_side_effects)]
+        let (middle, suffix) = unsafe { rest.split_at_unchecked(end - start) };
+
+        // SAFETY: `[u8]` has an alignment of 1, and any length is a valid
+        // size for `[u8]`.
+        let prefix = unsafe { Ref::new_unchecked(prefix) };
+        // SAFETY: By safety postcondition on `SplitByteSlice`, `middle` has
+        // the same address and length as `self.bytes().get(start..end)`, which
+        // `try_cast_into_no_leftover` validated to have a valid size and
+        // alignment for `U`.
+        let middle = unsafe { Ref::new_unchecked(middle) };
+        // SAFETY: `[u8]` has an alignment of 1, and any length is a valid
+        // size for `[u8]`.
+        let suffix = unsafe { Ref::new_unchecked(suffix) };
+        Ok((prefix, middle, suffix))
+    }
+
+    /// Splits a typed `Ref` off of the front of `self`.
+    ///
+    /// `cast_prefix` reinterprets the prefix of `self` as a `Ref<B, U>`, and
+    /// returns it along with the remaining bytes as a `Ref<B, [u8]>`, both of
+    /// which share the original byte slice's backing storage. This makes it
+    /// possible to peel successive headers off of a buffer without leaving the
+    /// `Ref` abstraction. If `self` is too short or is not aligned to
+    /// `align_of::<U>()`, it returns `Err`, which contains `self` unmodified.
+    ///
+    /// If `U` is a slice DST, the returned `Ref` has as many trailing elements
+    /// as fit in `self`, as with [`Ref::from_prefix`].
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// This method cannot yet be used on unsized types whose dynamically-sized
+    /// component is zero-sized. Attempting to use this method on such types
+    /// results in a compile-time assertion error; e.g.:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerocopy::*;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(Immutable, KnownLayout)]
+    /// #[repr(C)]
+    /// struct ZSTy {
+    ///     leading_sized: u16,
+    ///     trailing_dst: [()],
+    /// }
+    ///
+    /// let bytes = Ref::<_, [u8]>::from(&b"UU"[..]).unwrap();
+    /// let _ = bytes.cast_prefix::<ZSTy>(); // ⚠ Compile Error!
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "derive")] { // This example uses derives, and won't compile without them
+    /// use zerocopy::{FromBytes, Immutable, KnownLayout, Ref, Unaligned};
+    ///
+    /// #[derive(FromBytes, KnownLayout, Immutable, Unaligned)]
+    /// #[repr(C)]
+    /// struct Header {
+    ///     kind: u8,
+    ///     len: u8,
+    /// }
+    ///
+    /// let bytes = Ref::<_, [u8]>::unaligned_from(&[1, 2, 3, 4, 5][..]).unwrap();
+    ///
+    /// let (header, body) = bytes.cast_prefix::<Header>().unwrap();
+    /// assert_eq!((header.kind, header.len), (1, 2));
+    /// assert_eq!(*body, [3, 4, 5]);
+    /// # }
+    /// ```
+    #[must_use = "has no side effects"]
+    #[inline]
+    pub fn cast_prefix<U>(self) -> Result<(Ref<B, U>, Ref<B, [u8]>), CastError<Self, U>>
+    where
+        U: KnownLayout + Immutable + ?Sized,
+    {
+        util::assert_dst_is_not_zst::<U>();
+        let remainder = match Ptr::from_ref(self.bytes())
+            .try_cast_into::<U, BecauseImmutable>(CastType::Prefix, None)
+        {
+            Ok((_, remainder)) => remainder,
+            Err(e) => return Err(e.with_src(()).with_src(self)),
+        };
+        // SAFETY: `remainder` is constructed as a subset of `self.bytes()`,
+        // and so it cannot be longer than `self.bytes()`. Thus, this cannot
+        // underflow.
+        #[allow(unstable_name_collisions, clippy::incompatible_msrv)]
+        let split_at = unsafe { self.bytes().len().unchecked_sub(remainder.len()) };
+
+        // SAFETY: We don't call any methods on `bytes` other than those
+        // provided by `SplitByteSlice`.
+        let bytes = unsafe { self.into_split_byte_slice() };
+        // SAFETY: `split_at <= self.bytes().len()`, and by invariant on
+        // `ByteSlice`, a supertrait of `SplitByteSlice`, `bytes.deref()` has
+        // the same length as `self.bytes()` did.
+        let (prefix, suffix) = unsafe { bytes.split_at_unchecked(split_at) };
+        // SAFETY: By safety postcondition on `SplitByteSlice`, `prefix` has
+        // the same address as `self.bytes()` and a length of `split_at`, which
+        // `try_cast_into` validated to have a valid size and alignment for
+        // `U`.
+        let prefix = unsafe { Ref::new_unchecked(prefix) };
+        // SAFETY: `[u8]` has an alignment of 1, and any length is a valid
+        // size for `[u8]`.
+        let suffix = unsafe { Ref::new_unchecked(suffix) };
+        Ok((prefix, suffix))
+    }
+
+    /// Splits a typed `Ref` off of the back of `self`.
+    ///
+    /// `cast_suffix` reinterprets the suffix of `self` as a `Ref<B, U>`, and
+    /// returns it along with the preceding bytes as a `Ref<B, [u8]>`, both of
+    /// which share the original byte slice's backing storage. This makes it
+    /// possible to peel successive trailers off of a buffer without leaving the
+    /// `Ref` abstraction. If `self` is too short or its suffix is not aligned
+    /// to `align_of::<U>()`, it returns `Err`, which contains `self`
+    /// unmodified.
+    ///
+    /// If `U` is a slice DST, the returned `Ref` has as many trailing elements
+    /// as fit in `self`, as with [`Ref::from_suffix`].
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// This method cannot yet be used on unsized types whose dynamically-sized
+    /// component is zero-sized. Attempting to use this method on such types
+    /// results in a compile-time assertion error; e.g.:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerocopy::*;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(Immutable, KnownLayout)]
+    /// #[repr(C)]
+    /// struct ZSTy {
+    ///     leading_sized: u16,
+    ///     trailing_dst: [()],
+    /// }
+    ///
+    /// let bytes = Ref::<_, [u8]>::from(&b"UU"[..]).unwrap();
+    /// let _ = bytes.cast_suffix::<ZSTy>(); // ⚠ Compile Error!
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use zerocopy::Ref;
+    ///
+    /// let bytes = Ref::<_, [u8]>::unaligned_from(&[1, 2, 3, 4, 5][..]).unwrap();
+    ///
+    /// let (body, checksum) = bytes.cast_suffix::<[u8; 2]>().unwrap();
+    /// assert_eq!(*body, [1, 2, 3]);
+    /// assert_eq!(*checksum, [4, 5]);
+    /// ```
     #[must_use = "has no side effects"]
-    #[inline(always)]
-    pub fn into_mut(self) -> &'a mut T {
-        // Presumably unreachable, since we've guarded each constructor of `Ref`.
-        util::assert_dst_is_not_zst::<T>();
-
-        // SAFETY: We don't call any methods on `b` other than those provided by
-        // `IntoByteSliceMut`.
-        let b = unsafe { self.into_byte_slice_mut() };
+    #[inline]
+    pub fn cast_suffix<U>(self) -> Result<(Ref<B, [u8]>, Ref<B, U>), CastError<Self, U>>
+    where
+        U: KnownLayout + Immutable + ?Sized,
+    {
+        util::assert_dst_is_not_zst::<U>();
+        let split_at = match Ptr::from_ref(self.bytes())
+            .try_cast_into::<U, BecauseImmutable>(CastType::Suffix, None)
+        {
+            Ok((_, remainder)) => remainder.len(),
+            Err(e) => return Err(e.with_src(()).with_src(self)),
+        };
 
-        // PANICS: By post-condition on `into_byte_slice_mut`, `b`'s size and
-        // alignment are valid for `T`. By invariant on `IntoByteSliceMut`,
-        // `b.into()` produces a byte slice with identical address and length to
-        // that produced by `b.deref_mut()`.
-        let ptr = Ptr::from_mut(b.into())
-            .try_cast_into_no_leftover::<T, BecauseExclusive>(None)
-            .expect("zerocopy internal error: into_ref should be infallible");
-        let ptr = ptr.bikeshed_recall_valid();
-        ptr.as_mut()
+        // SAFETY: We don't call any methods on `bytes` other than those
+        // provided by `SplitByteSlice`.
+        let bytes = unsafe { self.into_split_byte_slice() };
+        // SAFETY: `split_at` is the length of `remainder`, which is a subset of
+        // `self.bytes()`, so `split_at <= self.bytes().len()`. By invariant on
+        // `ByteSlice`, a supertrait of `SplitByteSlice`, `bytes.deref()` has
+        // the same length as `self.bytes()` did.
+        let (prefix, suffix) = unsafe { bytes.split_at_unchecked(split_at) };
+        // SAFETY: `[u8]` has an alignment of 1, and any length is a valid
+        // size for `[u8]`.
+        let prefix = unsafe { Ref::new_unchecked(prefix) };
+        // SAFETY: By safety postcondition on `SplitByteSlice`, `suffix` begins
+        // `split_at` bytes into `self.bytes()` and extends to its end, which
+        // `try_cast_into` validated to have a valid size and alignment for
+        // `U`.
+        let suffix = unsafe { Ref::new_unchecked(suffix) };
+        Ok((prefix, suffix))
     }
 }
 
-impl<B, T> Ref<B, T>
+impl<B, T> Ref<B, [T]>
 where
     B: ByteSlice,
-    T: ?Sized,
+    T: FromBytes + Immutable,
 {
-    /// Gets the underlying bytes.
+    /// Returns an iterator over references to the elements of the slice.
+    ///
+    /// This is equivalent to `r.iter()` (via [`Deref`]), and borrows `self`
+    /// for the lifetime of the iterator. To iterate over independent `Ref`s
+    /// which own a portion of the underlying byte slice, use
+    /// [`into_iter_refs`].
+    ///
+    /// [`into_iter_refs`]: Ref::into_iter_refs
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use zerocopy::Ref;
+    ///
+    /// let bytes = &[1u8, 2, 3, 4][..];
+    /// let r = Ref::<_, [[u8; 2]]>::from(bytes).unwrap();
+    /// let mut iter = r.iter_refs();
+    /// assert_eq!(iter.next(), Some(&[1, 2]));
+    /// assert_eq!(iter.next(), Some(&[3, 4]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[must_use = "has no side effects"]
     #[inline]
-    pub fn bytes(&self) -> &[u8] {
-        // SAFETY: We don't call any methods on `b` other than those provided by
-        // `ByteSlice`.
-        unsafe { self.as_byte_slice().deref() }
+    pub fn iter_refs(&self) -> impl Iterator<Item = &T> {
+        self.deref().iter()
     }
 }
 
-impl<B, T> Ref<B, T>
+impl<B, T> Ref<B, [T]>
 where
-    B: ByteSliceMut,
-    T: ?Sized,
+    B: SplitByteSlice,
+    T: KnownLayout + Immutable,
 {
-    /// Gets the underlying bytes mutably.
+    /// Converts `self` into an iterator of `Ref`s, one per element.
+    ///
+    /// Each `Ref` yielded by the returned iterator is backed by a disjoint
+    /// portion of the original byte slice, split off using
+    /// [`SplitByteSlice`]. This permits processing the elements of a large
+    /// slice one at a time, and is useful when `B` is an owning type (such as
+    /// [`cell::Ref<[u8]>`][cell-ref]) or when `T` is not `Copy`.
+    ///
+    /// [cell-ref]: core::cell::Ref
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// This method cannot yet be used on slices of zero-sized types.
+    /// Attempting to use this method on such types results in a compile-time
+    /// assertion error; e.g.:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerocopy::Ref;
+    ///
+    /// fn into_iter_refs(r: Ref<&[u8], [()]>) {
+    ///     let _ = r.into_iter_refs(); // ⚠ Compile Error!
+    /// }
+    /// # let _ = into_iter_refs as fn(_);
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use zerocopy::Ref;
+    ///
+    /// let bytes = &[1u8, 2, 3, 4][..];
+    /// let r = Ref::<_, [[u8; 2]]>::from(bytes).unwrap();
+    /// let elems: Vec<Ref<&[u8], [u8; 2]>> = r.into_iter_refs().collect();
+    /// assert_eq!(elems.len(), 2);
+    /// assert_eq!(elems[1].bytes(), [3, 4]);
+    /// ```
+    #[must_use = "has no side effects"]
     #[inline]
-    pub fn bytes_mut(&mut self) -> &mut [u8] {
-        // SAFETY: We don't call any methods on `b` other than those provided by
-        // `ByteSliceMut`.
-        unsafe { self.as_byte_slice_mut().deref_mut() }
+    pub fn into_iter_refs(self) -> IntoIterRefs<B, T> {
+        util::assert_dst_is_not_zst::<[T]>();
+
+        // SAFETY: We don't call any methods on `bytes` other than those
+        // provided by `SplitByteSlice`.
+        let bytes = unsafe { self.into_split_byte_slice() };
+        // INVARIANTS: By postcondition on `into_split_byte_slice`, `bytes` is
+        // validly-aligned for `[T]` and has a valid size for `[T]`; that is,
+        // its length is a multiple of `size_of::<T>()`.
+        IntoIterRefs { bytes: Some(bytes), _marker: PhantomData }
     }
 }
 
@@ -782,6 +1890,141 @@ where
     }
 }
 
+/// An iterator over the elements of a [`Ref<B, [T]>`][Ref], yielding a
+/// `Ref<B, T>` per element.
+///
+/// This type is returned by [`Ref::into_iter_refs`].
+#[derive(Debug)]
+pub struct IntoIterRefs<B, T> {
+    /// # Invariants
+    ///
+    /// If `bytes` is `Some(b)`, then `b` is validly-aligned for `T`, and its
+    /// length is a multiple of `size_of::<T>()`, which is non-zero.
+    bytes: Option<B>,
+    _marker: PhantomData<T>,
+}
+
+impl<B, T> Iterator for IntoIterRefs<B, T>
+where
+    B: SplitByteSlice,
+    T: KnownLayout + Immutable,
+{
+    type Item = Ref<B, T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Ref<B, T>> {
+        let bytes = self.bytes.take()?;
+        if bytes.is_empty() {
+            return None;
+        }
+
+        // SAFETY: By invariant on `self.bytes`, `bytes.len()` is a non-zero
+        // multiple of `size_of::<T>()`, and so is at least `size_of::<T>()`.
+        let (elem, rest) = unsafe { bytes.split_at_unchecked(mem::size_of::<T>()) };
+        // INVARIANTS: By safety postcondition on `SplitByteSlice`, `rest`
+        // begins `size_of::<T>()` bytes after `bytes`, which is a multiple of
+        // `T`'s alignment, so `rest` is validly-aligned for `T`. Its length is
+        // `size_of::<T>()` less than that of `bytes`, and so is still a
+        // multiple of `size_of::<T>()`.
+        self.bytes = Some(rest);
+        // SAFETY: By invariant on `self.bytes`, `elem` is validly-aligned for
+        // `T`, and by safety postcondition on `SplitByteSlice`, it has length
+        // `size_of::<T>()`.
+        Some(unsafe { Ref::new_unchecked(elem) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // By invariant on `self.bytes`, `size_of::<T>()` is non-zero, so this
+        // will not divide by zero.
+        #[allow(clippy::arithmetic_side_effects)]
+        let len = self.bytes.as_ref().map_or(0, |b| b.len() / mem::size_of::<T>());
+        (len, Some(len))
+    }
+}
+
+impl<B, T> ExactSizeIterator for IntoIterRefs<B, T>
+where
+    B: SplitByteSlice,
+    T: KnownLayout + Immutable,
+{
+}
+
+/// An iterator over the `size_of::<T>()`-byte chunks of a byte slice, yielding
+/// a `&T` per chunk.
+///
+/// If the length of the byte slice is not a multiple of `size_of::<T>()`, the
+/// final item is a [`SizeError`] containing the trailing partial chunk.
+///
+/// This type is returned by [`FromBytes::ref_from_chunks`].
+#[derive(Debug)]
+pub struct RefChunks<'a, T> {
+    /// # Invariants
+    ///
+    /// `bytes` is validly-aligned for `T`, and `size_of::<T>()` is non-zero.
+    bytes: &'a [u8],
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T> RefChunks<'a, T> {
+    /// Constructs a new `RefChunks` over `bytes`.
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must be validly-aligned for `T`, and `size_of::<T>()` must be
+    /// non-zero.
+    pub(crate) unsafe fn new_unchecked(bytes: &'a [u8]) -> RefChunks<'a, T> {
+        // INVARIANTS: The caller promises that `bytes` is validly-aligned for
+        // `T`, and that `size_of::<T>()` is non-zero.
+        RefChunks { bytes, _marker: PhantomData }
+    }
+}
+
+impl<'a, T> Iterator for RefChunks<'a, T>
+where
+    T: 'a + FromBytes + Immutable,
+{
+    type Item = Result<&'a T, SizeError<&'a [u8], T>>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Result<&'a T, SizeError<&'a [u8], T>>> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+        if self.bytes.len() < mem::size_of::<T>() {
+            return Some(Err(SizeError::new(mem::take(&mut self.bytes))));
+        }
+
+        let (chunk, rest) = self.bytes.split_at(mem::size_of::<T>());
+        // INVARIANTS: `rest` begins `size_of::<T>()` bytes after `self.bytes`,
+        // which is a multiple of `T`'s alignment, so `rest` is validly-aligned
+        // for `T`.
+        self.bytes = rest;
+        // SAFETY: By invariant on `self.bytes`, `chunk` is validly-aligned for
+        // `T`, and it has length `size_of::<T>()`. Since `T: FromBytes`, any
+        // initialized sequence of bytes is a valid `T`, and since `T:
+        // Immutable`, `T` does not permit interior mutation, which could
+        // otherwise be used to violate the aliasing of `chunk`.
+        Some(Ok(unsafe { &*chunk.as_ptr().cast::<T>() }))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // By invariant on `self.bytes`, `size_of::<T>()` is non-zero, so
+        // neither of these operations will divide by zero.
+        #[allow(clippy::arithmetic_side_effects)]
+        let (chunks, partial) =
+            (self.bytes.len() / mem::size_of::<T>(), self.bytes.len() % mem::size_of::<T>());
+        // This cannot overflow, since `chunks` is at most `usize::MAX / 2` when
+        // `partial` is non-zero.
+        #[allow(clippy::arithmetic_side_effects)]
+        let len = chunks + usize::from(partial != 0);
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for RefChunks<'a, T> where T: 'a + FromBytes + Immutable {}
+
 impl<B, T> Deref for Ref<B, T>
 where
     B: ByteSlice,
@@ -877,6 +2120,18 @@ where
     }
 }
 
+impl<T, B> Hash for Ref<B, T>
+where
+    B: ByteSlice,
+    T: FromBytes + Hash + KnownLayout + Immutable + ?Sized,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let inner: &T = self;
+        inner.hash(state)
+    }
+}
+
 impl<T, B> Ord for Ref<B, T>
 where
     B: ByteSlice,
@@ -1289,6 +2544,574 @@ mod tests {
         .is_err());
     }
 
+    #[test]
+    fn test_carve() {
+        let mut buf = Align::<[u8; 16], AU64>::default();
+        buf.t = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+        let bytes = Ref::<_, [u8]>::from(&buf.t[..]).unwrap();
+        let (prefix, middle, suffix) = bytes.carve::<AU64>(8..16).unwrap();
+        assert_eq!(prefix.bytes(), [0, 1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(middle.bytes(), [8, 9, 10, 11, 12, 13, 14, 15]);
+        assert!(suffix.bytes().is_empty());
+
+        // A slice type may cover an empty range.
+        let bytes = Ref::<_, [u8]>::from(&buf.t[..]).unwrap();
+        let (prefix, middle, suffix) = bytes.carve::<[AU64]>(0..0).unwrap();
+        assert!(prefix.bytes().is_empty());
+        assert!(middle.is_empty());
+        assert_eq!(suffix.bytes().len(), 16);
+
+        // The carved `Ref`s are writable when the backing storage is.
+        let bytes = Ref::<_, [u8]>::from(&mut buf.t[..]).unwrap();
+        let (_, mut middle, _) = bytes.carve::<[u8; 4]>(2..6).unwrap();
+        *middle = [0xFF; 4];
+        assert_eq!(buf.t[..8], [0, 1, 0xFF, 0xFF, 0xFF, 0xFF, 6, 7]);
+
+        // Out-of-bounds and inverted ranges.
+        let bytes = Ref::<_, [u8]>::from(&buf.t[..]).unwrap();
+        let bytes = match bytes.carve::<[u8; 4]>(14..18) {
+            Err(CastError::Size(e)) => e.into_src(),
+            _ => unreachable!(),
+        };
+        #[allow(clippy::reversed_empty_ranges)]
+        let bytes = match bytes.carve::<[u8]>(4..2) {
+            Err(CastError::Size(e)) => e.into_src(),
+            _ => unreachable!(),
+        };
+        // Wrong size.
+        let bytes = match bytes.carve::<AU64>(0..4) {
+            Err(CastError::Size(e)) => e.into_src(),
+            _ => unreachable!(),
+        };
+        // Misaligned.
+        let bytes = match bytes.carve::<AU64>(1..9) {
+            Err(CastError::Alignment(e)) => e.into_src(),
+            _ => unreachable!(),
+        };
+        assert_eq!(bytes.len(), 16);
+    }
+
+    #[test]
+    fn test_cast_prefix_suffix() {
+        let mut buf = Align::<[u8; 16], AU64>::default();
+        buf.t = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+        // Peel a header off the front and a trailer off the back, leaving the
+        // body in between.
+        let bytes = Ref::<_, [u8]>::from(&buf.t[..]).unwrap();
+        let (header, rest) = bytes.cast_prefix::<AU64>().unwrap();
+        assert_eq!(header.bytes(), [0, 1, 2, 3, 4, 5, 6, 7]);
+        let (body, trailer) = rest.cast_suffix::<[u8; 2]>().unwrap();
+        assert_eq!(*body, [8, 9, 10, 11, 12, 13]);
+        assert_eq!(*trailer, [14, 15]);
+
+        // Slice types consume as many elements as fit.
+        let bytes = Ref::<_, [u8]>::from(&buf.t[..]).unwrap();
+        let (elems, rest) = bytes.cast_prefix::<[[u8; 3]]>().unwrap();
+        assert_eq!(elems.len(), 5);
+        assert_eq!(*rest, [15]);
+        let bytes = Ref::<_, [u8]>::from(&buf.t[..]).unwrap();
+        let (rest, elems) = bytes.cast_suffix::<[[u8; 3]]>().unwrap();
+        assert_eq!(*rest, [0]);
+        assert_eq!(elems.len(), 5);
+
+        // The typed `Ref`s are writable when the backing storage is.
+        let bytes = Ref::<_, [u8]>::from(&mut buf.t[..]).unwrap();
+        let (mut header, rest) = bytes.cast_prefix::<[u8; 2]>().unwrap();
+        let (_, mut trailer) = rest.cast_suffix::<[u8; 2]>().unwrap();
+        *header = [0xFF; 2];
+        *trailer = [0xEE; 2];
+        assert_eq!(buf.t[..2], [0xFF; 2]);
+        assert_eq!(buf.t[14..], [0xEE; 2]);
+
+        // Too short.
+        let bytes = Ref::<_, [u8]>::from(&buf.t[..]).unwrap();
+        let bytes = match bytes.cast_prefix::<[u8; 17]>() {
+            Err(CastError::Size(e)) => e.into_src(),
+            _ => unreachable!(),
+        };
+        let bytes = match bytes.cast_suffix::<[u8; 17]>() {
+            Err(CastError::Size(e)) => e.into_src(),
+            _ => unreachable!(),
+        };
+        assert_eq!(bytes.len(), 16);
+
+        // Misaligned.
+        let bytes = Ref::<_, [u8]>::from(&buf.t[1..]).unwrap();
+        let bytes = match bytes.cast_prefix::<AU64>() {
+            Err(CastError::Alignment(e)) => e.into_src(),
+            _ => unreachable!(),
+        };
+        assert_eq!(bytes.len(), 15);
+        let bytes = Ref::<_, [u8]>::from(&buf.t[..15]).unwrap();
+        let bytes = match bytes.cast_suffix::<AU64>() {
+            Err(CastError::Alignment(e)) => e.into_src(),
+            _ => unreachable!(),
+        };
+        assert_eq!(bytes.len(), 15);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_cast_prefix_suffix_shared_bytes() {
+        let buf = SharedBytes::from(vec![0u8, 1, 2, 3, 4, 5, 6, 7]);
+        let addr = buf.as_ptr();
+        let bytes = Ref::<_, [u8]>::unaligned_from(buf).unwrap();
+
+        let (header, rest) = bytes.cast_prefix::<[u8; 2]>().unwrap();
+        let (body, trailer) = rest.cast_suffix::<[u8; 2]>().unwrap();
+        assert_eq!(*header, [0, 1]);
+        assert_eq!(*body, [2, 3, 4, 5]);
+        assert_eq!(*trailer, [6, 7]);
+
+        // Each piece views the original allocation.
+        assert_eq!(header.bytes().as_ptr(), addr);
+        assert_eq!(body.as_ptr(), addr.wrapping_add(2));
+        assert_eq!(trailer.bytes().as_ptr(), addr.wrapping_add(6));
+    }
+
+    #[test]
+    fn test_from_prefix_aligned() {
+        let buf =
+            Align::<[u8; 16], AU64>::new([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+
+        // An intentionally misaligned sub-slice skips to the next
+        // `AU64`-aligned address.
+        let bytes = &buf.t[3..];
+        let (skipped, r, suffix) = Ref::<_, AU64>::from_prefix_aligned(bytes).unwrap();
+        assert_eq!(skipped, [3, 4, 5, 6, 7]);
+        assert_eq!(Ref::bytes(&r), [8, 9, 10, 11, 12, 13, 14, 15]);
+        assert!(suffix.is_empty());
+
+        // An aligned slice skips nothing.
+        let (skipped, r, suffix) = Ref::<_, [u16]>::from_prefix_aligned(&buf.t[..]).unwrap();
+        assert!(skipped.is_empty());
+        assert_eq!(r.len(), 8);
+        assert!(suffix.is_empty());
+
+        // After skipping, too few bytes remain.
+        let bytes = &buf.t[1..15];
+        let err = Ref::<_, AU64>::from_prefix_aligned(bytes).unwrap_err();
+        assert_eq!(err.into_src(), bytes);
+
+        // Mutable byte slices are supported.
+        let mut buf = Align::<[u8; 16], AU64>::default();
+        let (_, mut r, _) = Ref::<_, AU64>::from_prefix_aligned(&mut buf.t[2..]).unwrap();
+        *r = AU64(u64::MAX);
+        assert_eq!(buf.t[8..], [0xFF; 8]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_boxed_bytes() {
+        let bytes = vec![0u8; 16].into_boxed_slice();
+        let addr = bytes.as_ptr();
+        if bytes.as_ptr().align_offset(mem::align_of::<u32>()) != 0 {
+            // The allocator is only required to return 1-aligned memory.
+            let err = Ref::<_, [u32]>::from_boxed_bytes(bytes).unwrap_err();
+            assert!(matches!(err, CastError::Alignment(_)));
+            return;
+        }
+
+        let mut r = Ref::<_, [u32]>::from_boxed_bytes(bytes).unwrap();
+        assert_eq!(r.len(), 4);
+        r[0] = 1;
+        r[3] = u32::MAX;
+        let bytes = r.into_boxed_slice();
+        // The original allocation is returned.
+        assert_eq!(bytes.as_ptr(), addr);
+        assert_eq!(bytes[..4], 1u32.to_ne_bytes());
+        assert_eq!(bytes[4..12], [0; 8]);
+        assert_eq!(bytes[12..], [0xFF; 4]);
+
+        // The length must be a valid length for `[u32]`.
+        let bytes = vec![0u8; 3].into_boxed_slice();
+        assert!(Ref::<_, [u32]>::from_boxed_bytes(bytes).is_err());
+    }
+
+    #[test]
+    fn test_slice_with_elems() {
+        fn test<B: SplitByteSlice>(mk: impl Fn() -> B) {
+            let addr = mk().as_ptr();
+
+            let (r, suffix) = Ref::<_, [AU64]>::from_prefix_with_elems(mk(), 2).unwrap();
+            assert_eq!(*r, [AU64(0), AU64(1)]);
+            assert_eq!(Ref::bytes(&r).as_ptr(), addr);
+            assert_eq!(suffix.len(), 8);
+
+            let (prefix, r) = Ref::<_, [AU64]>::from_suffix_with_elems(mk(), 2).unwrap();
+            assert_eq!(*r, [AU64(1), AU64(2)]);
+            assert_eq!(Ref::bytes(&r).as_ptr(), addr.wrapping_add(8));
+            assert_eq!(prefix.len(), 8);
+
+            let (r, suffix) = Ref::<_, [AU64]>::from_prefix_with_elems(mk(), 0).unwrap();
+            assert!(r.is_empty());
+            assert_eq!(suffix.len(), 24);
+
+            let r = Ref::<_, [AU64]>::from(mk()).unwrap();
+            assert_eq!(*r, [AU64(0), AU64(1), AU64(2)]);
+
+            // Too many elements.
+            assert!(Ref::<_, [AU64]>::from_prefix_with_elems(mk(), 4).is_err());
+            assert!(Ref::<_, [AU64]>::from_suffix_with_elems(mk(), 4).is_err());
+        }
+
+        let mut buf = Align::<[u8; 24], AU64>::default();
+        buf.t[8..16].copy_from_slice(&AU64(1).to_bytes());
+        buf.t[16..].copy_from_slice(&AU64(2).to_bytes());
+
+        test(|| &buf.t[..]);
+
+        // `RefCell` doesn't guarantee the alignment of its contents beyond that
+        // of the contained type, so wrap the bytes in `Align`.
+        let refcell = cell::RefCell::new(buf);
+        test(|| cell::Ref::map(refcell.borrow(), |b| &b.t[..]));
+
+        // `cell::Ref<[u8]>` backings for unaligned types are always aligned.
+        let refcell = cell::RefCell::new([1u8, 2, 3, 4, 5]);
+        let bytes = cell::Ref::map(refcell.borrow(), |b| &b[..]);
+        let (prefix, r) = Ref::<_, [[u8; 2]]>::from_suffix_with_elems(bytes, 2).unwrap();
+        assert_eq!(*prefix, [1]);
+        assert_eq!(*r, [[2, 3], [4, 5]]);
+        // The borrow is held until both halves are dropped.
+        assert!(refcell.try_borrow_mut().is_err());
+        drop((prefix, r));
+        assert!(refcell.try_borrow_mut().is_ok());
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn test_bytes() {
+        let buf = ::bytes::Bytes::from_static(&[0, 1, 2, 3, 4, 5, 6, 7]);
+        let (header, body) = Ref::<_, [u8; 2]>::from_prefix(buf.clone()).unwrap();
+        assert_eq!(*header, [0, 1]);
+        assert_eq!(body[..], [2, 3, 4, 5, 6, 7]);
+        // Neither half is copied out of `buf`.
+        assert_eq!(Ref::bytes(&header).as_ptr(), buf.as_ptr());
+        assert_eq!(body.as_ptr(), buf[2..].as_ptr());
+
+        let (body, trailer) = Ref::<_, [[u8; 2]]>::from_prefix_with_elems(body, 2).unwrap();
+        assert_eq!(*body, [[2, 3], [4, 5]]);
+        assert_eq!(trailer[..], [6, 7]);
+
+        let buf = ::bytes::BytesMut::from(&[0u8; 8][..]);
+        let addr = buf.as_ptr();
+        let (mut header, body) = Ref::<_, [u8; 2]>::from_prefix(buf).unwrap();
+        *header = [0xFF, 0xFF];
+        assert_eq!(Ref::bytes(&header), [0xFF, 0xFF]);
+        assert_eq!(Ref::bytes(&header).as_ptr(), addr);
+        assert_eq!(body.as_ptr(), addr.wrapping_add(2));
+    }
+
+    #[test]
+    fn test_ref_bytes() {
+        let mut buf = [0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+        // Bytes split off while constructing the `Ref` are not included.
+        let (r, suffix) = Ref::<_, [u8; 4]>::from_prefix(&buf[..]).unwrap();
+        assert_eq!(r.bytes().len(), mem::size_of_val(&*r));
+        assert_eq!(r.bytes(), [0, 1, 2, 3]);
+        assert_eq!(suffix.len(), 12);
+
+        let (prefix, r) = Ref::<_, [[u8; 2]]>::from_suffix_with_elems(&buf[..], 3).unwrap();
+        assert_eq!(r.bytes().len(), mem::size_of_val(&*r));
+        assert_eq!(r.bytes(), [10, 11, 12, 13, 14, 15]);
+        assert_eq!(prefix.len(), 10);
+
+        let (mut r, _) = Ref::<_, [u8; 4]>::from_prefix(&mut buf[..]).unwrap();
+        assert_eq!(r.bytes_mut().len(), mem::size_of_val(&*r));
+        r.bytes_mut().copy_from_slice(&[0xFF; 4]);
+        assert_eq!(*r, [0xFF; 4]);
+        assert_eq!(buf[4], 4);
+    }
+
+    #[test]
+    fn test_ref_bytes_cell_ref() {
+        // `bytes` only requires `B: ByteSlice`, and so it works for backings
+        // such as `cell::Ref<[u8]>` which don't implement `IntoByteSlice`.
+        fn sum(bytes: &[u8]) -> u32 {
+            bytes.iter().map(|&b| u32::from(b)).sum()
+        }
+
+        let refcell = cell::RefCell::new(Align::<[u8; 8], u64>::new([1, 2, 3, 4, 5, 6, 7, 8]));
+        let addr = refcell.borrow().t.as_ptr();
+        let r = Ref::<_, u64>::from(cell::Ref::map(refcell.borrow(), |b| &b.t[..])).unwrap();
+        assert_eq!(*r, u64::from_ne_bytes([1, 2, 3, 4, 5, 6, 7, 8]));
+        assert_eq!(Ref::bytes(&r), [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(Ref::bytes(&r).as_ptr(), addr);
+        assert_eq!(sum(Ref::bytes(&r)), 36);
+
+        // The borrow is held for as long as the `Ref`.
+        assert!(refcell.try_borrow_mut().is_err());
+        drop(r);
+        refcell.borrow_mut().t[0] = 0;
+        let r = Ref::<_, u64>::from(cell::Ref::map(refcell.borrow(), |b| &b.t[..])).unwrap();
+        assert_eq!(sum(Ref::bytes(&r)), 35);
+    }
+
+    #[test]
+    fn test_ref_bytes_mut_patch() {
+        use crate::byteorder::{BigEndian, U16};
+
+        // Writes through `bytes_mut` are visible through `Deref`.
+        let mut buf = [0u8; 8];
+        let (mut r, rest) = Ref::<_, [U16<BigEndian>; 2]>::from_prefix(&mut buf[..]).unwrap();
+        r.bytes_mut()[1] = 0x34;
+        r.bytes_mut()[2] = 0x12;
+        assert_eq!(r[0].get(), 0x0034);
+        assert_eq!(r[1].get(), 0x1200);
+        r.bytes_mut().fill(0xFF);
+        assert_eq!(*r, [U16::MAX_VALUE; 2]);
+        assert_eq!(rest, [0; 4]);
+
+        // For unsized `T`, `bytes_mut` covers every element of the referent.
+        let mut r = Ref::<_, [U16<BigEndian>]>::from_prefix_with_elems(&mut buf[..], 3).unwrap().0;
+        assert_eq!(r.bytes_mut().len(), mem::size_of_val(&*r));
+        r.bytes_mut()[5] = 7;
+        assert_eq!(r.iter().map(|x| x.get()).collect::<Vec<_>>(), [0xFFFF, 0xFFFF, 0x0007]);
+        r.bytes_mut().fill(0);
+        assert!(r.iter().all(|x| x.get() == 0));
+        assert_eq!(buf, [0, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_iter_refs() {
+        let buf = Align::<[u8; 16], AU64>::new(transmute!([
+            1u32.to_ne_bytes(),
+            2u32.to_ne_bytes(),
+            3u32.to_ne_bytes(),
+            4u32.to_ne_bytes()
+        ]));
+        let r = Ref::<_, [u32]>::from(&buf.t[..]).unwrap();
+        assert_eq!(r.iter_refs().sum::<u32>(), 10);
+
+        let iter = r.into_iter_refs();
+        assert_eq!(iter.len(), 4);
+        let mut sum = 0;
+        for (i, elem) in iter.enumerate() {
+            assert_eq!(elem.bytes().as_ptr(), buf.t[i * 4..].as_ptr());
+            sum += *elem;
+        }
+        assert_eq!(sum, 10);
+
+        // Iterating over an empty slice yields no elements.
+        let r = Ref::<_, [u32]>::from(&buf.t[..0]).unwrap();
+        assert_eq!(r.iter_refs().count(), 0);
+        assert_eq!(r.into_iter_refs().count(), 0);
+
+        // Elements yielded by `into_iter_refs` can be mutated independently.
+        let mut buf = Align::<[u8; 8], AU64>::default();
+        let r = Ref::<_, [u32]>::from(&mut buf.t[..]).unwrap();
+        for (i, mut elem) in r.into_iter_refs().enumerate() {
+            let i: u32 = i.try_into().unwrap();
+            *elem = i + 1;
+        }
+        let r = Ref::<_, [u32]>::from(&buf.t[..]).unwrap();
+        assert_eq!(&*r, [1, 2]);
+    }
+
+    #[test]
+    fn test_try_into_ref() {
+        #[derive(TryFromBytes, KnownLayout, Immutable, Debug, PartialEq)]
+        #[repr(u8)]
+        enum Kind {
+            A = 1,
+            B = 2,
+        }
+
+        #[derive(TryFromBytes, KnownLayout, Immutable, Debug, PartialEq)]
+        #[repr(C)]
+        struct Payload {
+            kind: Kind,
+            flag: bool,
+        }
+
+        let bytes = [2u8, 1];
+        let r = Ref::<_, Payload>::from(&bytes[..]).unwrap();
+        assert_eq!(r.try_into_ref().unwrap(), &Payload { kind: Kind::B, flag: true });
+
+        // An invalid enum discriminant.
+        let bytes = [3u8, 1];
+        let r = Ref::<_, Payload>::from(&bytes[..]).unwrap();
+        let r = r.try_into_ref().unwrap_err().into_src();
+        assert_eq!(r.bytes(), [3, 1]);
+
+        // An invalid `bool`.
+        let bytes = [1u8, 2];
+        let r = Ref::<_, Payload>::from(&bytes[..]).unwrap();
+        assert!(r.try_into_ref().is_err());
+
+        // Slices are validated element-wise.
+        let bytes = [1u8, 2, 2, 1];
+        let r = Ref::<_, [Kind]>::from(&bytes[..]).unwrap();
+        assert_eq!(r.try_into_ref().unwrap(), [Kind::A, Kind::B, Kind::B, Kind::A]);
+        let bytes = [1u8, 2, 0, 1];
+        let r = Ref::<_, [Kind]>::from(&bytes[..]).unwrap();
+        assert!(r.try_into_ref().is_err());
+    }
+
+    #[test]
+    fn test_try_cast() {
+        #[derive(TryFromBytes, KnownLayout, Immutable, Debug, PartialEq)]
+        #[repr(u8)]
+        enum Kind {
+            A = 1,
+            B = 2,
+        }
+
+        #[derive(TryFromBytes, KnownLayout, Immutable, Debug, PartialEq)]
+        #[repr(C)]
+        struct Payload {
+            kind: Kind,
+            flag: bool,
+        }
+
+        // The cast shares `self`'s backing storage.
+        let bytes = [2u8, 1];
+        let r = Ref::<_, [u8; 2]>::from(&bytes[..]).unwrap();
+        let r = r.try_cast::<Payload>().unwrap();
+        assert_eq!(Ref::bytes(&r).as_ptr(), bytes.as_ptr());
+        assert_eq!(r.try_into_ref().unwrap(), &Payload { kind: Kind::B, flag: true });
+
+        // A validity failure returns the original `Ref`.
+        let bytes = [3u8, 1];
+        let r = Ref::<_, [u8; 2]>::from(&bytes[..]).unwrap();
+        let err = r.try_cast::<Payload>().err().unwrap();
+        assert!(matches!(err, TryCastError::Validity(_)));
+        let r = err.into_src();
+        assert_eq!(*r, [3, 1]);
+
+        // Slices are cast to as many elements as fit, and validated
+        // element-wise.
+        let bytes = [1u8, 2, 2, 1];
+        let r = Ref::<_, [u8]>::from(&bytes[..]).unwrap();
+        let r = r.try_cast::<[Kind]>().unwrap();
+        assert_eq!(r.try_into_ref().unwrap(), [Kind::A, Kind::B, Kind::B, Kind::A]);
+        let bytes = [1u8, 2, 0, 1];
+        let r = Ref::<_, [u8]>::from(&bytes[..]).unwrap();
+        assert!(matches!(r.try_cast::<[Kind]>(), Err(TryCastError::Validity(_))));
+
+        // A size failure returns the original `Ref`.
+        let bytes = [1u8, 2, 1];
+        let r = Ref::<_, [u8; 3]>::from(&bytes[..]).unwrap();
+        let err = r.try_cast::<Payload>().err().unwrap();
+        assert!(matches!(err, TryCastError::Size(_)));
+        assert_eq!(*err.into_src(), [1, 2, 1]);
+
+        // An alignment failure returns the original `Ref`.
+        let buf = Align::<[u8; 9], AU64>::default();
+        let r = Ref::<_, [u8; 8]>::from(&buf.t[1..]).unwrap();
+        let err = r.try_cast::<AU64>().unwrap_err();
+        assert!(matches!(err, TryCastError::Alignment(_)));
+        assert_eq!(Ref::bytes(&err.into_src()).as_ptr(), buf.t[1..].as_ptr());
+
+        // Casting between `FromBytes` types produces a dereferenceable `Ref`,
+        // which may be mutated.
+        let mut buf = Align::<[u8; 8], AU64>::default();
+        let r = Ref::<_, [u8; 8]>::from(&mut buf.t[..]).unwrap();
+        let mut r = r.try_cast::<AU64>().unwrap();
+        *r = AU64(u64::MAX);
+        assert_eq!(buf.t, [0xFF; 8]);
+    }
+
+    #[test]
+    fn test_try_from_bytes() {
+        #[derive(TryFromBytes, KnownLayout, Immutable, Debug, PartialEq)]
+        #[repr(u8)]
+        enum Kind {
+            A = 1,
+            B = 2,
+        }
+
+        let bytes = [1u8, 2];
+        let r = Ref::<_, [Kind; 2]>::try_from_bytes(&bytes[..]).unwrap();
+        assert_eq!(Ref::bytes(&r).as_ptr(), bytes.as_ptr());
+        assert_eq!(r.try_into_ref().unwrap(), &[Kind::A, Kind::B]);
+
+        // A validity failure returns the original bytes.
+        let bytes = [1u8, 3];
+        let err = Ref::<_, [Kind; 2]>::try_from_bytes(&bytes[..]).err().unwrap();
+        assert!(matches!(err, TryCastError::Validity(_)));
+        assert_eq!(err.into_src(), [1, 3]);
+
+        // Slices are validated element-wise.
+        let r = Ref::<_, [Kind]>::try_from_bytes(&[2u8, 1, 2][..]).unwrap();
+        assert_eq!(r.try_into_ref().unwrap(), [Kind::B, Kind::A, Kind::B]);
+        let r = Ref::<_, [Kind]>::try_from_bytes(&[2u8, 0, 2][..]);
+        assert!(matches!(r, Err(TryCastError::Validity(_))));
+
+        // Size and alignment failures.
+        let r = Ref::<_, [Kind; 2]>::try_from_bytes(&[1u8, 2, 1][..]);
+        assert!(matches!(r, Err(TryCastError::Size(_))));
+        let buf = Align::<[u8; 9], AU64>::default();
+        let r = Ref::<_, AU64>::try_from_bytes(&buf.t[1..]);
+        assert!(matches!(r, Err(TryCastError::Alignment(_))));
+
+        // `try_from_bytes_mut` does not require `T: Immutable`.
+        #[derive(TryFromBytes, KnownLayout)]
+        #[repr(C)]
+        struct NotImmutable {
+            kind: Kind,
+        }
+
+        let mut bytes = [2u8];
+        assert!(Ref::<_, NotImmutable>::try_from_bytes_mut(&mut bytes[..]).is_ok());
+        let mut bytes = [0u8];
+        let r = Ref::<_, NotImmutable>::try_from_bytes_mut(&mut bytes[..]);
+        assert!(matches!(r, Err(TryCastError::Validity(_))));
+
+        let mut buf = Align::<[u8; 8], AU64>::default();
+        let mut r = Ref::<_, AU64>::try_from_bytes_mut(&mut buf.t[..]).unwrap();
+        *r = AU64(u64::MAX);
+        assert_eq!(buf.t, [0xFF; 8]);
+
+        let mut bytes = [1u8, 2];
+        let err = Ref::<_, [bool; 2]>::try_from_bytes_mut(&mut bytes[..]).err().unwrap();
+        assert!(matches!(err, TryCastError::Validity(_)));
+        assert_eq!(err.into_src(), [1, 2]);
+        let mut bytes = [1u8, 0, 1];
+        let r = Ref::<_, [bool; 2]>::try_from_bytes_mut(&mut bytes[..]);
+        assert!(matches!(r, Err(TryCastError::Size(_))));
+    }
+
+    #[test]
+    fn test_try_from_with_trailing_elements() {
+        #[derive(TryFromBytes, KnownLayout, Immutable)]
+        #[repr(C)]
+        struct Flags {
+            len: u16,
+            flags: [bool],
+        }
+
+        let buf = Align::<[u8; 4], AU64>::new([2, 0, 1, 0]);
+        let r = Ref::<_, Flags>::try_from_with_trailing_elements(&buf.t[..], 2).unwrap();
+        assert_eq!(Ref::bytes(&r).as_ptr(), buf.t.as_ptr());
+        let flags = r.try_into_ref().unwrap();
+        assert_eq!(flags.len, 2);
+        assert_eq!(flags.flags, [true, false]);
+
+        // A trailing element of `2` is not a valid `bool`.
+        let buf = Align::<[u8; 4], AU64>::new([2, 0, 1, 2]);
+        let err = Ref::<_, Flags>::try_from_with_trailing_elements(&buf.t[..], 2).err().unwrap();
+        assert!(matches!(err, TryCastError::Validity(_)));
+        assert_eq!(err.into_src(), [2, 0, 1, 2]);
+
+        // `bytes` must be exactly the size of a `Flags` with `count` elements.
+        let buf = Align::<[u8; 4], AU64>::new([2, 0, 1, 0]);
+        let r = Ref::<_, Flags>::try_from_with_trailing_elements(&buf.t[..], 0);
+        assert!(matches!(r, Err(TryCastError::Size(_))));
+        let r = Ref::<_, Flags>::try_from_with_trailing_elements(&buf.t[..], 3);
+        assert!(matches!(r, Err(TryCastError::Size(_))));
+        let r = Ref::<_, Flags>::try_from_with_trailing_elements(&buf.t[..], usize::MAX);
+        assert!(matches!(r, Err(TryCastError::Size(_))));
+
+        // `bytes` must be aligned to `align_of::<Flags>()`.
+        let buf = Align::<[u8; 5], AU64>::new([0, 2, 0, 1, 0]);
+        let r = Ref::<_, Flags>::try_from_with_trailing_elements(&buf.t[1..], 2);
+        assert!(matches!(r, Err(TryCastError::Alignment(_))));
+    }
+
     #[test]
     fn test_display_debug() {
         let buf = Align::<[u8; 8], u64>::default();
@@ -1319,6 +3142,27 @@ mod tests {
         assert_ne!(r1, r2);
     }
 
+    #[test]
+    fn test_hash() {
+        use std::collections::HashSet;
+
+        let buf1 = 0_u64;
+        let buf2 = 1_u64;
+        let buf3 = 0_u64;
+        let buf4 = 2_u64;
+
+        let mut set = HashSet::new();
+        assert!(set.insert(Ref::<_, u64>::from(buf1.as_bytes()).unwrap()));
+        assert!(set.insert(Ref::<_, u64>::from(buf2.as_bytes()).unwrap()));
+        // `buf3` is a distinct buffer, but it holds a value equal to `buf1`'s,
+        // so it must hash and compare equal to the existing key.
+        assert!(!set.insert(Ref::<_, u64>::from(buf3.as_bytes()).unwrap()));
+        assert_eq!(set.len(), 2);
+
+        assert!(set.contains(&Ref::<_, u64>::from(buf3.as_bytes()).unwrap()));
+        assert!(!set.contains(&Ref::<_, u64>::from(buf4.as_bytes()).unwrap()));
+    }
+
     #[test]
     fn test_ord() {
         let buf1 = 0_u64;
@@ -1327,4 +3171,34 @@ mod tests {
         let r2 = Ref::<_, u64>::from(buf2.as_bytes()).unwrap();
         assert!(r1 < r2);
     }
+
+    #[test]
+    fn test_byteorder_get_set() {
+        use crate::byteorder::{BigEndian, LittleEndian, F32, I16, U32};
+
+        let mut buf = [0x01, 0x02, 0x03, 0x04, 0xFE, 0xFF, 0, 0, 0, 0];
+
+        let (r, _) = Ref::<_, U32<BigEndian>>::from_prefix(&buf[..]).unwrap();
+        assert_eq!(r.get(), 0x01020304);
+        let (r, _) = Ref::<_, U32<LittleEndian>>::from_prefix(&buf[..]).unwrap();
+        assert_eq!(r.get(), 0x04030201);
+
+        {
+            let (_, mut r) = Ref::<_, I16<LittleEndian>>::from_suffix(&mut buf[..6]).unwrap();
+            assert_eq!(r.get(), -2);
+            r.set(-3);
+            assert_eq!(r.get(), -3);
+        }
+        assert_eq!(buf[4..6], [0xFD, 0xFF]);
+
+        {
+            let (_, mut r) = Ref::<_, F32<BigEndian>>::from_suffix(&mut buf[..]).unwrap();
+            r.set(1.5);
+        }
+        assert_eq!(buf[6..], 1.5f32.to_be_bytes());
+
+        let mut r = Ref::<_, U32<BigEndian>>::from(&mut buf[..4]).unwrap();
+        r.set(0x0A0B0C0D);
+        assert_eq!(buf[..4], [0x0A, 0x0B, 0x0C, 0x0D]);
+    }
 }
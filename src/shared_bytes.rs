@@ -0,0 +1,211 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+use alloc::sync::Arc;
+
+use super::*;
+
+/// An owned, reference-counted, immutable byte buffer which can be split.
+///
+/// Owned buffers such as `Vec<u8>` or `Box<[u8]>` can't implement
+/// [`SplitByteSlice`], since each half of a split would need to own part of
+/// the same allocation. `SharedBytes` instead shares ownership of its backing
+/// buffer: each `SharedBytes` holds a reference to the buffer along with the
+/// range of it which it views. Cloning or splitting a `SharedBytes` never
+/// copies or moves the buffer, so owned buffers can back splittable [`Ref`]s.
+///
+/// The buffer is freed once every `SharedBytes` viewing it has been dropped.
+/// Since the buffer is an `Arc<[u8]>`, `SharedBytes` is `Send` and `Sync`, and
+/// so the `Ref`s split from a single buffer may be parsed on different
+/// threads.
+///
+/// # Examples
+///
+/// ```
+/// use zerocopy::{Ref, SharedBytes};
+///
+/// let buf = SharedBytes::from(vec![0u8, 1, 2, 3, 4, 5]);
+/// let (header, body) = Ref::<_, [u8; 2]>::from_prefix(buf.clone()).unwrap();
+///
+/// assert_eq!(*header, [0, 1]);
+/// assert_eq!(body[..], [2, 3, 4, 5]);
+/// // `body` views the same allocation as `buf`.
+/// assert_eq!(body.as_ptr(), buf[2..].as_ptr());
+/// ```
+#[derive(Clone)]
+pub struct SharedBytes {
+    // INVARIANT: `start <= end <= buf.len()`.
+    buf: Arc<[u8]>,
+    start: usize,
+    end: usize,
+}
+
+impl SharedBytes {
+    /// Constructs a new `SharedBytes` which views all of `buf`.
+    #[must_use = "has no side effects"]
+    #[inline]
+    pub fn new(buf: Arc<[u8]>) -> SharedBytes {
+        let end = buf.len();
+        SharedBytes { buf, start: 0, end }
+    }
+}
+
+impl From<Arc<[u8]>> for SharedBytes {
+    #[inline]
+    fn from(buf: Arc<[u8]>) -> SharedBytes {
+        SharedBytes::new(buf)
+    }
+}
+
+/// Moves `buf` into a new reference-counted allocation.
+///
+/// Like `Arc::from(buf)`, this copies the contents of `buf` once. No further
+/// copies are made when the returned `SharedBytes` is cloned or split.
+impl From<Vec<u8>> for SharedBytes {
+    #[inline]
+    fn from(buf: Vec<u8>) -> SharedBytes {
+        SharedBytes::new(buf.into())
+    }
+}
+
+/// Moves `buf` into a new reference-counted allocation.
+///
+/// Like `Arc::from(buf)`, this copies the contents of `buf` once. No further
+/// copies are made when the returned `SharedBytes` is cloned or split.
+impl From<Box<[u8]>> for SharedBytes {
+    #[inline]
+    fn from(buf: Box<[u8]>) -> SharedBytes {
+        SharedBytes::new(buf.into())
+    }
+}
+
+impl Deref for SharedBytes {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        // SAFETY: By invariant on `SharedBytes`, `start <= end <= buf.len()`.
+        unsafe { self.buf.get_unchecked(self.start..self.end) }
+    }
+}
+
+impl Debug for SharedBytes {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SharedBytes").field(&self.deref()).finish()
+    }
+}
+
+// SAFETY: `SharedBytes`'s `Deref` impl returns `buf[start..end]`. `buf` is an
+// `Arc<[u8]>`, whose referent is immutable and is never moved while any `Arc`
+// referencing it exists, and `start` and `end` can only be modified by
+// `SharedBytes`'s own methods which take `self`, none of which are called by
+// methods on `ByteSlice` or its super-traits.
+unsafe impl ByteSlice for SharedBytes {}
+
+// SAFETY: `SharedBytes::clone` clones `buf` - producing an `Arc` with the same
+// referent - and copies `start` and `end`, so its `Deref` impl returns a slice
+// with the same address and length as `self`'s.
+unsafe impl CloneableByteSlice for SharedBytes {}
+
+// SAFETY: Given `self` viewing `buf[start..end]` and `mid <= end - start`,
+// `split_at_unchecked` returns handles to the same `buf` viewing
+// `buf[start..start + mid]` and `buf[start + mid..end]`, which have the
+// addresses and lengths required by `SplitByteSlice`'s safety invariant.
+unsafe impl SplitByteSlice for SharedBytes {
+    #[inline]
+    unsafe fn split_at_unchecked(self, mid: usize) -> (SharedBytes, SharedBytes) {
+        // By contract on the caller, `mid <= self.end - self.start`, so
+        // `self.start <= split <= self.end`; this addition can't overflow, and
+        // it preserves the invariant on both halves.
+        #[allow(clippy::arithmetic_side_effects)]
+        let split = self.start + mid;
+        let first = SharedBytes { buf: self.buf.clone(), start: self.start, end: split };
+        let second = SharedBytes { buf: self.buf, start: split, end: self.end };
+        (first, second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split() {
+        let buf = SharedBytes::from(vec![0u8, 1, 2, 3, 4, 5, 6, 7]);
+        let addr = buf.as_ptr();
+        assert_eq!(buf[..], [0, 1, 2, 3, 4, 5, 6, 7]);
+
+        // Neither clones nor splits copy the backing buffer.
+        let clone = buf.clone();
+        assert_eq!(clone.as_ptr(), addr);
+        assert_eq!(clone.len(), 8);
+
+        let (a, rest) = buf.split_at(2);
+        let (b, rest) = rest.split_at(3);
+        let (c, d) = rest.split_at(3);
+        assert_eq!(
+            (&a[..], &b[..], &c[..], &d[..]),
+            (&[0, 1][..], &[2, 3, 4][..], &[5, 6, 7][..], &[][..])
+        );
+        assert_eq!(a.as_ptr(), addr);
+        assert_eq!(b.as_ptr(), addr.wrapping_add(2));
+        assert_eq!(c.as_ptr(), addr.wrapping_add(5));
+        assert_eq!(d.as_ptr(), addr.wrapping_add(8));
+
+        // Clones of split-off halves view the same range as the original.
+        let b2 = b.clone();
+        assert_eq!(b2.as_ptr(), b.as_ptr());
+        assert_eq!(b2[..], b[..]);
+
+        // Splitting out of bounds returns the original.
+        let b = b.try_split_at(4).unwrap_err();
+        assert_eq!(b[..], [2, 3, 4]);
+        let (empty, b) = b.try_split_at(0).unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(b.as_ptr(), addr.wrapping_add(2));
+
+        // The halves outlive the original handle.
+        drop(clone);
+        assert_eq!(c[..], [5, 6, 7]);
+    }
+
+    #[test]
+    fn test_split_keeps_backing_alive() {
+        let arc: Arc<[u8]> = Arc::from(&[0u8, 1, 2, 3, 4, 5][..]);
+        let buf = SharedBytes::from(arc.clone());
+        assert_eq!(Arc::strong_count(&arc), 2);
+
+        // Each half holds its own reference to the buffer.
+        let (a, b) = buf.split_at(2);
+        assert_eq!(Arc::strong_count(&arc), 3);
+        drop(arc);
+
+        let (header, body) = Ref::<_, [u8; 2]>::from_prefix(b).unwrap();
+        drop(a);
+        // The buffer is still alive, and may be used from other threads.
+        let handle = std::thread::spawn(move || body[..].to_vec());
+        assert_eq!(*header, [2, 3]);
+        assert_eq!(handle.join().unwrap(), [4, 5]);
+    }
+
+    #[test]
+    fn test_ref() {
+        let buf = SharedBytes::from(vec![0u8, 1, 2, 3, 4, 5, 6, 7].into_boxed_slice());
+        let addr = buf.as_ptr();
+        let (header, body) = Ref::<_, [u8; 2]>::from_prefix(buf).unwrap();
+        assert_eq!(*header, [0, 1]);
+        assert_eq!(Ref::bytes(&header).as_ptr(), addr);
+
+        let (body, trailer) = Ref::<_, [[u8; 2]]>::from_prefix_with_elems(body, 2).unwrap();
+        assert_eq!(*body, [[2, 3], [4, 5]]);
+        assert_eq!(Ref::bytes(&body).as_ptr(), addr.wrapping_add(2));
+        assert_eq!(trailer[..], [6, 7]);
+        assert_eq!(trailer.as_ptr(), addr.wrapping_add(6));
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+//! Property-test helpers for checking implementations of zerocopy's traits.
+//!
+//! Crates which hand-write `unsafe impl`s of zerocopy's traits (rather than
+//! deriving them) can use the functions in this module to check, for
+//! particular byte sequences, that their impls uphold the properties that the
+//! rest of zerocopy relies on. The functions panic with a descriptive message
+//! if a property is violated, and so are intended to be called from tests,
+//! typically with byte sequences produced by a fuzzer or property-testing
+//! framework.
+//!
+//! Passing these checks does not prove that an impl is sound; they only check
+//! the behavior of the impl on the given inputs.
+//!
+//! # Examples
+//!
+//! ```
+//! use zerocopy::test_util;
+//!
+//! test_util::assert_from_into_roundtrip::<u32>(&[0xDE, 0xAD, 0xBE, 0xEF]);
+//! test_util::assert_try_from_rejects_invalid::<bool>(&[2]);
+//! ```
+
+use core::mem::size_of;
+
+use super::*;
+
+/// Asserts that `bytes` round-trips through `T`.
+///
+/// This reads a `T` from `bytes` using [`FromBytes::read_from`], and asserts
+/// that the bytes of the resulting value, as returned by
+/// [`IntoBytes::as_bytes`], are equal to `bytes`. It also asserts that
+/// [`FromZeros::new_zeroed`] produces a value whose bytes are all zero.
+///
+/// # Panics
+///
+/// Panics if `bytes.len() != size_of::<T>()`, or if either property does not
+/// hold.
+#[inline]
+#[track_caller]
+pub fn assert_from_into_roundtrip<T>(bytes: &[u8])
+where
+    T: FromBytes + IntoBytes + Immutable,
+{
+    let t = match T::read_from(bytes) {
+        Ok(t) => t,
+        Err(_) => panic!(
+            "expected {} bytes for `{}`, but got {}",
+            size_of::<T>(),
+            core::any::type_name::<T>(),
+            bytes.len()
+        ),
+    };
+    assert_eq!(
+        t.as_bytes(),
+        bytes,
+        "bytes did not round-trip through `{}`",
+        core::any::type_name::<T>()
+    );
+
+    let zeroed = T::new_zeroed();
+    assert!(
+        zeroed.as_bytes().iter().all(|&b| b == 0),
+        "`{}::new_zeroed()` is not all zeros: {:?}",
+        core::any::type_name::<T>(),
+        zeroed.as_bytes()
+    );
+}
+
+/// Asserts that `bytes` is rejected as an invalid `T`.
+///
+/// This asserts that [`TryFromBytes::try_read_from`] fails with a
+/// [`ValidityError`] when reading a `T` from `bytes`.
+///
+/// # Panics
+///
+/// Panics if `bytes.len() != size_of::<T>()`, or if `bytes` is accepted as a
+/// valid `T`.
+#[inline]
+#[track_caller]
+pub fn assert_try_from_rejects_invalid<T>(bytes: &[u8])
+where
+    T: TryFromBytes,
+{
+    match T::try_read_from(bytes) {
+        Err(TryReadError::Validity(_)) => {}
+        Err(TryReadError::Size(_)) => panic!(
+            "expected {} bytes for `{}`, but got {}",
+            size_of::<T>(),
+            core::any::type_name::<T>(),
+            bytes.len()
+        ),
+        Err(TryReadError::Alignment(i)) => match i {},
+        Ok(_) => panic!(
+            "expected {:?} to be rejected as an invalid `{}`, but it was accepted",
+            bytes,
+            core::any::type_name::<T>()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_into_roundtrip() {
+        assert_from_into_roundtrip::<u32>(&[0, 0, 0, 0]);
+        assert_from_into_roundtrip::<u32>(&[0xDE, 0xAD, 0xBE, 0xEF]);
+        assert_from_into_roundtrip::<[u8; 3]>(&[1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected 4 bytes for `u32`, but got 3")]
+    fn test_from_into_roundtrip_wrong_size() {
+        assert_from_into_roundtrip::<u32>(&[0, 0, 0]);
+    }
+
+    #[test]
+    fn test_try_from_rejects_invalid() {
+        assert_try_from_rejects_invalid::<bool>(&[2]);
+        assert_try_from_rejects_invalid::<NonZeroU32>(&[0, 0, 0, 0]);
+        assert_try_from_rejects_invalid::<char>(&0xD800u32.to_ne_bytes());
+    }
+
+    #[test]
+    #[should_panic(expected = "to be rejected as an invalid `bool`, but it was accepted")]
+    fn test_try_from_rejects_invalid_accepts() {
+        assert_try_from_rejects_invalid::<bool>(&[1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected 4 bytes for `u32`, but got 5")]
+    fn test_try_from_rejects_invalid_wrong_size() {
+        assert_try_from_rejects_invalid::<u32>(&[0; 5]);
+    }
+}
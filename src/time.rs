@@ -0,0 +1,237 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+//! Durations which can be parsed from and serialized to bytes.
+//!
+//! The layout of the standard library's [`core::time::Duration`] is not
+//! guaranteed, and so zerocopy cannot soundly implement [`TryFromBytes`] or
+//! [`IntoBytes`] for it. Instead, this module provides [`Duration`], which
+//! stores a whole number of seconds as a [`U64`] followed by a number of
+//! nanoseconds as a [`U32`], both in byte order `O`, and which converts to and
+//! from `core::time::Duration`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use zerocopy::{byteorder::LittleEndian, time::Duration, TryFromBytes};
+//!
+//! let bytes = [
+//!     // Seconds.
+//!     90, 0, 0, 0, 0, 0, 0, 0,
+//!     // Nanoseconds.
+//!     0x40, 0x42, 0x0F, 0x00,
+//! ];
+//! let d = Duration::<LittleEndian>::try_ref_from(&bytes[..]).unwrap();
+//! assert_eq!(d.get(), core::time::Duration::new(90, 1_000_000));
+//!
+//! // More than a second's worth of nanoseconds is rejected.
+//! let bytes = [0, 0, 0, 0, 0, 0, 0, 0, 0x00, 0xCA, 0x9A, 0x3B];
+//! assert!(Duration::<LittleEndian>::try_ref_from(&bytes[..]).is_err());
+//! ```
+//!
+//! [`TryFromBytes`]: crate::TryFromBytes
+//! [`IntoBytes`]: crate::IntoBytes
+//! [`U64`]: crate::byteorder::U64
+//! [`U32`]: crate::byteorder::U32
+
+use core::{
+    fmt::{self, Debug, Formatter},
+    time,
+};
+
+use super::*;
+
+/// The number of nanoseconds in a second.
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+/// A span of time stored as seconds and nanoseconds in a given byte order.
+///
+/// `Duration` has the layout of a `#[repr(C)]` struct containing a [`U64<O>`]
+/// number of seconds followed by a [`U32<O>`] number of nanoseconds, which is
+/// always less than 1,000,000,000. It has no padding and no alignment
+/// requirement, which makes it suitable for use as a field in an on-disk or
+/// on-the-wire structure. It can be constructed from a
+/// [`core::time::Duration`] using the [`new`] method, and converted back using
+/// the [`get`] method.
+///
+/// `Duration` implements [`TryFromBytes`], which rejects out-of-range
+/// nanoseconds, as well as [`FromZeros`], [`IntoBytes`], and [`Unaligned`].
+///
+/// [`U64<O>`]: crate::byteorder::U64
+/// [`U32<O>`]: crate::byteorder::U32
+/// [`new`]: Duration::new
+/// [`get`]: Duration::get
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(any(feature = "derive", test), derive(KnownLayout, Immutable, IntoBytes, Unaligned))]
+#[repr(C)]
+pub struct Duration<O> {
+    // INVARIANT: `nanos.get() < NANOS_PER_SEC`.
+    secs: U64<O>,
+    nanos: U32<O>,
+}
+
+#[cfg(not(any(feature = "derive", test)))]
+impl_known_layout!(O => Duration<O>);
+
+safety_comment! {
+    /// SAFETY:
+    /// `Duration<O>` is `repr(C)`, and its fields are a `U64<O>` followed by a
+    /// `U32<O>`, both of which have alignment 1. It thus has no padding, and
+    /// has alignment 1. `U64<O>` and `U32<O>` are `Immutable`, `IntoBytes`,
+    /// and `Unaligned`.
+    impl_or_verify!(O => Immutable for Duration<O>);
+    impl_or_verify!(O => IntoBytes for Duration<O>);
+    impl_or_verify!(O => Unaligned for Duration<O>);
+    /// SAFETY:
+    /// - `Duration<O>` has the same layout as `[u8; 12]`: its fields are a
+    ///   `[u8; 8]` and a `[u8; 4]` (each wrapped in a `repr(transparent)`
+    ///   type), laid out consecutively with no padding. Neither contains
+    ///   `UnsafeCell`s, so a `*mut Duration<O>` and the same pointer cast to
+    ///   `*mut [u8; 12]` refer to objects of the same size with `UnsafeCell`s
+    ///   at the same (empty set of) byte ranges.
+    /// - `is_bit_valid` is only called on initialized bytes, and any
+    ///   initialized bytes are a valid `[u8; 12]`.
+    /// - A `[u8; 12]` is a valid `Duration<O>` if its last four bytes, which
+    ///   hold `nanos`, encode a value less than `NANOS_PER_SEC` in byte order
+    ///   `O`. The closure only returns `true` in that case.
+    unsafe_impl!(O: ByteOrder => TryFromBytes for Duration<O>; |d: MaybeAligned<[u8; 12]>| {
+        let [_, _, _, _, _, _, _, _, n0, n1, n2, n3] = d.read_unaligned();
+        U32::<O>::from_bytes([n0, n1, n2, n3]).get() < NANOS_PER_SEC
+    });
+    /// SAFETY:
+    /// The all-zeros `Duration<O>` has zero nanoseconds, which is less than
+    /// `NANOS_PER_SEC`, and is thus valid.
+    unsafe_impl!(O: ByteOrder => FromZeros for Duration<O>);
+}
+
+impl<O: ByteOrder> Duration<O> {
+    /// Constructs a new `Duration` from a [`core::time::Duration`], possibly
+    /// performing an endianness swap to guarantee that the returned value has
+    /// endianness `O`.
+    #[must_use = "has no side effects"]
+    #[inline(always)]
+    pub const fn new(d: time::Duration) -> Duration<O> {
+        Duration { secs: U64::new(d.as_secs()), nanos: U32::new(d.subsec_nanos()) }
+    }
+
+    /// Returns the duration as a [`core::time::Duration`], possibly performing
+    /// an endianness swap to guarantee that the return value has the
+    /// endianness of the native platform.
+    #[must_use = "has no side effects"]
+    #[inline(always)]
+    pub fn get(self) -> time::Duration {
+        // `self.nanos < NANOS_PER_SEC` by invariant, so this does not carry
+        // into (and cannot overflow) the seconds.
+        time::Duration::new(self.secs.get(), self.nanos.get())
+    }
+
+    /// Returns the number of whole seconds in the duration.
+    #[must_use = "has no side effects"]
+    #[inline(always)]
+    pub const fn secs(self) -> U64<O> {
+        self.secs
+    }
+
+    /// Returns the fractional part of the duration, in nanoseconds.
+    ///
+    /// The returned value is always less than 1,000,000,000.
+    #[must_use = "has no side effects"]
+    #[inline(always)]
+    pub const fn subsec_nanos(self) -> U32<O> {
+        self.nanos
+    }
+}
+
+impl<O: ByteOrder> From<time::Duration> for Duration<O> {
+    #[inline(always)]
+    fn from(d: time::Duration) -> Duration<O> {
+        Duration::new(d)
+    }
+}
+
+impl<O: ByteOrder> From<Duration<O>> for time::Duration {
+    #[inline(always)]
+    fn from(d: Duration<O>) -> time::Duration {
+        d.get()
+    }
+}
+
+impl<O: ByteOrder> Debug for Duration<O> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.get(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::byteorder::{BigEndian, LittleEndian};
+
+    #[test]
+    fn test_round_trip() {
+        for d in [
+            time::Duration::ZERO,
+            time::Duration::new(1, 0),
+            time::Duration::new(0, NANOS_PER_SEC - 1),
+            time::Duration::from_millis(1_234_567),
+            time::Duration::MAX,
+        ] {
+            let le = Duration::<LittleEndian>::new(d);
+            assert_eq!(le.get(), d);
+            assert_eq!(time::Duration::from(le), d);
+            let le = Duration::<LittleEndian>::try_read_from(le.as_bytes()).unwrap();
+            assert_eq!(le.get(), d);
+
+            let be = Duration::<BigEndian>::from(d);
+            assert_eq!(be.get(), d);
+            let be = Duration::<BigEndian>::try_ref_from(be.as_bytes()).unwrap();
+            assert_eq!(be.get(), d);
+        }
+
+        assert_eq!(Duration::<LittleEndian>::new_zeroed().get(), time::Duration::ZERO);
+    }
+
+    #[test]
+    fn test_layout() {
+        let d = Duration::<BigEndian>::new(time::Duration::new(0x0102, 0x0304));
+        assert_eq!(d.as_bytes(), [0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 3, 4]);
+        assert_eq!(d.secs().get(), 0x0102);
+        assert_eq!(d.subsec_nanos().get(), 0x0304);
+        assert_eq!(format!("{:?}", d), format!("{:?}", d.get()));
+
+        let d = Duration::<LittleEndian>::new(time::Duration::new(0x0102, 0x0304));
+        assert_eq!(d.as_bytes(), [2, 1, 0, 0, 0, 0, 0, 0, 4, 3, 0, 0]);
+    }
+
+    #[test]
+    fn test_invalid_nanos() {
+        let mut bytes = Duration::<BigEndian>::new(time::Duration::new(7, NANOS_PER_SEC - 1))
+            .as_bytes()
+            .to_vec();
+        Duration::<BigEndian>::try_ref_from(&bytes[..]).unwrap();
+
+        // Exactly one second's worth of nanoseconds is out of range.
+        bytes[8..].copy_from_slice(&NANOS_PER_SEC.to_be_bytes());
+        assert!(matches!(
+            Duration::<BigEndian>::try_ref_from(&bytes[..]),
+            Err(TryCastError::Validity(_))
+        ));
+        bytes[8..].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert!(matches!(
+            Duration::<BigEndian>::try_read_from(&bytes[..]),
+            Err(TryReadError::Validity(_))
+        ));
+
+        // The same bytes are in range when interpreted in the other byte
+        // order.
+        bytes[8..].copy_from_slice(&NANOS_PER_SEC.to_be_bytes());
+        let d = Duration::<LittleEndian>::try_read_from(&bytes[..]).unwrap();
+        assert_eq!(d.get().subsec_nanos(), NANOS_PER_SEC.swap_bytes());
+    }
+}
@@ -423,9 +423,12 @@ safety_comment! {
         AtomicI16 [i16], AtomicI32 [i32], AtomicI8 [i8], AtomicIsize [isize],
         AtomicU16 [u16], AtomicU32 [u32], AtomicU8 [u8], AtomicUsize [usize],
     );
-    #[cfg(not(target_arch = "powerpc"))]
+    #[cfg(any(
+        all(zerocopy_target_has_atomic, target_has_atomic = "64"),
+        all(not(zerocopy_target_has_atomic), not(target_arch = "powerpc"))
+    ))]
     unsafe_impl_transparent_wrapper_for_atomic!(
-        #[cfg_attr(doc_cfg, doc(cfg(not(target_arch = "powerpc"))))]
+        #[cfg_attr(doc_cfg, doc(cfg(target_has_atomic = "64")))]
         core::sync::atomic::AtomicI64 [i64],
         core::sync::atomic::AtomicU64 [u64],
     );
@@ -607,6 +610,36 @@ where
     const_assert!(<T as ConstAssert>::DST_IS_NOT_ZST);
 }
 
+/// Computes the pointer metadata of a `T` whose size is `len`.
+///
+/// If `T` is sized, this returns `()`. If `T` is a slice DST, this returns the
+/// largest number of trailing slice elements for which a `T` fits in `len`
+/// bytes. This is the same element count computed by
+/// `DstLayout::validate_cast_and_convert_metadata`.
+///
+/// # Postconditions
+///
+/// If `len` is a valid size for `T`, then a `T` with the returned metadata has
+/// size `len`.
+///
+/// # Compile-Time Assertions
+///
+/// `T` must not have a zero-sized DST component.
+#[inline(always)]
+pub(crate) fn elems_for_valid_len<T>(len: usize) -> T::PointerMetadata
+where
+    T: crate::KnownLayout + ?Sized,
+{
+    assert_dst_is_not_zst::<T>();
+    // `elems_for_bytes` returns `None` for sized types, whose element count is
+    // ignored by `from_elem_count`. For slice DSTs, it returns `Some`, since
+    // `elem_size` is non-zero thanks to `assert_dst_is_not_zst`, and since, if
+    // `len` is a valid size for `T`, then `len >= offset`. Since a valid size
+    // is a multiple of `T`'s alignment, the returned count is exact.
+    let elems = T::LAYOUT.elems_for_bytes(len).unwrap_or(0);
+    crate::PointerMetadata::from_elem_count(elems)
+}
+
 /// Since we support multiple versions of Rust, there are often features which
 /// have been stabilized in the most recent stable release which do not yet
 /// exist (stably) on our MSRV. This module provides polyfills for those
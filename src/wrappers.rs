@@ -83,6 +83,8 @@ safety_comment! {
     ///   - Since `T` and `Unalign<T>` have the same fields, they have
     ///     `UnsafeCell`s at the same byte ranges (as required by
     ///     `unsafe_impl!`).
+    /// - `IntoBytesWithPadding`: `Unalign<T>` has the same fields as `T`, so
+    ///   it contains `UnsafeCell`s exactly when `T` does.
     impl_or_verify!(T => Unaligned for Unalign<T>);
     impl_or_verify!(T: Immutable => Immutable for Unalign<T>);
     impl_or_verify!(
@@ -92,6 +94,7 @@ safety_comment! {
     impl_or_verify!(T: FromZeros => FromZeros for Unalign<T>);
     impl_or_verify!(T: FromBytes => FromBytes for Unalign<T>);
     impl_or_verify!(T: IntoBytes => IntoBytes for Unalign<T>);
+    unsafe_impl!(T: IntoBytesWithPadding => IntoBytesWithPadding for Unalign<T>);
 }
 
 // Note that `Unalign: Clone` only if `T: Copy`. Since the inner `T` may not be
@@ -173,6 +176,56 @@ impl<T> Unalign<T> {
         }
     }
 
+    /// Returns a reference to the wrapped `T` if `self` happens to be properly
+    /// aligned.
+    ///
+    /// `try_as_aligned` checks the address of `self` against
+    /// `mem::align_of::<T>()` at runtime. If `self` is aligned, it returns
+    /// `Some`, allowing the wrapped `T` to be used in place rather than copied
+    /// out with [`get`]; otherwise, it returns `None`. This is useful for data
+    /// which is usually, but not always, aligned, and which is read in place
+    /// when possible and copied out otherwise:
+    ///
+    /// ```
+    /// use zerocopy::Unalign;
+    ///
+    /// fn sum(u: &Unalign<[u64; 4]>) -> u64 {
+    ///     match u.try_as_aligned() {
+    ///         Some(elems) => elems.iter().sum(),
+    ///         None => u.get().iter().sum(),
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(sum(&Unalign::new([1, 2, 3, 4])), 10);
+    /// ```
+    ///
+    /// `try_as_aligned` is equivalent to `self.try_deref().ok()`, and is
+    /// provided for callers which, like the one above, only need to know
+    /// whether the in-place fast path is available. Such callers have no use
+    /// for the [`AlignmentError`] returned by [`try_deref`], which exists to
+    /// give the caller back `self` and to describe the failure.
+    ///
+    /// [`get`]: Unalign::get
+    /// [`try_deref`]: Unalign::try_deref
+    #[must_use = "has no side effects"]
+    #[inline(always)]
+    pub fn try_as_aligned(&self) -> Option<&T> {
+        self.try_deref().ok()
+    }
+
+    /// Returns a mutable reference to the wrapped `T` if `self` happens to be
+    /// properly aligned.
+    ///
+    /// This is the mutable counterpart of [`try_as_aligned`], and is provided
+    /// for the same reason. It is equivalent to `self.try_deref_mut().ok()`.
+    ///
+    /// [`try_as_aligned`]: Unalign::try_as_aligned
+    #[must_use = "has no side effects"]
+    #[inline(always)]
+    pub fn try_as_aligned_mut(&mut self) -> Option<&mut T> {
+        self.try_deref_mut().ok()
+    }
+
     /// Returns a reference to the wrapped `T` without checking alignment.
     ///
     /// If `T: Unaligned`, then `Unalign<T>` implements[ `Deref`], and callers
@@ -331,6 +384,15 @@ impl<T: Copy> Unalign<T> {
     }
 }
 
+// `impl<T> From<Unalign<T>> for T` is forbidden by the orphan rules; use
+// `Unalign::into_inner` to convert in that direction.
+impl<T> From<T> for Unalign<T> {
+    #[inline(always)]
+    fn from(val: T) -> Unalign<T> {
+        Unalign::new(val)
+    }
+}
+
 impl<T: Unaligned> Deref for Unalign<T> {
     type Target = T;
 
@@ -394,6 +456,237 @@ impl<T: Unaligned + Display> Display for Unalign<T> {
     }
 }
 
+/// A byte slice which contains only ASCII bytes.
+///
+/// `Ascii` is to `[u8]` as `str` is to `[u8]`: it has the same layout as
+/// `[u8]`, but its [`TryFromBytes`] impl only accepts byte sequences in which
+/// every byte is less than `0x80`. Validating ASCII is cheaper than validating
+/// UTF-8, and is stricter, which makes `Ascii` useful for protocol fields which
+/// are specified to be ASCII.
+///
+/// An `Ascii` can be viewed as a `[u8]` via [`Deref`], or as a `str` via
+/// [`to_str`].
+///
+/// # Examples
+///
+/// ```
+/// use zerocopy::{Ascii, TryFromBytes};
+///
+/// let method = Ascii::try_ref_from(b"GET").unwrap();
+/// assert_eq!(method.to_str(), "GET");
+/// assert_eq!(&method[..], b"GET");
+///
+/// assert!(Ascii::try_ref_from(b"caf\xC3\xA9").is_err());
+/// ```
+///
+/// [`to_str`]: Ascii::to_str
+#[repr(transparent)]
+pub struct Ascii([u8]);
+
+safety_comment! {
+    /// SAFETY:
+    /// `Ascii` is `repr(transparent)` over `[u8]`, and so it has the same
+    /// layout as `[u8]`.
+    /// - `Immutable`: `[u8]` does not contain any `UnsafeCell`s.
+    /// - `FromZeros`: The zero byte is ASCII, so an all-zeros `Ascii` is
+    ///   valid.
+    /// - `IntoBytes`, `Unaligned`: `[u8]` is `IntoBytes` and `Unaligned`.
+    unsafe_impl!(Ascii: Immutable, FromZeros, IntoBytes, Unaligned);
+    /// SAFETY:
+    /// - The safety requirements for `unsafe_impl!` with an `is_bit_valid`
+    ///   closure:
+    ///   - Given `t: *mut Ascii` and `let r = *mut [u8]`, `r` refers to an
+    ///     object of the same size as that referred to by `t`. This is true
+    ///     because `Ascii` is `repr(transparent)` over `[u8]`. Neither `t` nor
+    ///     `r` contain `UnsafeCell`s because `[u8]` doesn't.
+    ///   - Since the closure takes a `&[u8]` argument, given a `Maybe<'a,
+    ///     Ascii>` which satisfies the preconditions of
+    ///     `TryFromBytes::<Ascii>::is_bit_valid`, it must be guaranteed that
+    ///     the memory referenced by that `MaybeValid` always contains a valid
+    ///     `[u8]`. `is_bit_valid`'s precondition requires that the referenced
+    ///     bytes are initialized, and `[u8]`'s only bit validity invariant is
+    ///     that its bytes must be initialized.
+    ///   - The impl must only return `true` for its argument if the original
+    ///     `Maybe<Ascii>` refers to a valid `Ascii`. `Ascii`'s only validity
+    ///     invariant beyond that of `[u8]` is that every byte is less than
+    ///     `0x80`, which is exactly what `<[u8]>::is_ascii` checks.
+    unsafe_impl!(Ascii: TryFromBytes; |candidate: MaybeAligned<[u8]>| {
+        let candidate = candidate.unaligned_as_ref();
+        candidate.is_ascii()
+    });
+    /// SAFETY: `Ascii` is `repr(transparent)` over `[u8]`, and so it has the
+    /// same layout as `[u8]`.
+    unsafe_impl_known_layout!(#[repr([u8])] Ascii);
+}
+
+impl Ascii {
+    /// Views these bytes as a `str`.
+    ///
+    /// This never fails, since ASCII is a subset of UTF-8.
+    #[must_use = "has no side effects"]
+    #[inline(always)]
+    pub fn to_str(&self) -> &str {
+        // SAFETY: `Ascii`'s validity invariant guarantees that every byte is
+        // less than `0x80`, and any such byte sequence is valid UTF-8.
+        unsafe { core::str::from_utf8_unchecked(&self.0) }
+    }
+}
+
+impl Deref for Ascii {
+    type Target = [u8];
+
+    #[inline(always)]
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl PartialEq for Ascii {
+    #[inline(always)]
+    fn eq(&self, other: &Ascii) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for Ascii {}
+
+impl Debug for Ascii {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.to_str(), f)
+    }
+}
+
+impl Display for Ascii {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.to_str(), f)
+    }
+}
+
+/// An array of byte-encoded booleans.
+///
+/// `BoolArray<N>` has the same layout as `[u8; N]`, but its [`TryFromBytes`]
+/// impl only accepts byte sequences in which every byte is `0` (`false`) or
+/// `1` (`true`) - the same validity check performed for `bool`. This makes it
+/// possible to parse arrays of byte-encoded flags without copying, and then
+/// access them as `bool`s.
+///
+/// A `BoolArray<N>` can be viewed as a `[bool; N]` via [`Deref`] and
+/// [`DerefMut`], and so can be indexed to read or write individual `bool`s.
+/// Its bytes can be accessed via [`IntoBytes`].
+///
+/// `BoolArray<N>` has the same layout and bit validity as `[bool; N]`, which
+/// also implements `TryFromBytes`. Unlike `[bool; N]`, `BoolArray<N>` can be
+/// named in generic code which expects a wrapper around bytes, and its
+/// [`Debug`] and [`PartialEq`] impls operate on `bool`s rather than bytes.
+///
+/// # Examples
+///
+/// ```
+/// use zerocopy::{BoolArray, TryFromBytes};
+///
+/// let flags = BoolArray::<4>::try_ref_from(&[1, 0, 0, 1]).unwrap();
+/// assert!(flags[0]);
+/// assert!(!flags[1]);
+/// assert_eq!(**flags, [true, false, false, true]);
+///
+/// // `2` is not a valid `bool`.
+/// assert!(BoolArray::<4>::try_ref_from(&[1, 0, 2, 1]).is_err());
+/// ```
+#[repr(transparent)]
+pub struct BoolArray<const N: usize>([u8; N]);
+
+safety_comment! {
+    /// SAFETY:
+    /// `BoolArray<N>` is `repr(transparent)` over `[u8; N]`, and so it has the
+    /// same layout as `[u8; N]`.
+    /// - `Immutable`: `[u8; N]` does not contain any `UnsafeCell`s.
+    /// - `FromZeros`: The zero byte is a valid `bool` (`false`), so an
+    ///   all-zeros `BoolArray<N>` is valid.
+    /// - `IntoBytes`, `Unaligned`: `[u8; N]` is `IntoBytes` and `Unaligned`.
+    unsafe_impl!(const N: usize => Immutable for BoolArray<N>);
+    unsafe_impl!(const N: usize => FromZeros for BoolArray<N>);
+    unsafe_impl!(const N: usize => IntoBytes for BoolArray<N>);
+    unsafe_impl!(const N: usize => Unaligned for BoolArray<N>);
+    /// SAFETY:
+    /// - The safety requirements for `unsafe_impl!` with an `is_bit_valid`
+    ///   closure:
+    ///   - Given `t: *mut BoolArray<N>` and `let r = *mut [u8; N]`, `r`
+    ///     refers to an object of the same size as that referred to by `t`.
+    ///     This is true because `BoolArray<N>` is `repr(transparent)` over
+    ///     `[u8; N]`. Neither `t` nor `r` contain `UnsafeCell`s because `[u8;
+    ///     N]` doesn't.
+    ///   - Since the closure takes a `&[u8; N]` argument, given a `Maybe<'a,
+    ///     BoolArray<N>>` which satisfies the preconditions of
+    ///     `TryFromBytes::<BoolArray<N>>::is_bit_valid`, it must be guaranteed
+    ///     that the memory referenced by that `MaybeValid` always contains a
+    ///     valid `[u8; N]`. `is_bit_valid`'s precondition requires that the
+    ///     referenced bytes are initialized, and `[u8; N]`'s only bit validity
+    ///     invariant is that its bytes must be initialized.
+    ///   - The impl must only return `true` for its argument if the original
+    ///     `Maybe<BoolArray<N>>` refers to a valid `BoolArray<N>`.
+    ///     `BoolArray<N>`'s only validity invariant beyond that of `[u8; N]`
+    ///     is that every byte is 0 or 1, which is exactly what this closure
+    ///     checks.
+    unsafe_impl!(const N: usize => TryFromBytes for BoolArray<N>; |candidate: MaybeAligned<[u8; N]>| {
+        candidate.unaligned_as_ref().iter().all(|byte| *byte < 2)
+    });
+}
+
+impl_known_layout!(const N: usize => BoolArray<N>);
+
+impl<const N: usize> From<[bool; N]> for BoolArray<N> {
+    #[inline(always)]
+    fn from(bools: [bool; N]) -> BoolArray<N> {
+        // INVARIANTS: `false` and `true` are represented as the bytes 0 and 1.
+        BoolArray(bools.map(u8::from))
+    }
+}
+
+impl<const N: usize> Deref for BoolArray<N> {
+    type Target = [bool; N];
+
+    #[inline(always)]
+    fn deref(&self) -> &[bool; N] {
+        let ptr: *const [u8; N] = &self.0;
+        // SAFETY: `[bool; N]` has the same size and alignment as `[u8; N]`.
+        // `BoolArray`'s validity invariant guarantees that every byte is 0 or
+        // 1, which are the bit patterns of `false` and `true` respectively.
+        // Neither type contains any `UnsafeCell`s.
+        unsafe { &*ptr.cast::<[bool; N]>() }
+    }
+}
+
+impl<const N: usize> DerefMut for BoolArray<N> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut [bool; N] {
+        let ptr: *mut [u8; N] = &mut self.0;
+        // SAFETY: `[bool; N]` has the same size and alignment as `[u8; N]`.
+        // `BoolArray`'s validity invariant guarantees that every byte is 0 or
+        // 1, which are the bit patterns of `false` and `true` respectively.
+        // Any value written through the returned `&mut [bool; N]` is a valid
+        // `[bool; N]`, and so preserves that invariant.
+        unsafe { &mut *ptr.cast::<[bool; N]>() }
+    }
+}
+
+impl<const N: usize> PartialEq for BoolArray<N> {
+    #[inline(always)]
+    fn eq(&self, other: &BoolArray<N>) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<const N: usize> Eq for BoolArray<N> {}
+
+impl<const N: usize> Debug for BoolArray<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.deref(), f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::panic::AssertUnwindSafe;
@@ -443,12 +736,17 @@ mod tests {
         assert_eq!(unsafe { u.t.deref_mut_unchecked() }, &mut AU64(123));
         *u.t.try_deref_mut().unwrap() = AU64(321);
         assert_eq!(u.t.get(), AU64(321));
+        assert_eq!(u.t.try_as_aligned(), Some(&AU64(321)));
+        *u.t.try_as_aligned_mut().unwrap() = AU64(123);
+        assert_eq!(u.t.get(), AU64(123));
 
         // Test methods that depend on alignment (when alignment is not
         // satisfied).
         let mut u: ForceUnalign<_, AU64> = ForceUnalign::new(Unalign::new(AU64(123)));
         assert!(matches!(u.t.try_deref(), Err(AlignmentError { .. })));
         assert!(matches!(u.t.try_deref_mut(), Err(AlignmentError { .. })));
+        assert_eq!(u.t.try_as_aligned(), None);
+        assert_eq!(u.t.try_as_aligned_mut(), None);
 
         // Test methods that depend on `T: Unaligned`.
         let mut u = Unalign::new(123u8);
@@ -481,6 +779,126 @@ mod tests {
         };
     }
 
+    #[test]
+    fn test_unalign_from() {
+        let u: Unalign<u32> = 5.into();
+        assert_eq!(u.get(), 5);
+        assert_eq!(u.into_inner(), 5);
+        assert_eq!(Unalign::from(AU64(123)).get(), AU64(123));
+
+        // `From` works in generic contexts, including for non-`Copy` types.
+        fn wrap<T, U: From<T>>(t: T) -> U {
+            t.into()
+        }
+        let u: Unalign<Box<u64>> = wrap(Box::new(7));
+        assert_eq!(*u.into_inner(), 7);
+    }
+
+    #[test]
+    fn test_unalign_field() {
+        // `Unalign<u64>` forwards `u64`'s trait impls, and so a struct
+        // containing one can derive them without `repr(packed)`.
+        #[derive(KnownLayout, Immutable, FromBytes, IntoBytes, Unaligned)]
+        #[repr(C)]
+        struct Foo {
+            a: u8,
+            b: Unalign<u64>,
+        }
+
+        assert_eq!(mem::size_of::<Foo>(), 9);
+        assert_eq!(mem::align_of::<Foo>(), 1);
+
+        let foo = Foo { a: 1, b: Unalign::new(0x0102_0304_0506_0708) };
+        let bytes = foo.as_bytes();
+        assert_eq!(bytes[0], 1);
+        assert_eq!(bytes[1..], 0x0102_0304_0506_0708u64.to_ne_bytes());
+
+        // `Foo` is `Unaligned`, so it can be read from any offset.
+        let mut buf = [0u8; 10];
+        buf[1..].copy_from_slice(bytes);
+        let r = Foo::ref_from(&buf[1..]).unwrap();
+        assert_eq!(r.a, 1);
+        assert_eq!(r.b.get(), 0x0102_0304_0506_0708);
+        let r = Foo::try_ref_from(&buf[1..]).unwrap();
+        assert_eq!(r.b.get(), 0x0102_0304_0506_0708);
+
+        let read = Foo::read_from(&buf[1..]).unwrap();
+        assert_eq!(read.as_bytes(), bytes);
+        assert_eq!(Foo::new_zeroed().as_bytes(), [0u8; 9]);
+
+        // Each trait is forwarded from `T` on its own, so `Unalign<T>` only
+        // implements the traits that `T` does (except `Unaligned`, which it
+        // always implements).
+        fn is_from_bytes<T: FromBytes + IntoBytes + Immutable + KnownLayout + Unaligned>() {}
+        fn is_try_from_bytes<T: TryFromBytes + IntoBytes + Immutable + KnownLayout + Unaligned>() {}
+        is_from_bytes::<Unalign<u64>>();
+        is_from_bytes::<Unalign<[AU64; 2]>>();
+        is_try_from_bytes::<Unalign<NonZeroU16>>();
+
+        // `Unalign<NonZeroU16>` forwards to `NonZeroU16`'s validity check.
+        assert!(Unalign::<NonZeroU16>::try_read_from(&[0, 0][..]).is_err());
+        let nz = Unalign::<NonZeroU16>::try_read_from(&1u16.to_ne_bytes()[..]).unwrap();
+        assert_eq!(nz.get().get(), 1);
+    }
+
+    #[test]
+    fn test_ascii() {
+        let a = Ascii::try_ref_from(b"hello, world!\0\x7F").unwrap();
+        assert_eq!(a.to_str(), "hello, world!\0\x7F");
+        assert_eq!(&a[..], b"hello, world!\0\x7F");
+        assert_eq!(a.as_bytes(), b"hello, world!\0\x7F");
+
+        let empty = Ascii::try_ref_from(&[]).unwrap();
+        assert_eq!(empty.to_str(), "");
+
+        // A single byte with the high bit set is rejected, even if the bytes
+        // are otherwise valid UTF-8.
+        assert!(Ascii::try_ref_from(b"abc\x80").is_err());
+        assert!(Ascii::try_ref_from(b"\xFF").is_err());
+        assert!(Ascii::try_ref_from("café".as_bytes()).is_err());
+
+        let mut bytes = *b"GET /";
+        let (a, rest) =
+            Ascii::try_mut_from_prefix_with_trailing_elements(&mut bytes[..], 3).unwrap();
+        assert_eq!(a.to_str(), "GET");
+        assert_eq!(rest, b" /");
+
+        assert_eq!(format!("{:?}", Ascii::try_ref_from(b"hi").unwrap()), "\"hi\"");
+        assert_eq!(format!("{}", Ascii::try_ref_from(b"hi").unwrap()), "hi");
+    }
+
+    #[test]
+    fn test_bool_array() {
+        let a = BoolArray::<4>::try_ref_from(&[0, 1, 1, 0]).unwrap();
+        assert_eq!(**a, [false, true, true, false]);
+        assert!(!a[0] && a[1]);
+        assert_eq!(a.as_bytes(), [0, 1, 1, 0]);
+        assert_eq!(format!("{:?}", a), "[false, true, true, false]");
+
+        assert_eq!(*BoolArray::<3>::try_ref_from(&[0; 3]).unwrap(), BoolArray::new_zeroed());
+        assert_eq!(**BoolArray::<3>::try_ref_from(&[1; 3]).unwrap(), [true; 3]);
+        assert!(BoolArray::<0>::try_ref_from(&[]).is_ok());
+
+        // Any byte other than 0 or 1 is rejected.
+        assert!(BoolArray::<4>::try_ref_from(&[0, 1, 2, 0]).is_err());
+        assert!(BoolArray::<1>::try_ref_from(&[0xFF]).is_err());
+        assert!(BoolArray::<2>::try_read_from(&[1, 0x80]).is_err());
+        // The size must match exactly.
+        assert!(BoolArray::<4>::try_ref_from(&[0, 1, 0]).is_err());
+
+        // Writes through `DerefMut` are reflected in the underlying bytes.
+        let mut bytes = [0u8, 1, 0];
+        let (a, rest) = BoolArray::<2>::try_mut_from_prefix(&mut bytes[..]).unwrap();
+        a[0] = true;
+        a[1] = false;
+        assert_eq!(rest, [0]);
+        assert_eq!(bytes, [1, 0, 0]);
+
+        let a = BoolArray::from([true, false, true]);
+        assert_eq!(a.as_bytes(), [1, 0, 1]);
+        assert_eq!(BoolArray::<3>::try_read_from(a.as_bytes()).unwrap(), a);
+    }
+
     #[test]
     fn test_unalign_update() {
         let mut u = Unalign::new(AU64(123));
@@ -0,0 +1 @@
+../ui-nightly/assert-from-bytes-not-from-bytes.rs
\ No newline at end of file
@@ -0,0 +1 @@
+../ui-nightly/require_layout_mismatch.rs
\ No newline at end of file
@@ -0,0 +1 @@
+../ui-nightly/transmute-generic-size-mismatch.rs
\ No newline at end of file
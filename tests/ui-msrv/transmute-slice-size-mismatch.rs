@@ -0,0 +1 @@
+../ui-nightly/transmute-slice-size-mismatch.rs
\ No newline at end of file
@@ -0,0 +1 @@
+../ui-nightly/try_transmute-size-decrease.rs
\ No newline at end of file
@@ -0,0 +1 @@
+../ui-nightly/try_transmute_mut-alignment-increase.rs
\ No newline at end of file
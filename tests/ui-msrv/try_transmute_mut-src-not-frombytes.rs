@@ -0,0 +1 @@
+../ui-nightly/try_transmute_mut-src-not-frombytes.rs
\ No newline at end of file
@@ -0,0 +1 @@
+../ui-nightly/try_transmute_ref-alignment-increase.rs
\ No newline at end of file
@@ -0,0 +1 @@
+../ui-nightly/try_transmute_ref-dst-not-immutable.rs
\ No newline at end of file
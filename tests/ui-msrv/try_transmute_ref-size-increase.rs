@@ -0,0 +1 @@
+../ui-nightly/try_transmute_ref-size-increase.rs
\ No newline at end of file
@@ -0,0 +1 @@
+../ui-nightly/zeroed-not-from-zeros.rs
\ No newline at end of file
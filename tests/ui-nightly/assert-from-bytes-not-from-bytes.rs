@@ -0,0 +1,22 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+include!("../../zerocopy-derive/tests/include.rs");
+
+#[macro_use]
+extern crate zerocopy;
+
+use util::NotZerocopy;
+
+fn main() {}
+
+// Should fail because `NotZerocopy<u32>: !FromBytes`.
+assert_from_bytes!(u32, NotZerocopy<u32>);
+
+// Should fail because `bool: !FromBytes`.
+assert_from_bytes!(bool);
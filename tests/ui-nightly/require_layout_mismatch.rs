@@ -0,0 +1,24 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+#[macro_use]
+extern crate zerocopy;
+
+fn main() {}
+
+#[repr(C)]
+struct Register {
+    status: u32,
+    control: u32,
+}
+
+// Should fail because `Register` is 8 bytes long, not 16.
+const _: () = require_layout!(Register, size = 16, align = 4);
+
+// Should fail because `Register` is 4-aligned, not 8-aligned.
+const _: () = require_layout!(Register, size = 8, align = 8);
@@ -0,0 +1,21 @@
+// Copyright 2023 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+extern crate zerocopy;
+
+use zerocopy::transmute;
+
+fn main() {}
+
+const fn truncate<const N: usize>(bytes: [u8; N]) -> [u16; N] {
+    transmute!(#![allow(generic)] bytes)
+}
+
+// `transmute!(#![allow(generic)] ...)` reports a size mismatch once the
+// invocation is monomorphized.
+const NARROW: [u16; 2] = truncate([1, 2]);
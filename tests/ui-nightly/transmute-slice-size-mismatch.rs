@@ -0,0 +1,17 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+extern crate zerocopy;
+
+use zerocopy::transmute_slice;
+
+fn main() {}
+
+// `transmute_slice!` requires that the source and destination element types
+// have the same size.
+const MISMATCHED_SIZE: &[[u8; 2]] = transmute_slice!(&[0u8; 2][..]);
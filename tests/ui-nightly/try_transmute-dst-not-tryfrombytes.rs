@@ -0,0 +1,19 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+include!("../../zerocopy-derive/tests/include.rs");
+
+extern crate zerocopy;
+
+use util::NotZerocopy;
+use zerocopy::try_transmute;
+
+// `try_transmute` requires that the destination type implements `TryFromBytes`
+fn main() {
+    let _dst_not_try_from_bytes: Result<NotZerocopy, _> = try_transmute!(0u8);
+}
@@ -0,0 +1,25 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+include!("../../zerocopy-derive/tests/include.rs");
+
+extern crate zerocopy;
+
+use zerocopy::try_transmute_mut;
+
+fn main() {}
+
+#[derive(zerocopy::TryFromBytes)]
+#[repr(C)]
+struct Dst(u8);
+
+// `try_transmute_mut!` requires that the destination type implements
+// `IntoBytes`.
+fn dst_not_into_bytes() {
+    let _: Result<&mut Dst, _> = try_transmute_mut!(&mut 0u8);
+}
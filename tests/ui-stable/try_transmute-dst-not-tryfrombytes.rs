@@ -0,0 +1 @@
+../ui-nightly/try_transmute-dst-not-tryfrombytes.rs
\ No newline at end of file
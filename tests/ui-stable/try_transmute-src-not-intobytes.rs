@@ -0,0 +1 @@
+../ui-nightly/try_transmute-src-not-intobytes.rs
\ No newline at end of file
@@ -0,0 +1 @@
+../ui-nightly/try_transmute_mut-dst-not-intobytes.rs
\ No newline at end of file
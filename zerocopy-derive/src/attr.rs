@@ -0,0 +1,177 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+//! Parsing of `#[zerocopy(...)]` helper attributes.
+
+use {
+    proc_macro2::Span,
+    syn::punctuated::Punctuated,
+    syn::spanned::Spanned,
+    syn::token::Comma,
+    syn::{Attribute, Error, Expr, Meta, Path},
+};
+
+/// The options parsed from all of a type's `#[zerocopy(...)]` attributes.
+#[derive(Default)]
+pub(crate) struct ZerocopyAttrs {
+    /// Set by `#[zerocopy(byte_array_conv)]` or
+    /// `#[zerocopy(byte_array_conv(N))]`.
+    pub(crate) byte_array_conv: Option<ByteArrayConv>,
+    /// Set by `#[zerocopy(validate = path::to_fn)]`.
+    pub(crate) validate: Option<Path>,
+    /// Set by `#[zerocopy(crate = path::to::zerocopy)]`.
+    pub(crate) zerocopy_crate: Option<Path>,
+    /// Set by `#[zerocopy(as_bytes_padded)]`. Holds the span of the option,
+    /// used for error reporting.
+    pub(crate) as_bytes_padded: Option<Span>,
+    /// Set by `#[zerocopy(exhaustive)]`. Holds the span of the option, used for
+    /// error reporting.
+    pub(crate) exhaustive: Option<Span>,
+}
+
+pub(crate) struct ByteArrayConv {
+    /// The explicitly-specified array length, if any.
+    pub(crate) len: Option<Expr>,
+    /// The span of the `byte_array_conv` option, used for error reporting.
+    pub(crate) span: Span,
+}
+
+/// Parses all of the `#[zerocopy(...)]` attributes in `attrs`, ignoring any
+/// other attributes.
+pub(crate) fn zerocopy_attrs(attrs: &[Attribute]) -> Result<ZerocopyAttrs, Vec<Error>> {
+    let mut parsed = ZerocopyAttrs::default();
+    let mut errors = Vec::new();
+    for attr in attrs {
+        if !attr.path().is_ident("zerocopy") {
+            continue;
+        }
+
+        let metas = match attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated) {
+            Ok(metas) => metas,
+            Err(err) => {
+                errors.push(err);
+                continue;
+            }
+        };
+
+        for meta in metas {
+            if meta.path().is_ident("validate") {
+                parse_path(
+                    &meta,
+                    "validate",
+                    "path::to_fn",
+                    "a path to a function",
+                    &mut parsed.validate,
+                    &mut errors,
+                );
+                continue;
+            }
+
+            if meta.path().is_ident("crate") {
+                parse_path(
+                    &meta,
+                    "crate",
+                    "path::to::zerocopy",
+                    "a path to a crate",
+                    &mut parsed.zerocopy_crate,
+                    &mut errors,
+                );
+                continue;
+            }
+
+            if meta.path().is_ident("as_bytes_padded") {
+                parse_flag(&meta, "as_bytes_padded", &mut parsed.as_bytes_padded, &mut errors);
+                continue;
+            }
+
+            if meta.path().is_ident("exhaustive") {
+                parse_flag(&meta, "exhaustive", &mut parsed.exhaustive, &mut errors);
+                continue;
+            }
+
+            if !meta.path().is_ident("byte_array_conv") {
+                errors.push(Error::new_spanned(meta.path(), "unrecognized zerocopy attribute"));
+                continue;
+            }
+
+            let len = match &meta {
+                Meta::Path(_) => None,
+                Meta::List(list) => match list.parse_args::<Expr>() {
+                    Ok(len) => Some(len),
+                    Err(err) => {
+                        errors.push(err);
+                        continue;
+                    }
+                },
+                Meta::NameValue(_) => {
+                    errors.push(Error::new_spanned(
+                        &meta,
+                        "expected `byte_array_conv` or `byte_array_conv(N)`",
+                    ));
+                    continue;
+                }
+            };
+
+            if parsed.byte_array_conv.is_some() {
+                errors.push(Error::new_spanned(&meta, "duplicate `byte_array_conv` attribute"));
+                continue;
+            }
+            parsed.byte_array_conv = Some(ByteArrayConv { len, span: meta.span() });
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(parsed)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Parses a path-valued option (e.g., `#[zerocopy(validate = path::to_fn)]`)
+/// named `name`, storing the path in `path`. `example` and `what` describe the
+/// expected value in error messages.
+fn parse_path(
+    meta: &Meta,
+    name: &str,
+    example: &str,
+    what: &str,
+    path: &mut Option<Path>,
+    errors: &mut Vec<Error>,
+) {
+    let value = match meta {
+        Meta::NameValue(nv) => match &nv.value {
+            Expr::Path(value) if value.qself.is_none() => value.path.clone(),
+            value => {
+                errors.push(Error::new_spanned(value, format!("expected {}", what)));
+                return;
+            }
+        },
+        _ => {
+            errors.push(Error::new_spanned(meta, format!("expected `{} = {}`", name, example)));
+            return;
+        }
+    };
+
+    if path.is_some() {
+        errors.push(Error::new_spanned(meta, format!("duplicate `{}` attribute", name)));
+    } else {
+        *path = Some(value);
+    }
+}
+
+/// Parses a flag option (e.g., `#[zerocopy(exhaustive)]`) named `name`, storing
+/// its span in `flag`.
+fn parse_flag(meta: &Meta, name: &str, flag: &mut Option<Span>, errors: &mut Vec<Error>) {
+    if !matches!(meta, Meta::Path(_)) {
+        errors.push(Error::new_spanned(meta, format!("expected `{}`", name)));
+    } else if flag.is_some() {
+        errors.push(Error::new_spanned(meta, format!("duplicate `{}` attribute", name)));
+    } else {
+        *flag = Some(meta.span());
+    }
+}
@@ -28,6 +28,7 @@
 )]
 #![recursion_limit = "128"]
 
+mod attr;
 mod ext;
 mod repr;
 
@@ -37,12 +38,12 @@ use {
     proc_macro2::Span,
     quote::quote,
     syn::{
-        parse_quote, parse_quote_spanned, Data, DataEnum, DataStruct, DataUnion, DeriveInput,
-        Error, Expr, ExprLit, GenericParam, Ident, Lit, Path, Type, WherePredicate,
+        parse_quote, parse_quote_spanned, spanned::Spanned, Data, DataEnum, DataStruct, DataUnion,
+        DeriveInput, Error, Expr, ExprLit, GenericParam, Ident, Lit, Path, Type, WherePredicate,
     },
 };
 
-use {crate::ext::*, crate::repr::*};
+use {crate::attr::ZerocopyAttrs, crate::ext::*, crate::repr::*};
 
 // Unwraps a `Result<_, Vec<Error>>`, converting any `Err` value into a
 // `TokenStream` and returning it.
@@ -75,31 +76,48 @@ macro_rules! try_or_print {
 /// Defines a derive function named `$outer` which parses its input
 /// `TokenStream` as a `DeriveInput` and then invokes the `$inner` function.
 ///
+/// Every derive accepts `#[zerocopy(...)]` attributes. Before invoking
+/// `$inner`, `$outer` parses them exactly once, and passes both the parsed
+/// attributes and the path to the zerocopy crate - either `::zerocopy` or the
+/// path given by `#[zerocopy(crate = ...)]` - which `$inner` must use to refer
+/// to zerocopy's items in the code it generates. `$inner` and its helpers must
+/// not re-parse the attributes.
+/// Malformed attributes are reported by every derive on the type, but since
+/// each reports the same errors with the same spans, rustc only reports each
+/// error once.
+///
 /// Note that the separate `$outer` parameter is required - proc macro functions
 /// are currently required to live at the crate root, and so the caller must
 /// specify the name in order to avoid name collisions.
 macro_rules! derive {
     ($trait:ident => $outer:ident => $inner:ident) => {
-        #[proc_macro_derive($trait)]
+        #[proc_macro_derive($trait, attributes(zerocopy))]
         pub fn $outer(ts: proc_macro::TokenStream) -> proc_macro::TokenStream {
             let ast = syn::parse_macro_input!(ts as DeriveInput);
-            $inner(&ast).into()
+            let attrs = try_or_print!(attr::zerocopy_attrs(&ast.attrs));
+            let zerocopy_crate =
+                attrs.zerocopy_crate.clone().unwrap_or_else(|| parse_quote!(::zerocopy));
+            $inner(&ast, &attrs, &zerocopy_crate).into()
         }
     };
 }
 
 derive!(KnownLayout => derive_known_layout => derive_known_layout_inner);
 derive!(Immutable => derive_no_cell => derive_no_cell_inner);
-derive!(TryFromBytes => derive_try_from_bytes => derive_try_from_bytes_inner);
+derive!(TryFromBytes => derive_try_from_bytes => derive_try_from_bytes_outer);
 derive!(FromZeros => derive_from_zeros => derive_from_zeros_inner);
 derive!(FromBytes => derive_from_bytes => derive_from_bytes_inner);
 derive!(IntoBytes => derive_into_bytes => derive_into_bytes_inner);
+derive!(
+    IntoBytesWithPadding => derive_into_bytes_with_padding => derive_into_bytes_with_padding_inner
+);
 derive!(Unaligned => derive_unaligned => derive_unaligned_inner);
+derive!(ByteEq => derive_byte_eq => derive_byte_eq_inner);
 
 /// Deprecated: prefer [`FromZeros`] instead.
 #[deprecated(since = "0.8.0", note = "`FromZeroes` was renamed to `FromZeros`")]
 #[doc(hidden)]
-#[proc_macro_derive(FromZeroes)]
+#[proc_macro_derive(FromZeroes, attributes(zerocopy))]
 pub fn derive_from_zeroes(ts: proc_macro::TokenStream) -> proc_macro::TokenStream {
     derive_from_zeros(ts)
 }
@@ -107,16 +125,57 @@ pub fn derive_from_zeroes(ts: proc_macro::TokenStream) -> proc_macro::TokenStrea
 /// Deprecated: prefer [`IntoBytes`] instead.
 #[deprecated(since = "0.8.0", note = "`AsBytes` was renamed to `IntoBytes`")]
 #[doc(hidden)]
-#[proc_macro_derive(AsBytes)]
+#[proc_macro_derive(AsBytes, attributes(zerocopy))]
 pub fn derive_as_bytes(ts: proc_macro::TokenStream) -> proc_macro::TokenStream {
     derive_into_bytes(ts)
 }
 
-fn derive_known_layout_inner(ast: &DeriveInput) -> proc_macro2::TokenStream {
+/// Is `ty` syntactically a slice or `str` (and thus certainly unsized)?
+fn is_slice_or_str(ty: &Type) -> bool {
+    match ty {
+        Type::Slice(_) => true,
+        Type::Path(path) => path.qself.is_none() && path.path.is_ident("str"),
+        Type::Group(group) => is_slice_or_str(&group.elem),
+        Type::Paren(paren) => is_slice_or_str(&paren.elem),
+        _ => false,
+    }
+}
+
+fn derive_known_layout_inner(
+    ast: &DeriveInput,
+    _attrs: &ZerocopyAttrs,
+    zerocopy_crate: &Path,
+) -> proc_macro2::TokenStream {
     let is_repr_c_struct = match &ast.data {
-        Data::Struct(..) => {
+        Data::Struct(strct) => {
             let reprs = try_or_print!(repr::reprs::<Repr>(&ast.attrs));
-            if reprs.iter().any(|(_meta, repr)| repr == &Repr::C) {
+            // A `repr(transparent)` struct has the same layout as its one field
+            // with non-trivial size or alignment; all of its other fields are
+            // 1-ZSTs (i.e., have size 0 and alignment 1) [1]. Laying out its
+            // fields as though it were `repr(C)` places that field at offset 0
+            // and gives `Self` that field's size and alignment, so its layout
+            // can be reflected in the same way as that of a `repr(C)` struct.
+            //
+            // [1] Per https://doc.rust-lang.org/1.81.0/reference/type-layout.html#the-transparent-representation:
+            //
+            //   The `transparent` representation can only be used on a
+            //   `struct` or an `enum` with a single variant that has:
+            //   - any number of fields with size 0 and alignment 1 (e.g.
+            //     `PhantomData<T>`), and
+            //   - at most one other field.
+            //
+            //   Structs and enums with this representation have the same
+            //   layout and ABI as the only non-size 0 non-alignment 1 field,
+            //   if present, or unit otherwise.
+            //
+            // Reflecting the layout requires the trailing field to be
+            // `KnownLayout`, so we only do this for `repr(transparent)` structs
+            // whose trailing field is certainly unsized. Other
+            // `repr(transparent)` structs are handled as `Sized` below, which
+            // places no bound on their fields.
+            let is_transparent_dst = reprs.iter().any(|(_meta, repr)| repr == &Repr::Transparent)
+                && strct.fields().last().map_or(false, |(_name, ty)| is_slice_or_str(ty));
+            if is_transparent_dst || reprs.iter().any(|(_meta, repr)| repr == &Repr::C) {
                 Some(reprs)
             } else {
                 None
@@ -133,7 +192,7 @@ fn derive_known_layout_inner(ast: &DeriveInput) -> proc_macro2::TokenStream {
         let (_name, trailing_field_ty) = trailing_field;
         let leading_fields_tys = leading_fields.iter().map(|(_name, ty)| ty);
 
-        let core_path = quote!(::zerocopy::macro_util::core_reexport);
+        let core_path = quote!(#zerocopy_crate::macro_util::core_reexport);
         let repr_align = reprs
             .iter()
             .find_map(
@@ -161,7 +220,7 @@ fn derive_known_layout_inner(ast: &DeriveInput) -> proc_macro2::TokenStream {
         (
             SelfBounds::None,
             quote!(
-                type PointerMetadata = <#trailing_field_ty as ::zerocopy::KnownLayout>::PointerMetadata;
+                type PointerMetadata = <#trailing_field_ty as #zerocopy_crate::KnownLayout>::PointerMetadata;
 
                 // SAFETY: `LAYOUT` accurately describes the layout of `Self`.
                 // The layout of `Self` is reflected using a sequence of
@@ -176,10 +235,13 @@ fn derive_known_layout_inner(ast: &DeriveInput) -> proc_macro2::TokenStream {
                 // We respect all three of these preconditions here. This
                 // expansion is only used if `is_repr_c_struct`, we enumerate
                 // the fields in order, and we extract the values of `align(N)`
-                // and `packed(N)`.
-                const LAYOUT: ::zerocopy::DstLayout = {
-                    use ::zerocopy::macro_util::core_reexport::num::NonZeroUsize;
-                    use ::zerocopy::{DstLayout, KnownLayout};
+                // and `packed(N)`. `is_repr_c_struct` also holds for
+                // `repr(transparent)` slice DSTs, whose layout is identical to
+                // that which they would have if they were `repr(C)`, since
+                // all but at most one of their fields are 1-ZSTs.
+                const LAYOUT: #zerocopy_crate::DstLayout = {
+                    use #zerocopy_crate::macro_util::core_reexport::num::NonZeroUsize;
+                    use #zerocopy_crate::{DstLayout, KnownLayout};
 
                     let repr_align = #repr_align;
                     let repr_packed = #repr_packed;
@@ -196,20 +258,20 @@ fn derive_known_layout_inner(ast: &DeriveInput) -> proc_macro2::TokenStream {
                 // - `NonNull::new_unchecked` preserves both address and provenance.
                 #[inline(always)]
                 fn raw_from_ptr_len(
-                    bytes: ::zerocopy::macro_util::core_reexport::ptr::NonNull<u8>,
+                    bytes: #zerocopy_crate::macro_util::core_reexport::ptr::NonNull<u8>,
                     meta: Self::PointerMetadata,
-                ) -> ::zerocopy::macro_util::core_reexport::ptr::NonNull<Self> {
-                    use ::zerocopy::KnownLayout;
+                ) -> #zerocopy_crate::macro_util::core_reexport::ptr::NonNull<Self> {
+                    use #zerocopy_crate::KnownLayout;
                     let trailing = <#trailing_field_ty as KnownLayout>::raw_from_ptr_len(bytes, meta);
                     let slf = trailing.as_ptr() as *mut Self;
                     // SAFETY: Constructed from `trailing`, which is non-null.
-                    unsafe { ::zerocopy::macro_util::core_reexport::ptr::NonNull::new_unchecked(slf) }
+                    unsafe { #zerocopy_crate::macro_util::core_reexport::ptr::NonNull::new_unchecked(slf) }
                 }
 
                 #[inline(always)]
-                fn pointer_to_metadata(ptr: ::zerocopy::macro_util::core_reexport::ptr::NonNull<Self>) -> Self::PointerMetadata {
+                fn pointer_to_metadata(ptr: #zerocopy_crate::macro_util::core_reexport::ptr::NonNull<Self>) -> Self::PointerMetadata {
                     // SAFETY: `ptr` is non-null.
-                    let ptr = unsafe { ::zerocopy::macro_util::core_reexport::ptr::NonNull::new_unchecked(ptr.as_ptr() as *mut _) };
+                    let ptr = unsafe { #zerocopy_crate::macro_util::core_reexport::ptr::NonNull::new_unchecked(ptr.as_ptr() as *mut _) };
                     <#trailing_field_ty>::pointer_to_metadata(ptr)
                 }
             ),
@@ -226,7 +288,7 @@ fn derive_known_layout_inner(ast: &DeriveInput) -> proc_macro2::TokenStream {
                 // SAFETY: `LAYOUT` is guaranteed to accurately describe the
                 // layout of `Self`, because that is the documented safety
                 // contract of `DstLayout::for_type`.
-                const LAYOUT: ::zerocopy::DstLayout = ::zerocopy::DstLayout::for_type::<Self>();
+                const LAYOUT: #zerocopy_crate::DstLayout = #zerocopy_crate::DstLayout::for_type::<Self>();
 
                 // SAFETY: `.cast` preserves address and provenance.
                 //
@@ -234,15 +296,15 @@ fn derive_known_layout_inner(ast: &DeriveInput) -> proc_macro2::TokenStream {
                 // it preserves provenance.
                 #[inline(always)]
                 fn raw_from_ptr_len(
-                    bytes: ::zerocopy::macro_util::core_reexport::ptr::NonNull<u8>,
+                    bytes: #zerocopy_crate::macro_util::core_reexport::ptr::NonNull<u8>,
                     _meta: (),
-                ) -> ::zerocopy::macro_util::core_reexport::ptr::NonNull<Self> {
+                ) -> #zerocopy_crate::macro_util::core_reexport::ptr::NonNull<Self> {
                     bytes.cast::<Self>()
                 }
 
                 #[inline(always)]
                 fn pointer_to_metadata(
-                    _ptr: ::zerocopy::macro_util::core_reexport::ptr::NonNull<Self>,
+                    _ptr: #zerocopy_crate::macro_util::core_reexport::ptr::NonNull<Self>,
                 ) -> () {
                 }
             ),
@@ -263,6 +325,7 @@ fn derive_known_layout_inner(ast: &DeriveInput) -> proc_macro2::TokenStream {
             // `KnownLayout`.
             impl_block(
                 ast,
+                zerocopy_crate,
                 strct,
                 Trait::KnownLayout,
                 require_trait_bound_on_field_types,
@@ -276,6 +339,7 @@ fn derive_known_layout_inner(ast: &DeriveInput) -> proc_macro2::TokenStream {
             // currently be unsized.
             impl_block(
                 ast,
+                zerocopy_crate,
                 enm,
                 Trait::KnownLayout,
                 FieldBounds::None,
@@ -289,6 +353,7 @@ fn derive_known_layout_inner(ast: &DeriveInput) -> proc_macro2::TokenStream {
             // cannot currently be unsized.
             impl_block(
                 ast,
+                zerocopy_crate,
                 unn,
                 Trait::KnownLayout,
                 FieldBounds::None,
@@ -300,10 +365,15 @@ fn derive_known_layout_inner(ast: &DeriveInput) -> proc_macro2::TokenStream {
     }
 }
 
-fn derive_no_cell_inner(ast: &DeriveInput) -> proc_macro2::TokenStream {
+fn derive_no_cell_inner(
+    ast: &DeriveInput,
+    _attrs: &ZerocopyAttrs,
+    zerocopy_crate: &Path,
+) -> proc_macro2::TokenStream {
     match &ast.data {
         Data::Struct(strct) => impl_block(
             ast,
+            zerocopy_crate,
             strct,
             Trait::Immutable,
             FieldBounds::ALL_SELF,
@@ -313,6 +383,7 @@ fn derive_no_cell_inner(ast: &DeriveInput) -> proc_macro2::TokenStream {
         ),
         Data::Enum(enm) => impl_block(
             ast,
+            zerocopy_crate,
             enm,
             Trait::Immutable,
             FieldBounds::ALL_SELF,
@@ -322,6 +393,7 @@ fn derive_no_cell_inner(ast: &DeriveInput) -> proc_macro2::TokenStream {
         ),
         Data::Union(unn) => impl_block(
             ast,
+            zerocopy_crate,
             unn,
             Trait::Immutable,
             FieldBounds::ALL_SELF,
@@ -332,67 +404,392 @@ fn derive_no_cell_inner(ast: &DeriveInput) -> proc_macro2::TokenStream {
     }
 }
 
-fn derive_try_from_bytes_inner(ast: &DeriveInput) -> proc_macro2::TokenStream {
+fn derive_try_from_bytes_outer(
+    ast: &DeriveInput,
+    attrs: &ZerocopyAttrs,
+    zerocopy_crate: &Path,
+) -> proc_macro2::TokenStream {
+    let try_from_bytes =
+        derive_try_from_bytes_inner(ast, attrs, zerocopy_crate, Trait::TryFromBytes);
+    let exhaustive = attrs.exhaustive.map(|span| derive_exhaustive_enum(ast, zerocopy_crate, span));
+    IntoIterator::into_iter([Some(try_from_bytes), exhaustive]).flatten().collect()
+}
+
+// A field-less enum annotated with `#[zerocopy(exhaustive)]` additionally
+// implements `FromZeros` and `FromBytes` if:
+// - it has a repr which guarantees its size (see `derive_from_bytes_enum`)
+// - it has a variant for every possible discriminant value
+//
+// These are exactly the conditions checked by `derive_from_bytes_enum`; an enum
+// which satisfies them has a variant with the discriminant `0`, and so also
+// satisfies `derive_from_zeros_enum`.
+
+fn derive_exhaustive_enum(
+    ast: &DeriveInput,
+    zerocopy_crate: &Path,
+    span: Span,
+) -> proc_macro2::TokenStream {
     match &ast.data {
-        Data::Struct(strct) => derive_try_from_bytes_struct(ast, strct),
-        Data::Enum(enm) => derive_try_from_bytes_enum(ast, enm),
-        Data::Union(unn) => derive_try_from_bytes_union(ast, unn),
+        Data::Enum(enm) if enm.is_fieldless() => {
+            let from_zeros = derive_from_zeros_enum(ast, zerocopy_crate, enm);
+            let from_bytes = derive_from_bytes_enum(ast, zerocopy_crate, enm);
+            IntoIterator::into_iter([from_zeros, from_bytes]).collect()
+        }
+        _ => Error::new(span, "`exhaustive` is only supported on field-less enums")
+            .to_compile_error(),
     }
 }
 
-fn derive_from_zeros_inner(ast: &DeriveInput) -> proc_macro2::TokenStream {
-    let try_from_bytes = derive_try_from_bytes_inner(ast);
+// `top_level` is `Trait::FromZeros` when this is invoked by
+// `derive_from_zeros_inner` (including on behalf of `derive(FromBytes)`).
+fn derive_try_from_bytes_inner(
+    ast: &DeriveInput,
+    attrs: &ZerocopyAttrs,
+    zerocopy_crate: &Path,
+    top_level: Trait,
+) -> proc_macro2::TokenStream {
+    let validate = attrs.validate.as_ref();
+
+    // A `FromZeros` type must be valid when zeroed, and a `FromBytes` type must
+    // be valid for any initialized bytes, so neither can support a custom
+    // validator. We still emit the impl so that the error isn't accompanied by
+    // spurious trait errors.
+    let error = match validate {
+        Some(validate) if top_level != Trait::TryFromBytes => Some(Error::new_spanned(
+            validate,
+            "`validate` is not supported when deriving `FromZeros` or `FromBytes`",
+        )),
+        Some(validate) if !matches!(ast.data, Data::Struct(_)) => {
+            Some(Error::new_spanned(validate, "`validate` is only supported on structs"))
+        }
+        _ => None,
+    };
+    let (validate, error) = match error {
+        Some(error) => (None, Some(error.to_compile_error())),
+        None => (validate, None),
+    };
+
+    // `exhaustive` emits `FromZeros` and `FromBytes` impls on behalf of
+    // `derive(TryFromBytes)`, which would conflict with those emitted by
+    // `derive(FromZeros)` or `derive(FromBytes)`.
+    let exhaustive_error = match attrs.exhaustive {
+        Some(span) if top_level != Trait::TryFromBytes => Some(
+            Error::new(
+                span,
+                "`exhaustive` is not supported when deriving `FromZeros` or `FromBytes`; it implies them when deriving `TryFromBytes`",
+            )
+            .to_compile_error(),
+        ),
+        _ => None,
+    };
+
+    let try_from_bytes = match &ast.data {
+        Data::Struct(strct) => derive_try_from_bytes_struct(ast, zerocopy_crate, strct, validate),
+        Data::Enum(enm) => derive_try_from_bytes_enum(ast, zerocopy_crate, enm),
+        Data::Union(unn) => derive_try_from_bytes_union(ast, zerocopy_crate, unn),
+    };
+    IntoIterator::into_iter([error, exhaustive_error, Some(try_from_bytes)]).flatten().collect()
+}
+
+fn derive_from_zeros_inner(
+    ast: &DeriveInput,
+    attrs: &ZerocopyAttrs,
+    zerocopy_crate: &Path,
+) -> proc_macro2::TokenStream {
+    let try_from_bytes = derive_try_from_bytes_inner(ast, attrs, zerocopy_crate, Trait::FromZeros);
     let from_zeros = match &ast.data {
-        Data::Struct(strct) => derive_from_zeros_struct(ast, strct),
-        Data::Enum(enm) => derive_from_zeros_enum(ast, enm),
-        Data::Union(unn) => derive_from_zeros_union(ast, unn),
+        Data::Struct(strct) => derive_from_zeros_struct(ast, zerocopy_crate, strct),
+        Data::Enum(enm) => derive_from_zeros_enum(ast, zerocopy_crate, enm),
+        Data::Union(unn) => derive_from_zeros_union(ast, zerocopy_crate, unn),
     };
     IntoIterator::into_iter([try_from_bytes, from_zeros]).collect()
 }
 
-fn derive_from_bytes_inner(ast: &DeriveInput) -> proc_macro2::TokenStream {
-    let from_zeros = derive_from_zeros_inner(ast);
+fn derive_from_bytes_inner(
+    ast: &DeriveInput,
+    attrs: &ZerocopyAttrs,
+    zerocopy_crate: &Path,
+) -> proc_macro2::TokenStream {
+    let from_zeros = derive_from_zeros_inner(ast, attrs, zerocopy_crate);
     let from_bytes = match &ast.data {
-        Data::Struct(strct) => derive_from_bytes_struct(ast, strct),
-        Data::Enum(enm) => derive_from_bytes_enum(ast, enm),
-        Data::Union(unn) => derive_from_bytes_union(ast, unn),
+        Data::Struct(strct) => derive_from_bytes_struct(ast, zerocopy_crate, strct),
+        Data::Enum(enm) => derive_from_bytes_enum(ast, zerocopy_crate, enm),
+        Data::Union(unn) => derive_from_bytes_union(ast, zerocopy_crate, unn),
     };
+    let byte_array_conv = derive_byte_array_conv(ast, attrs, zerocopy_crate, Trait::FromBytes);
 
-    IntoIterator::into_iter([from_zeros, from_bytes]).collect()
+    IntoIterator::into_iter([from_zeros, from_bytes, byte_array_conv]).collect()
 }
 
-fn derive_into_bytes_inner(ast: &DeriveInput) -> proc_macro2::TokenStream {
+fn derive_into_bytes_inner(
+    ast: &DeriveInput,
+    attrs: &ZerocopyAttrs,
+    zerocopy_crate: &Path,
+) -> proc_macro2::TokenStream {
+    let into_bytes = match &ast.data {
+        Data::Struct(strct) => derive_into_bytes_struct(ast, zerocopy_crate, strct),
+        Data::Enum(enm) => derive_into_bytes_enum(ast, zerocopy_crate, enm),
+        Data::Union(unn) => derive_into_bytes_union(ast, zerocopy_crate, unn),
+    };
+    let byte_array_conv = derive_byte_array_conv(ast, attrs, zerocopy_crate, Trait::IntoBytes);
+
+    IntoIterator::into_iter([into_bytes, byte_array_conv]).collect()
+}
+
+// If the type is annotated with `#[zerocopy(as_bytes_padded)]`, then, in
+// addition to implementing `IntoBytesWithPadding`, `derive(IntoBytesWithPadding)`
+// emits an inherent `as_bytes_padded` method which returns the type's bytes
+// with any padding zeroed. If the type turns out not to have padding, then it
+// could be `IntoBytes`, and so we emit an error: the inherent impl is bounded
+// on the type having padding, which, since the type is not generic, fails to
+// compile if it doesn't.
+//
+// `as_bytes_padded` is implemented entirely in safe code: it starts with a
+// zeroed byte array the size of the type, and then copies each field's bytes,
+// obtained using `IntoBytes::as_bytes`, to that field's offset. Padding bytes
+// are never read; they retain the value zero.
+//
+// Since the return type is a byte array whose length is the size of the type,
+// this is only supported on non-generic, sized, `repr(C)` structs.
+fn derive_as_bytes_padded(
+    ast: &DeriveInput,
+    zerocopy_crate: &Path,
+    strct: &DataStruct,
+    span: Span,
+) -> proc_macro2::TokenStream {
+    let _: Vec<StructRepr> = try_or_print!(STRUCT_AS_BYTES_PADDED_CFG.validate_reprs(ast));
+
+    // The array length of a generic type can't be named without
+    // `generic_const_exprs`.
+    if !ast.generics.params.is_empty() {
+        return Error::new(span, "`as_bytes_padded` is not supported on generic types")
+            .to_compile_error();
+    }
+
+    let fields = strct.fields();
+    if let Some((_name, Type::Slice(_))) = fields.last() {
+        return Error::new(span, "`as_bytes_padded` is not supported on dynamically-sized types")
+            .to_compile_error();
+    }
+
+    let type_ident = &ast.ident;
+    let vis = &ast.vis;
+    let field_names = fields.iter().map(|(name, _ty)| name);
+    let core_path = quote!(#zerocopy_crate::macro_util::core_reexport);
+    let array = quote!([#core_path::primitive::u8; #core_path::mem::size_of::<#type_ident>()]);
+    let field_types = fields.iter().map(|(_name, ty)| ty);
+    let has_padding = quote_spanned!(span=>
+        #zerocopy_crate::macro_util::HasPadding<
+            #type_ident,
+            {#zerocopy_crate::struct_has_padding!(#type_ident, #(#field_types),*)}
+        >: #zerocopy_crate::macro_util::ShouldBe<true>
+    );
+
+    quote! {
+        impl #type_ident where #has_padding {
+            /// Returns the bytes of `self`, with any padding bytes set to zero.
+            ///
+            /// This method was generated by `#[zerocopy(as_bytes_padded)]`.
+            #[must_use = "has no side effects"]
+            #[inline]
+            #[allow(dead_code, unused_mut, unused_variables)]
+            #vis fn as_bytes_padded(&self) -> #array {
+                let mut bytes = [0; #core_path::mem::size_of::<#type_ident>()];
+                let base = self as *const #type_ident as usize;
+                #({
+                    let field = #zerocopy_crate::IntoBytes::as_bytes(&self.#field_names);
+                    let offset = #core_path::ptr::addr_of!(self.#field_names) as usize - base;
+                    bytes[offset..offset + field.len()].copy_from_slice(field);
+                })*
+                bytes
+            }
+        }
+    }
+}
+
+const STRUCT_AS_BYTES_PADDED_CFG: Config<StructRepr> = Config {
+    allowed_combinations_message: r#"`as_bytes_padded` requires repr of "C""#,
+    derive_unaligned: false,
+    allowed_combinations: &[&[StructRepr::C]],
+    disallowed_but_legal_combinations: &[
+        &[StructRepr::Transparent],
+        &[StructRepr::Packed],
+        &[StructRepr::C, StructRepr::Packed],
+    ],
+};
+
+// A struct is `IntoBytesWithPadding` if all fields are `Immutable`.
+//
+// Padding is permitted, since `IntoBytesWithPadding` only exposes its bytes as
+// `MaybeUninit<u8>`s. For the same reason, the struct's `repr` is irrelevant.
+
+fn derive_into_bytes_with_padding_inner(
+    ast: &DeriveInput,
+    attrs: &ZerocopyAttrs,
+    zerocopy_crate: &Path,
+) -> proc_macro2::TokenStream {
     match &ast.data {
-        Data::Struct(strct) => derive_into_bytes_struct(ast, strct),
-        Data::Enum(enm) => derive_into_bytes_enum(ast, enm),
-        Data::Union(unn) => derive_into_bytes_union(ast, unn),
+        Data::Struct(strct) => {
+            // Since `IntoBytesWithPadding` exposes padding bytes as
+            // possibly-uninitialized, the struct's layout is irrelevant, and
+            // so we don't inspect its `repr`.
+            let into_bytes_with_padding = impl_block(
+                ast,
+                zerocopy_crate,
+                strct,
+                Trait::IntoBytesWithPadding,
+                FieldBounds::All(&[TraitBound::Other(Trait::Immutable)]),
+                SelfBounds::None,
+                None,
+                None,
+            );
+            let as_bytes_padded = match attrs.as_bytes_padded {
+                Some(span) => derive_as_bytes_padded(ast, zerocopy_crate, strct, span),
+                None => quote!(),
+            };
+            quote!(#into_bytes_with_padding #as_bytes_padded)
+        }
+        Data::Enum(_) | Data::Union(_) => {
+            Error::new_spanned(ast, "IntoBytesWithPadding can only be derived on structs")
+                .to_compile_error()
+        }
+    }
+}
+
+// If the type is annotated with `#[zerocopy(byte_array_conv)]`, emits a `From`
+// impl converting between the type and `[u8; N]`: `derive(FromBytes)` emits
+// `From<[u8; N]> for T`, and `derive(IntoBytes)` emits `From<T> for [u8; N]`.
+// Both conversions are implemented using `transmute!`, which fails to compile
+// if `N` is not equal to the size of the type.
+fn derive_byte_array_conv(
+    ast: &DeriveInput,
+    attrs: &ZerocopyAttrs,
+    zerocopy_crate: &Path,
+    trt: Trait,
+) -> proc_macro2::TokenStream {
+    let conv = match &attrs.byte_array_conv {
+        Some(conv) => conv,
+        None => return quote!(),
+    };
+
+    // The array length of a generic type can't be named without
+    // `generic_const_exprs`, and `transmute!` can't be used in a generic
+    // context.
+    if !ast.generics.params.is_empty() {
+        return Error::new(conv.span, "`byte_array_conv` is not supported on generic types")
+            .to_compile_error();
+    }
+
+    let type_ident = &ast.ident;
+    let len = match &conv.len {
+        Some(len) => quote!(#len),
+        None => quote!(#zerocopy_crate::macro_util::core_reexport::mem::size_of::<#type_ident>()),
+    };
+    let array = quote!([#zerocopy_crate::macro_util::core_reexport::primitive::u8; #len]);
+
+    match trt {
+        Trait::FromBytes => quote! {
+            impl #zerocopy_crate::macro_util::core_reexport::convert::From<#array> for #type_ident {
+                #[inline]
+                fn from(bytes: #array) -> #type_ident {
+                    #zerocopy_crate::transmute!(bytes)
+                }
+            }
+        },
+        _ => quote! {
+            impl #zerocopy_crate::macro_util::core_reexport::convert::From<#type_ident> for #array {
+                #[inline]
+                fn from(value: #type_ident) -> #array {
+                    #zerocopy_crate::transmute!(value)
+                }
+            }
+        },
     }
 }
 
-fn derive_unaligned_inner(ast: &DeriveInput) -> proc_macro2::TokenStream {
+fn derive_unaligned_inner(
+    ast: &DeriveInput,
+    _attrs: &ZerocopyAttrs,
+    zerocopy_crate: &Path,
+) -> proc_macro2::TokenStream {
     match &ast.data {
-        Data::Struct(strct) => derive_unaligned_struct(ast, strct),
-        Data::Enum(enm) => derive_unaligned_enum(ast, enm),
-        Data::Union(unn) => derive_unaligned_union(ast, unn),
+        Data::Struct(strct) => derive_unaligned_struct(ast, zerocopy_crate, strct),
+        Data::Enum(enm) => derive_unaligned_enum(ast, zerocopy_crate, enm),
+        Data::Union(unn) => derive_unaligned_union(ast, zerocopy_crate, unn),
+    }
+}
+
+// Implements `PartialEq` and `Eq` by comparing the bytes of the two values.
+// This is only sound to do if the type has no padding, and so the impls are
+// bounded on `Self: IntoBytes + Immutable`. If the type is not generic, these
+// bounds are trivially false when it isn't `IntoBytes`, and so the impls fail
+// to compile at the type's definition. If the type is generic, the impls only
+// apply to those instantiations which are `IntoBytes`.
+fn derive_byte_eq_inner(
+    ast: &DeriveInput,
+    _attrs: &ZerocopyAttrs,
+    zerocopy_crate: &Path,
+) -> proc_macro2::TokenStream {
+    let type_ident = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let predicates =
+        where_clause.map(|where_clause| where_clause.predicates.iter()).into_iter().flatten();
+    let predicates =
+        quote!(#(#predicates,)* Self: #zerocopy_crate::IntoBytes + #zerocopy_crate::Immutable);
+
+    quote! {
+        impl #impl_generics #zerocopy_crate::macro_util::core_reexport::cmp::PartialEq for #type_ident #ty_generics
+        where
+            #predicates
+        {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                #zerocopy_crate::macro_util::core_reexport::cmp::PartialEq::eq(
+                    #zerocopy_crate::IntoBytes::as_bytes(self),
+                    #zerocopy_crate::IntoBytes::as_bytes(other),
+                )
+            }
+        }
+
+        impl #impl_generics #zerocopy_crate::macro_util::core_reexport::cmp::Eq for #type_ident #ty_generics
+        where
+            #predicates
+        {
+        }
     }
 }
 
 // A struct is `TryFromBytes` if:
 // - all fields are `TryFromBytes`
 
-fn derive_try_from_bytes_struct(ast: &DeriveInput, strct: &DataStruct) -> proc_macro2::TokenStream {
+fn derive_try_from_bytes_struct(
+    ast: &DeriveInput,
+    zerocopy_crate: &Path,
+    strct: &DataStruct,
+    validate: Option<&Path>,
+) -> proc_macro2::TokenStream {
     let extras = Some({
         let fields = strct.fields();
         let field_names = fields.iter().map(|(name, _ty)| name);
         let field_tys = fields.iter().map(|(_name, ty)| ty);
+        let validate = validate.map(|validate| {
+            quote!(
+                && {
+                    // SAFETY: `&&` short-circuits, so this is only reached if
+                    // every field is bit-valid, and thus if `candidate`'s
+                    // referent is a bit-valid `Self`.
+                    unsafe { #zerocopy_crate::macro_util::run_validator(candidate, #validate) }
+                }
+            )
+        });
         quote!(
             // SAFETY: We use `is_bit_valid` to validate that each field is
             // bit-valid, and only return `true` if all of them are. The bit
             // validity of a struct is just the composition of the bit
             // validities of its fields, so this is a sound implementation of
             // `is_bit_valid`.
-            fn is_bit_valid<A: ::zerocopy::pointer::invariant::Aliasing + ::zerocopy::pointer::invariant::AtLeast<::zerocopy::pointer::invariant::Shared>>(
-                mut candidate: ::zerocopy::Maybe<Self, A>
+            fn is_bit_valid<A: #zerocopy_crate::pointer::invariant::Aliasing + #zerocopy_crate::pointer::invariant::AtLeast<#zerocopy_crate::pointer::invariant::Shared>>(
+                mut candidate: #zerocopy_crate::Maybe<Self, A>
             ) -> bool {
                 true #(&& {
                     // SAFETY:
@@ -404,18 +801,19 @@ fn derive_try_from_bytes_struct(ast: &DeriveInput, strct: &DataStruct) -> proc_m
                     //   as they do in `*slf`
                     let field_candidate = unsafe {
                         let project = |slf: *mut Self|
-                            ::zerocopy::macro_util::core_reexport::ptr::addr_of_mut!((*slf).#field_names);
+                            #zerocopy_crate::macro_util::core_reexport::ptr::addr_of_mut!((*slf).#field_names);
 
                         candidate.reborrow().project(project)
                     };
 
-                    <#field_tys as ::zerocopy::TryFromBytes>::is_bit_valid(field_candidate)
-                })*
+                    <#field_tys as #zerocopy_crate::TryFromBytes>::is_bit_valid(field_candidate)
+                })* #validate
             }
         )
     });
     impl_block(
         ast,
+        zerocopy_crate,
         strct,
         Trait::TryFromBytes,
         FieldBounds::ALL_SELF,
@@ -428,7 +826,11 @@ fn derive_try_from_bytes_struct(ast: &DeriveInput, strct: &DataStruct) -> proc_m
 // A union is `TryFromBytes` if:
 // - all of its fields are `TryFromBytes` and `Immutable`
 
-fn derive_try_from_bytes_union(ast: &DeriveInput, unn: &DataUnion) -> proc_macro2::TokenStream {
+fn derive_try_from_bytes_union(
+    ast: &DeriveInput,
+    zerocopy_crate: &Path,
+    unn: &DataUnion,
+) -> proc_macro2::TokenStream {
     // TODO(#5): Remove the `Immutable` bound.
     let field_type_trait_bounds =
         FieldBounds::All(&[TraitBound::Slf, TraitBound::Other(Trait::Immutable)]);
@@ -442,8 +844,8 @@ fn derive_try_from_bytes_union(ast: &DeriveInput, unn: &DataUnion) -> proc_macro
             // bit validity of a union is not yet well defined in Rust, but it
             // is guaranteed to be no more strict than this definition. See #696
             // for a more in-depth discussion.
-            fn is_bit_valid<A: ::zerocopy::pointer::invariant::Aliasing + ::zerocopy::pointer::invariant::AtLeast<::zerocopy::pointer::invariant::Shared>>(
-                mut candidate: ::zerocopy::Maybe<Self, A>
+            fn is_bit_valid<A: #zerocopy_crate::pointer::invariant::Aliasing + #zerocopy_crate::pointer::invariant::AtLeast<#zerocopy_crate::pointer::invariant::Shared>>(
+                mut candidate: #zerocopy_crate::Maybe<Self, A>
             ) -> bool {
                 false #(|| {
                     // SAFETY:
@@ -455,18 +857,19 @@ fn derive_try_from_bytes_union(ast: &DeriveInput, unn: &DataUnion) -> proc_macro
                     //   returned pointer's referent contain any `UnsafeCell`s
                     let field_candidate = unsafe {
                         let project = |slf: *mut Self|
-                            ::zerocopy::macro_util::core_reexport::ptr::addr_of_mut!((*slf).#field_names);
+                            #zerocopy_crate::macro_util::core_reexport::ptr::addr_of_mut!((*slf).#field_names);
 
                         candidate.reborrow().project(project)
                     };
 
-                    <#field_tys as ::zerocopy::TryFromBytes>::is_bit_valid(field_candidate)
+                    <#field_tys as #zerocopy_crate::TryFromBytes>::is_bit_valid(field_candidate)
                 })*
             }
         )
     });
     impl_block(
         ast,
+        zerocopy_crate,
         unn,
         Trait::TryFromBytes,
         field_type_trait_bounds,
@@ -483,14 +886,17 @@ const STRUCT_UNION_ALLOWED_REPR_COMBINATIONS: &[&[StructRepr]] = &[
     &[StructRepr::C, StructRepr::Packed],
 ];
 
-fn derive_try_from_bytes_enum(ast: &DeriveInput, enm: &DataEnum) -> proc_macro2::TokenStream {
+fn derive_try_from_bytes_enum(
+    ast: &DeriveInput,
+    zerocopy_crate: &Path,
+    enm: &DataEnum,
+) -> proc_macro2::TokenStream {
+    let reprs = try_or_print!(ENUM_TRY_FROM_BYTES_CFG.validate_reprs(ast));
+
     if !enm.is_fieldless() {
-        return Error::new_spanned(ast, "only field-less enums can implement TryFromBytes")
-            .to_compile_error();
+        return derive_try_from_bytes_data_enum(ast, zerocopy_crate, enm, reprs.as_slice());
     }
 
-    let reprs = try_or_print!(ENUM_TRY_FROM_BYTES_CFG.validate_reprs(ast));
-
     // Figure out whether the enum could in theory implement `FromBytes`.
     let from_bytes = enum_size_from_repr(reprs.as_slice())
         .map(|size| {
@@ -516,7 +922,7 @@ fn derive_try_from_bytes_enum(ast: &DeriveInput, enm: &DataEnum) -> proc_macro2:
         })
     } else {
         quote!(
-            use ::zerocopy::macro_util::core_reexport;
+            use #zerocopy_crate::macro_util::core_reexport;
             // SAFETY:
             // - The closure is a pointer cast, and `Self` and `[u8;
             //   size_of::<Self>()]` have the same size, so the returned pointer
@@ -558,21 +964,204 @@ fn derive_try_from_bytes_enum(ast: &DeriveInput, enm: &DataEnum) -> proc_macro2:
         // SAFETY: We use `is_bit_valid` to validate that the bit pattern
         // corresponds to one of the field-less enum's variant discriminants.
         // Thus, this is a sound implementation of `is_bit_valid`.
-        fn is_bit_valid<A: ::zerocopy::pointer::invariant::Aliasing + ::zerocopy::pointer::invariant::AtLeast<::zerocopy::pointer::invariant::Shared>>(
-            candidate: ::zerocopy::Ptr<
+        fn is_bit_valid<A: #zerocopy_crate::pointer::invariant::Aliasing + #zerocopy_crate::pointer::invariant::AtLeast<#zerocopy_crate::pointer::invariant::Shared>>(
+            candidate: #zerocopy_crate::Ptr<
                 '_,
                 Self,
                 (
                     A,
-                    ::zerocopy::pointer::invariant::Any,
-                    ::zerocopy::pointer::invariant::Initialized,
+                    #zerocopy_crate::pointer::invariant::Any,
+                    #zerocopy_crate::pointer::invariant::Initialized,
                 ),
             >,
-        ) -> ::zerocopy::macro_util::core_reexport::primitive::bool {
+        ) -> #zerocopy_crate::macro_util::core_reexport::primitive::bool {
             #is_bit_valid_body
         }
     ));
-    impl_block(ast, enm, Trait::TryFromBytes, FieldBounds::ALL_SELF, SelfBounds::None, None, extras)
+    impl_block(
+        ast,
+        zerocopy_crate,
+        enm,
+        Trait::TryFromBytes,
+        FieldBounds::ALL_SELF,
+        SelfBounds::None,
+        None,
+        extras,
+    )
+}
+
+// An enum with fields is `TryFromBytes` if:
+// - it has a defined representation (`repr`s `C`, `u8`, `u16`, `u32`, `u64`,
+//   `usize`, `i8`, `i16`, `i32`, `i64`, or `isize`)
+// - all of its fields are `TryFromBytes` and `Immutable`
+//
+// Its layout is given by RFC 2195 [1]. If the enum is `repr(Int)`, it is laid
+// out as a `repr(C)` union of `repr(C)` structs, one per variant, each of
+// which begins with a `repr(Int)` tag followed by the variant's fields. If the
+// enum is `repr(C)`, it is laid out as a `repr(C)` struct containing a
+// `repr(C)` tag followed by a `repr(C)` union of `repr(C)` structs, one per
+// variant, each of which contains the variant's fields. In both cases, the
+// tag is at offset 0. `is_bit_valid` reads the tag, and then validates the
+// fields of the variant it identifies, by projecting through local types
+// which mirror the layout described above.
+//
+// [1] https://rust-lang.github.io/rfcs/2195-really-tagged-unions.html
+
+fn derive_try_from_bytes_data_enum(
+    ast: &DeriveInput,
+    zerocopy_crate: &Path,
+    enm: &DataEnum,
+    reprs: &[EnumRepr],
+) -> proc_macro2::TokenStream {
+    let type_ident = &ast.ident;
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+    let params = &ast.generics.params;
+    let where_clause = &ast.generics.where_clause;
+    let is_repr_c = reprs == [EnumRepr::C];
+
+    // The tag has the same `repr` as the enum itself. We've already validated
+    // that the enum has exactly one `repr`, so it's sound to copy the `repr`
+    // attributes verbatim.
+    let repr_attrs = ast.attrs.iter().filter(|attr| attr.path().is_ident("repr"));
+    let tag_variants = enm.variants.iter().map(|v| {
+        let ident = &v.ident;
+        match &v.discriminant {
+            Some((_, discriminant)) => quote!(#ident = #discriminant),
+            None => quote!(#ident),
+        }
+    });
+
+    let variant_struct_idents = enm
+        .variants
+        .iter()
+        .map(|v| Ident::new(&format!("___ZerocopyVariant{}", v.ident), v.ident.span()))
+        .collect::<Vec<_>>();
+    let tag_field = (!is_repr_c).then(|| quote!(___zerocopy_tag: ___ZerocopyTag,));
+    let variant_structs = enm.variants.iter().zip(variant_struct_idents.iter()).map(|(v, ident)| {
+        let field_names = (0..v.fields.len()).map(|i| Ident::new(&format!("___field_{}", i), Span::call_site()));
+        let field_tys = v.fields.iter().map(|f| &f.ty);
+        quote! {
+            #[repr(C)]
+            #[allow(dead_code, non_camel_case_types)]
+            struct #ident<#params> #where_clause {
+                #tag_field
+                #(#field_names: #field_tys,)*
+                ___zerocopy_phantom: core_reexport::marker::PhantomData<#type_ident #ty_generics>,
+            }
+        }
+    });
+
+    // In the `repr(C)` layout, the variant structs live in a union which
+    // follows the tag.
+    let raw_enum = is_repr_c.then(|| {
+        quote! {
+            #[repr(C)]
+            #[allow(dead_code, non_snake_case)]
+            union ___ZerocopyVariants<#params> #where_clause {
+                #(#variant_struct_idents: core_reexport::mem::ManuallyDrop<#variant_struct_idents #ty_generics>,)*
+            }
+
+            #[repr(C)]
+            #[allow(dead_code)]
+            struct ___ZerocopyRawEnum<#params> #where_clause {
+                ___zerocopy_tag: ___ZerocopyTag,
+                ___zerocopy_variants: ___ZerocopyVariants #ty_generics,
+            }
+        }
+    });
+
+    let variant_checks = enm.variants.iter().zip(variant_struct_idents.iter()).map(|(v, variant_struct_ident)| {
+        let variant_ident = &v.ident;
+        let field_names = (0..v.fields.len()).map(|i| Ident::new(&format!("___field_{}", i), Span::call_site()));
+        let field_tys = v.fields.iter().map(|f| &f.ty);
+        let variant_ptr = if is_repr_c {
+            quote!(core_reexport::ptr::addr_of_mut!((*(slf as *mut ___ZerocopyRawEnum #ty_generics)).___zerocopy_variants) as *mut #variant_struct_ident #ty_generics)
+        } else {
+            quote!(slf as *mut #variant_struct_ident #ty_generics)
+        };
+        quote! {
+            // SAFETY: `___ZerocopyTag` is a field-less enum with a primitive
+            // or `C` representation, and so all of its bytes are initialized.
+            if tag == unsafe { core_reexport::mem::transmute::<___ZerocopyTag, [core_reexport::primitive::u8; core_reexport::mem::size_of::<___ZerocopyTag>()]>(___ZerocopyTag::#variant_ident) } {
+                return true #(&& {
+                    // SAFETY:
+                    // - `project` casts `slf` to a variant struct whose
+                    //   layout is a prefix of the layout of `Self` prescribed
+                    //   by `Self`'s `repr`, and then projects a field, and so
+                    //   it addresses a subset of the bytes addressed by `slf`
+                    // - ..., and so it preserves provenance
+                    // - Since all fields are required to be `Immutable`,
+                    //   neither `*slf` nor the returned pointer's referent
+                    //   contain any `UnsafeCell`s
+                    let field_candidate = unsafe {
+                        let project = |slf: *mut Self|
+                            core_reexport::ptr::addr_of_mut!((*(#variant_ptr)).#field_names);
+
+                        candidate.reborrow().project(project)
+                    };
+
+                    <#field_tys as #zerocopy_crate::TryFromBytes>::is_bit_valid(field_candidate)
+                })*;
+            }
+        }
+    });
+
+    let extras = Some(quote!(
+        // SAFETY: We use `is_bit_valid` to validate that the tag corresponds
+        // to one of the enum's variants, and that each field of that variant
+        // is bit-valid. Thus, this is a sound implementation of
+        // `is_bit_valid`.
+        fn is_bit_valid<A: #zerocopy_crate::pointer::invariant::Aliasing + #zerocopy_crate::pointer::invariant::AtLeast<#zerocopy_crate::pointer::invariant::Shared>>(
+            mut candidate: #zerocopy_crate::Maybe<Self, A>,
+        ) -> #zerocopy_crate::macro_util::core_reexport::primitive::bool {
+            use #zerocopy_crate::macro_util::core_reexport;
+
+            #(#repr_attrs)*
+            #[allow(dead_code)]
+            enum ___ZerocopyTag {
+                #(#tag_variants,)*
+            }
+
+            #(#variant_structs)*
+            #raw_enum
+
+            // SAFETY:
+            // - The closure is a pointer cast, and the tag is at offset 0 in
+            //   `Self` and is no larger than `Self`, so the returned pointer
+            //   addresses a subset of the bytes addressed by `p`
+            // - ..., and so it preserves provenance
+            // - Since all fields are required to be `Immutable`, `Self`
+            //   cannot contain any `UnsafeCell`s. Neither does `[u8; N]`.
+            let tag = unsafe {
+                candidate.reborrow().project(|p: *mut Self| {
+                    p as *mut [core_reexport::primitive::u8; core_reexport::mem::size_of::<___ZerocopyTag>()]
+                })
+            };
+            // SAFETY: `tag` has the invariant `Initialized`, and `[u8; N]`'s
+            // validity invariant is just that all of its bytes are
+            // initialized.
+            let tag = unsafe { tag.assume_valid() };
+            let tag = tag.read_unaligned();
+
+            #(#variant_checks)*
+
+            false
+        }
+    ));
+
+    // TODO(#5): Remove the `Immutable` bound.
+    let field_type_trait_bounds =
+        FieldBounds::All(&[TraitBound::Slf, TraitBound::Other(Trait::Immutable)]);
+    impl_block(
+        ast,
+        zerocopy_crate,
+        enm,
+        Trait::TryFromBytes,
+        field_type_trait_bounds,
+        SelfBounds::None,
+        None,
+        extras,
+    )
 }
 
 #[rustfmt::skip]
@@ -601,15 +1190,32 @@ const ENUM_TRY_FROM_BYTES_CFG: Config<EnumRepr> = {
 // A struct is `FromZeros` if:
 // - all fields are `FromZeros`
 
-fn derive_from_zeros_struct(ast: &DeriveInput, strct: &DataStruct) -> proc_macro2::TokenStream {
-    impl_block(ast, strct, Trait::FromZeros, FieldBounds::ALL_SELF, SelfBounds::None, None, None)
+fn derive_from_zeros_struct(
+    ast: &DeriveInput,
+    zerocopy_crate: &Path,
+    strct: &DataStruct,
+) -> proc_macro2::TokenStream {
+    impl_block(
+        ast,
+        zerocopy_crate,
+        strct,
+        Trait::FromZeros,
+        FieldBounds::ALL_SELF,
+        SelfBounds::None,
+        None,
+        None,
+    )
 }
 
 // An enum is `FromZeros` if:
 // - all of its variants are fieldless
 // - one of the variants has a discriminant of `0`
 
-fn derive_from_zeros_enum(ast: &DeriveInput, enm: &DataEnum) -> proc_macro2::TokenStream {
+fn derive_from_zeros_enum(
+    ast: &DeriveInput,
+    zerocopy_crate: &Path,
+    enm: &DataEnum,
+) -> proc_macro2::TokenStream {
     if !enm.is_fieldless() {
         return Error::new_spanned(ast, "only field-less enums can implement FromZeros")
             .to_compile_error();
@@ -640,25 +1246,64 @@ fn derive_from_zeros_enum(ast: &DeriveInput, enm: &DataEnum) -> proc_macro2::Tok
         .to_compile_error();
     }
 
-    impl_block(ast, enm, Trait::FromZeros, FieldBounds::ALL_SELF, SelfBounds::None, None, None)
+    impl_block(
+        ast,
+        zerocopy_crate,
+        enm,
+        Trait::FromZeros,
+        FieldBounds::ALL_SELF,
+        SelfBounds::None,
+        None,
+        None,
+    )
 }
 
 // Unions are `FromZeros` if
 // - all fields are `FromZeros` and `Immutable`
 
-fn derive_from_zeros_union(ast: &DeriveInput, unn: &DataUnion) -> proc_macro2::TokenStream {
+fn derive_from_zeros_union(
+    ast: &DeriveInput,
+    zerocopy_crate: &Path,
+    unn: &DataUnion,
+) -> proc_macro2::TokenStream {
     // TODO(#5): Remove the `Immutable` bound. It's only necessary for
     // compatibility with `derive(TryFromBytes)` on unions; not for soundness.
     let field_type_trait_bounds =
         FieldBounds::All(&[TraitBound::Slf, TraitBound::Other(Trait::Immutable)]);
-    impl_block(ast, unn, Trait::FromZeros, field_type_trait_bounds, SelfBounds::None, None, None)
+    impl_block(
+        ast,
+        zerocopy_crate,
+        unn,
+        Trait::FromZeros,
+        field_type_trait_bounds,
+        SelfBounds::None,
+        None,
+        None,
+    )
 }
 
 // A struct is `FromBytes` if:
 // - all fields are `FromBytes`
 
-fn derive_from_bytes_struct(ast: &DeriveInput, strct: &DataStruct) -> proc_macro2::TokenStream {
-    impl_block(ast, strct, Trait::FromBytes, FieldBounds::ALL_SELF, SelfBounds::None, None, None)
+fn derive_from_bytes_struct(
+    ast: &DeriveInput,
+    zerocopy_crate: &Path,
+    strct: &DataStruct,
+) -> proc_macro2::TokenStream {
+    let field_checks = field_checks(ast, zerocopy_crate, "from_bytes", &strct.fields);
+    let field_bounds =
+        if field_checks.is_some() { FieldBounds::None } else { FieldBounds::ALL_SELF };
+    let impl_block = impl_block(
+        ast,
+        zerocopy_crate,
+        strct,
+        Trait::FromBytes,
+        field_bounds,
+        SelfBounds::None,
+        None,
+        None,
+    );
+    IntoIterator::into_iter([impl_block, field_checks.unwrap_or_default()]).collect()
 }
 
 // An enum is `FromBytes` if:
@@ -675,7 +1320,11 @@ fn derive_from_bytes_struct(ast: &DeriveInput, strct: &DataStruct) -> proc_macro
 //   platform-specific and, b) even on Rust's smallest bit width platform (32),
 //   this would require ~4 billion enum variants, which obviously isn't a thing.
 
-fn derive_from_bytes_enum(ast: &DeriveInput, enm: &DataEnum) -> proc_macro2::TokenStream {
+fn derive_from_bytes_enum(
+    ast: &DeriveInput,
+    zerocopy_crate: &Path,
+    enm: &DataEnum,
+) -> proc_macro2::TokenStream {
     if !enm.is_fieldless() {
         return Error::new_spanned(ast, "only field-less enums can implement FromBytes")
             .to_compile_error();
@@ -697,7 +1346,16 @@ fn derive_from_bytes_enum(ast: &DeriveInput, enm: &DataEnum) -> proc_macro2::Tok
         .to_compile_error();
     }
 
-    impl_block(ast, enm, Trait::FromBytes, FieldBounds::ALL_SELF, SelfBounds::None, None, None)
+    impl_block(
+        ast,
+        zerocopy_crate,
+        enm,
+        Trait::FromBytes,
+        FieldBounds::ALL_SELF,
+        SelfBounds::None,
+        None,
+        None,
+    )
 }
 
 // Returns `None` if the enum's size is not guaranteed by the repr.
@@ -736,19 +1394,119 @@ const ENUM_FROM_BYTES_CFG: Config<EnumRepr> = {
 // Unions are `FromBytes` if
 // - all fields are `FromBytes` and `Immutable`
 
-fn derive_from_bytes_union(ast: &DeriveInput, unn: &DataUnion) -> proc_macro2::TokenStream {
+fn derive_from_bytes_union(
+    ast: &DeriveInput,
+    zerocopy_crate: &Path,
+    unn: &DataUnion,
+) -> proc_macro2::TokenStream {
+    let field_checks = field_checks(ast, zerocopy_crate, "from_bytes", &unn.fields.named);
     // TODO(#5): Remove the `Immutable` bound. It's only necessary for
     // compatibility with `derive(TryFromBytes)` on unions; not for soundness.
-    let field_type_trait_bounds =
-        FieldBounds::All(&[TraitBound::Slf, TraitBound::Other(Trait::Immutable)]);
-    impl_block(ast, unn, Trait::FromBytes, field_type_trait_bounds, SelfBounds::None, None, None)
+    let field_type_trait_bounds = if field_checks.is_some() {
+        FieldBounds::All(&[TraitBound::Other(Trait::Immutable)])
+    } else {
+        FieldBounds::All(&[TraitBound::Slf, TraitBound::Other(Trait::Immutable)])
+    };
+    let impl_block = impl_block(
+        ast,
+        zerocopy_crate,
+        unn,
+        Trait::FromBytes,
+        field_type_trait_bounds,
+        SelfBounds::None,
+        None,
+        None,
+    );
+    IntoIterator::into_iter([impl_block, field_checks.unwrap_or_default()]).collect()
+}
+
+// If a field doesn't implement the derived trait, the bounds emitted by
+// `impl_block` produce an error which points at the `#[derive(...)]` attribute
+// and names the field's type, but not the field itself. For types with many
+// fields (or with several fields of the same type), that error is hard to act
+// on.
+//
+// To name the offending field, we instead emit a check for each field which
+// fails with an error spanned at the field's type, and which mentions both the
+// field's name and its type. `check` names the function in `macro_util` which
+// performs the check: `assert_field_is_<check>` (see, e.g.,
+// `macro_util::FieldIsFromBytes`). The checks are type-checked even though
+// they're never called, so they reject the same fields as the bounds would.
+// When checks are emitted, callers omit the corresponding field bounds so that
+// each offending field is reported exactly once.
+//
+// Since the checks live outside of the impl, they can't refer to type
+// parameters, and so we only emit them for types without generic parameters.
+// Generic types already get an error at the use site which names the field's
+// type. Returns `None` if no checks are emitted, in which case callers must
+// emit field bounds instead.
+fn field_checks<'a>(
+    ast: &DeriveInput,
+    zerocopy_crate: &Path,
+    check: &str,
+    fields: impl 'a + IntoIterator<Item = &'a syn::Field>,
+) -> Option<proc_macro2::TokenStream> {
+    if !ast.generics.params.is_empty() {
+        return None;
+    }
+
+    let type_ident = &ast.ident;
+    let (markers, tys): (Vec<_>, Vec<_>) = fields
+        .into_iter()
+        .enumerate()
+        .map(|(idx, field)| {
+            let marker = field
+                .ident
+                .clone()
+                .unwrap_or_else(|| Ident::new(&format!("_{}", idx), field.ty.span()));
+            (marker, &field.ty)
+        })
+        .unzip();
+
+    if markers.is_empty() {
+        return None;
+    }
+
+    let assert_fn = Ident::new(&format!("assert_field_is_{}", check), Span::call_site());
+    let check_fn =
+        Ident::new(&format!("__zerocopy_assert_fields_are_{}", check), Span::call_site());
+
+    Some(quote!(
+        const _: () = {
+            // The markers live in their own module so that a field named after
+            // a type (e.g., `u8: u8`) doesn't shadow that type below.
+            #[allow(non_camel_case_types, dead_code)]
+            mod __zerocopy_fields {
+                #(pub enum #markers {})*
+            }
+
+            #[allow(dead_code)]
+            fn #check_fn() {
+                #(#zerocopy_crate::macro_util::#assert_fn::<
+                    #tys,
+                    #type_ident,
+                    __zerocopy_fields::#markers,
+                >();)*
+            }
+        };
+    ))
 }
 
-fn derive_into_bytes_struct(ast: &DeriveInput, strct: &DataStruct) -> proc_macro2::TokenStream {
+fn derive_into_bytes_struct(
+    ast: &DeriveInput,
+    zerocopy_crate: &Path,
+    strct: &DataStruct,
+) -> proc_macro2::TokenStream {
     let reprs = try_or_print!(STRUCT_UNION_INTO_BYTES_CFG.validate_reprs(ast));
     let is_transparent = reprs.contains(&StructRepr::Transparent);
     let is_packed = reprs.contains(&StructRepr::Packed);
     let num_fields = strct.fields().len();
+    let is_slice_dst = matches!(strct.fields().last(), Some((_name, Type::Slice(_))));
+    // `validate_reprs` strips `align(N)`, but an over-aligned struct may need
+    // trailing padding even if its fields are laid out back-to-back.
+    let is_align_gt_one = try_or_print!(repr::reprs::<StructRepr>(&ast.attrs))
+        .iter()
+        .any(|(_meta, repr)| repr.is_align_gt_one());
 
     let (padding_check, require_unaligned_fields) = if is_transparent || is_packed {
         // No padding check needed.
@@ -761,15 +1519,29 @@ fn derive_into_bytes_struct(ast: &DeriveInput, strct: &DataStruct) -> proc_macro
         //   which we require to be `IntoBytes` (meaning they don't have any
         //   padding).
         (None, false)
-    } else if reprs.contains(&StructRepr::C) && num_fields <= 1 {
+    } else if reprs.contains(&StructRepr::C) && num_fields <= 1 && !is_align_gt_one {
         // No padding check needed. A repr(C) struct with zero or one field has
-        // no padding.
+        // no padding unless `repr(align(N))` requires trailing padding.
         (None, false)
     } else if ast.generics.params.is_empty() {
         // Since there are no generics, we can emit a padding check. This is
         // more permissive than the next case, which requires that all field
         // types implement `Unaligned`.
-        (Some(PaddingCheck::Struct), false)
+        //
+        // If the trailing field is a slice, the struct is a slice DST, and
+        // `size_of` can't be used to check for padding. Instead, we check
+        // that the sized prefix has no padding, and that the trailing slice
+        // never requires trailing padding, whatever its length.
+        let check = if is_slice_dst { PaddingCheck::ReprCSliceDst } else { PaddingCheck::Struct };
+        (Some(check), false)
+    } else if is_align_gt_one {
+        // Requiring `Unaligned` fields (below) doesn't rule out trailing
+        // padding introduced by `repr(align(N))`.
+        return Error::new(
+            Span::call_site(),
+            "cannot derive IntoBytes on a generic repr(C) struct with repr(align(N > 1))",
+        )
+        .to_compile_error();
     } else {
         // Based on the allowed reprs, we know that this type must be repr(C) by
         // the time we get here, but the soundness of this impl relies on it, so
@@ -791,7 +1563,16 @@ fn derive_into_bytes_struct(ast: &DeriveInput, strct: &DataStruct) -> proc_macro
         FieldBounds::ALL_SELF
     };
 
-    impl_block(ast, strct, Trait::IntoBytes, field_bounds, SelfBounds::None, padding_check, None)
+    impl_block(
+        ast,
+        zerocopy_crate,
+        strct,
+        Trait::IntoBytes,
+        field_bounds,
+        SelfBounds::None,
+        padding_check,
+        None,
+    )
 }
 
 const STRUCT_UNION_INTO_BYTES_CFG: Config<StructRepr> = Config {
@@ -805,7 +1586,11 @@ const STRUCT_UNION_INTO_BYTES_CFG: Config<StructRepr> = Config {
 
 // An enum is `IntoBytes` if it is field-less and has a defined repr.
 
-fn derive_into_bytes_enum(ast: &DeriveInput, enm: &DataEnum) -> proc_macro2::TokenStream {
+fn derive_into_bytes_enum(
+    ast: &DeriveInput,
+    zerocopy_crate: &Path,
+    enm: &DataEnum,
+) -> proc_macro2::TokenStream {
     if !enm.is_fieldless() {
         return Error::new_spanned(ast, "only field-less enums can implement IntoBytes")
             .to_compile_error();
@@ -814,7 +1599,16 @@ fn derive_into_bytes_enum(ast: &DeriveInput, enm: &DataEnum) -> proc_macro2::Tok
     // We don't care what the repr is; we only care that it is one of the
     // allowed ones.
     try_or_print!(ENUM_FROM_ZEROS_INTO_BYTES_CFG.validate_reprs(ast));
-    impl_block(ast, enm, Trait::IntoBytes, FieldBounds::None, SelfBounds::None, None, None)
+    impl_block(
+        ast,
+        zerocopy_crate,
+        enm,
+        Trait::IntoBytes,
+        FieldBounds::None,
+        SelfBounds::None,
+        None,
+        None,
+    )
 }
 
 #[rustfmt::skip]
@@ -847,7 +1641,11 @@ const ENUM_FROM_ZEROS_INTO_BYTES_CFG: Config<EnumRepr> = {
 // - `repr(C)`, `repr(transparent)`, or `repr(packed)`
 // - no padding (size of union equals size of each field type)
 
-fn derive_into_bytes_union(ast: &DeriveInput, unn: &DataUnion) -> proc_macro2::TokenStream {
+fn derive_into_bytes_union(
+    ast: &DeriveInput,
+    zerocopy_crate: &Path,
+    unn: &DataUnion,
+) -> proc_macro2::TokenStream {
     // TODO(#10): Support type parameters.
     if !ast.generics.params.is_empty() {
         return Error::new(Span::call_site(), "unsupported on types with type parameters")
@@ -858,6 +1656,7 @@ fn derive_into_bytes_union(ast: &DeriveInput, unn: &DataUnion) -> proc_macro2::T
 
     impl_block(
         ast,
+        zerocopy_crate,
         unn,
         Trait::IntoBytes,
         FieldBounds::ALL_SELF,
@@ -873,15 +1672,34 @@ fn derive_into_bytes_union(ast: &DeriveInput, unn: &DataUnion) -> proc_macro2::T
 //     - all fields `Unaligned`
 //   - `repr(packed)`
 
-fn derive_unaligned_struct(ast: &DeriveInput, strct: &DataStruct) -> proc_macro2::TokenStream {
+fn derive_unaligned_struct(
+    ast: &DeriveInput,
+    zerocopy_crate: &Path,
+    strct: &DataStruct,
+) -> proc_macro2::TokenStream {
     let reprs = try_or_print!(STRUCT_UNION_UNALIGNED_CFG.validate_reprs(ast));
-    let field_bounds = if !reprs.contains(&StructRepr::Packed) {
-        FieldBounds::ALL_SELF
+    let field_checks = if !reprs.contains(&StructRepr::Packed) {
+        field_checks(ast, zerocopy_crate, "unaligned", &strct.fields)
     } else {
+        None
+    };
+    let field_bounds = if reprs.contains(&StructRepr::Packed) || field_checks.is_some() {
         FieldBounds::None
+    } else {
+        FieldBounds::ALL_SELF
     };
 
-    impl_block(ast, strct, Trait::Unaligned, field_bounds, SelfBounds::None, None, None)
+    let impl_block = impl_block(
+        ast,
+        zerocopy_crate,
+        strct,
+        Trait::Unaligned,
+        field_bounds,
+        SelfBounds::None,
+        None,
+        None,
+    );
+    IntoIterator::into_iter([impl_block, field_checks.unwrap_or_default()]).collect()
 }
 
 const STRUCT_UNION_UNALIGNED_CFG: Config<StructRepr> = Config {
@@ -897,7 +1715,11 @@ const STRUCT_UNION_UNALIGNED_CFG: Config<StructRepr> = Config {
 // - No `repr(align(N > 1))`
 // - `repr(u8)` or `repr(i8)`
 
-fn derive_unaligned_enum(ast: &DeriveInput, enm: &DataEnum) -> proc_macro2::TokenStream {
+fn derive_unaligned_enum(
+    ast: &DeriveInput,
+    zerocopy_crate: &Path,
+    enm: &DataEnum,
+) -> proc_macro2::TokenStream {
     if !enm.is_fieldless() {
         return Error::new_spanned(ast, "only field-less enums can implement Unaligned")
             .to_compile_error();
@@ -912,7 +1734,16 @@ fn derive_unaligned_enum(ast: &DeriveInput, enm: &DataEnum) -> proc_macro2::Toke
     // true for `require_trait_bound_on_field_types` doesn't really do anything.
     // But it's marginally more future-proof in case that restriction is lifted
     // in the future.
-    impl_block(ast, enm, Trait::Unaligned, FieldBounds::ALL_SELF, SelfBounds::None, None, None)
+    impl_block(
+        ast,
+        zerocopy_crate,
+        enm,
+        Trait::Unaligned,
+        FieldBounds::ALL_SELF,
+        SelfBounds::None,
+        None,
+        None,
+    )
 }
 
 #[rustfmt::skip]
@@ -946,15 +1777,34 @@ const ENUM_UNALIGNED_CFG: Config<EnumRepr> = {
 //     - all fields `Unaligned`
 //   - `repr(packed)`
 
-fn derive_unaligned_union(ast: &DeriveInput, unn: &DataUnion) -> proc_macro2::TokenStream {
+fn derive_unaligned_union(
+    ast: &DeriveInput,
+    zerocopy_crate: &Path,
+    unn: &DataUnion,
+) -> proc_macro2::TokenStream {
     let reprs = try_or_print!(STRUCT_UNION_UNALIGNED_CFG.validate_reprs(ast));
-    let field_type_trait_bounds = if !reprs.contains(&StructRepr::Packed) {
-        FieldBounds::ALL_SELF
+    let field_checks = if !reprs.contains(&StructRepr::Packed) {
+        field_checks(ast, zerocopy_crate, "unaligned", &unn.fields.named)
     } else {
+        None
+    };
+    let field_type_trait_bounds = if reprs.contains(&StructRepr::Packed) || field_checks.is_some() {
         FieldBounds::None
+    } else {
+        FieldBounds::ALL_SELF
     };
 
-    impl_block(ast, unn, Trait::Unaligned, field_type_trait_bounds, SelfBounds::None, None, None)
+    let impl_block = impl_block(
+        ast,
+        zerocopy_crate,
+        unn,
+        Trait::Unaligned,
+        field_type_trait_bounds,
+        SelfBounds::None,
+        None,
+        None,
+    );
+    IntoIterator::into_iter([impl_block, field_checks.unwrap_or_default()]).collect()
 }
 
 // This enum describes what kind of padding check needs to be generated for the
@@ -962,6 +1812,11 @@ fn derive_unaligned_union(ast: &DeriveInput, unn: &DataUnion) -> proc_macro2::To
 enum PaddingCheck {
     // Check that the sum of the fields' sizes exactly equals the struct's size.
     Struct,
+    // Check that the sum of the sizes of the leading (sized) fields of a
+    // `repr(C)` struct with a trailing slice field exactly equals the offset of
+    // that slice, and that the size of the slice's element type is a multiple
+    // of the struct's alignment.
+    ReprCSliceDst,
     // Check that the size of each field exactly equals the union's size.
     Union,
 }
@@ -972,6 +1827,7 @@ impl PaddingCheck {
     fn validator_macro_ident(&self) -> Ident {
         let s = match self {
             PaddingCheck::Struct => "struct_has_padding",
+            PaddingCheck::ReprCSliceDst => "repr_c_slice_dst_has_padding",
             PaddingCheck::Union => "union_has_padding",
         };
 
@@ -987,17 +1843,18 @@ enum Trait {
     FromZeros,
     FromBytes,
     IntoBytes,
+    IntoBytesWithPadding,
     Unaligned,
     Sized,
 }
 
 impl Trait {
-    fn path(&self) -> Path {
+    fn path(&self, zerocopy_crate: &Path) -> Path {
         let span = Span::call_site();
         let root = if *self == Self::Sized {
-            quote_spanned!(span=> ::zerocopy::macro_util::core_reexport::marker)
+            quote_spanned!(span=> #zerocopy_crate::macro_util::core_reexport::marker)
         } else {
-            quote_spanned!(span=> ::zerocopy)
+            quote_spanned!(span=> #zerocopy_crate)
         };
         let ident = Ident::new(&format!("{:?}", self), span);
         parse_quote_spanned! {span=> #root::#ident}
@@ -1040,8 +1897,10 @@ fn normalize_bounds(slf: Trait, bounds: &[TraitBound]) -> impl '_ + Iterator<Ite
     })
 }
 
+#[allow(clippy::too_many_arguments)]
 fn impl_block<D: DataExt>(
     input: &DeriveInput,
+    zerocopy_crate: &Path,
     data: &D,
     trt: Trait,
     field_type_trait_bounds: FieldBounds,
@@ -1108,20 +1967,25 @@ fn impl_block<D: DataExt>(
     //       = note: required by `zerocopy::Unaligned`
 
     let type_ident = &input.ident;
-    let trait_path = trt.path();
+    let trait_path = trt.path(zerocopy_crate);
     let fields = data.fields();
 
-    fn bound_tt(ty: &Type, traits: impl Iterator<Item = Trait>) -> WherePredicate {
-        let traits = traits.map(|t| t.path());
+    fn bound_tt(
+        ty: &Type,
+        traits: impl Iterator<Item = Trait>,
+        zerocopy_crate: &Path,
+    ) -> WherePredicate {
+        let traits = traits.map(|t| t.path(zerocopy_crate));
         parse_quote!(#ty: #(#traits)+*)
     }
     let field_type_bounds: Vec<_> = match (field_type_trait_bounds, &fields[..]) {
-        (FieldBounds::All(traits), _) => {
-            fields.iter().map(|(_name, ty)| bound_tt(ty, normalize_bounds(trt, traits))).collect()
-        }
+        (FieldBounds::All(traits), _) => fields
+            .iter()
+            .map(|(_name, ty)| bound_tt(ty, normalize_bounds(trt, traits), zerocopy_crate))
+            .collect(),
         (FieldBounds::None, _) | (FieldBounds::Trailing(..), []) => vec![],
         (FieldBounds::Trailing(traits), [.., last]) => {
-            vec![bound_tt(last.1, normalize_bounds(trt, traits))]
+            vec![bound_tt(last.1, normalize_bounds(trt, traits), zerocopy_crate)]
         }
     };
 
@@ -1129,17 +1993,33 @@ fn impl_block<D: DataExt>(
     #[allow(unstable_name_collisions)] // See `BoolExt` below
     #[allow(clippy::incompatible_msrv)] // Work around https://github.com/rust-lang/rust-clippy/issues/12280
     let padding_check_bound = padding_check.and_then(|check| (!fields.is_empty()).then_some(check)).map(|check| {
-        let fields = fields.iter().map(|(_name, ty)| ty);
         let validator_macro = check.validator_macro_ident();
+        let validator_args = match (check, &fields[..]) {
+            (PaddingCheck::ReprCSliceDst, [prefix @ .., (_name, Type::Slice(trailing))]) => {
+                // The check lays out a sized struct in place of `Self`, so it
+                // needs all of `Self`'s `repr` attributes, including any
+                // `align(N)` or `packed(N)` modifiers.
+                let reprs = input.attrs.iter().filter(|attr| attr.path().is_ident("repr"));
+                let prefix = prefix.iter().map(|(_name, ty)| ty);
+                let elem = &trailing.elem;
+                quote!(#(#reprs)* #type_ident, [#(#prefix),*], #elem)
+            }
+            _ => {
+                let fields = fields.iter().map(|(_name, ty)| ty);
+                quote!(#type_ident, #(#fields),*)
+            }
+        };
         parse_quote!(
-            ::zerocopy::macro_util::HasPadding<#type_ident, {::zerocopy::#validator_macro!(#type_ident, #(#fields),*)}>:
-                ::zerocopy::macro_util::ShouldBe<false>
+            #zerocopy_crate::macro_util::HasPadding<#type_ident, {#zerocopy_crate::#validator_macro!(#validator_args)}>:
+                #zerocopy_crate::macro_util::ShouldBe<false>
         )
     });
 
     let self_bounds: Option<WherePredicate> = match self_type_trait_bounds {
         SelfBounds::None => None,
-        SelfBounds::All(traits) => Some(bound_tt(&parse_quote!(Self), traits.iter().copied())),
+        SelfBounds::All(traits) => {
+            Some(bound_tt(&parse_quote!(Self), traits.iter().copied(), zerocopy_crate))
+        }
     };
 
     let bounds = input
@@ -0,0 +1,144 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+// Make sure that `#[zerocopy(crate = ...)]` is respected by every derive, so
+// that crates which re-export zerocopy (e.g., through a facade crate) can use
+// the derives without depending on zerocopy directly.
+//
+// The derives must not emit any reference to `::zerocopy` when given a crate
+// path. To catch any which do, we shadow the real zerocopy in the extern prelude
+// with an unrelated crate named `zerocopy`, so that such references fail to
+// resolve. For the same reason, this test doesn't use `include.rs`, which refers
+// to `::zerocopy`.
+
+#![allow(warnings)]
+
+#[macro_use]
+extern crate static_assertions;
+extern crate core as zerocopy;
+pub extern crate zerocopy as zc;
+
+// Stands in for a facade crate which re-exports zerocopy under another name.
+pub mod facade {
+    pub use crate::zc;
+}
+
+/// A `u16` with alignment 2.
+#[derive(zc::KnownLayout, zc::Immutable, zc::FromBytes, zc::IntoBytes)]
+#[zerocopy(crate = zc)]
+#[repr(C, align(2))]
+pub struct AU16(u16);
+
+#[derive(
+    zc::KnownLayout, zc::Immutable, zc::FromBytes, zc::IntoBytes, zc::Unaligned, zc::ByteEq,
+)]
+#[zerocopy(crate = zc)]
+#[repr(C)]
+pub struct Struct {
+    a: u8,
+    b: [u8; 2],
+}
+
+assert_impl_all!(
+    Struct: zc::KnownLayout,
+    zc::Immutable,
+    zc::TryFromBytes,
+    zc::FromZeros,
+    zc::FromBytes,
+    zc::IntoBytes,
+    zc::Unaligned,
+    PartialEq,
+    Eq
+);
+
+#[derive(zc::TryFromBytes)]
+#[zerocopy(crate = zc)]
+#[repr(C)]
+pub struct TryFromBytes {
+    a: bool,
+}
+
+assert_impl_all!(TryFromBytes: zc::TryFromBytes);
+
+#[derive(zc::FromZeros)]
+#[zerocopy(crate = zc)]
+#[repr(C)]
+pub struct FromZeros {
+    a: bool,
+}
+
+assert_impl_all!(FromZeros: zc::TryFromBytes, zc::FromZeros);
+
+// Exercises the padding check.
+#[derive(zc::IntoBytes)]
+#[zerocopy(crate = facade::zc)]
+#[repr(C)]
+pub struct Padded {
+    a: u16,
+    b: [u8; 2],
+}
+
+assert_impl_all!(Padded: zc::IntoBytes);
+
+// Exercises the `repr(C)` slice DST layout and padding check.
+#[derive(zc::KnownLayout, zc::Immutable, zc::FromBytes, zc::IntoBytes)]
+#[zerocopy(crate = zc)]
+#[repr(C)]
+pub struct SliceDst {
+    len: u16,
+    elems: [AU16],
+}
+
+assert_impl_all!(SliceDst: zc::KnownLayout, zc::FromBytes, zc::IntoBytes);
+
+#[derive(zc::KnownLayout, zc::Immutable, zc::TryFromBytes, zc::IntoBytes)]
+#[zerocopy(crate = zc)]
+#[repr(u8)]
+pub enum Enum {
+    A,
+    B,
+}
+
+assert_impl_all!(Enum: zc::KnownLayout, zc::TryFromBytes, zc::IntoBytes);
+
+#[derive(zc::Immutable, zc::TryFromBytes)]
+#[zerocopy(crate = zc)]
+#[repr(u8)]
+pub enum DataEnum {
+    A(u8),
+    B(bool),
+}
+
+assert_impl_all!(DataEnum: zc::TryFromBytes);
+
+#[derive(zc::KnownLayout, zc::Immutable, zc::FromBytes, zc::IntoBytes, zc::Unaligned)]
+#[zerocopy(crate = zc)]
+#[repr(C)]
+pub union Union {
+    a: u8,
+    b: [u8; 1],
+}
+
+assert_impl_all!(Union: zc::KnownLayout, zc::FromBytes, zc::IntoBytes, zc::Unaligned);
+
+#[derive(zc::FromBytes, zc::IntoBytes, zc::Immutable)]
+#[zerocopy(crate = zc, byte_array_conv)]
+#[repr(C)]
+pub struct Conv(pub [u8; 4]);
+
+assert_impl_all!(Conv: From<[u8; 4]>);
+assert_impl_all!([u8; 4]: From<Conv>);
+
+#[test]
+fn test_crate_path() {
+    use zc::{FromBytes, IntoBytes};
+
+    let s = Struct::read_from(&[1, 2, 3][..]).unwrap();
+    assert_eq!(s.as_bytes(), [1, 2, 3]);
+    assert!(s == Struct::read_from(&[1, 2, 3][..]).unwrap());
+}
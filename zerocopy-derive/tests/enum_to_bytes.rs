@@ -101,3 +101,44 @@ enum Isize {
 }
 
 util_assert_impl_all!(Isize: imp::IntoBytes);
+
+// An enum is `IntoBytes` regardless of whether its discriminants cover every
+// value of its repr, since the discriminant of every instance is a valid
+// integer.
+
+#[derive(
+    Eq, PartialEq, Debug, imp::KnownLayout, imp::Immutable, imp::TryFromBytes, imp::IntoBytes,
+)]
+#[repr(u8)]
+enum Sparse {
+    A = 1,
+    B = 7,
+    C = 200,
+}
+
+util_assert_impl_all!(Sparse: imp::IntoBytes);
+
+#[derive(imp::Immutable, imp::IntoBytes)]
+#[repr(i16)]
+enum SparseI16 {
+    A = -300,
+    B,
+    C = 1000,
+}
+
+util_assert_impl_all!(SparseI16: imp::IntoBytes);
+
+#[test]
+fn test_sparse() {
+    imp::assert_eq!(imp::IntoBytes::as_bytes(&Sparse::A), [1]);
+    imp::assert_eq!(imp::IntoBytes::as_bytes(&Sparse::B), [7]);
+    imp::assert_eq!(imp::IntoBytes::as_bytes(&Sparse::C), [200]);
+    imp::assert_eq!(
+        <Sparse as imp::TryFromBytes>::try_read_from(imp::IntoBytes::as_bytes(&Sparse::B)),
+        imp::Ok(Sparse::B)
+    );
+
+    imp::assert_eq!(imp::IntoBytes::as_bytes(&SparseI16::A), (-300i16).to_ne_bytes());
+    imp::assert_eq!(imp::IntoBytes::as_bytes(&SparseI16::B), (-299i16).to_ne_bytes());
+    imp::assert_eq!(imp::IntoBytes::as_bytes(&SparseI16::C), 1000i16.to_ne_bytes());
+}
@@ -176,3 +176,374 @@ fn test_weird_discriminants() {
         <WeirdDiscriminants as imp::TryFromBytes>::try_read_from(&[0xFF; SIZE][..]).is_err()
     );
 }
+
+// Enums with fields are laid out as described in RFC 2195, and so their tag
+// can be validated before their fields.
+
+#[derive(Eq, PartialEq, Debug, imp::KnownLayout, imp::Immutable, imp::TryFromBytes)]
+#[repr(u8)]
+enum Tagged {
+    Empty,
+    Flag(bool),
+    Pair { a: u8, b: u16 },
+}
+
+util_assert_impl_all!(Tagged: imp::TryFromBytes);
+
+#[test]
+fn test_tagged() {
+    let b = u16::from_ne_bytes([3, 4]);
+    imp::assert_eq!(
+        <Tagged as imp::TryFromBytes>::try_read_from(&[0, 9, 9, 9]),
+        imp::Ok(Tagged::Empty)
+    );
+    imp::assert_eq!(
+        <Tagged as imp::TryFromBytes>::try_read_from(&[1, 0, 9, 9]),
+        imp::Ok(Tagged::Flag(false))
+    );
+    imp::assert_eq!(
+        <Tagged as imp::TryFromBytes>::try_read_from(&[1, 1, 9, 9]),
+        imp::Ok(Tagged::Flag(true))
+    );
+    imp::assert_eq!(
+        <Tagged as imp::TryFromBytes>::try_read_from(&[2, 7, 3, 4]),
+        imp::Ok(Tagged::Pair { a: 7, b })
+    );
+
+    // Invalid tags.
+    imp::assert!(<Tagged as imp::TryFromBytes>::try_read_from(&[3, 0, 0, 0]).is_err());
+    imp::assert!(<Tagged as imp::TryFromBytes>::try_read_from(&[0xFF, 0, 0, 0]).is_err());
+    // Invalid field for the variant selected by the tag.
+    imp::assert!(<Tagged as imp::TryFromBytes>::try_read_from(&[1, 2, 0, 0]).is_err());
+    // Wrong size.
+    imp::assert!(<Tagged as imp::TryFromBytes>::try_read_from(&[0]).is_err());
+}
+
+#[derive(Eq, PartialEq, Debug, imp::KnownLayout, imp::Immutable, imp::TryFromBytes)]
+#[repr(C)]
+enum CTagged {
+    A(u32),
+    B(bool),
+}
+
+util_assert_impl_all!(CTagged: imp::TryFromBytes);
+
+#[test]
+fn test_c_tagged() {
+    // On all supported targets, the tag of a `repr(C)` enum is a 4-byte C
+    // `int`, and the union of the variants' fields follows it.
+    imp::assert_eq!(::core::mem::size_of::<CTagged>(), 8);
+    let read = |tag: u32, field: u32| {
+        <CTagged as imp::TryFromBytes>::try_read_from(imp::IntoBytes::as_bytes(&[tag, field]))
+            .map_err(|_| ())
+    };
+
+    imp::assert_eq!(read(0, 5), imp::Ok(CTagged::A(5)));
+    imp::assert_eq!(read(1, 1), imp::Ok(CTagged::B(true)));
+    imp::assert!(read(1, 2).is_err());
+    imp::assert!(read(2, 0).is_err());
+}
+
+#[derive(Eq, PartialEq, Debug, imp::KnownLayout, imp::Immutable, imp::TryFromBytes)]
+#[repr(u8)]
+enum GenericTagged<T> {
+    None,
+    Some(T),
+}
+
+util_assert_impl_all!(GenericTagged<bool>: imp::TryFromBytes);
+util_assert_not_impl_any!(GenericTagged<imp::UnsafeCell<u8>>: imp::TryFromBytes);
+
+#[test]
+fn test_generic_tagged() {
+    imp::assert_eq!(
+        <GenericTagged<bool> as imp::TryFromBytes>::try_read_from(&[0, 2]),
+        imp::Ok(GenericTagged::None)
+    );
+    imp::assert_eq!(
+        <GenericTagged<bool> as imp::TryFromBytes>::try_read_from(&[1, 1]),
+        imp::Ok(GenericTagged::Some(true))
+    );
+    imp::assert!(<GenericTagged<bool> as imp::TryFromBytes>::try_read_from(&[1, 2]).is_err());
+}
+
+// An enum annotated with `#[zerocopy(exhaustive)]` which has a variant for
+// every possible `u8` discriminant additionally implements `FromZeros` and
+// `FromBytes`.
+#[derive(Eq, PartialEq, Debug, imp::KnownLayout, imp::Immutable, imp::TryFromBytes)]
+#[zerocopy(exhaustive)]
+#[repr(u8)]
+enum Exhaustive {
+    Variant0,
+    Variant1,
+    Variant2,
+    Variant3,
+    Variant4,
+    Variant5,
+    Variant6,
+    Variant7,
+    Variant8,
+    Variant9,
+    Variant10,
+    Variant11,
+    Variant12,
+    Variant13,
+    Variant14,
+    Variant15,
+    Variant16,
+    Variant17,
+    Variant18,
+    Variant19,
+    Variant20,
+    Variant21,
+    Variant22,
+    Variant23,
+    Variant24,
+    Variant25,
+    Variant26,
+    Variant27,
+    Variant28,
+    Variant29,
+    Variant30,
+    Variant31,
+    Variant32,
+    Variant33,
+    Variant34,
+    Variant35,
+    Variant36,
+    Variant37,
+    Variant38,
+    Variant39,
+    Variant40,
+    Variant41,
+    Variant42,
+    Variant43,
+    Variant44,
+    Variant45,
+    Variant46,
+    Variant47,
+    Variant48,
+    Variant49,
+    Variant50,
+    Variant51,
+    Variant52,
+    Variant53,
+    Variant54,
+    Variant55,
+    Variant56,
+    Variant57,
+    Variant58,
+    Variant59,
+    Variant60,
+    Variant61,
+    Variant62,
+    Variant63,
+    Variant64,
+    Variant65,
+    Variant66,
+    Variant67,
+    Variant68,
+    Variant69,
+    Variant70,
+    Variant71,
+    Variant72,
+    Variant73,
+    Variant74,
+    Variant75,
+    Variant76,
+    Variant77,
+    Variant78,
+    Variant79,
+    Variant80,
+    Variant81,
+    Variant82,
+    Variant83,
+    Variant84,
+    Variant85,
+    Variant86,
+    Variant87,
+    Variant88,
+    Variant89,
+    Variant90,
+    Variant91,
+    Variant92,
+    Variant93,
+    Variant94,
+    Variant95,
+    Variant96,
+    Variant97,
+    Variant98,
+    Variant99,
+    Variant100,
+    Variant101,
+    Variant102,
+    Variant103,
+    Variant104,
+    Variant105,
+    Variant106,
+    Variant107,
+    Variant108,
+    Variant109,
+    Variant110,
+    Variant111,
+    Variant112,
+    Variant113,
+    Variant114,
+    Variant115,
+    Variant116,
+    Variant117,
+    Variant118,
+    Variant119,
+    Variant120,
+    Variant121,
+    Variant122,
+    Variant123,
+    Variant124,
+    Variant125,
+    Variant126,
+    Variant127,
+    Variant128,
+    Variant129,
+    Variant130,
+    Variant131,
+    Variant132,
+    Variant133,
+    Variant134,
+    Variant135,
+    Variant136,
+    Variant137,
+    Variant138,
+    Variant139,
+    Variant140,
+    Variant141,
+    Variant142,
+    Variant143,
+    Variant144,
+    Variant145,
+    Variant146,
+    Variant147,
+    Variant148,
+    Variant149,
+    Variant150,
+    Variant151,
+    Variant152,
+    Variant153,
+    Variant154,
+    Variant155,
+    Variant156,
+    Variant157,
+    Variant158,
+    Variant159,
+    Variant160,
+    Variant161,
+    Variant162,
+    Variant163,
+    Variant164,
+    Variant165,
+    Variant166,
+    Variant167,
+    Variant168,
+    Variant169,
+    Variant170,
+    Variant171,
+    Variant172,
+    Variant173,
+    Variant174,
+    Variant175,
+    Variant176,
+    Variant177,
+    Variant178,
+    Variant179,
+    Variant180,
+    Variant181,
+    Variant182,
+    Variant183,
+    Variant184,
+    Variant185,
+    Variant186,
+    Variant187,
+    Variant188,
+    Variant189,
+    Variant190,
+    Variant191,
+    Variant192,
+    Variant193,
+    Variant194,
+    Variant195,
+    Variant196,
+    Variant197,
+    Variant198,
+    Variant199,
+    Variant200,
+    Variant201,
+    Variant202,
+    Variant203,
+    Variant204,
+    Variant205,
+    Variant206,
+    Variant207,
+    Variant208,
+    Variant209,
+    Variant210,
+    Variant211,
+    Variant212,
+    Variant213,
+    Variant214,
+    Variant215,
+    Variant216,
+    Variant217,
+    Variant218,
+    Variant219,
+    Variant220,
+    Variant221,
+    Variant222,
+    Variant223,
+    Variant224,
+    Variant225,
+    Variant226,
+    Variant227,
+    Variant228,
+    Variant229,
+    Variant230,
+    Variant231,
+    Variant232,
+    Variant233,
+    Variant234,
+    Variant235,
+    Variant236,
+    Variant237,
+    Variant238,
+    Variant239,
+    Variant240,
+    Variant241,
+    Variant242,
+    Variant243,
+    Variant244,
+    Variant245,
+    Variant246,
+    Variant247,
+    Variant248,
+    Variant249,
+    Variant250,
+    Variant251,
+    Variant252,
+    Variant253,
+    Variant254,
+    Variant255,
+}
+
+util_assert_impl_all!(Exhaustive: imp::TryFromBytes, imp::FromZeros, imp::FromBytes);
+
+#[test]
+fn test_exhaustive() {
+    imp::assert_eq!(<Exhaustive as imp::FromZeros>::new_zeroed(), Exhaustive::Variant0);
+    imp::assert_eq!(<Exhaustive as imp::FromBytes>::read_from(&[1]), imp::Ok(Exhaustive::Variant1));
+    imp::assert_eq!(
+        <Exhaustive as imp::FromBytes>::read_from(&[255]),
+        imp::Ok(Exhaustive::Variant255)
+    );
+    imp::assert_eq!(
+        <Exhaustive as imp::TryFromBytes>::try_read_from(&[128]),
+        imp::Ok(Exhaustive::Variant128)
+    );
+}
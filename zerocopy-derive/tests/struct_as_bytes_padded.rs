@@ -0,0 +1,82 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+// See comment in `include.rs` for why we disable the prelude.
+#![no_implicit_prelude]
+#![allow(warnings)]
+
+include!("include.rs");
+
+// `#[zerocopy(as_bytes_padded)]` makes `derive(IntoBytesWithPadding)` emit an
+// inherent `as_bytes_padded` method, which zeroes the struct's padding.
+// Structs without padding are rejected; see `ui-nightly/as_bytes_padded.rs`.
+
+#[derive(imp::IntoBytesWithPadding, imp::Immutable)]
+#[zerocopy(as_bytes_padded)]
+#[repr(C)]
+struct Padded {
+    a: u8,
+    b: u32,
+    c: u16,
+}
+
+util_assert_impl_all!(Padded: imp::IntoBytesWithPadding);
+util_assert_not_impl_any!(Padded: imp::IntoBytes);
+
+#[test]
+fn test_padded() {
+    let padded = Padded { a: 1, b: imp::u32::from_ne_bytes([2, 3, 4, 5]), c: 0x0606 };
+    imp::assert_eq!(padded.as_bytes_padded(), [1, 0, 0, 0, 2, 3, 4, 5, 6, 6, 0, 0]);
+}
+
+#[derive(imp::IntoBytesWithPadding, imp::Immutable)]
+#[zerocopy(as_bytes_padded)]
+#[repr(C, align(8))]
+struct TrailingPadding(u8, [u8; 2]);
+
+#[test]
+fn test_trailing_padding() {
+    imp::assert_eq!(TrailingPadding(1, [2, 3]).as_bytes_padded(), [1, 2, 3, 0, 0, 0, 0, 0]);
+}
+
+// Padding within a field is not permitted, since each field's bytes are
+// obtained using `IntoBytes::as_bytes`, but padding around a field is.
+
+#[derive(imp::IntoBytes, imp::Immutable)]
+#[repr(C)]
+struct Inner {
+    a: u8,
+    b: [u8; 3],
+}
+
+#[derive(imp::IntoBytesWithPadding, imp::Immutable)]
+#[zerocopy(as_bytes_padded)]
+#[repr(C)]
+struct Nested {
+    a: Inner,
+    b: u16,
+    c: u32,
+}
+
+#[test]
+fn test_nested() {
+    let nested = Nested { a: Inner { a: 1, b: [2, 3, 4] }, b: 0x0505, c: 0x07070707 };
+    imp::assert_eq!(nested.as_bytes_padded(), [1, 2, 3, 4, 5, 5, 0, 0, 7, 7, 7, 7]);
+}
+
+// `#[zerocopy(as_bytes_padded)]` has no effect on `derive(IntoBytes)`.
+
+#[derive(imp::IntoBytes, imp::Immutable)]
+#[zerocopy(as_bytes_padded)]
+#[repr(C)]
+struct NoPadding {
+    a: u8,
+    b: [u8; 3],
+}
+
+util_assert_impl_all!(NoPadding: imp::IntoBytes);
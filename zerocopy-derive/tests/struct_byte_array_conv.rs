@@ -0,0 +1,83 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+// See comment in `include.rs` for why we disable the prelude.
+#![no_implicit_prelude]
+#![allow(warnings)]
+
+include!("include.rs");
+
+// `#[zerocopy(byte_array_conv)]` emits `From<[u8; N]> for T` from
+// `derive(FromBytes)` and `From<T> for [u8; N]` from `derive(IntoBytes)`.
+
+#[derive(imp::FromBytes, imp::IntoBytes, imp::Immutable)]
+#[zerocopy(byte_array_conv)]
+#[repr(C)]
+struct Inferred {
+    a: u16,
+    b: [u8; 2],
+    c: u32,
+}
+
+util_assert_impl_all!(Inferred: imp::From<[u8; 8]>);
+util_assert_impl_all!([u8; 8]: imp::From<Inferred>);
+
+#[test]
+fn test_inferred() {
+    let bytes = [1, 0, 2, 3, 4, 0, 0, 0];
+    let x = <Inferred as imp::From<_>>::from(bytes);
+    imp::assert_eq!(x.a, u16::from_ne_bytes([1, 0]));
+    imp::assert_eq!(x.b, [2, 3]);
+    imp::assert_eq!(x.c, u32::from_ne_bytes([4, 0, 0, 0]));
+    let round_trip: [u8; 8] = imp::Into::into(x);
+    imp::assert_eq!(round_trip, bytes);
+}
+
+#[derive(imp::FromBytes, imp::IntoBytes, imp::Immutable)]
+#[zerocopy(byte_array_conv(4))]
+#[repr(transparent)]
+struct Explicit(u32);
+
+util_assert_impl_all!(Explicit: imp::From<[u8; 4]>);
+util_assert_impl_all!([u8; 4]: imp::From<Explicit>);
+
+#[test]
+fn test_explicit() {
+    let x = <Explicit as imp::From<_>>::from([0xFF; 4]);
+    imp::assert_eq!(x.0, u32::MAX);
+    imp::assert_eq!(<[u8; 4] as imp::From<_>>::from(x), [0xFF; 4]);
+}
+
+// Only the conversion corresponding to each derive is emitted.
+
+#[derive(imp::FromBytes)]
+#[zerocopy(byte_array_conv)]
+#[repr(C)]
+struct FromOnly {
+    a: u8,
+    b: u16,
+}
+
+util_assert_impl_all!(FromOnly: imp::From<[u8; 4]>);
+util_assert_not_impl_any!([u8; 4]: imp::From<FromOnly>);
+
+#[derive(imp::IntoBytes, imp::Immutable)]
+#[zerocopy(byte_array_conv)]
+#[repr(u8)]
+enum IntoOnly {
+    A = 1,
+    B = 2,
+}
+
+util_assert_impl_all!([u8; 1]: imp::From<IntoOnly>);
+
+#[test]
+fn test_into_only() {
+    imp::assert_eq!(<[u8; 1] as imp::From<_>>::from(IntoOnly::A), [1]);
+    imp::assert_eq!(<[u8; 1] as imp::From<_>>::from(IntoOnly::B), [2]);
+}
@@ -0,0 +1,67 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+// See comment in `include.rs` for why we disable the prelude.
+#![no_implicit_prelude]
+#![allow(warnings)]
+
+include!("include.rs");
+
+// A struct is `ByteEq` if it is `IntoBytes` and `Immutable`.
+
+#[derive(imp::ByteEq, imp::IntoBytes, imp::Immutable)]
+#[repr(C)]
+struct Arrays {
+    a: [u8; 3],
+    b: u8,
+    c: [[u16; 2]; 4],
+}
+
+util_assert_impl_all!(Arrays: imp::PartialEq, imp::Eq);
+
+#[test]
+fn test_arrays() {
+    let a = Arrays { a: [1, 2, 3], b: 4, c: [[5, 6]; 4] };
+    let b = Arrays { a: [1, 2, 3], b: 4, c: [[5, 6]; 4] };
+    imp::assert!(a == b);
+
+    let b = Arrays { a: [1, 2, 0], b: 4, c: [[5, 6]; 4] };
+    imp::assert!(a != b);
+
+    let b = Arrays { a: [1, 2, 3], b: 4, c: [[5, 6], [5, 6], [5, 6], [5, 0]] };
+    imp::assert!(a != b);
+}
+
+#[derive(imp::ByteEq, imp::IntoBytes, imp::Immutable)]
+#[repr(C)]
+struct Floats {
+    a: [f32; 2],
+    b: f64,
+}
+
+#[test]
+fn test_floats() {
+    // Floats are compared by their bytes, not by their values.
+    let nan = Floats { a: [f32::NAN; 2], b: 0.0 };
+    imp::assert!(nan == Floats { a: [f32::NAN; 2], b: 0.0 });
+    imp::assert!(nan != Floats { a: [f32::NAN; 2], b: -0.0 });
+}
+
+#[derive(imp::ByteEq, imp::IntoBytes, imp::Immutable)]
+#[repr(transparent)]
+struct TypeParams<T>([T; 2]);
+
+util_assert_impl_all!(TypeParams<u8>: imp::PartialEq, imp::Eq);
+util_assert_impl_all!(TypeParams<util::AU16>: imp::PartialEq, imp::Eq);
+util_assert_not_impl_any!(TypeParams<util::NotZerocopy>: imp::PartialEq, imp::Eq);
+
+#[test]
+fn test_type_params() {
+    imp::assert!(TypeParams([1u8, 2]) == TypeParams([1, 2]));
+    imp::assert!(TypeParams([1u8, 2]) != TypeParams([2, 1]));
+}
@@ -42,6 +42,17 @@ struct Unsized {
 
 util_assert_impl_all!(Unsized: imp::FromBytes);
 
+// The derive emits a check per field which is named after that field. A field
+// whose name is also the name of another field's type should not confuse it.
+
+#[derive(imp::FromBytes)]
+struct FieldNamedAfterType {
+    u8: u8,
+    b: u8,
+}
+
+util_assert_impl_all!(FieldNamedAfterType: imp::FromBytes);
+
 #[derive(imp::FromBytes)]
 struct TypeParams<'a, T: ?imp::Sized, I: imp::Iterator> {
     a: I::Item,
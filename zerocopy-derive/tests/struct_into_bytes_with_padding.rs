@@ -0,0 +1,102 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+// See comment in `include.rs` for why we disable the prelude.
+#![no_implicit_prelude]
+#![allow(warnings)]
+
+include!("include.rs");
+
+// A struct is `IntoBytesWithPadding` if all fields are `Immutable`. Its `repr`
+// doesn't matter.
+
+#[derive(imp::IntoBytesWithPadding)]
+#[repr(C)]
+struct Zst;
+
+util_assert_impl_all!(Zst: imp::IntoBytesWithPadding);
+
+#[derive(imp::IntoBytesWithPadding)]
+#[repr(C)]
+struct Padded {
+    a: u8,
+    b: u32,
+}
+
+util_assert_impl_all!(Padded: imp::IntoBytesWithPadding);
+
+#[test]
+fn test_padded() {
+    let padded = Padded { a: 1, b: 0x02020202 };
+    let bytes = imp::IntoBytesWithPadding::as_bytes_including_padding(&padded);
+    imp::assert_eq!(bytes.len(), 8);
+    // SAFETY: Byte 0 is the initialized field `a`.
+    imp::assert_eq!(unsafe { bytes[0].assume_init() }, 1);
+    for byte in &bytes[4..] {
+        // SAFETY: Bytes 4..8 are the initialized field `b`.
+        imp::assert_eq!(unsafe { byte.assume_init() }, 2);
+    }
+}
+
+#[derive(imp::IntoBytesWithPadding)]
+#[repr(C, align(8))]
+struct TrailingPadding(u8);
+
+util_assert_impl_all!(TrailingPadding: imp::IntoBytesWithPadding);
+
+#[test]
+fn test_trailing_padding() {
+    let bytes = imp::IntoBytesWithPadding::as_bytes_including_padding(&TrailingPadding(3));
+    imp::assert_eq!(bytes.len(), 8);
+    // SAFETY: Byte 0 is the initialized field.
+    imp::assert_eq!(unsafe { bytes[0].assume_init() }, 3);
+}
+
+#[derive(imp::IntoBytesWithPadding)]
+#[repr(C, packed)]
+struct Packed {
+    a: u8,
+    b: u32,
+}
+
+util_assert_impl_all!(Packed: imp::IntoBytesWithPadding);
+
+#[derive(imp::IntoBytesWithPadding)]
+#[repr(C)]
+struct TypeParams<T> {
+    a: u8,
+    b: T,
+}
+
+util_assert_impl_all!(TypeParams<u64>: imp::IntoBytesWithPadding);
+util_assert_impl_all!(TypeParams<util::AU16>: imp::IntoBytesWithPadding);
+util_assert_not_impl_any!(TypeParams<util::NotZerocopy>: imp::IntoBytesWithPadding);
+util_assert_not_impl_any!(TypeParams<imp::UnsafeCell<u8>>: imp::IntoBytesWithPadding);
+
+#[derive(imp::IntoBytesWithPadding)]
+struct ReprRust {
+    a: u8,
+    b: u32,
+}
+
+util_assert_impl_all!(ReprRust: imp::IntoBytesWithPadding);
+
+#[derive(imp::IntoBytesWithPadding)]
+#[repr(transparent)]
+struct Transparent(u8);
+
+util_assert_impl_all!(Transparent: imp::IntoBytesWithPadding);
+
+#[derive(imp::IntoBytesWithPadding)]
+#[repr(packed)]
+struct ReprPacked {
+    a: u8,
+    b: u32,
+}
+
+util_assert_impl_all!(ReprPacked: imp::IntoBytesWithPadding);
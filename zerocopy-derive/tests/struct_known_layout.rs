@@ -55,3 +55,54 @@ where
     T: 'a + 'b + imp::KnownLayout;
 
 util_assert_impl_all!(WithParams<'static, 'static, u8, 42>: imp::KnownLayout);
+
+// Deriving `KnownLayout` should work for `repr(transparent)` newtypes around
+// unsized types, which have the same pointer metadata as the type they wrap.
+
+#[derive(imp::KnownLayout, imp::Immutable, imp::FromBytes)]
+#[repr(transparent)]
+struct TransparentSlice([u8]);
+
+util_assert_impl_all!(TransparentSlice: imp::KnownLayout);
+
+#[derive(imp::KnownLayout)]
+#[repr(transparent)]
+struct TransparentDst(imp::PhantomData<u8>, [util::AU16]);
+
+util_assert_impl_all!(TransparentDst: imp::KnownLayout);
+
+// `repr(transparent)` newtypes around sized types don't require their field
+// to be `KnownLayout`.
+
+struct NotKnownLayout;
+
+#[derive(imp::KnownLayout)]
+#[repr(transparent)]
+struct TransparentSized(NotKnownLayout);
+
+util_assert_impl_all!(TransparentSized: imp::KnownLayout);
+
+#[derive(imp::KnownLayout)]
+#[repr(transparent)]
+struct TransparentGeneric<T>(T);
+
+util_assert_impl_all!(TransparentGeneric<NotKnownLayout>: imp::KnownLayout);
+
+#[test]
+fn test_transparent_dst() {
+    fn assert_metadata_is_usize<T: ?imp::Sized + imp::KnownLayout<PointerMetadata = usize>>() {}
+
+    assert_metadata_is_usize::<TransparentSlice>();
+    assert_metadata_is_usize::<TransparentDst>();
+
+    let bytes = &[1u8, 2, 3][..];
+    let slc = <TransparentSlice as imp::FromBytes>::ref_from(bytes).unwrap();
+    imp::assert_eq!(&slc.0, bytes);
+    imp::assert_eq!(::core::mem::size_of_val(slc), 3);
+
+    let (slc, rest) =
+        <TransparentSlice as imp::FromBytes>::ref_from_prefix_with_trailing_elements(bytes, 2)
+            .unwrap();
+    imp::assert_eq!(&slc.0, [1, 2]);
+    imp::assert_eq!(rest, [3]);
+}
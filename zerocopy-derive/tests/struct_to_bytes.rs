@@ -197,3 +197,46 @@ pub struct IndexEntry<const SIZE_BLOCK_ID: usize> {
 
 util_assert_impl_all!(IndexEntry<0>: imp::IntoBytes);
 util_assert_impl_all!(IndexEntry<1>: imp::IntoBytes);
+
+// A `repr(C)` struct with a trailing slice field is `IntoBytes` if its sized
+// prefix has no padding and its trailing slice never requires trailing
+// padding.
+
+#[derive(imp::KnownLayout, imp::Immutable, imp::FromBytes, imp::IntoBytes)]
+#[repr(C)]
+struct ReprCSliceDst {
+    kind: u8,
+    flags: u8,
+    len: util::AU16,
+    body: [util::AU16],
+}
+
+util_assert_impl_all!(ReprCSliceDst: imp::IntoBytes);
+
+#[test]
+fn test_repr_c_slice_dst_as_bytes() {
+    let bytes: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8];
+    let msg = <ReprCSliceDst as imp::FromBytes>::ref_from(bytes).unwrap();
+    imp::assert_eq!(msg.body.len(), 2);
+    imp::assert_eq!(imp::IntoBytes::as_bytes(msg), bytes);
+}
+
+#[derive(imp::IntoBytes)]
+#[repr(C)]
+struct ReprCSliceDstUnaligned {
+    header: [u8; 3],
+    body: [[u8; 5]],
+}
+
+util_assert_impl_all!(ReprCSliceDstUnaligned: imp::IntoBytes);
+
+// `repr(align(N))` is accounted for: the element size is a multiple of the
+// struct's alignment, so no trailing padding is ever required.
+#[derive(imp::IntoBytes)]
+#[repr(C, align(4))]
+struct ReprCAlignSliceDst {
+    header: [u8; 4],
+    body: [[u8; 4]],
+}
+
+util_assert_impl_all!(ReprCAlignSliceDst: imp::IntoBytes);
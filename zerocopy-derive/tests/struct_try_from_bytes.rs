@@ -216,3 +216,119 @@ fn packed_unsized() {
     let converted = <CPackedUnsized as imp::TryFromBytes>::try_ref_from(candidate);
     imp::assert!(converted.is_ok());
 }
+
+#[derive(imp::TryFromBytes, imp::IntoBytes, imp::Immutable, imp::Debug, imp::PartialEq)]
+#[repr(C)]
+struct CharAndInt {
+    c: char,
+    n: u32,
+}
+
+util_assert_impl_all!(CharAndInt: imp::TryFromBytes);
+
+#[test]
+fn char_and_int() {
+    fn bytes(c: u32, n: u32) -> [u8; 8] {
+        let mut bytes = [0u8; 8];
+        bytes[..4].copy_from_slice(&c.to_ne_bytes());
+        bytes[4..].copy_from_slice(&n.to_ne_bytes());
+        bytes
+    }
+
+    let valid = CharAndInt { c: 'z', n: 0xFFFF_FFFF };
+    let candidate = bytes('z' as u32, 0xFFFF_FFFF);
+    imp::assert_eq!(imp::IntoBytes::as_bytes(&valid), &candidate[..]);
+    let converted = <CharAndInt as imp::TryFromBytes>::try_read_from(&candidate[..]);
+    imp::assert_eq!(converted.unwrap(), valid);
+
+    // Surrogate code points and code points above `char::MAX` are rejected,
+    // even though the `n` field is valid.
+    for c in [0xD800, 0xDFFF, 0x110000, 0xFFFF_FFFF] {
+        let candidate = bytes(c, 0);
+        let converted = <CharAndInt as imp::TryFromBytes>::try_read_from(&candidate[..]);
+        match converted {
+            imp::Err(imp::TryReadError::Validity(err)) => {
+                imp::assert_eq!(err.into_src(), &candidate[..])
+            }
+            _ => ::core::panic!("expected a validity error for {:#x}", c),
+        }
+    }
+}
+
+#[derive(imp::TryFromBytes)]
+#[repr(C)]
+struct NestedChar {
+    a: u32,
+    inner: CharAndInt,
+}
+
+#[test]
+fn nested_char() {
+    // Validation recurses into fields which are themselves structs.
+    let mut candidate = [0u8; 12];
+    candidate[4..8].copy_from_slice(&('z' as u32).to_ne_bytes());
+    let converted = <NestedChar as imp::TryFromBytes>::try_read_from(&candidate[..]);
+    imp::assert!(converted.is_ok());
+
+    candidate[4..8].copy_from_slice(&0xD800u32.to_ne_bytes());
+    let converted = <NestedChar as imp::TryFromBytes>::try_read_from(&candidate[..]);
+    imp::assert!(converted.is_err());
+}
+
+#[derive(imp::TryFromBytes, imp::KnownLayout, imp::Immutable, imp::Debug, imp::PartialEq)]
+#[zerocopy(validate = len_le_cap)]
+#[repr(C)]
+struct LenAndCap {
+    len: u32,
+    cap: u32,
+}
+
+fn len_le_cap(slf: &LenAndCap) -> bool {
+    slf.len <= slf.cap
+}
+
+util_assert_impl_all!(LenAndCap: imp::TryFromBytes);
+
+fn len_and_cap_bytes(len: u32, cap: u32) -> [u8; 8] {
+    let mut bytes = [0u8; 8];
+    bytes[..4].copy_from_slice(&len.to_ne_bytes());
+    bytes[4..].copy_from_slice(&cap.to_ne_bytes());
+    bytes
+}
+
+#[test]
+fn validate() {
+    let candidate = len_and_cap_bytes(3, 4);
+    let converted = <LenAndCap as imp::TryFromBytes>::try_read_from(&candidate[..]);
+    imp::assert_eq!(converted.unwrap(), LenAndCap { len: 3, cap: 4 });
+
+    // Every field is bit-valid, but the validator rejects `len > cap`.
+    let candidate = len_and_cap_bytes(5, 4);
+    let converted = <LenAndCap as imp::TryFromBytes>::try_read_from(&candidate[..]);
+    match converted {
+        imp::Err(imp::TryReadError::Validity(err)) => {
+            imp::assert_eq!(err.into_src(), &candidate[..])
+        }
+        _ => ::core::panic!("expected a validity error"),
+    }
+}
+
+#[derive(imp::TryFromBytes, imp::KnownLayout, imp::Immutable)]
+#[repr(C, packed)]
+struct PackedLenAndCap {
+    a: u8,
+    inner: LenAndCap,
+}
+
+#[test]
+fn validate_misaligned() {
+    // `inner` is misaligned, so the validator is run on a copy of it.
+    let mut candidate = [0u8; 9];
+    candidate[1..].copy_from_slice(&len_and_cap_bytes(3, 4));
+    let converted = <PackedLenAndCap as imp::TryFromBytes>::try_ref_from(&candidate[..]);
+    imp::assert!(converted.is_ok());
+
+    candidate[1..].copy_from_slice(&len_and_cap_bytes(5, 4));
+    let converted = <PackedLenAndCap as imp::TryFromBytes>::try_ref_from(&candidate[..]);
+    imp::assert!(converted.is_err());
+}
@@ -0,0 +1 @@
+../ui-nightly/byte_array_conv.rs
\ No newline at end of file
@@ -0,0 +1 @@
+../ui-nightly/byte_eq.rs
\ No newline at end of file
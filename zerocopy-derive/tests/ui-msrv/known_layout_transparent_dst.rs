@@ -0,0 +1 @@
+../ui-nightly/known_layout_transparent_dst.rs
\ No newline at end of file
@@ -0,0 +1,64 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+#[macro_use]
+extern crate zerocopy;
+
+fn main() {}
+
+//
+// Structs without padding could be `IntoBytes`, and so are rejected
+//
+
+#[derive(IntoBytesWithPadding, Immutable)]
+#[zerocopy(as_bytes_padded)]
+#[repr(C)]
+struct NoPadding {
+    a: u8,
+    b: [u8; 3],
+}
+
+#[derive(IntoBytesWithPadding, Immutable)]
+#[zerocopy(as_bytes_padded)]
+#[repr(C)]
+struct Zst;
+
+//
+// Unsupported types
+//
+
+#[derive(IntoBytesWithPadding, Immutable)]
+#[zerocopy(as_bytes_padded)]
+#[repr(u8)]
+enum Enum {
+    A,
+}
+
+#[derive(IntoBytesWithPadding, Immutable)]
+#[zerocopy(as_bytes_padded)]
+#[repr(C)]
+struct Generic<T> {
+    a: u8,
+    b: T,
+}
+
+#[derive(IntoBytesWithPadding, Immutable)]
+#[zerocopy(as_bytes_padded)]
+#[repr(packed)]
+struct Packed {
+    a: u8,
+    b: u16,
+}
+
+#[derive(IntoBytesWithPadding, Immutable)]
+#[zerocopy(as_bytes_padded(foo))]
+#[repr(C)]
+struct Malformed {
+    a: u8,
+    b: u16,
+}
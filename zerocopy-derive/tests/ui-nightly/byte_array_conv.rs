@@ -0,0 +1,49 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+#[macro_use]
+extern crate zerocopy;
+
+fn main() {}
+
+//
+// Malformed attributes
+//
+
+#[derive(FromBytes)]
+#[zerocopy(byte_array_convert)]
+#[repr(C)]
+struct ByteArrayConv1(u8);
+
+#[derive(FromBytes)]
+#[zerocopy(byte_array_conv = 1)]
+#[repr(C)]
+struct ByteArrayConv2(u8);
+
+#[derive(FromBytes)]
+#[zerocopy(byte_array_conv, byte_array_conv(1))]
+#[repr(C)]
+struct ByteArrayConv3(u8);
+
+//
+// Generic types
+//
+
+#[derive(FromBytes)]
+#[zerocopy(byte_array_conv)]
+#[repr(C)]
+struct ByteArrayConv4<T>(T);
+
+//
+// Length mismatch
+//
+
+#[derive(FromBytes, IntoBytes, Immutable)]
+#[zerocopy(byte_array_conv(3))]
+#[repr(C)]
+struct ByteArrayConv5(u16);
@@ -0,0 +1,22 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+#[macro_use]
+extern crate zerocopy;
+
+fn main() {}
+
+// `ByteEq` requires `IntoBytes`, since comparing padding bytes would be
+// meaningless.
+
+#[derive(ByteEq, Immutable)]
+#[repr(C)]
+struct ByteEq1 {
+    a: u8,
+    b: u16,
+}
@@ -0,0 +1,38 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+#[macro_use]
+extern crate zerocopy;
+
+fn main() {}
+
+//
+// Malformed attributes
+//
+
+#[derive(KnownLayout)]
+#[zerocopy(crate)]
+#[repr(C)]
+struct Crate1(u8);
+
+#[derive(KnownLayout)]
+#[zerocopy(crate = "zerocopy")]
+#[repr(C)]
+struct Crate2(u8);
+
+#[derive(KnownLayout)]
+#[zerocopy(crate = zerocopy, crate = zerocopy)]
+#[repr(C)]
+struct Crate3(u8);
+
+
+// Each derive reports the same error, but it is only reported once.
+#[derive(KnownLayout, Immutable, FromBytes, IntoBytes)]
+#[zerocopy(crate = "zerocopy")]
+#[repr(C)]
+struct Crate4(u8);
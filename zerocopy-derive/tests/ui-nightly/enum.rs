@@ -74,7 +74,7 @@ enum TryFromBytes1 {
 #[derive(TryFromBytes)]
 #[repr(u8)]
 enum TryFromBytes2 {
-    A(u8),
+    A(core::cell::UnsafeCell<u8>),
 }
 
 //
@@ -0,0 +1,73 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+#[macro_use]
+extern crate zerocopy;
+
+fn main() {}
+
+//
+// Malformed attributes
+//
+
+#[derive(TryFromBytes)]
+#[zerocopy(exhaustive(u8))]
+#[repr(u8)]
+enum Exhaustive1 {
+    A,
+}
+
+#[derive(TryFromBytes)]
+#[zerocopy(exhaustive, exhaustive)]
+#[repr(u8)]
+enum Exhaustive2 {
+    A,
+}
+
+//
+// Missing variants
+//
+
+#[derive(TryFromBytes)]
+#[zerocopy(exhaustive)]
+#[repr(u8)]
+enum Exhaustive3 {
+    A,
+    B,
+    C,
+}
+
+#[derive(TryFromBytes)]
+#[zerocopy(exhaustive)]
+#[repr(i16)]
+enum Exhaustive4 {
+    A = 0,
+}
+
+//
+// Unsupported types and traits
+//
+
+#[derive(TryFromBytes)]
+#[zerocopy(exhaustive)]
+#[repr(u32)]
+enum Exhaustive5 {
+    A,
+}
+
+#[derive(TryFromBytes)]
+#[zerocopy(exhaustive)]
+#[repr(C)]
+struct Exhaustive6(u8);
+
+#[derive(FromZeros)]
+#[zerocopy(exhaustive)]
+#[repr(u8)]
+enum Exhaustive7 {
+    A,
+}
@@ -0,0 +1,41 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+#[macro_use]
+extern crate zerocopy;
+
+#[path = "../include.rs"]
+mod util;
+
+use self::util::util::AU16;
+
+fn main() {}
+
+// Padding between the sized prefix and the trailing slice.
+#[derive(IntoBytes)]
+#[repr(C)]
+struct InterFieldPadding {
+    foo: u8,
+    bar: [AU16],
+}
+
+// Trailing padding after an odd number of trailing elements.
+#[derive(IntoBytes)]
+#[repr(C)]
+struct TrailingPadding {
+    foo: AU16,
+    bar: [u8],
+}
+
+// Trailing padding required by `repr(align(N))`.
+#[derive(IntoBytes)]
+#[repr(C, align(2))]
+struct OverAligned {
+    foo: u8,
+    bar: [u8],
+}
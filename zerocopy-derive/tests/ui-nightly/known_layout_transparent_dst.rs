@@ -0,0 +1,27 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+extern crate zerocopy;
+
+#[path = "../include.rs"]
+mod util;
+
+use zerocopy::KnownLayout;
+
+fn main() {}
+
+#[derive(KnownLayout)]
+#[repr(transparent)]
+struct Slice([u8]);
+
+// `derive(KnownLayout)` only reflects the layout of a `repr(transparent)`
+// struct whose trailing field is a slice or `str`, so it cannot be derived for
+// a `repr(transparent)` struct which wraps another unsized type.
+#[derive(KnownLayout)]
+#[repr(transparent)]
+struct Wrapper(Slice);
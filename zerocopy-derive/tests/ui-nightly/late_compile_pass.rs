@@ -82,3 +82,17 @@ struct Unaligned2 {
 struct Unaligned3 {
     aligned: AU16,
 }
+
+// A `repr(packed)` struct has an alignment of 1, but isn't `Unaligned` unless
+// it derives `Unaligned`. The error should name the field.
+#[repr(C, packed)]
+struct Packed {
+    a: u16,
+}
+
+#[derive(Unaligned)]
+#[repr(C)]
+struct Unaligned4 {
+    header: u8,
+    packed: Packed,
+}
@@ -0,0 +1,51 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+#[macro_use]
+extern crate zerocopy;
+
+fn main() {}
+
+fn validate<T>(_: &T) -> bool {
+    true
+}
+
+//
+// Malformed attributes
+//
+
+#[derive(TryFromBytes, Immutable)]
+#[zerocopy(validate)]
+#[repr(C)]
+struct Validate1(u8);
+
+#[derive(TryFromBytes, Immutable)]
+#[zerocopy(validate = 1)]
+#[repr(C)]
+struct Validate2(u8);
+
+#[derive(TryFromBytes, Immutable)]
+#[zerocopy(validate = validate, validate = validate)]
+#[repr(C)]
+struct Validate3(u8);
+
+//
+// Unsupported types and traits
+//
+
+#[derive(TryFromBytes, Immutable)]
+#[zerocopy(validate = validate)]
+#[repr(u8)]
+enum Validate4 {
+    A,
+}
+
+#[derive(FromBytes, Immutable)]
+#[zerocopy(validate = validate)]
+#[repr(C)]
+struct Validate5(u8);
@@ -0,0 +1 @@
+../ui-nightly/as_bytes_padded.rs
\ No newline at end of file
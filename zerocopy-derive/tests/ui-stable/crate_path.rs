@@ -0,0 +1 @@
+../ui-nightly/crate_path.rs
\ No newline at end of file
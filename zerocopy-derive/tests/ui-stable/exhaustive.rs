@@ -0,0 +1 @@
+../ui-nightly/exhaustive.rs
\ No newline at end of file
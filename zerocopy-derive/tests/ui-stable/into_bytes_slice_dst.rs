@@ -0,0 +1 @@
+../ui-nightly/into_bytes_slice_dst.rs
\ No newline at end of file
@@ -0,0 +1 @@
+../ui-nightly/validate.rs
\ No newline at end of file